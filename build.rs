@@ -1,4 +1,81 @@
-const COMMANDS: &[&str] = &["is_glass_supported", "set_liquid_glass_effect"];
+const COMMANDS: &[&str] = &[
+    "is_glass_supported",
+    "get_support_details",
+    "get_capabilities",
+    "get_private_api_report",
+    "get_macos_version",
+    "get_hig_preset",
+    "set_liquid_glass_effect",
+    "set_liquid_glass_effects",
+    "update_liquid_glass_effect",
+    "set_experimental_glass_property",
+    "apply_glass_layout",
+    "set_glass_view",
+    "add_glass_region",
+    "sync_glass_rect",
+    "remove_glass_view",
+    "claim_glass_handle",
+    "release_glass_handle",
+    "attach_region_to_channel",
+    "open_glass_rect_channel",
+    "close_glass_rect_channel",
+    "set_glass",
+    "get_glass",
+    "remove_glass",
+    "remove_all_effects",
+    "create_glass_group",
+    "remove_glass_group",
+    "join_theme_group",
+    "leave_theme_group",
+    "set_theme_group_effect",
+    "get_glass_audit_log",
+    "get_main_thread_stats",
+    "get_liquid_glass_effect",
+    "get_glass_state",
+    "get_recommended_foreground",
+    "get_glass_frame",
+    "get_safe_area_insets",
+    "get_system_colors",
+    "list_glass_views",
+    "list_effects",
+    "liquid_glass_doctor",
+    "dump_view_hierarchy",
+    "export_glass_state",
+    "import_glass_state",
+    "validate_glass_views",
+    "set_glass_window_level",
+    "make_rounded_glass_window",
+    "set_window_background",
+    "set_webview_draws_background",
+    "set_window_transparent",
+    "set_webview_transparent",
+    "attach_media_controls",
+    "reveal_media_controls",
+    "detach_media_controls",
+    "show_context_menu",
+    "dismiss_context_menu",
+    "create_launcher",
+    "toggle_launcher",
+    "dismiss_launcher",
+    "resize_launcher",
+    "suspend_all",
+    "resume_all",
+    "is_suspended",
+    "show_with_fade",
+    "hide_with_fade",
+    "present_sheet",
+    "dismiss_sheet",
+    "attach_pip",
+    "detach_pip",
+    "snap_pip",
+    "show_glass_toast",
+    "dismiss_glass_toast",
+    "attach_menubar_panel",
+    "toggle_menubar_panel",
+    "detach_menubar_panel",
+    "set_scroll_edge_effect",
+    "report_scroll_offset",
+];
 
 fn main() {
     tauri_plugin::Builder::new(COMMANDS)
@@ -2,6 +2,8 @@ import { useState, useEffect } from "react";
 
 import {
   setLiquidGlassEffect,
+  setScrollEdgeEffect,
+  reportScrollOffset,
   isGlassSupported,
   GlassMaterialVariant,
   type LiquidGlassConfig,
@@ -45,6 +47,7 @@ function App() {
     GlassMaterialVariant.Bubbles
   );
   const [collapsed, setCollapsed] = useState(true);
+  const [scrollEdge, setScrollEdge] = useState(false);
 
   // Apply glass effect whenever settings change
   const applyGlass = async (settings?: {
@@ -100,6 +103,17 @@ function App() {
     await applyGlass({ variant: newVariant });
   };
 
+  // Show the strip over the panel header, which must stay clickable
+  const updateScrollEdge = async (enabled: boolean) => {
+    setScrollEdge(enabled);
+    try {
+      await setScrollEdgeEffect(enabled ? {} : null);
+      if (enabled) await reportScrollOffset(100);
+    } catch (e) {
+      console.error(e);
+    }
+  };
+
   const reset = async () => {
     const defaults = {
       cornerRadius: 50,
@@ -195,6 +209,15 @@ function App() {
               )}
             </div>
 
+            <label className="checkbox">
+              <input
+                type="checkbox"
+                checked={scrollEdge}
+                onChange={(e) => updateScrollEdge(e.target.checked)}
+              />
+              <span>Scroll Edge</span>
+            </label>
+
             <button className="button" onClick={reset}>
               Reset
             </button>
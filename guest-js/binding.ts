@@ -0,0 +1,29 @@
+/**
+ * Options shared by the Svelte action and Vue directive
+ *
+ * @module
+ */
+
+import { parseGlassVariant } from "./index";
+import { GlassMaterialVariant, LiquidGlassConfig } from "./types";
+
+/**
+ * Glass configuration of a bound element
+ *
+ * `variant` also accepts a case-insensitive name, e.g. `"sidebar"`, and
+ * `frame` is replaced by the element's box.
+ */
+export type GlassBindingOptions = Omit<LiquidGlassConfig, "variant"> & {
+  variant?: GlassMaterialVariant | string;
+};
+
+/** `options` as a plugin config, with a named variant resolved */
+export function resolveGlassBindingConfig(
+  options: GlassBindingOptions | undefined | null
+): LiquidGlassConfig {
+  const { variant, ...config } = options ?? {};
+  const resolved: LiquidGlassConfig = config;
+  const parsed = variant === undefined ? undefined : parseGlassVariant(variant);
+  if (parsed !== undefined) resolved.variant = parsed;
+  return resolved;
+}
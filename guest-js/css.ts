@@ -0,0 +1,199 @@
+/**
+ * Capability-driven CSS for design systems
+ *
+ * Turns what the webview can render into one of three tiers (native glass,
+ * frosted CSS, solid) and publishes it as a class plus custom properties on
+ * the document root, so stylesheets encode the fallback chain once:
+ *
+ * ```css
+ * .panel {
+ *   background: var(--liquid-glass-background);
+ *   backdrop-filter: var(--liquid-glass-backdrop-filter);
+ * }
+ * ```
+ *
+ * @module
+ */
+
+import {
+  isSupportedSync,
+  getMacOSVersionSync,
+  isSuspended,
+  onEffectsSuspended,
+} from "./index";
+import {
+  GlassTier,
+  GlassCapabilities,
+  GlassCssRecommendation,
+} from "./types";
+
+export type { GlassTier, GlassCapabilities, GlassCssRecommendation };
+
+const TIERS: GlassTier[] = ["native", "frosted", "solid"];
+
+const REDUCED_TRANSPARENCY = "(prefers-reduced-transparency: reduce)";
+
+/**
+ * Custom property values per tier
+ *
+ * The frosted values match `cssGlassStyle()` for the regular variant.
+ */
+const TIER_PROPERTIES: Record<GlassTier, Record<string, string>> = {
+  native: {
+    "--liquid-glass-blur": "0px",
+    "--liquid-glass-saturation": "100%",
+    "--liquid-glass-tint-opacity": "0",
+    "--liquid-glass-backdrop-filter": "none",
+    "--liquid-glass-background": "transparent",
+  },
+  frosted: {
+    "--liquid-glass-blur": "20px",
+    "--liquid-glass-saturation": "180%",
+    "--liquid-glass-tint-opacity": "0.35",
+    "--liquid-glass-backdrop-filter": "blur(20px) saturate(180%)",
+    "--liquid-glass-background": "color-mix(in srgb, Canvas 35%, transparent)",
+  },
+  solid: {
+    "--liquid-glass-blur": "0px",
+    "--liquid-glass-saturation": "100%",
+    "--liquid-glass-tint-opacity": "1",
+    "--liquid-glass-backdrop-filter": "none",
+    "--liquid-glass-background": "Canvas",
+  },
+};
+
+/**
+ * Read the current webview's capabilities synchronously
+ *
+ * Native support comes from the info the plugin injects at startup; CSS
+ * support and the reduced transparency preference from the webview itself.
+ *
+ * @param suspended Whether effects are suspended, see `isSuspended()`
+ */
+export function detectGlassCapabilities(
+  suspended: boolean = false
+): GlassCapabilities {
+  const backdropFilter =
+    typeof CSS !== "undefined" &&
+    (CSS.supports("backdrop-filter", "blur(1px)") ||
+      CSS.supports("-webkit-backdrop-filter", "blur(1px)"));
+  const reducedTransparency =
+    typeof matchMedia !== "undefined" &&
+    matchMedia(REDUCED_TRANSPARENCY).matches;
+
+  const macos = getMacOSVersionSync() !== null;
+  return {
+    nativeGlass: macos && isSupportedSync(),
+    // Outside macOS, support means a Windows backdrop or compositor blur
+    nativeMaterial: macos || isSupportedSync(),
+    backdropFilter,
+    reducedTransparency,
+    suspended,
+  };
+}
+
+/**
+ * Pick the best tier for a set of capabilities
+ *
+ * Reduced transparency and suspended effects always get `solid`; otherwise a
+ * native material beats a CSS backdrop filter.
+ */
+export function glassTier(capabilities: GlassCapabilities): GlassTier {
+  if (capabilities.reducedTransparency || capabilities.suspended) {
+    return "solid";
+  }
+  if (capabilities.nativeMaterial) return "native";
+  return capabilities.backdropFilter ? "frosted" : "solid";
+}
+
+/**
+ * Get the class and custom properties recommended for a set of capabilities
+ *
+ * Properties:
+ * - `--liquid-glass-blur`: CSS blur radius (`0px` unless frosted)
+ * - `--liquid-glass-saturation`: saturation boost for the backdrop
+ * - `--liquid-glass-tint-opacity`: opacity of the surface fill, 0 to 1
+ * - `--liquid-glass-backdrop-filter`: ready-made `backdrop-filter` value
+ * - `--liquid-glass-background`: ready-made background color
+ *
+ * @param capabilities Capabilities to map (default: `detectGlassCapabilities()`)
+ *
+ * @example
+ * ```typescript
+ * const { tier } = recommendGlassCss();
+ * if (tier === "solid") showSolidIcons();
+ * ```
+ */
+export function recommendGlassCss(
+  capabilities: GlassCapabilities = detectGlassCapabilities()
+): GlassCssRecommendation {
+  const tier = glassTier(capabilities);
+  return {
+    tier,
+    className: `liquid-glass-${tier}`,
+    properties: { ...TIER_PROPERTIES[tier] },
+  };
+}
+
+/**
+ * Publish a recommendation on an element
+ *
+ * Sets the tier class (removing the other tiers' classes) and the custom
+ * properties, which inherit to all descendants.
+ *
+ * @param recommendation Recommendation to apply (default: `recommendGlassCss()`)
+ * @param root Element to style (default: the document element)
+ */
+export function applyGlassCss(
+  recommendation: GlassCssRecommendation = recommendGlassCss(),
+  root: HTMLElement = document.documentElement
+): void {
+  for (const tier of TIERS) {
+    root.classList.remove(`liquid-glass-${tier}`);
+  }
+  root.classList.add(recommendation.className);
+  for (const [property, value] of Object.entries(recommendation.properties)) {
+    root.style.setProperty(property, value);
+  }
+}
+
+/**
+ * Apply the recommended CSS and keep it current
+ *
+ * Re-applies when the reduced transparency preference changes and, where
+ * the plugin renders native material, when effects are suspended or resumed.
+ *
+ * @param root Element to style (default: the document element)
+ * @returns A function that stops observing; the styling is left in place
+ *
+ * @example
+ * ```typescript
+ * const stop = await observeGlassCss();
+ * ```
+ */
+export async function observeGlassCss(
+  root: HTMLElement = document.documentElement
+): Promise<() => Promise<void>> {
+  let suspended = false;
+  const update = () =>
+    applyGlassCss(recommendGlassCss(detectGlassCapabilities(suspended)), root);
+
+  let unlisten: (() => void) | null = null;
+  if (getMacOSVersionSync() !== null) {
+    suspended = await isSuspended().catch(() => false);
+    unlisten = await onEffectsSuspended((payload) => {
+      suspended = payload.suspended;
+      update();
+    });
+  }
+
+  const query =
+    typeof matchMedia !== "undefined" ? matchMedia(REDUCED_TRANSPARENCY) : null;
+  query?.addEventListener("change", update);
+  update();
+
+  return async () => {
+    query?.removeEventListener("change", update);
+    unlisten?.();
+  };
+}
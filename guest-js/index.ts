@@ -1,20 +1,421 @@
-import { invoke } from "@tauri-apps/api/core";
+import { Channel, invoke } from "@tauri-apps/api/core";
+import { emit, listen, UnlistenFn } from "@tauri-apps/api/event";
 import { getCurrentWindow } from "@tauri-apps/api/window";
-import { LiquidGlassConfig, GlassMaterialVariant } from "./types";
+import {
+  LiquidGlassConfig,
+  LiquidGlassPatch,
+  Units,
+  GlassFrame,
+  SizeCondition,
+  WindowShape,
+  GlassMask,
+  CornerRadii,
+  CornerCurve,
+  FallbackMaterial,
+  Anchor,
+  Length,
+  GlassPreset,
+  HigComponent,
+  GlassRegion,
+  GlassLayout,
+  LayoutDiff,
+  RemoveGlassOptions,
+  GlassTarget,
+  GlassRegionBinding,
+  OwnedGlassRegionBinding,
+  GlassRectChannel,
+  RegionMessage,
+  AnimationConfig,
+  TimingFunction,
+  WindowsBackdrop,
+  GlassPlacement,
+  GlassMaterialVariant,
+  SupportDetails,
+  NativeCapabilities,
+  PrivateApiReport,
+  SelectorProbe,
+  ExperimentalProperty,
+  MacOSVersion,
+  AuditEntry,
+  ConfigChange,
+  MainThreadStats,
+  TintLayer,
+  GlassRim,
+  GlassShadow,
+  BlendMode,
+  WindowLevel,
+  WindowLevelConfig,
+  MediaControlsConfig,
+  MediaControlsVisibility,
+  ContextMenuConfig,
+  ContextMenuDismissReason,
+  ContextMenuDismissed,
+  MenubarPanelConfig,
+  MenubarPanelVisibility,
+  LauncherConfig,
+  LauncherVisibility,
+  ToastContent,
+  ScreenCorner,
+  ToastOptions,
+  ToastDismissReason,
+  ToastDismissed,
+  PipConfig,
+  PipSnapped,
+  SheetConfig,
+  SheetVisibility,
+  ScrollEdgeConfig,
+  GlassBackendKind,
+  GlassViewInfo,
+  GlassStateDocument,
+  WindowGlassState,
+  GlassViewState,
+  GlassStateImportOptions,
+  Appearance,
+  ForegroundRecommendation,
+  ForegroundChanged,
+  AppearanceChanged,
+  ViewRect,
+  GlassViewFrame,
+  SafeAreaInsets,
+  FrameChanged,
+  ObservedProperty,
+  GlassExternallyModified,
+  OrphanReason,
+  OrphanedView,
+  GlassDoctorReport,
+  LayerInfo,
+  GlassHealthReport,
+  TitlebarStyle,
+  ViewNode,
+  KeyStateChanged,
+  FallbackReason,
+  GlassFallback,
+  GlassApplied,
+  GlassRemoved,
+  SystemColors,
+  SystemColorsChanged,
+  EffectsSuspended,
+} from "./types";
 
-export { LiquidGlassConfig, GlassMaterialVariant };
+export {
+  LiquidGlassConfig,
+  LiquidGlassPatch,
+  Units,
+  GlassFrame,
+  SizeCondition,
+  WindowShape,
+  GlassMask,
+  CornerRadii,
+  CornerCurve,
+  FallbackMaterial,
+  Anchor,
+  Length,
+  GlassPreset,
+  HigComponent,
+  GlassRegion,
+  GlassLayout,
+  LayoutDiff,
+  RemoveGlassOptions,
+  GlassTarget,
+  GlassRegionBinding,
+  OwnedGlassRegionBinding,
+  GlassRectChannel,
+  RegionMessage,
+  AnimationConfig,
+  TimingFunction,
+  WindowsBackdrop,
+  GlassPlacement,
+  GlassMaterialVariant,
+  SupportDetails,
+  NativeCapabilities,
+  PrivateApiReport,
+  SelectorProbe,
+  ExperimentalProperty,
+  MacOSVersion,
+  AuditEntry,
+  ConfigChange,
+  MainThreadStats,
+  TintLayer,
+  GlassRim,
+  GlassShadow,
+  BlendMode,
+  WindowLevel,
+  WindowLevelConfig,
+  MediaControlsConfig,
+  MediaControlsVisibility,
+  ContextMenuConfig,
+  ContextMenuDismissReason,
+  ContextMenuDismissed,
+  MenubarPanelConfig,
+  MenubarPanelVisibility,
+  LauncherConfig,
+  LauncherVisibility,
+  ToastContent,
+  ScreenCorner,
+  ToastOptions,
+  ToastDismissReason,
+  ToastDismissed,
+  PipConfig,
+  PipSnapped,
+  SheetConfig,
+  SheetVisibility,
+  ScrollEdgeConfig,
+  GlassBackendKind,
+  GlassViewInfo,
+  GlassStateDocument,
+  WindowGlassState,
+  GlassViewState,
+  GlassStateImportOptions,
+  Appearance,
+  ForegroundRecommendation,
+  ForegroundChanged,
+  AppearanceChanged,
+  ViewRect,
+  GlassViewFrame,
+  SafeAreaInsets,
+  FrameChanged,
+  ObservedProperty,
+  GlassExternallyModified,
+  OrphanReason,
+  OrphanedView,
+  GlassDoctorReport,
+  LayerInfo,
+  GlassHealthReport,
+  TitlebarStyle,
+  ViewNode,
+  KeyStateChanged,
+  FallbackReason,
+  GlassFallback,
+  GlassApplied,
+  GlassRemoved,
+  SystemColors,
+  SystemColorsChanged,
+  EffectsSuspended,
+};
 
 const PLUGIN_NAME = "liquid-glass";
 
+/** State injected into every webview by the plugin at creation */
+interface InjectedState {
+  supported: boolean;
+  macosVersion: MacOSVersion | null;
+  /** This window's config, fetched from the plugin once on first call */
+  initialConfig(): LiquidGlassConfig | null;
+}
+
+declare global {
+  interface Window {
+    __LIQUID_GLASS__?: InjectedState;
+  }
+}
+
 /**
  * Check if liquid glass effect is supported on the current platform
  *
- * @returns true if running on macOS 26+ with NSGlassEffectView available
+ * @returns true if running on macOS 26+ with NSGlassEffectView available, on
+ * Windows 11 22H2+ where `setLiquidGlassEffect` applies a system backdrop, or
+ * on Linux sessions whose compositor blurs behind windows
  */
 export async function isGlassSupported(): Promise<boolean> {
   return invoke<boolean>(`plugin:${PLUGIN_NAME}|is_glass_supported`);
 }
 
+/**
+ * Synchronously check if liquid glass effect is supported
+ *
+ * Reads the support info the plugin injects into every webview at startup, so it
+ * can be used during the first render without waiting on an IPC call.
+ *
+ * @returns true if running on macOS 26+ with NSGlassEffectView available, on
+ * Windows 11 22H2+ with system backdrops or on Linux with compositor blur,
+ * false otherwise (including when the plugin is not registered)
+ *
+ * @example
+ * ```typescript
+ * const className = isSupportedSync() ? "glass" : "solid";
+ * ```
+ */
+export function isSupportedSync(): boolean {
+  return window.__LIQUID_GLASS__?.supported ?? false;
+}
+
+/**
+ * Synchronously get the running macOS version
+ *
+ * Reads the info injected into every webview at creation.
+ *
+ * @returns The version, or `null` on other platforms (or when the plugin is
+ * not registered)
+ */
+export function getMacOSVersionSync(): MacOSVersion | null {
+  return window.__LIQUID_GLASS__?.macosVersion ?? null;
+}
+
+/**
+ * Synchronously get the glass config the plugin applies to the current window
+ * at creation (registered with `Builder::window_effect` on the Rust side or
+ * declared in `tauri.conf.json`)
+ *
+ * Lets frontends pick matching styles on first paint, before any IPC call.
+ * The plugin hands each webview only its own window's config when the page
+ * starts loading, so it is available to module and deferred scripts; inline
+ * scripts at the top of the document may run too early and see `null`.
+ * Changes made later at runtime are not reflected here; use
+ * `getGlassState()`.
+ *
+ * @returns The config, or `null` if none was registered for this window
+ *
+ * @example
+ * ```typescript
+ * const initial = getInitialConfigSync();
+ * document.documentElement.classList.toggle("glass", initial?.enabled ?? false);
+ * ```
+ */
+export function getInitialConfigSync(): LiquidGlassConfig | null {
+  return window.__LIQUID_GLASS__?.initialConfig() ?? null;
+}
+
+/**
+ * Get detailed support information, including which variants are honored
+ *
+ * @returns Support details for the running OS build
+ *
+ * @example
+ * ```typescript
+ * const { supportedVariants } = await getSupportDetails();
+ * const canUseSidebar = supportedVariants.includes(GlassMaterialVariant.Sidebar);
+ * ```
+ */
+export async function getSupportDetails(): Promise<SupportDetails> {
+  return invoke<SupportDetails>(`plugin:${PLUGIN_NAME}|get_support_details`);
+}
+
+/**
+ * Get which native glass features are usable at runtime
+ *
+ * Finer-grained than `getSupportDetails()`: reports the backend new views
+ * get and each private setter separately, so UIs can degrade per feature.
+ *
+ * @example
+ * ```typescript
+ * const { scrim, tintColor } = await getCapabilities();
+ * scrimToggle.hidden = !scrim;
+ * ```
+ */
+export async function getCapabilities(): Promise<NativeCapabilities> {
+  return invoke<NativeCapabilities>(`plugin:${PLUGIN_NAME}|get_capabilities`);
+}
+
+/**
+ * Get which NSGlassEffectView selectors respond on the running OS build
+ *
+ * Variant, scrim, subdued and interactive use private selectors that change
+ * between betas; a property without a `setter` is ignored when set.
+ *
+ * @example
+ * ```typescript
+ * const { properties } = await getPrivateApiReport();
+ * const missing = properties.filter((p) => p.setter === null).map((p) => p.key);
+ * ```
+ */
+export async function getPrivateApiReport(): Promise<PrivateApiReport> {
+  return invoke<PrivateApiReport>(`plugin:${PLUGIN_NAME}|get_private_api_report`);
+}
+
+/**
+ * Get the running macOS version
+ *
+ * @returns The version, or `null` on other platforms
+ *
+ * @example
+ * ```typescript
+ * const version = await getMacOSVersion();
+ * if (version && !version.isTahoeOrLater) {
+ *   hint.textContent = `Liquid Glass requires macOS 26 (running ${version.major}.${version.minor})`;
+ * }
+ * ```
+ */
+export async function getMacOSVersion(): Promise<MacOSVersion | null> {
+  return invoke<MacOSVersion | null>(`plugin:${PLUGIN_NAME}|get_macos_version`);
+}
+
+/**
+ * Get the configuration replicating an Apple component
+ *
+ * The set covers variant, frame, radius, tint, rim and shadow, validated
+ * against the running macOS build: the variant falls back when the build
+ * doesn't honor it, and pre-Tahoe systems get the flatter classic look.
+ *
+ * @param component Component to replicate
+ * @returns The configuration, ready to pass to `setGlassView`
+ *
+ * @example
+ * ```typescript
+ * await setGlassView("sidebar", await getHigPreset("finderSidebar"));
+ * ```
+ */
+export async function getHigPreset(
+  component: HigComponent
+): Promise<LiquidGlassConfig> {
+  return invoke<LiquidGlassConfig>(`plugin:${PLUGIN_NAME}|get_hig_preset`, {
+    component,
+  });
+}
+
+/**
+ * Thrown by the `experimental` API when the running OS lacks a property
+ */
+export class UnsupportedCapability extends Error {
+  constructor(public readonly capability: ExperimentalProperty) {
+    super(`Unsupported capability: ${capability}`);
+    this.name = "UnsupportedCapability";
+  }
+}
+
+async function setExperimentalProperty(
+  property: ExperimentalProperty,
+  enabled: boolean
+): Promise<void> {
+  const { experimentalProperties } = await getSupportDetails();
+  if (!experimentalProperties.includes(property)) {
+    throw new UnsupportedCapability(property);
+  }
+
+  await invoke(`plugin:${PLUGIN_NAME}|set_experimental_glass_property`, {
+    property,
+    enabled,
+  });
+}
+
+/**
+ * Experimental NSGlassEffectView properties of the current window's glass
+ *
+ * Each setter consults `getSupportDetails()` first and rejects with
+ * `UnsupportedCapability` when the running OS lacks the property, instead of
+ * silently doing nothing. The value is written into the glass config, so
+ * later updates, re-applies and `exportGlassState()` keep it.
+ *
+ * @example
+ * ```typescript
+ * try {
+ *   await experimental.setScrim(true);
+ * } catch (e) {
+ *   if (e instanceof UnsupportedCapability) {
+ *     panel.classList.add("dimmed");
+ *   }
+ * }
+ * ```
+ */
+export const experimental = {
+  /** Toggle the darkening scrim behind the glass */
+  setScrim: (enabled: boolean) => setExperimentalProperty("scrim", enabled),
+  /** Toggle the subdued glass appearance */
+  setSubdued: (enabled: boolean) => setExperimentalProperty("subdued", enabled),
+  /** Toggle glass that reacts to pointer input */
+  setInteractive: (enabled: boolean) =>
+    setExperimentalProperty("interactive", enabled),
+};
+
 /**
  * Set liquid glass effect on the current window
  *
@@ -44,3 +445,2539 @@ export async function setLiquidGlassEffect(
     config,
   });
 }
+
+/**
+ * Change some settings of the current window's glass, keeping the rest
+ *
+ * Unlike `setLiquidGlassEffect()`, which replaces the whole config, fields
+ * left out of the patch keep their current values. `null` clears optional
+ * fields.
+ *
+ * @example
+ * ```typescript
+ * await updateLiquidGlassEffect({ tintColor: "#00000030" }); // variant etc. kept
+ * await updateLiquidGlassEffect({ tintColor: null }); // remove the tint
+ * ```
+ */
+export async function updateLiquidGlassEffect(patch: LiquidGlassPatch): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|update_liquid_glass_effect`, { patch });
+}
+
+/**
+ * Set liquid glass effects on several windows at once
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * Every window is resolved before any changes, and on macOS all native
+ * updates run in one main-thread dispatch, so theming many windows doesn't
+ * stagger. A failing window doesn't stop the rest; the first error rejects.
+ *
+ * @param effects `[label, config]` pairs
+ *
+ * @example
+ * ```typescript
+ * const tint = { tintColor: "#ff000020" };
+ * await setLiquidGlassEffects([
+ *   ["main", tint],
+ *   ["settings", tint],
+ * ]);
+ * ```
+ */
+export async function setLiquidGlassEffects(
+  effects: Array<[string, LiquidGlassConfig]>
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_liquid_glass_effects`, { effects });
+}
+
+/**
+ * Apply a declarative layout of named glass regions to the current window
+ *
+ * The layout is validated and diffed against the current regions: new regions
+ * are created, changed ones updated and missing ones removed. Pass
+ * `{ regions: [] }` to remove all regions.
+ *
+ * @returns Which region ids were added, updated, removed or left unchanged
+ *
+ * @example
+ * ```typescript
+ * await applyGlassLayout({
+ *   regions: [
+ *     { id: "sidebar", preset: "sidebar", frame: { top: 0, bottom: 0, left: 0, width: 240 } },
+ *     { id: "toolbar", frame: { top: 0, left: 240, right: 0, height: 52 } },
+ *     { id: "inspector", preset: "inspector", frame: { top: 52, bottom: 0, right: 0, width: 280 } },
+ *   ],
+ * });
+ * ```
+ */
+export async function applyGlassLayout(layout: GlassLayout): Promise<LayoutDiff> {
+  return invoke<LayoutDiff>(`plugin:${PLUGIN_NAME}|apply_glass_layout`, {
+    layout,
+  });
+}
+
+/**
+ * Create or update a glass view in the current window with your own id
+ *
+ * Named views are layout regions: a later `applyGlassLayout()` that doesn't
+ * list the id removes the view. New views stack above existing regions.
+ *
+ * @param id Caller-chosen id, e.g. `"sidebar"` or `"toolbar"`
+ * @param config Glass configuration, usually with a `frame`
+ *
+ * @example
+ * ```typescript
+ * await setGlassView("toolbar", { frame: { height: 52 } });
+ * await setGlassView("sidebar", { frame: { width: 240 }, variant: GlassMaterialVariant.Sidebar });
+ * ```
+ */
+export async function setGlassView(
+  id: string,
+  config: LiquidGlassConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_glass_view`, { id, config });
+}
+
+/**
+ * Add a glass view at a fixed rectangle of the current window
+ *
+ * The rectangle is in the config's units, measured from the top-left corner
+ * of the window's content, and replaces any `frame` in the config. Update the
+ * view by passing the returned id to `setGlassView`.
+ *
+ * @returns The generated id of the new view
+ *
+ * @example
+ * ```typescript
+ * const id = await addGlassRegion(
+ *   { x: 24, y: 80, width: 320, height: 200 },
+ *   { cornerRadius: 20 }
+ * );
+ * await removeGlassView(id);
+ * ```
+ */
+export async function addGlassRegion(
+  rect: ViewRect,
+  config: LiquidGlassConfig = {}
+): Promise<string> {
+  return invoke<string>(`plugin:${PLUGIN_NAME}|add_glass_region`, {
+    rect,
+    config,
+  });
+}
+
+/**
+ * Remove a named glass view from the current window
+ *
+ * @returns Whether a view with this id existed
+ */
+export async function removeGlassView(id: string): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|remove_glass_view`, { id });
+}
+
+/**
+ * Claim a handle for a component that owns a glass view in the current window
+ *
+ * Handles are unique for the life of the app, so a remounted component or a
+ * reloaded page never adopts a view left behind by an earlier instance. Use
+ * the handle as the view id, e.g. with {@link bindGlassRegion}, and release
+ * it on unmount. Views of handles still claimed when the page reloads are
+ * removed by the plugin.
+ */
+export async function claimGlassHandle(): Promise<string> {
+  return invoke<string>(`plugin:${PLUGIN_NAME}|claim_glass_handle`);
+}
+
+/**
+ * Release a component handle and remove its glass view
+ *
+ * @returns Whether the current window had claimed the handle
+ */
+export async function releaseGlassHandle(handle: string): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|release_glass_handle`, {
+    handle,
+  });
+}
+
+/**
+ * Set glass on a window, addressed by window label, view id or both
+ *
+ * With an `id`, creates or updates that named view like `setGlassView`;
+ * without, sets the window's full-window glass like `setLiquidGlassEffect`.
+ * Only the app's local pages may address another window by `label`.
+ *
+ * @param target Window and view to set (default: the current window's
+ * full-window glass)
+ * @param config Glass configuration
+ *
+ * @example
+ * ```typescript
+ * await setGlass({ id: "sidebar" }, { variant: GlassMaterialVariant.Sidebar });
+ * await setGlass({ label: "inspector" }, { cornerRadius: 16 });
+ * ```
+ */
+export async function setGlass(
+  target: GlassTarget = {},
+  config: LiquidGlassConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_glass`, {
+    label: target.label ?? null,
+    id: target.id ?? null,
+    config,
+  });
+}
+
+/**
+ * Get one glass view of a window, addressed like `setGlass`
+ *
+ * @returns The view, or `null` if the window has none by that address
+ */
+export async function getGlass(
+  target: GlassTarget = {}
+): Promise<GlassViewInfo | null> {
+  return invoke<GlassViewInfo | null>(`plugin:${PLUGIN_NAME}|get_glass`, {
+    label: target.label ?? null,
+    id: target.id ?? null,
+  });
+}
+
+/**
+ * Remove glass from a window, addressed by window label, view id or both
+ *
+ * With an `id`, removes that named view and rejects with a
+ * `Glass view not found` error if there is none. Without, removes the
+ * window's full-window glass and all of its named views. Only the app's
+ * local pages may address another window by `label`; remote pages allowed
+ * by the scope are limited to their own window.
+ *
+ * @param options Window and view to target (default: all glass of the
+ * current window)
+ * @returns The number of views removed
+ *
+ * @example
+ * ```typescript
+ * await removeGlass({ id: "sidebar" });
+ * await removeGlass({ label: "inspector" });
+ * ```
+ */
+export async function removeGlass(
+  options: RemoveGlassOptions = {}
+): Promise<number> {
+  return invoke<number>(`plugin:${PLUGIN_NAME}|remove_glass`, {
+    label: options.label ?? null,
+    id: options.id ?? null,
+  });
+}
+
+/**
+ * Remove all glass from every window
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * Useful when tearing down a theme or honoring a "disable transparency"
+ * setting. Removes nothing off macOS.
+ *
+ * @returns Number of glass views removed
+ */
+export async function removeAllEffects(): Promise<number> {
+  return invoke<number>(`plugin:${PLUGIN_NAME}|remove_all_effects`);
+}
+
+/**
+ * Group named glass views so nearby shapes merge and morph into each other
+ *
+ * The views are hosted in one `NSGlassEffectContainerView`; shapes closer
+ * than `spacing` points render as one piece of glass. Calling it again for
+ * the same group updates its members and spacing. Views keep their ids,
+ * configs and frames. Rejects with a `Glass view not found` error if a view
+ * doesn't exist. Fallback glass on older macOS is grouped but doesn't merge.
+ *
+ * @param group Group id, e.g. `"controls"`
+ * @param regions Ids of the named views to group, see {@link setGlassView}
+ * @param spacing Distance in points within which shapes merge (default: 0)
+ *
+ * @example
+ * ```typescript
+ * await setGlassView("back", { frame: { left: 12, top: 12, width: 32, height: 32 }, cornerRadius: 16 });
+ * await setGlassView("forward", { frame: { left: 48, top: 12, width: 32, height: 32 }, cornerRadius: 16 });
+ * await createGlassGroup("controls", ["back", "forward"], 8);
+ * ```
+ */
+export async function createGlassGroup(
+  group: string,
+  regions: string[],
+  spacing: number = 0
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|create_glass_group`, {
+    group,
+    regions,
+    spacing,
+  });
+}
+
+/**
+ * Dissolve a glass group; its views stay, rendered separately again
+ *
+ * @returns Whether a group with this id existed
+ */
+export async function removeGlassGroup(group: string): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|remove_glass_group`, {
+    group,
+  });
+}
+
+/**
+ * Whether glass is rendered with CSS instead of native views
+ *
+ * True when the webview doesn't run on macOS (or the plugin isn't
+ * registered), where there is no native material to draw. Older macOS
+ * releases still get the native NSVisualEffectView fallback.
+ */
+export function usesCssGlassPolyfill(): boolean {
+  return getMacOSVersionSync() === null;
+}
+
+/** Backdrop blur in pixels per variant; variants not listed use 24 */
+const CSS_BLUR: Partial<Record<GlassMaterialVariant, number>> = {
+  [GlassMaterialVariant.Regular]: 20,
+  [GlassMaterialVariant.Clear]: 6,
+  [GlassMaterialVariant.Sidebar]: 30,
+  [GlassMaterialVariant.AbuttedSidebar]: 30,
+  [GlassMaterialVariant.Inspector]: 30,
+  [GlassMaterialVariant.Dock]: 28,
+};
+
+/** CSS easing per timing function */
+const CSS_TIMING: Record<TimingFunction, string> = {
+  linear: "linear",
+  easeIn: "ease-in",
+  easeOut: "ease-out",
+  easeInOut: "ease-in-out",
+  default: "ease",
+};
+
+/**
+ * Approximate a glass config with CSS
+ *
+ * Maps the variant to a `backdrop-filter` blur, the tint and tint layers to
+ * backgrounds, and the corner radius, rim and shadow to their CSS
+ * counterparts, and the mask to a border radius or `clip-path`. Opaque configs get a solid `opaqueColor` fill, and an
+ * `animation` becomes a `transition`. The backdrop filter can only blur the
+ * page behind the element, not the desktop.
+ *
+ * @param config Glass configuration to translate
+ * @returns CSS property values keyed by (kebab-case) property name
+ */
+export function cssGlassStyle(
+  config: LiquidGlassConfig
+): Record<string, string> {
+  if (config.enabled === false) return {};
+
+  const style: Record<string, string> = {};
+  if (config.cornerRadius !== undefined) {
+    style["border-radius"] = `${config.cornerRadius}px`;
+  }
+  if (config.cornerRadii) {
+    const {
+      topLeft = 0,
+      topRight = 0,
+      bottomRight = 0,
+      bottomLeft = 0,
+    } = config.cornerRadii;
+    style["border-radius"] =
+      `${topLeft}px ${topRight}px ${bottomRight}px ${bottomLeft}px`;
+  }
+  const mask = config.mask;
+  if (mask?.type === "roundedRect") {
+    style["border-radius"] = `${mask.radius}px`;
+  } else if (mask?.type === "capsule") {
+    style["border-radius"] = "9999px";
+  } else if (mask?.type === "circle") {
+    style["clip-path"] = "circle(closest-side)";
+  } else if (mask?.type === "path" && !mask.viewBox) {
+    // CSS paths can't be scaled to the element like a viewBox
+    style["clip-path"] = `path(${JSON.stringify(mask.d)})`;
+  }
+  if (config.rim) {
+    const { color = "#FFFFFF33", width = 1 } = config.rim;
+    style["border"] = `${width}px solid ${color}`;
+  }
+  if (config.shadow) {
+    const {
+      color = "#00000040",
+      radius = 12,
+      offsetX = 0,
+      offsetY = 4,
+    } = config.shadow;
+    style["box-shadow"] = `${offsetX}px ${offsetY}px ${radius}px ${color}`;
+  }
+  if (config.animation) {
+    const { durationMs = 250, timing = "easeInOut" } = config.animation;
+    style["transition"] = `all ${durationMs}ms ${CSS_TIMING[timing]}`;
+  }
+
+  if (config.opaque) {
+    style["background-color"] = config.opaqueColor ?? "Canvas";
+    return style;
+  }
+
+  const variant = config.variant ?? GlassMaterialVariant.Regular;
+  const saturation = variant === GlassMaterialVariant.Clear ? 120 : 180;
+  const filter = `blur(${CSS_BLUR[variant] ?? 24}px) saturate(${saturation}%)`;
+  style["backdrop-filter"] = filter;
+  style["-webkit-backdrop-filter"] = filter;
+  style["background-color"] =
+    config.tintColor ?? "color-mix(in srgb, Canvas 35%, transparent)";
+
+  const layers = config.tintLayers ?? [];
+  if (layers.length > 0) {
+    // Tint layers stack above the tint, so the first layer is drawn last
+    const reversed = [...layers].reverse();
+    style["background-image"] = reversed
+      .map(({ color, opacity = 1 }) => {
+        const layer = `color-mix(in srgb, ${color} ${opacity * 100}%, transparent)`;
+        return `linear-gradient(${layer}, ${layer})`;
+      })
+      .join(", ");
+    style["background-blend-mode"] = reversed
+      .map(({ blendMode = "normal" }) =>
+        blendMode.replace(/[A-Z]/g, (c) => `-${c.toLowerCase()}`)
+      )
+      .join(", ");
+  }
+
+  return style;
+}
+
+/** Properties set on each element by `applyCssGlass` */
+const cssGlassProperties = new WeakMap<HTMLElement, string[]>();
+
+/**
+ * Style an element with the CSS approximation of a glass config
+ *
+ * Properties set by an earlier call are cleared first, so the element can be
+ * restyled as its config changes. Pass `null` to remove the styling.
+ *
+ * @param element Element to style inline
+ * @param config Glass configuration, see {@link cssGlassStyle}
+ *
+ * @example
+ * ```typescript
+ * if (usesCssGlassPolyfill()) {
+ *   applyCssGlass(toolbar, { variant: GlassMaterialVariant.Regular, cornerRadius: 12 });
+ * }
+ * ```
+ */
+export function applyCssGlass(
+  element: HTMLElement,
+  config: LiquidGlassConfig | null
+): void {
+  for (const property of cssGlassProperties.get(element) ?? []) {
+    element.style.removeProperty(property);
+  }
+
+  const style = config ? cssGlassStyle(config) : {};
+  for (const [property, value] of Object.entries(style)) {
+    element.style.setProperty(property, value);
+  }
+  cssGlassProperties.set(element, Object.keys(style));
+}
+
+/** Counter for generated ids of element-bound glass views */
+let regionElementCount = 0;
+
+function generateRegionId(): string {
+  return `liquid-glass-region-${regionElementCount++}`;
+}
+
+/** Visibility ratios at which element-bound views re-sync */
+const INTERSECTION_THRESHOLDS = [
+  0, 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1,
+];
+
+/**
+ * Keeps a named glass view on an element's bounding box
+ *
+ * Syncs at most once per animation frame. The full config is only sent when
+ * it changes; moves and resizes stream just the rect through `syncGlassRect`.
+ * With a channel, the config is sent through `attachRegionToChannel` and the
+ * region listens for native frame changes and removals on it.
+ */
+class ElementRegion {
+  /** Last config sent, without its frame, to skip unchanged syncs */
+  private lastConfig = "";
+  /** Last rect sent */
+  private lastRect = "";
+  private stopped = false;
+  private frameRequest: number | null = null;
+  private resizeObserver: ResizeObserver | null = null;
+  private intersectionObserver: IntersectionObserver | null = null;
+  /** Serializes invokes so a removal never overtakes an update */
+  private queue: Promise<unknown> = Promise.resolve();
+  private readonly schedule = () => this.scheduleSync();
+
+  constructor(
+    private readonly element: HTMLElement,
+    public id: string,
+    private readonly resolveConfig: () => LiquidGlassConfig,
+    private readonly channel: Channel<RegionMessage> | null = null
+  ) {
+    if (channel) channel.onmessage = (message) => this.receive(message);
+  }
+
+  start() {
+    this.resizeObserver = new ResizeObserver(this.schedule);
+    this.resizeObserver.observe(this.element);
+    // Catches the element moving in or out of view without being resized or
+    // scrolled, e.g. through transforms or layout shifts
+    this.intersectionObserver = new IntersectionObserver(this.schedule, {
+      threshold: INTERSECTION_THRESHOLDS,
+    });
+    this.intersectionObserver.observe(this.element);
+    window.addEventListener("resize", this.schedule);
+    // Capture scrolls of any container the element may sit in
+    document.addEventListener("scroll", this.schedule, true);
+    this.scheduleSync();
+  }
+
+  stop() {
+    this.stopped = true;
+    this.resizeObserver?.disconnect();
+    this.resizeObserver = null;
+    this.intersectionObserver?.disconnect();
+    this.intersectionObserver = null;
+    window.removeEventListener("resize", this.schedule);
+    document.removeEventListener("scroll", this.schedule, true);
+    if (this.frameRequest !== null) {
+      cancelAnimationFrame(this.frameRequest);
+      this.frameRequest = null;
+    }
+    this.remove();
+  }
+
+  /** Resend the config at the next sync, e.g. after it changed */
+  invalidate() {
+    this.lastConfig = "";
+    this.scheduleSync();
+  }
+
+  /** Move the glass to another id */
+  rename(id: string) {
+    this.remove();
+    this.id = id;
+    this.scheduleSync();
+  }
+
+  private receive(message: RegionMessage) {
+    if (this.stopped) return;
+    if (message.type === "removed") {
+      // Removed elsewhere, e.g. by `removeGlass`; the next sync recreates it
+      this.lastConfig = "";
+      return;
+    }
+    const { css, hidden } = message.frame;
+    const sent: ViewRect | null = this.lastRect
+      ? JSON.parse(this.lastRect)
+      : null;
+    const drifted =
+      !sent ||
+      Math.abs(sent.x - css.x) > 0.5 ||
+      Math.abs(sent.y - css.y) > 0.5 ||
+      Math.abs(sent.width - css.width) > 0.5 ||
+      Math.abs(sent.height - css.height) > 0.5;
+    // The view was moved natively, e.g. by an anchored frame on window
+    // resize; put it back on the element
+    if (!hidden && drifted) {
+      this.lastRect = "";
+      this.scheduleSync();
+    }
+  }
+
+  private scheduleSync() {
+    if (this.frameRequest !== null || !this.element.isConnected) return;
+    this.frameRequest = requestAnimationFrame(() => {
+      this.frameRequest = null;
+      this.sync();
+    });
+  }
+
+  private sync() {
+    const id = this.id;
+    const config: LiquidGlassConfig = { ...this.resolveConfig() };
+    delete config.frame;
+    const configKey = JSON.stringify(config);
+
+    if (usesCssGlassPolyfill()) {
+      if (configKey === this.lastConfig) return;
+      this.lastConfig = configKey;
+      applyCssGlass(this.element, config);
+      return;
+    }
+
+    const { left, top, width, height } = this.element.getBoundingClientRect();
+    const rect: ViewRect = { x: left, y: top, width, height };
+    const rectKey = JSON.stringify(rect);
+    if (configKey !== this.lastConfig) {
+      config.frame = { left, top, width, height };
+      const channel = this.channel;
+      this.enqueue(() =>
+        channel
+          ? attachRegionToChannel(id, config, channel)
+          : setGlassView(id, config)
+      );
+    } else if (rectKey !== this.lastRect) {
+      this.enqueue(() => syncGlassRect(id, rect));
+    } else {
+      return;
+    }
+    this.lastConfig = configKey;
+    this.lastRect = rectKey;
+  }
+
+  private remove() {
+    this.lastConfig = "";
+    this.lastRect = "";
+    if (usesCssGlassPolyfill()) {
+      applyCssGlass(this.element, null);
+    } else {
+      const id = this.id;
+      this.enqueue(() => removeGlassView(id));
+    }
+  }
+
+  private enqueue(task: () => Promise<unknown>) {
+    this.queue = this.queue.then(task).catch(() => {
+      // Send the full config next time, recreating the view if it is gone
+      this.lastConfig = "";
+    });
+  }
+}
+
+/**
+ * Move a named glass view of the current window to a new rectangle
+ *
+ * Only the frame changes; the rest of the view's config is kept. Cheap
+ * enough to call on every animation frame, and subject to the main-thread
+ * budget like `setGlassView`. Rejects with a `Glass view not found` error if
+ * the view doesn't exist.
+ *
+ * @param id View id, see {@link setGlassView}
+ * @param rect Rectangle in points (CSS pixels at 100% zoom), relative to the
+ * window's content, e.g. from `getBoundingClientRect()`
+ */
+export async function syncGlassRect(
+  id: string,
+  rect: ViewRect
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|sync_glass_rect`, { id, rect });
+}
+
+/**
+ * Create or update a named glass view and stream its state over a channel
+ *
+ * Applies the config like {@link setGlassView}, then sends the view's frame
+ * right away and whenever it changes natively, and a final `removed`
+ * message when the view is removed by any means. Attaching again replaces
+ * the view's channel; the same channel can be passed each time. Rects still
+ * go to the plugin with {@link syncGlassRect}.
+ *
+ * @param id View id, see {@link setGlassView}
+ * @param config Glass configuration, usually with a `frame`
+ * @param channel Channel receiving the view's messages
+ *
+ * @example
+ * ```typescript
+ * import { Channel } from "@tauri-apps/api/core";
+ *
+ * const channel = new Channel<RegionMessage>();
+ * channel.onmessage = (message) => {
+ *   if (message.type === "frame") console.log(message.frame.css);
+ * };
+ * await attachRegionToChannel("toolbar", { frame: { height: 52 } }, channel);
+ * ```
+ */
+export async function attachRegionToChannel(
+  id: string,
+  config: LiquidGlassConfig,
+  channel: Channel<RegionMessage>
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|attach_region_to_channel`, {
+    id,
+    config,
+    channel,
+  });
+}
+
+/**
+ * Back an element's bounding box with a native glass view
+ *
+ * The view follows the element through resizes, scrolling, layout shifts and
+ * visibility changes, at most once per animation frame, streaming only its
+ * rect with {@link syncGlassRect} unless the config changed. Where
+ * {@link usesCssGlassPolyfill} is true, the element is styled with
+ * {@link applyCssGlass} instead.
+ *
+ * @param element Element to track; it should already be in the document
+ * @param config Glass configuration; its `frame` is replaced by the element's box
+ * @param id View id (default: a generated `liquid-glass-region-N`)
+ * @returns A binding to update the config or to unbind, which removes the view
+ *
+ * @example
+ * ```typescript
+ * const toolbar = bindGlassRegion(document.querySelector("header")!, {
+ *   variant: GlassMaterialVariant.Regular,
+ * });
+ * toolbar.setConfig({ variant: GlassMaterialVariant.Clear });
+ * toolbar.unbind();
+ * ```
+ */
+export function bindGlassRegion(
+  element: HTMLElement,
+  config: LiquidGlassConfig = {},
+  id: string = generateRegionId()
+): GlassRegionBinding {
+  let current = config;
+  const region = new ElementRegion(element, id, () => current);
+  region.start();
+  return {
+    id,
+    setConfig(next: LiquidGlassConfig) {
+      current = next;
+      region.invalidate();
+    },
+    unbind() {
+      region.stop();
+    },
+  };
+}
+
+/**
+ * Open a rect stream for a named glass view of the current window
+ *
+ * For glass that follows scrolling at 60-120 Hz, where awaiting
+ * {@link syncGlassRect} every frame is too slow. `send()` emits the rect
+ * without waiting; the plugin keeps only the latest rect per view and moves
+ * all streamed views in one main-thread pass per frame, without implicit
+ * animations. The stream closes with `close()`, when the view is removed,
+ * or when the page reloads. Rejects with a `Glass view not found` error if
+ * the view doesn't exist. Emitting needs the `core:event:default`
+ * permission, which `core:default` includes.
+ *
+ * @param regionId View id, see {@link setGlassView}
+ *
+ * @example
+ * ```typescript
+ * const stream = await openGlassRectChannel("header");
+ * scroller.addEventListener("scroll", () => {
+ *   const { left, top, width, height } = header.getBoundingClientRect();
+ *   stream.send({ x: left, y: top, width, height });
+ * });
+ * await stream.close();
+ * ```
+ */
+export async function openGlassRectChannel(
+  regionId: string
+): Promise<GlassRectChannel> {
+  const event = await invoke<string>(
+    `plugin:${PLUGIN_NAME}|open_glass_rect_channel`,
+    { regionId }
+  );
+  let open = true;
+  return {
+    event,
+    send(rect: ViewRect) {
+      if (!open) return;
+      emit(event, rect).catch((e) =>
+        console.warn("Failed to stream glass rect:", e)
+      );
+    },
+    async close() {
+      if (!open) return false;
+      open = false;
+      return invoke<boolean>(`plugin:${PLUGIN_NAME}|close_glass_rect_channel`, {
+        channel: event,
+      });
+    },
+  };
+}
+
+/**
+ * Back an element's bounding box with a glass view owned by the binding
+ *
+ * Like {@link bindGlassRegion}, but the view id is a handle claimed with
+ * {@link claimGlassHandle} and released on unbind, and the view is attached
+ * to a channel with {@link attachRegionToChannel}: it is put back on the
+ * element when moved natively, and recreated at the next sync when removed
+ * elsewhere. This is what the Svelte action and Vue directive build on.
+ *
+ * @param element Element to track; it should already be in the document
+ * @param config Glass configuration; its `frame` is replaced by the element's box
+ *
+ * @example
+ * ```typescript
+ * const binding = bindOwnedGlassRegion(element, { cornerRadius: 16 });
+ * binding.setConfig({ cornerRadius: 24 });
+ * binding.unbind();
+ * ```
+ */
+export function bindOwnedGlassRegion(
+  element: HTMLElement,
+  config: LiquidGlassConfig = {}
+): OwnedGlassRegionBinding {
+  let current = config;
+  if (usesCssGlassPolyfill()) {
+    const binding = bindGlassRegion(element, config);
+    return {
+      handle: Promise.resolve(binding.id),
+      setConfig: binding.setConfig,
+      unbind: binding.unbind,
+    };
+  }
+
+  let region: ElementRegion | null = null;
+  let unbound = false;
+  const handle = claimGlassHandle();
+  handle
+    .then((id) => {
+      if (unbound) {
+        void releaseGlassHandle(id);
+        return;
+      }
+      region = new ElementRegion(
+        element,
+        id,
+        () => current,
+        new Channel<RegionMessage>()
+      );
+      region.start();
+    })
+    .catch((e) => console.warn("Failed to claim a glass handle:", e));
+
+  return {
+    handle,
+    setConfig(next: LiquidGlassConfig) {
+      current = next;
+      region?.invalidate();
+    },
+    unbind() {
+      if (unbound) return;
+      unbound = true;
+      if (!region) return;
+      region.stop();
+      void handle.then(releaseGlassHandle);
+    },
+  };
+}
+
+/**
+ * Resolve a variant given as a number or a case-insensitive variant name
+ *
+ * @returns The variant, or `undefined` for an empty or unknown name
+ *
+ * @example
+ * ```typescript
+ * parseGlassVariant("sidebar"); // GlassMaterialVariant.Sidebar
+ * parseGlassVariant("16"); // 16
+ * ```
+ */
+export function parseGlassVariant(
+  value: string | number
+): GlassMaterialVariant | undefined {
+  if (typeof value === "number") return value as GlassMaterialVariant;
+  const trimmed = value.trim();
+  if (!trimmed) return undefined;
+  const number = Number(trimmed);
+  if (Number.isInteger(number)) return number as GlassMaterialVariant;
+  const name = Object.keys(GlassMaterialVariant).find(
+    (key) => key.toLowerCase() === trimmed.toLowerCase()
+  ) as keyof typeof GlassMaterialVariant | undefined;
+  return name === undefined ? undefined : GlassMaterialVariant[name];
+}
+
+/**
+ * Register the `<liquid-glass-region>` custom element
+ *
+ * Each element backs its own bounding box with a native glass view, like
+ * {@link bindGlassRegion}, for as long as it is in the document.
+ *
+ * Attributes:
+ * - `region-id`: view id (default: a generated `liquid-glass-region-N`)
+ * - `variant`: variant number or name, e.g. `16` or `"sidebar"`
+ * - `tint`: tint color as a CSS color string
+ * - `radius`: corner radius in points
+ *
+ * Other config fields can be set through the element's `config` property;
+ * attributes take precedence. Like any custom element it is inline by
+ * default, so give it `display: block` (or flex, grid) to size it.
+ *
+ * Where {@link usesCssGlassPolyfill} is true, no native view is created;
+ * the element is styled with {@link applyCssGlass} instead, so the same
+ * markup yields an approximate look on every platform.
+ *
+ * @param tagName Tag to register (default: `"liquid-glass-region"`)
+ * @returns The element class, or the one already registered under `tagName`
+ *
+ * @example
+ * ```typescript
+ * defineGlassRegionElement();
+ * ```
+ * ```html
+ * <liquid-glass-region region-id="sidebar" variant="sidebar" radius="16">
+ *   <nav>...</nav>
+ * </liquid-glass-region>
+ * ```
+ */
+export function defineGlassRegionElement(
+  tagName: string = "liquid-glass-region"
+): CustomElementConstructor {
+  const existing = customElements.get(tagName);
+  if (existing) return existing;
+
+  class LiquidGlassRegionElement extends HTMLElement {
+    static observedAttributes = ["region-id", "variant", "tint", "radius"];
+
+    private _config: LiquidGlassConfig = {};
+    private region: ElementRegion | null = null;
+
+    /** Base glass configuration; its `frame` is replaced by the element's box */
+    get config(): LiquidGlassConfig {
+      return this._config;
+    }
+
+    set config(config: LiquidGlassConfig) {
+      this._config = config;
+      this.region?.invalidate();
+    }
+
+    connectedCallback() {
+      this.region = new ElementRegion(
+        this,
+        this.getAttribute("region-id") ?? generateRegionId(),
+        () => this.resolvedConfig()
+      );
+      this.region.start();
+    }
+
+    disconnectedCallback() {
+      this.region?.stop();
+      this.region = null;
+    }
+
+    attributeChangedCallback(
+      name: string,
+      oldValue: string | null,
+      newValue: string | null
+    ) {
+      if (oldValue === newValue || this.region === null) return;
+      if (name === "region-id") {
+        this.region.rename(newValue ?? generateRegionId());
+      } else {
+        this.region.invalidate();
+      }
+    }
+
+    /** `config` with the attributes applied */
+    private resolvedConfig(): LiquidGlassConfig {
+      const config: LiquidGlassConfig = { ...this._config };
+      const variant = this.variantAttribute();
+      if (variant !== undefined) config.variant = variant;
+      const tint = this.getAttribute("tint");
+      if (tint !== null) config.tintColor = tint;
+      const radius = parseFloat(this.getAttribute("radius") ?? "");
+      if (!Number.isNaN(radius)) config.cornerRadius = radius;
+      return config;
+    }
+
+    /** `variant` as a number or a case-insensitive variant name */
+    private variantAttribute(): GlassMaterialVariant | undefined {
+      const value = this.getAttribute("variant");
+      return value === null ? undefined : parseGlassVariant(value);
+    }
+  }
+
+  customElements.define(tagName, LiquidGlassRegionElement);
+  return LiquidGlassRegionElement;
+}
+
+/**
+ * Tag the current window into a theme group
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * A window belongs to at most one group. If the group already has an effect,
+ * it is applied to this window right away.
+ *
+ * @param group Group name, e.g. "documents"
+ */
+export async function joinThemeGroup(group: string): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|join_theme_group`, { group });
+}
+
+/**
+ * Remove the current window from its theme group (its effect is kept)
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ */
+export async function leaveThemeGroup(): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|leave_theme_group`);
+}
+
+/**
+ * Apply a configuration to every window in a theme group
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * The configuration is also applied to windows that join the group later.
+ *
+ * @example
+ * ```typescript
+ * await joinThemeGroup("documents");
+ * await setThemeGroupEffect("documents", { variant: GlassMaterialVariant.Clear });
+ * ```
+ */
+export async function setThemeGroupEffect(
+  group: string,
+  config: LiquidGlassConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_theme_group_effect`, {
+    group,
+    config,
+  });
+}
+
+/**
+ * Set the window level and collection behavior of the current window
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * @param config Window level configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * // Keep a glass overlay above full-screen apps
+ * await setGlassWindowLevel({
+ *   level: "status",
+ *   canJoinAllSpaces: true,
+ *   fullScreenAuxiliary: true,
+ * });
+ * ```
+ */
+export async function setGlassWindowLevel(
+  config: WindowLevelConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_glass_window_level`, { config });
+}
+
+/**
+ * Turn the current window into a rounded glass window in one call
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * Makes the window and webview transparent, hides the title bar chrome,
+ * rounds the window to `radius`, applies the glass with a matching corner
+ * radius and refreshes the window shadow.
+ *
+ * @param radius Corner radius of the window and glass in points
+ * @param config Glass configuration; its corner radius and window shape are overridden
+ *
+ * @example
+ * ```typescript
+ * await makeRoundedGlassWindow(16, { variant: GlassMaterialVariant.Clear });
+ * ```
+ */
+export async function makeRoundedGlassWindow(
+  radius: number,
+  config: LiquidGlassConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|make_rounded_glass_window`, {
+    radius,
+    config,
+  });
+}
+
+/**
+ * Set the current window's background color, or clear it with `null`
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * @param color CSS color string (same formats as `tintColor`), or `null` for a clear background
+ *
+ * @example
+ * ```typescript
+ * // Faint base under a clear glass variant
+ * await setWindowBackground("rgb(255 255 255 / 0.06)");
+ * ```
+ */
+export async function setWindowBackground(color: string | null): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_window_background`, { color });
+}
+
+/**
+ * Toggle whether the current window's webview draws its own opaque background
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * @param drawsBackground `false` for glass-transparent mode, `true` for opaque content
+ *
+ * @example
+ * ```typescript
+ * // Honor a "reduce transparency" setting
+ * await setWebviewDrawsBackground(settings.reduceTransparency);
+ * ```
+ */
+export async function setWebviewDrawsBackground(
+  drawsBackground: boolean
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_webview_draws_background`, {
+    drawsBackground,
+  });
+}
+
+/**
+ * Switch a window between transparent and opaque at runtime
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * Transparent makes the window non-opaque with a clear background; opaque
+ * restores the system window background. Combine with
+ * `setWebviewTransparent()` to flip between glass and opaque modes.
+ *
+ * @param label Label of the window
+ * @param transparent `true` for glass mode, `false` for an opaque window
+ *
+ * @example
+ * ```typescript
+ * const glass = !settings.reduceTransparency;
+ * await setWindowTransparent("main", glass);
+ * await setWebviewTransparent("main", glass);
+ * ```
+ */
+export async function setWindowTransparent(
+  label: string,
+  transparent: boolean
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_window_transparent`, {
+    label,
+    transparent,
+  });
+}
+
+/**
+ * Switch a window's webview between transparent and opaque at runtime
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * Transparent stops the webview from drawing its background and clears the
+ * color shown when overscrolling; opaque restores both.
+ *
+ * @param label Label of the window
+ * @param transparent `true` for glass mode, `false` for an opaque webview
+ */
+export async function setWebviewTransparent(
+  label: string,
+  transparent: boolean
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_webview_transparent`, {
+    label,
+    transparent,
+  });
+}
+
+/** Event emitted when a media-controls window is shown or auto-hidden */
+export const MEDIA_CONTROLS_VISIBILITY_EVENT =
+  "liquid-glass://media-controls-visibility";
+
+/**
+ * Turn a window into a fullscreen media-controls overlay
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * The window is sized into a bottom-anchored strip, floated above full-screen
+ * content, given an Avplayer glass and shown. It hides itself after
+ * `autoHideAfterMs` unless {@link revealMediaControls} is called again. Create
+ * the window transparent, undecorated and hidden.
+ *
+ * @param label Label of the window to use as the control bar
+ * @param config Media controls configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * await attachMediaControls("player-controls", { autoHideAfterMs: 2500 });
+ * videoElement.addEventListener("mousemove", () =>
+ *   revealMediaControls("player-controls")
+ * );
+ * ```
+ */
+export async function attachMediaControls(
+  label: string,
+  config: MediaControlsConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|attach_media_controls`, {
+    label,
+    config,
+  });
+}
+
+/**
+ * Show a media-controls window and restart its auto-hide timer
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the media-controls window
+ */
+export async function revealMediaControls(label: string): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|reveal_media_controls`, { label });
+}
+
+/**
+ * Stop auto-hiding a media-controls window
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the media-controls window
+ */
+export async function detachMediaControls(label: string): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|detach_media_controls`, { label });
+}
+
+/**
+ * Listen for media-controls windows being shown or auto-hidden
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onMediaControlsVisibility(({ visible }) => {
+ *   document.body.classList.toggle("controls-hidden", !visible);
+ * });
+ * ```
+ */
+export async function onMediaControlsVisibility(
+  handler: (payload: MediaControlsVisibility) => void
+): Promise<UnlistenFn> {
+  return listen<MediaControlsVisibility>(
+    MEDIA_CONTROLS_VISIBILITY_EVENT,
+    (event) => handler(event.payload)
+  );
+}
+
+/** Event emitted when a context menu window is dismissed */
+export const CONTEXT_MENU_DISMISSED_EVENT = "liquid-glass://context-menu-dismissed";
+
+/**
+ * Show a window as a glass context menu
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * The window is sized, placed next to the given point (flipping to stay on
+ * screen), floated at pop-up menu level, rounded with glass and a shadow,
+ * then shown and focused. It hides itself when it loses focus. Create the
+ * window transparent, undecorated, hidden and skipped from the taskbar, and
+ * render the menu items in it.
+ *
+ * @param label Label of the menu window
+ * @param position Point relative to the current window's content, e.g. from a
+ * `contextmenu` event; omit to open at the cursor
+ * @param config Context menu configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * document.addEventListener("contextmenu", (e) => {
+ *   e.preventDefault();
+ *   showContextMenu("menu", { x: e.clientX, y: e.clientY }, { height: 180 });
+ * });
+ * ```
+ */
+export async function showContextMenu(
+  label: string,
+  position?: { x: number; y: number },
+  config: ContextMenuConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|show_context_menu`, {
+    label,
+    x: position?.x ?? null,
+    y: position?.y ?? null,
+    config,
+  });
+}
+
+/**
+ * Hide a context menu window, e.g. after an item was chosen or on Escape
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the menu window (default: the current window)
+ * @returns false if the window was not a shown context menu
+ *
+ * @example
+ * ```typescript
+ * // Inside the menu window
+ * document.addEventListener("keydown", (e) => {
+ *   if (e.key === "Escape") dismissContextMenu();
+ * });
+ * ```
+ */
+export async function dismissContextMenu(
+  label: string = getCurrentWindow().label
+): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|dismiss_context_menu`, { label });
+}
+
+/**
+ * Listen for context menu windows being dismissed
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onContextMenuDismissed(({ reason }) => {
+ *   if (reason === "blur") clearMenuSelection();
+ * });
+ * ```
+ */
+export async function onContextMenuDismissed(
+  handler: (payload: ContextMenuDismissed) => void
+): Promise<UnlistenFn> {
+  return listen<ContextMenuDismissed>(CONTEXT_MENU_DISMISSED_EVENT, (event) =>
+    handler(event.payload)
+  );
+}
+
+/** Event emitted when a menubar panel is shown or hidden */
+export const MENUBAR_PANEL_VISIBILITY_EVENT = "liquid-glass://menubar-panel-visibility";
+
+/**
+ * Show a window as a glass panel under a tray item, toggled by clicking it
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * The window is floated above other windows on every Space and rounded with
+ * glass and a shadow. Left-clicking the tray item then shows it centered
+ * under the item and the next click (or, by default, losing focus) hides it.
+ * Create the window transparent, undecorated, hidden and skipped from the
+ * taskbar. Requires the plugin's `tray-icon` Cargo feature.
+ *
+ * @param trayId Id of the tray item, as passed to `TrayIconBuilder.with_id`
+ * @param label Label of the panel window
+ * @param config Menubar panel configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * await attachMenubarPanel("main-tray", "panel", { width: 320, height: 400 });
+ * ```
+ */
+export async function attachMenubarPanel(
+  trayId: string,
+  label: string,
+  config: MenubarPanelConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|attach_menubar_panel`, {
+    trayId,
+    label,
+    config,
+  });
+}
+
+/**
+ * Show or hide the panel of a tray item, as if the item was clicked
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param trayId Id of the tray item
+ */
+export async function toggleMenubarPanel(trayId: string): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|toggle_menubar_panel`, { trayId });
+}
+
+/**
+ * Stop toggling a window from a tray item; the window keeps its glass
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param trayId Id of the tray item
+ * @returns false if the tray item had no panel
+ */
+export async function detachMenubarPanel(trayId: string): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|detach_menubar_panel`, {
+    trayId,
+  });
+}
+
+/**
+ * Listen for menubar panels being shown or hidden
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onMenubarPanelVisibility(({ visible }) => {
+ *   if (visible) refreshStatus();
+ * });
+ * ```
+ */
+export async function onMenubarPanelVisibility(
+  handler: (payload: MenubarPanelVisibility) => void
+): Promise<UnlistenFn> {
+  return listen<MenubarPanelVisibility>(MENUBAR_PANEL_VISIBILITY_EVENT, (event) =>
+    handler(event.payload)
+  );
+}
+
+/**
+ * Show a window by fading it in
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * Popping a transparent glass window in without a transition reads as a
+ * glitch; this animates the whole window's alpha instead. A window that is
+ * mid fade-out fades back in from its current alpha.
+ *
+ * @param label Label of the window (default: the current window)
+ * @param durationMs Fade duration in milliseconds (default: 200)
+ */
+export async function showWithFade(
+  label: string = getCurrentWindow().label,
+  durationMs?: number
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|show_with_fade`, {
+    label,
+    durationMs: durationMs ?? null,
+  });
+}
+
+/**
+ * Hide a window by fading it out
+ *
+ * Needs the `liquid-glass:window-management` permission set.
+ *
+ * The window is hidden (and its alpha restored) once the fade completes,
+ * unless it was faded back in meanwhile.
+ *
+ * @param label Label of the window (default: the current window)
+ * @param durationMs Fade duration in milliseconds (default: 200)
+ *
+ * @example
+ * ```typescript
+ * await hideWithFade("settings", 150);
+ * ```
+ */
+export async function hideWithFade(
+  label: string = getCurrentWindow().label,
+  durationMs?: number
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|hide_with_fade`, {
+    label,
+    durationMs: durationMs ?? null,
+  });
+}
+
+/** Event emitted when a sheet is presented or dismissed */
+export const SHEET_VISIBILITY_EVENT = "liquid-glass://sheet-visibility";
+
+/**
+ * Present a window as a glass sheet attached to a parent window
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * The sheet is sized and given rounded glass while hidden, then attached
+ * with `beginSheet`, so macOS animates it out of the parent's title bar and
+ * blocks the parent until {@link dismissSheet} is called. Create the sheet
+ * window hidden and undecorated.
+ *
+ * @param label Label of the sheet window
+ * @param config Sheet configuration. All fields are optional.
+ * @param parent Label of the parent window (default: the current window)
+ *
+ * @example
+ * ```typescript
+ * await presentSheet("confirm-delete", { width: 420, height: 180 });
+ * ```
+ */
+export async function presentSheet(
+  label: string,
+  config: SheetConfig = {},
+  parent?: string
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|present_sheet`, {
+    label,
+    parent: parent ?? null,
+    config,
+  });
+}
+
+/**
+ * Dismiss a glass sheet, animating it back into its parent
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the sheet window (default: the current window)
+ * @returns false if the window is not a presented sheet
+ */
+export async function dismissSheet(
+  label: string = getCurrentWindow().label
+): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|dismiss_sheet`, { label });
+}
+
+/**
+ * Listen for sheets being presented or dismissed
+ *
+ * @returns A function that removes the listener
+ */
+export async function onSheetVisibility(
+  handler: (payload: SheetVisibility) => void
+): Promise<UnlistenFn> {
+  return listen<SheetVisibility>(SHEET_VISIBILITY_EVENT, (event) =>
+    handler(event.payload)
+  );
+}
+
+/** Event emitted when a picture-in-picture window snaps to a corner */
+export const PIP_SNAPPED_EVENT = "liquid-glass://pip-snapped";
+
+/**
+ * Turn a window into a picture-in-picture mini window
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * The window is sized, floated above other windows on every Space, rounded
+ * with glass, placed in `config.corner` and shown. Whenever the user drops it
+ * after a drag (give the content a `data-tauri-drag-region`), it animates to
+ * the nearest corner of its monitor.
+ *
+ * @param label Label of the window
+ * @param config Picture-in-picture configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * await attachPip("player", { width: 384, height: 216, corner: "topRight" });
+ * ```
+ */
+export async function attachPip(
+  label: string,
+  config: PipConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|attach_pip`, { label, config });
+}
+
+/**
+ * Stop snapping a picture-in-picture window; it keeps its glass
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the window (default: the current window)
+ * @returns false if the window was not attached
+ */
+export async function detachPip(
+  label: string = getCurrentWindow().label
+): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|detach_pip`, { label });
+}
+
+/**
+ * Animate a picture-in-picture window into a corner
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param corner Target corner; omit for the nearest one
+ * @param label Label of the window (default: the current window)
+ * @returns The corner the window snapped to
+ */
+export async function snapPip(
+  corner?: ScreenCorner,
+  label: string = getCurrentWindow().label
+): Promise<ScreenCorner> {
+  return invoke<ScreenCorner>(`plugin:${PLUGIN_NAME}|snap_pip`, {
+    label,
+    corner: corner ?? null,
+  });
+}
+
+/**
+ * Listen for picture-in-picture windows snapping to a corner
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onPipSnapped(({ corner }) => {
+ *   controls.classList.toggle("controls-top", corner.startsWith("bottom"));
+ * });
+ * ```
+ */
+export async function onPipSnapped(
+  handler: (payload: PipSnapped) => void
+): Promise<UnlistenFn> {
+  return listen<PipSnapped>(PIP_SNAPPED_EVENT, (event) => handler(event.payload));
+}
+
+/** Event emitted when a toast is dismissed */
+export const TOAST_DISMISSED_EVENT = "liquid-glass://toast-dismissed";
+
+/**
+ * Show a transient glass toast in a corner of the primary monitor
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * Pass `{ html }` to have the plugin create a small window rendering the
+ * HTML (closed when the toast ends; it has no IPC access), or `{ window }`
+ * to show an app-created window (hidden again when the toast ends). The
+ * toast slides in without taking focus, pushes older toasts in the same
+ * corner aside, and fades out after `durationMs`.
+ *
+ * @param options Toast options. All fields except `content` are optional.
+ * @returns The label of the toast window
+ *
+ * @example
+ * ```typescript
+ * const toast = await showGlassToast({
+ *   content: { html: "<p style='padding:12px'>Export finished</p>" },
+ *   position: "bottomRight",
+ *   durationMs: 3000,
+ * });
+ * ```
+ */
+export async function showGlassToast(options: ToastOptions): Promise<string> {
+  return invoke<string>(`plugin:${PLUGIN_NAME}|show_glass_toast`, { options });
+}
+
+/**
+ * Fade out a toast before its duration elapses
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label returned by {@link showGlassToast} (default: the current
+ * window, for toasts showing an app window)
+ * @returns false if the window is not a shown toast
+ */
+export async function dismissGlassToast(
+  label: string = getCurrentWindow().label
+): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|dismiss_glass_toast`, { label });
+}
+
+/**
+ * Listen for toasts being dismissed
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onToastDismissed(({ window, reason }) => {
+ *   if (reason === "timeout") markSeen(window);
+ * });
+ * ```
+ */
+export async function onToastDismissed(
+  handler: (payload: ToastDismissed) => void
+): Promise<UnlistenFn> {
+  return listen<ToastDismissed>(TOAST_DISMISSED_EVENT, (event) =>
+    handler(event.payload)
+  );
+}
+
+/** Event emitted when a launcher window is shown or dismissed */
+export const LAUNCHER_VISIBILITY_EVENT = "liquid-glass://launcher-visibility";
+
+/**
+ * Create a Spotlight-style launcher window
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * Creates a hidden, undecorated glass palette with a fixed width that floats
+ * over every Space and full-screen app, is centered on the monitor under the
+ * cursor when shown and is dismissed when it loses focus. By default the app
+ * also stops showing a Dock icon. If a window with the label already exists it
+ * is configured instead and `url` is ignored.
+ *
+ * Summon it with {@link toggleLauncher}, e.g. from a global shortcut
+ * registered with `@tauri-apps/plugin-global-shortcut`, and call
+ * {@link observeLauncher} inside the launcher to grow it with its content.
+ *
+ * @param label Label of the launcher window
+ * @param url Page to load, an app path such as `"launcher.html"` or a URL
+ * @param config Launcher configuration. All fields are optional.
+ *
+ * @example
+ * ```typescript
+ * await createLauncher("launcher", "launcher.html", { width: 640 });
+ * await register("Alt+Space", (e) => {
+ *   if (e.state === "Pressed") toggleLauncher("launcher");
+ * });
+ * ```
+ */
+export async function createLauncher(
+  label: string,
+  url: string,
+  config: LauncherConfig = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|create_launcher`, { label, url, config });
+}
+
+/**
+ * Show a launcher, or dismiss it if it is already visible
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the launcher window
+ */
+export async function toggleLauncher(label: string): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|toggle_launcher`, { label });
+}
+
+/**
+ * Hide a launcher, e.g. after an item was chosen
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param label Label of the launcher window (default: the current window)
+ * @returns false if the launcher was not visible
+ */
+export async function dismissLauncher(
+  label: string = getCurrentWindow().label
+): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|dismiss_launcher`, { label });
+}
+
+/**
+ * Fit a launcher to its content, keeping its top edge in place
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * @param height Content height in logical pixels, clamped to the launcher's
+ * `minHeight`/`maxHeight`
+ * @param label Label of the launcher window (default: the current window)
+ * @returns The height applied
+ */
+export async function resizeLauncher(
+  height: number,
+  label: string = getCurrentWindow().label
+): Promise<number> {
+  return invoke<number>(`plugin:${PLUGIN_NAME}|resize_launcher`, {
+    label,
+    height,
+  });
+}
+
+/**
+ * Drive the current launcher window from its content
+ *
+ * Needs the `liquid-glass:components` permission set.
+ *
+ * Resizes the launcher whenever `element` changes height and dismisses it
+ * when Escape is pressed. Call it inside the launcher window.
+ *
+ * @param element Element wrapping the launcher's content
+ * @returns A function that stops observing
+ *
+ * @example
+ * ```typescript
+ * const stop = observeLauncher(document.getElementById("palette")!);
+ * ```
+ */
+export function observeLauncher(element: HTMLElement): () => void {
+  let lastHeight = -1;
+  const observer = new ResizeObserver(() => {
+    const height = Math.ceil(element.getBoundingClientRect().height);
+    if (height === lastHeight) return;
+    lastHeight = height;
+    resizeLauncher(height).catch(() => {});
+  });
+  const onKeyDown = (event: KeyboardEvent) => {
+    if (event.key === "Escape" && !event.defaultPrevented) {
+      event.preventDefault();
+      dismissLauncher().catch(() => {});
+    }
+  };
+
+  observer.observe(element);
+  document.addEventListener("keydown", onKeyDown);
+  return () => {
+    observer.disconnect();
+    document.removeEventListener("keydown", onKeyDown);
+  };
+}
+
+/**
+ * Listen for launcher windows being shown or dismissed
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * // Inside the launcher: start each session with an empty query
+ * const unlisten = await onLauncherVisibility(({ visible }) => {
+ *   if (visible) resetQuery();
+ * });
+ * ```
+ */
+export async function onLauncherVisibility(
+  handler: (payload: LauncherVisibility) => void
+): Promise<UnlistenFn> {
+  return listen<LauncherVisibility>(LAUNCHER_VISIBILITY_EVENT, (event) =>
+    handler(event.payload)
+  );
+}
+
+/**
+ * Install, update or (with `null`) remove the current window's scroll-edge strip
+ *
+ * The strip starts hidden. Use {@link observeScrollEdge} to drive it from a
+ * scroll container, or report offsets yourself with {@link reportScrollOffset}.
+ *
+ * @param config Scroll-edge configuration, or `null` to remove the strip
+ */
+export async function setScrollEdgeEffect(
+  config: ScrollEdgeConfig | null = {}
+): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|set_scroll_edge_effect`, { config });
+}
+
+/**
+ * Report the current window's scroll offset to its scroll-edge strip
+ *
+ * Show/hide thresholds and hysteresis are applied natively.
+ *
+ * @param offset Vertical scroll offset in CSS pixels
+ */
+export async function reportScrollOffset(offset: number): Promise<void> {
+  return invoke(`plugin:${PLUGIN_NAME}|report_scroll_offset`, { offset });
+}
+
+/**
+ * Install a scroll-edge strip and drive it from a scroll container
+ *
+ * Offsets are reported at most once per animation frame and only when they
+ * change.
+ *
+ * @param target Scroll container, or `window` for the document (default: `window`)
+ * @param config Scroll-edge configuration
+ * @returns A function that stops observing and removes the strip
+ *
+ * @example
+ * ```typescript
+ * const stop = await observeScrollEdge(document.querySelector("main")!);
+ * // later
+ * await stop();
+ * ```
+ */
+export async function observeScrollEdge(
+  target: HTMLElement | Window = window,
+  config: ScrollEdgeConfig = {}
+): Promise<() => Promise<void>> {
+  await setScrollEdgeEffect(config);
+
+  const readOffset = () =>
+    target instanceof Window ? target.scrollY : target.scrollTop;
+
+  let lastReported: number | null = null;
+  let frame: number | null = null;
+
+  const report = () => {
+    frame = null;
+    const offset = readOffset();
+    if (offset !== lastReported) {
+      lastReported = offset;
+      void reportScrollOffset(offset);
+    }
+  };
+
+  const onScroll = () => {
+    if (frame === null) {
+      frame = requestAnimationFrame(report);
+    }
+  };
+
+  target.addEventListener("scroll", onScroll, { passive: true });
+  report();
+
+  return async () => {
+    target.removeEventListener("scroll", onScroll);
+    if (frame !== null) {
+      cancelAnimationFrame(frame);
+    }
+    await setScrollEdgeEffect(null);
+  };
+}
+
+/**
+ * Put a glass strip behind a sticky header and fade it in on scroll
+ *
+ * Combines {@link observeScrollEdge} with frame tracking: the strip is sized
+ * to the header's bounding box, re-synced when the header or container
+ * resizes, the window resizes or the header moves while scrolling, and fades
+ * in once the container scrolls under the header. Frame updates and offsets
+ * are sent at most once per animation frame and only when they change.
+ *
+ * @param container Scroll container, or `window` for the document
+ * @param header Sticky header inside the container
+ * @param config Scroll-edge configuration; `height` and `glass.frame` are
+ * taken from the header
+ * @returns A function that stops observing and removes the strip
+ *
+ * @example
+ * ```typescript
+ * const stop = await observeStickyHeader(
+ *   document.querySelector("main")!,
+ *   document.querySelector("main > header")!,
+ *   { glass: { variant: GlassMaterialVariant.Regular } }
+ * );
+ * ```
+ */
+export async function observeStickyHeader(
+  container: HTMLElement | Window,
+  header: HTMLElement,
+  config: ScrollEdgeConfig = {}
+): Promise<() => Promise<void>> {
+  let lastFrame = "";
+
+  // Re-sends the config only when the header's frame changed
+  const syncFrame = async (): Promise<void> => {
+    const rect = header.getBoundingClientRect();
+    const frame = {
+      left: rect.left,
+      top: rect.top,
+      width: rect.width,
+      height: rect.height,
+    };
+    const key = JSON.stringify(frame);
+    if (key === lastFrame) return;
+    lastFrame = key;
+    await setScrollEdgeEffect({
+      ...config,
+      height: rect.height,
+      glass: { ...config.glass, frame },
+    });
+  };
+
+  const readOffset = () =>
+    container instanceof Window ? container.scrollY : container.scrollTop;
+
+  let lastReported: number | null = null;
+  let frameRequest: number | null = null;
+  let stopped = false;
+
+  const update = () => {
+    frameRequest = null;
+    if (stopped) return;
+    void syncFrame();
+    const offset = readOffset();
+    if (offset !== lastReported) {
+      lastReported = offset;
+      void reportScrollOffset(offset);
+    }
+  };
+
+  const schedule = () => {
+    if (frameRequest === null && !stopped) {
+      frameRequest = requestAnimationFrame(update);
+    }
+  };
+
+  await syncFrame();
+
+  const resizeObserver = new ResizeObserver(schedule);
+  resizeObserver.observe(header);
+  if (!(container instanceof Window)) resizeObserver.observe(container);
+  container.addEventListener("scroll", schedule, { passive: true });
+  window.addEventListener("resize", schedule);
+  update();
+
+  return async () => {
+    stopped = true;
+    resizeObserver.disconnect();
+    container.removeEventListener("scroll", schedule);
+    window.removeEventListener("resize", schedule);
+    if (frameRequest !== null) {
+      cancelAnimationFrame(frameRequest);
+    }
+    await setScrollEdgeEffect(null);
+  };
+}
+
+/**
+ * Get the current window's full-window glass: its applied config and backend
+ *
+ * Reads back what the plugin applied, so apps don't have to mirror it.
+ * Regions are not included; use `getGlassState()` for those.
+ *
+ * @returns The view, or `null` if the window has no full-window glass
+ *
+ * @example
+ * ```typescript
+ * const effect = await getLiquidGlassEffect();
+ * const fallback = effect?.backend === "visualEffect";
+ * ```
+ */
+export async function getLiquidGlassEffect(): Promise<GlassViewInfo | null> {
+  return invoke<GlassViewInfo | null>(
+    `plugin:${PLUGIN_NAME}|get_liquid_glass_effect`
+  );
+}
+
+/**
+ * Get the glass views of the current window, including which backend each uses
+ *
+ * @example
+ * ```typescript
+ * const views = await getGlassState();
+ * const onFallback = views.some((view) => view.backend === "visualEffect");
+ * document.body.classList.toggle("glass-fallback", onFallback);
+ * ```
+ */
+export async function getGlassState(): Promise<GlassViewInfo[]> {
+  return invoke<GlassViewInfo[]>(`plugin:${PLUGIN_NAME}|get_glass_state`);
+}
+
+/**
+ * Get the recommended foreground color for text over the current window's glass
+ *
+ * @param region Layout region id; omit for the full-window glass
+ * @returns The recommendation, or `null` if there is no such glass view
+ *
+ * @example
+ * ```typescript
+ * const foreground = await getRecommendedForeground("sidebar");
+ * if (foreground) sidebar.style.color = foreground.color;
+ * ```
+ */
+export async function getRecommendedForeground(
+  region?: string
+): Promise<ForegroundRecommendation | null> {
+  return invoke<ForegroundRecommendation | null>(
+    `plugin:${PLUGIN_NAME}|get_recommended_foreground`,
+    { region: region ?? null }
+  );
+}
+
+/**
+ * Get the on-screen frame of one of the current window's glass views
+ *
+ * Use it to check that a glass view still lines up with the element it
+ * should cover, and to correct drift after unusual layout changes.
+ *
+ * @param region Named view id; omit for the full-window glass
+ * @returns The frame, or `null` if there is no such glass view
+ *
+ * @example
+ * ```typescript
+ * const frame = await getGlassFrame("sidebar");
+ * const rect = sidebar.getBoundingClientRect();
+ * if (frame && Math.abs(frame.css.width - rect.width) > 1) {
+ *   await setGlassView("sidebar", { frame: { top: 0, bottom: 0, left: 0, width: rect.width } });
+ * }
+ * ```
+ */
+export async function getGlassFrame(region?: string): Promise<GlassViewFrame | null> {
+  return invoke<GlassViewFrame | null>(`plugin:${PLUGIN_NAME}|get_glass_frame`, {
+    region: region ?? null,
+  });
+}
+
+/**
+ * Get the insets of the current window's content view that lie outside the
+ * display's safe area
+ *
+ * Non-zero in fullscreen on displays with a camera housing. Glass frames with
+ * `safeArea: true` avoid the housing on their own; use this to keep web
+ * content clear of it too. Insets change with the window, so re-read them on
+ * resize.
+ *
+ * @example
+ * ```typescript
+ * const { top } = await getSafeAreaInsets();
+ * document.documentElement.style.setProperty("--safe-top", `${top}px`);
+ * ```
+ */
+export async function getSafeAreaInsets(): Promise<SafeAreaInsets> {
+  return invoke<SafeAreaInsets>(`plugin:${PLUGIN_NAME}|get_safe_area_insets`);
+}
+
+/**
+ * Get the user's accent, highlight and focus ring colors
+ *
+ * @returns The colors as `#rrggbbaa` strings, or `null` on other platforms
+ *
+ * @example
+ * ```typescript
+ * const colors = await getSystemColors();
+ * if (colors) {
+ *   document.documentElement.style.setProperty("--accent", colors.accent);
+ * }
+ * ```
+ */
+export async function getSystemColors(): Promise<SystemColors | null> {
+  return invoke<SystemColors | null>(`plugin:${PLUGIN_NAME}|get_system_colors`);
+}
+
+/** Event emitted when the user changes their accent or highlight color */
+export const SYSTEM_COLORS_CHANGED_EVENT = "liquid-glass://system-colors-changed";
+
+/**
+ * Listen for changes to the user's accent, highlight or focus ring color
+ *
+ * Unlike the other events this is app-wide, since the colors are.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onSystemColorsChanged(({ colors }) => {
+ *   document.documentElement.style.setProperty("--accent", colors.accent);
+ * });
+ * ```
+ */
+export async function onSystemColorsChanged(
+  handler: (payload: SystemColorsChanged) => void
+): Promise<UnlistenFn> {
+  return listen<SystemColorsChanged>(SYSTEM_COLORS_CHANGED_EVENT, (event) => {
+    handler(event.payload);
+  });
+}
+
+/**
+ * Temporarily swap every glass view for a flat fill
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * Useful during screen recording or presentations, or when the user turns
+ * fancy effects off. Each view is hidden behind its opaque base (its
+ * `opaqueColor`, or the window background). Configs are remembered, and
+ * changes made while suspended apply on {@link resumeAll}.
+ *
+ * @returns false if effects were already suspended (or on other platforms)
+ */
+export async function suspendAll(): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|suspend_all`);
+}
+
+/**
+ * Restore every glass view suspended by {@link suspendAll}
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * @returns false if effects were not suspended
+ */
+export async function resumeAll(): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|resume_all`);
+}
+
+/**
+ * Check whether glass effects are currently suspended
+ */
+export async function isSuspended(): Promise<boolean> {
+  return invoke<boolean>(`plugin:${PLUGIN_NAME}|is_suspended`);
+}
+
+/** Event emitted when glass effects are suspended or resumed */
+export const EFFECTS_SUSPENDED_EVENT = "liquid-glass://effects-suspended";
+
+/**
+ * Listen for glass effects being suspended or resumed, app-wide
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onEffectsSuspended(({ suspended }) => {
+ *   document.body.classList.toggle("flat", suspended);
+ * });
+ * ```
+ */
+export async function onEffectsSuspended(
+  handler: (payload: EffectsSuspended) => void
+): Promise<UnlistenFn> {
+  return listen<EffectsSuspended>(EFFECTS_SUSPENDED_EVENT, (event) => {
+    handler(event.payload);
+  });
+}
+
+/** Event emitted when the recommended foreground of a glass view changes */
+export const FOREGROUND_CHANGED_EVENT = "liquid-glass://foreground-changed";
+
+/**
+ * Listen for changes to the recommended foreground of the current window's glass
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onForegroundChanged(({ region, recommendation }) => {
+ *   if (region === null) document.body.style.color = recommendation.color;
+ * });
+ * ```
+ */
+export async function onForegroundChanged(
+  handler: (payload: ForegroundChanged) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<ForegroundChanged>(FOREGROUND_CHANGED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/** Event emitted when the native frame of a glass view changes */
+export const FRAME_CHANGED_EVENT = "liquid-glass://frame-changed";
+
+/**
+ * Listen for native moves and resizes of the current window's glass views
+ *
+ * Fires for changes the frontend did not initiate too, such as autoresizing
+ * with the window or a relayout after a display change.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onFrameChanged(({ region, frame, resized }) => {
+ *   if (region === "sidebar" && resized) {
+ *     content.style.paddingLeft = `${frame.css.width}px`;
+ *   }
+ * });
+ * ```
+ */
+export async function onFrameChanged(
+  handler: (payload: FrameChanged) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<FrameChanged>(FRAME_CHANGED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/** Event emitted when a glass view is modified outside the plugin */
+export const GLASS_EXTERNALLY_MODIFIED_EVENT = "liquid-glass://glass-externally-modified";
+
+/**
+ * Listen for changes other code makes to the current window's glass views
+ *
+ * Fires when the tint, variant or corner radius of a native glass view is
+ * changed by something other than this plugin. The plugin's stored config
+ * has already been updated to match when the handler runs.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onGlassExternallyModified(({ region, properties }) => {
+ *   console.warn(`glass ${region ?? "window"} modified externally:`, properties);
+ * });
+ * ```
+ */
+export async function onGlassExternallyModified(
+  handler: (payload: GlassExternallyModified) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<GlassExternallyModified>(GLASS_EXTERNALLY_MODIFIED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/** Event emitted when a window's effective appearance changes */
+export const APPEARANCE_CHANGED_EVENT = "liquid-glass://appearance-changed";
+
+/**
+ * Listen for changes to the current window's effective appearance
+ *
+ * Follows the appearance the glass is rendered with, including per-window
+ * overrides that `prefers-color-scheme` does not reflect.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onAppearanceChanged(({ appearance }) => {
+ *   document.documentElement.dataset.appearance = appearance;
+ * });
+ * ```
+ */
+export async function onAppearanceChanged(
+  handler: (payload: AppearanceChanged) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<AppearanceChanged>(APPEARANCE_CHANGED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/** Event emitted when a window with key-state-following glass becomes or resigns key */
+export const KEY_STATE_CHANGED_EVENT = "liquid-glass://key-state-changed";
+
+/**
+ * Listen for the current window becoming or resigning key
+ *
+ * Only fires while the window has fallback glass with `keyStateTransitionMs`
+ * set. The native views crossfade over `durationMs`, so use it to dim web
+ * content in lockstep.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onKeyStateChanged(({ key, durationMs }) => {
+ *   document.body.style.transition = `opacity ${durationMs}ms`;
+ *   document.body.style.opacity = key ? "1" : "0.6";
+ * });
+ * ```
+ */
+export async function onKeyStateChanged(
+  handler: (payload: KeyStateChanged) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<KeyStateChanged>(KEY_STATE_CHANGED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/** Event emitted when applying glass ends up on NSVisualEffectView */
+export const FALLBACK_EVENT = "liquid-glass://fallback";
+
+/**
+ * Listen for glass applied to the current window falling back to
+ * NSVisualEffectView
+ *
+ * Fires after every apply that renders with the fallback, so web content
+ * can switch to a higher-contrast stylesheet right away.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onFallback(({ reason }) => {
+ *   document.documentElement.dataset.glass = "fallback";
+ *   console.info("glass fallback:", reason);
+ * });
+ * ```
+ */
+export async function onFallback(
+  handler: (payload: GlassFallback) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<GlassFallback>(FALLBACK_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/**
+ * Event emitted when glass is created or updated on a window
+ */
+export const APPLIED_EVENT = "liquid-glass://applied";
+
+/**
+ * Event emitted when glass is removed from a window
+ */
+export const REMOVED_EVENT = "liquid-glass://removed";
+
+/**
+ * Listen for glass being created or updated on the current window
+ *
+ * Fires after every successful change, from the frontend or Rust, with the
+ * applied config and the backend the view renders with, so web content can
+ * match its styling to the glass on screen.
+ *
+ * @returns A function that removes the listener
+ *
+ * @example
+ * ```typescript
+ * const unlisten = await onGlassApplied(({ region, backend }) => {
+ *   if (region === null) document.documentElement.dataset.glass = backend ?? "none";
+ * });
+ * ```
+ */
+export async function onGlassApplied(
+  handler: (payload: GlassApplied) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<GlassApplied>(APPLIED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/**
+ * Listen for glass being removed from the current window
+ *
+ * @returns A function that removes the listener
+ */
+export async function onGlassRemoved(
+  handler: (payload: GlassRemoved) => void
+): Promise<UnlistenFn> {
+  const label = getCurrentWindow().label;
+  return listen<GlassRemoved>(REMOVED_EVENT, (event) => {
+    if (event.payload.window === label) {
+      handler(event.payload);
+    }
+  });
+}
+
+/**
+ * List every glass view tracked by the plugin, across all windows
+ *
+ * Needs the `liquid-glass:diagnostics` permission set.
+ */
+export async function listGlassViews(): Promise<GlassViewInfo[]> {
+  return invoke<GlassViewInfo[]>(`plugin:${PLUGIN_NAME}|list_glass_views`);
+}
+
+/**
+ * Diagnose why glass may not show in a window
+ *
+ * Reports OS support, window opacity, whether the webview draws its
+ * background, the title bar style and the window's glass views, plus a
+ * plain-language list of problems found. Needs the
+ * `liquid-glass:diagnostics` permission set.
+ *
+ * @param label Window to diagnose, the current one by default
+ *
+ * @example
+ * ```typescript
+ * const report = await liquidGlassDoctor();
+ * report.problems.forEach((problem) => console.warn(problem));
+ * ```
+ */
+export async function liquidGlassDoctor(label?: string): Promise<GlassDoctorReport> {
+  return invoke<GlassDoctorReport>(`plugin:${PLUGIN_NAME}|liquid_glass_doctor`, { label });
+}
+
+/**
+ * Dump the view hierarchy under the current window's content view
+ *
+ * Shows where glass views sit relative to the webview. The command only
+ * exists when the plugin is built with the `devtools` feature, and is not
+ * in the default permission set: grant `liquid-glass:allow-dump-view-hierarchy`
+ * in a development capability.
+ *
+ * @example
+ * ```typescript
+ * console.log(JSON.stringify(await dumpViewHierarchy(), null, 2));
+ * ```
+ */
+export async function dumpViewHierarchy(): Promise<ViewNode> {
+  return invoke<ViewNode>(`plugin:${PLUGIN_NAME}|dump_view_hierarchy`);
+}
+
+/**
+ * List the full-window glass config of every window
+ *
+ * Configs are returned as applied, in points; regions are not included.
+ * Empty off macOS. Needs the `liquid-glass:diagnostics` permission set.
+ *
+ * @returns `[label, config]` pairs
+ */
+export async function listEffects(): Promise<Array<[string, LiquidGlassConfig]>> {
+  return invoke<Array<[string, LiquidGlassConfig]>>(`plugin:${PLUGIN_NAME}|list_effects`);
+}
+
+/**
+ * Describe every window's glass views and configs as a versioned document
+ *
+ * Needs the `liquid-glass:diagnostics` permission set.
+ *
+ * @example
+ * ```typescript
+ * const state = await exportGlassState();
+ * const blob = new Blob([JSON.stringify(state, null, 2)], { type: "application/json" });
+ * ```
+ */
+export async function exportGlassState(): Promise<GlassStateDocument> {
+  return invoke<GlassStateDocument>(`plugin:${PLUGIN_NAME}|export_glass_state`);
+}
+
+/**
+ * Apply a glass state document from exportGlassState, e.g. a theme file
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * The document is validated and every window resolved before anything
+ * changes; if applying fails part way, changed windows are restored.
+ *
+ * @param document The document, or its JSON text
+ * @param options Window label mapping and handling of missing windows
+ * @returns Labels of the windows changed
+ *
+ * @example
+ * ```typescript
+ * const theme = await (await fetch("/themes/frosted.json")).text();
+ * await importGlassState(theme, { windowLabels: { main: "editor" } });
+ * ```
+ */
+export async function importGlassState(
+  document: GlassStateDocument | string,
+  options?: GlassStateImportOptions
+): Promise<string[]> {
+  return invoke<string[]>(`plugin:${PLUGIN_NAME}|import_glass_state`, {
+    document: typeof document === "string" ? document : JSON.stringify(document),
+    options,
+  });
+}
+
+/**
+ * Check every registered glass view and prune orphans
+ *
+ * Needs the `liquid-glass:app-wide` permission set.
+ *
+ * Views whose window was closed, or that other code detached from the view
+ * hierarchy, are removed from the registry. Useful after hot reloads in long
+ * dev sessions.
+ *
+ * @example
+ * ```typescript
+ * const { orphans } = await validateGlassViews();
+ * if (orphans.length) console.warn("pruned orphaned glass views", orphans);
+ * ```
+ */
+export async function validateGlassViews(): Promise<GlassHealthReport> {
+  return invoke<GlassHealthReport>(`plugin:${PLUGIN_NAME}|validate_glass_views`);
+}
+
+/**
+ * Get the recorded glass effect operations, oldest first
+ *
+ * The log is only recorded when enabled on the Rust side with
+ * `tauri_plugin_liquid_glass::Builder::new().audit_log(capacity)`. It covers
+ * every window, so it needs the `liquid-glass:diagnostics` permission set.
+ *
+ * @returns Recent operations, or an empty list if the audit log is disabled
+ *
+ * @example
+ * ```typescript
+ * const log = await getGlassAuditLog();
+ * console.table(log.filter((e) => e.changes.some((c) => c.field === "tintColor")));
+ * ```
+ */
+export async function getGlassAuditLog(): Promise<AuditEntry[]> {
+  return invoke<AuditEntry[]>(`plugin:${PLUGIN_NAME}|get_glass_audit_log`);
+}
+
+/**
+ * Get the counters of the main-thread flood guard
+ *
+ * Effect, named view and scroll-edge updates share a per-frame budget of
+ * main-thread operations (`Builder::main_thread_budget` on the Rust side).
+ * Updates over budget are parked until the next frame, where a newer update
+ * to the same target replaces the parked one. A parked update resolves once
+ * it is applied, and a replaced one with the result of its replacement. Once
+ * too many targets are parked, updates are rejected with a `Throttled` error.
+ * Needs the `liquid-glass:diagnostics` permission set.
+ *
+ * @example
+ * ```typescript
+ * const { merged } = await getMainThreadStats();
+ * if (merged > 0) console.warn("Glass updates are sent faster than frames are drawn");
+ * ```
+ */
+export async function getMainThreadStats(): Promise<MainThreadStats> {
+  return invoke<MainThreadStats>(`plugin:${PLUGIN_NAME}|get_main_thread_stats`);
+}
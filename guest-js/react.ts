@@ -0,0 +1,149 @@
+/**
+ * React bindings
+ *
+ * `<LiquidGlass>` renders a `div` backed by a native glass view that follows
+ * the element's box, like `bindGlassRegion()`. Each instance claims its own
+ * handle from the plugin on mount and releases it on unmount, so views never
+ * outlive their component, even across StrictMode remounts and page
+ * reloads:
+ *
+ * ```tsx
+ * import { LiquidGlass } from "tauri-plugin-liquid-glass-api/react";
+ *
+ * <LiquidGlass variant="sidebar" cornerRadius={16}>
+ *   <nav>...</nav>
+ * </LiquidGlass>
+ * ```
+ *
+ * Where `usesCssGlassPolyfill()` is true the element is styled with
+ * `applyCssGlass()` instead, so the same markup works on every platform.
+ *
+ * @module
+ */
+
+import {
+  createElement,
+  forwardRef,
+  useCallback,
+  useEffect,
+  useRef,
+  type HTMLAttributes,
+} from "react";
+
+import {
+  bindGlassRegion,
+  claimGlassHandle,
+  parseGlassVariant,
+  releaseGlassHandle,
+  usesCssGlassPolyfill,
+} from "./index";
+import {
+  GlassMaterialVariant,
+  GlassRegionBinding,
+  LiquidGlassConfig,
+} from "./types";
+
+/**
+ * Props of {@link LiquidGlass}
+ *
+ * Other props are passed to the rendered `div`.
+ */
+export interface LiquidGlassProps extends HTMLAttributes<HTMLDivElement> {
+  /** Variant number or case-insensitive name, e.g. `"sidebar"` */
+  variant?: GlassMaterialVariant | string;
+  /** Corner radius in points */
+  cornerRadius?: number;
+  /** Tint color as a CSS color string */
+  tintColor?: string;
+  /**
+   * Base glass configuration; `variant`, `cornerRadius` and `tintColor`
+   * take precedence and `frame` is replaced by the element's box
+   */
+  config?: LiquidGlassConfig;
+}
+
+/** `config` with the shorthand props applied */
+function resolveConfig({
+  variant,
+  cornerRadius,
+  tintColor,
+  config,
+}: LiquidGlassProps): LiquidGlassConfig {
+  const resolved: LiquidGlassConfig = { ...config };
+  const parsed = variant === undefined ? undefined : parseGlassVariant(variant);
+  if (parsed !== undefined) resolved.variant = parsed;
+  if (cornerRadius !== undefined) resolved.cornerRadius = cornerRadius;
+  if (tintColor !== undefined) resolved.tintColor = tintColor;
+  return resolved;
+}
+
+/**
+ * A `div` backed by a native glass view that tracks its bounding box
+ *
+ * @example
+ * ```tsx
+ * <LiquidGlass variant="sidebar" cornerRadius={16} className="sidebar">
+ *   <nav>...</nav>
+ * </LiquidGlass>
+ * ```
+ */
+export const LiquidGlass = forwardRef<HTMLDivElement, LiquidGlassProps>(
+  function LiquidGlass(props, ref) {
+    const { variant, cornerRadius, tintColor, config, ...divProps } = props;
+    const glassConfig = resolveConfig({ variant, cornerRadius, tintColor, config });
+    const configKey = JSON.stringify(glassConfig);
+
+    const element = useRef<HTMLDivElement | null>(null);
+    const binding = useRef<GlassRegionBinding | null>(null);
+    const latestConfig = useRef(glassConfig);
+    latestConfig.current = glassConfig;
+
+    useEffect(() => {
+      const target = element.current;
+      if (!target) return;
+      const polyfill = usesCssGlassPolyfill();
+      let mounted = true;
+
+      if (polyfill) {
+        binding.current = bindGlassRegion(target, latestConfig.current);
+      } else {
+        claimGlassHandle()
+          .then((handle) => {
+            if (mounted) {
+              binding.current = bindGlassRegion(
+                target,
+                latestConfig.current,
+                handle
+              );
+            } else {
+              void releaseGlassHandle(handle);
+            }
+          })
+          .catch((e) => console.warn("Failed to claim a glass handle:", e));
+      }
+
+      return () => {
+        mounted = false;
+        const current = binding.current;
+        binding.current = null;
+        current?.unbind();
+        if (current && !polyfill) void releaseGlassHandle(current.id);
+      };
+    }, []);
+
+    useEffect(() => {
+      binding.current?.setConfig(latestConfig.current);
+    }, [configKey]);
+
+    const setRef = useCallback(
+      (node: HTMLDivElement | null) => {
+        element.current = node;
+        if (typeof ref === "function") ref(node);
+        else if (ref) ref.current = node;
+      },
+      [ref]
+    );
+
+    return createElement("div", { ...divProps, ref: setRef });
+  }
+);
@@ -0,0 +1,70 @@
+/**
+ * Svelte bindings
+ *
+ * The `liquidGlass` action backs an element with a native glass view that
+ * follows its box, like `bindGlassRegion()`. Each element claims its own
+ * handle from the plugin and releases it when destroyed, and the view is
+ * streamed over a channel, so it is put back on the element after native
+ * relayouts and recreated if removed elsewhere:
+ *
+ * ```svelte
+ * <script>
+ *   import { liquidGlass } from "tauri-plugin-liquid-glass-api/svelte";
+ * </script>
+ *
+ * <nav use:liquidGlass={{ variant: "sidebar", cornerRadius: 16 }}>...</nav>
+ * ```
+ *
+ * Where `usesCssGlassPolyfill()` is true the element is styled with
+ * `applyCssGlass()` instead, so the same markup works on every platform.
+ *
+ * @module
+ */
+
+import { bindOwnedGlassRegion } from "./index";
+import { resolveGlassBindingConfig, GlassBindingOptions } from "./binding";
+
+export { GlassBindingOptions } from "./binding";
+
+/**
+ * Object returned by the {@link liquidGlass} action, as Svelte expects
+ */
+export interface LiquidGlassActionReturn {
+  /** Called by Svelte when the action's parameter changes */
+  update(options?: GlassBindingOptions): void;
+  /** Called by Svelte when the element is removed */
+  destroy(): void;
+}
+
+/**
+ * Svelte action backing an element with a native glass view
+ *
+ * @param node Element the action is used on
+ * @param options Glass configuration with optional shorthands
+ *
+ * @example
+ * ```svelte
+ * <header use:liquidGlass={{ variant: "regular", cornerRadius: 12 }} />
+ * ```
+ */
+export function liquidGlass(
+  node: HTMLElement,
+  options: GlassBindingOptions = {}
+): LiquidGlassActionReturn {
+  const binding = bindOwnedGlassRegion(
+    node,
+    resolveGlassBindingConfig(options)
+  );
+  let key = JSON.stringify(options);
+  return {
+    update(next: GlassBindingOptions = {}) {
+      const nextKey = JSON.stringify(next);
+      if (nextKey === key) return;
+      key = nextKey;
+      binding.setConfig(resolveGlassBindingConfig(next));
+    },
+    destroy() {
+      binding.unbind();
+    },
+  };
+}
@@ -4,15 +4,373 @@
  * All fields are optional and have sensible defaults.
  * Pass an empty object `{}` to enable the effect with default settings.
  */
+/**
+ * Unit of a config's corner radius and frame lengths
+ *
+ * - `points`: AppKit points, which equal CSS pixels in the webview at 100% zoom
+ * - `pixels`: device pixels, e.g. measured on a screenshot or from a design
+ *   exported at @2x; divided by the window's scale factor
+ */
+export type Units = "points" | "pixels";
+
 export interface LiquidGlassConfig {
+  /**
+   * Schema version the config was written against (default: 1). Older
+   * versions are migrated; newer ones than the plugin supports are rejected.
+   */
+  version?: number;
   /** Whether the glass effect is enabled (default: true) */
   enabled?: boolean;
-  /** Corner radius for the glass view in pixels (default: 0) */
+  /** Corner radius for the glass view, in `units` (default: 0) */
   cornerRadius?: number;
-  /** Tint color in hex format (#RRGGBB or #RRGGBBAA) */
+  /**
+   * Radius of each corner, e.g. only the top ones for sheet-style panels.
+   * Overrides `cornerRadius`; yields to `mask` and wins over `windowShape`.
+   */
+  cornerRadii?: CornerRadii;
+  /**
+   * Corner shape: "circular" arcs (default) or the "continuous" squircle
+   * curve of system UI. Per-corner radii, masks and shadows stay circular.
+   */
+  cornerCurve?: CornerCurve;
+  /**
+   * Tint color as a CSS color string: hex (#RGB, #RRGGBB, #RRGGBBAA),
+   * rgb()/rgba() (e.g. `rgb(255 255 255 / 0.2)`), hsl()/hsla() or a named color
+   */
   tintColor?: string;
   /** Glass material variant - experimental (default: Regular) */
   variant?: GlassMaterialVariant;
+  /** Additional tint layers composed above the glass, bottom-most first */
+  tintLayers?: TintLayer[];
+  /**
+   * NSVisualEffectView material used by the fallback before macOS 26
+   * (default: "underWindowBackground"). Native glass ignores this.
+   */
+  fallbackMaterial?: FallbackMaterial;
+  /**
+   * What the fallback blurs: "behindWindow" (default) or "withinWindow",
+   * needed when the glass is layered above other native views
+   */
+  fallbackBlendingMode?: "behindWindow" | "withinWindow";
+  /**
+   * Pin the fallback's state. Unset keeps it active, or follows key status
+   * with `keyStateTransitionMs`; any value overrides that.
+   */
+  fallbackState?: "active" | "inactive" | "followsWindowActiveState";
+  /**
+   * Darken the content behind the glass (experimental). Unset leaves the
+   * view's current state; ignored where unsupported.
+   */
+  scrim?: boolean;
+  /**
+   * Render the subdued glass appearance (experimental). Unset leaves the
+   * view's current state; ignored where unsupported.
+   */
+  subdued?: boolean;
+  /**
+   * Highlight the glass under the pointer and when pressed, like system
+   * controls (experimental). Only pointer input that reaches the glass view
+   * counts; ignored on the fallback and where unsupported (default: false).
+   */
+  interactive?: boolean;
+  /** Insert an opaque base behind the glass for legibility over bright desktops (default: false) */
+  opaque?: boolean;
+  /** Color of the opaque base as a CSS color string (default: the window's background color) */
+  opaqueColor?: string;
+  /** Hairline border drawn along the glass edge */
+  rim?: GlassRim;
+  /** Drop shadow cast by the glass onto the content behind it */
+  shadow?: GlassShadow;
+  /** Frame of the glass within the content view (default: fill the window) */
+  frame?: GlassFrame;
+  /**
+   * Unit of `cornerRadius`, rim and shadow sizes and the frame's numeric
+   * lengths (default: "points").
+   * Pixel values are divided by the window's scale factor when applied.
+   */
+  units?: Units;
+  /** Only show the glass while the window matches this size condition */
+  visibleWhen?: SizeCondition;
+  /** Clip the glass to a custom-shaped window's silhouette, kept in sync on resize */
+  windowShape?: WindowShape;
+  /**
+   * Clip the glass to a shape within its own frame, e.g. a capsule for pill
+   * buttons. Takes precedence over `windowShape`; the shadow follows it.
+   */
+  mask?: GlassMask;
+  /**
+   * Stack the glass below the webview (default) or above it for HUD
+   * overlays. Glass above the webview passes clicks through to it.
+   */
+  placement?: GlassPlacement;
+  /**
+   * Let the glass join the window's key view loop and draw a focus ring.
+   * When false (the default) it refuses focus so Tab stays in the webview.
+   */
+  focusable?: boolean;
+  /**
+   * Make the window and webview transparent when the glass is applied: a
+   * clear, non-opaque window and a webview that draws no background or
+   * under-page color. When false (the default) opaque ones are only warned
+   * about.
+   */
+  autoTransparency?: boolean;
+  /**
+   * Make the NSVisualEffectView fallback follow the window's key status,
+   * crossfading to the inactive (desaturated) look over this many
+   * milliseconds when the window resigns key. Pair with `onKeyStateChanged`
+   * to dim web content in lockstep. Native glass ignores this.
+   */
+  keyStateTransitionMs?: number;
+  /**
+   * Animate changes to an existing view (frame, corner radius, tint) instead
+   * of applying them instantly. New views always appear without animation.
+   */
+  animation?: AnimationConfig;
+  /**
+   * System backdrop used on Windows 11, where the whole window gets the
+   * material and the other settings don't apply. Ignored on macOS
+   * (default: `"mica"`).
+   */
+  windowsBackdrop?: WindowsBackdrop;
+  /**
+   * Free-form JSON attached to the view for debugging (component name, owner
+   * route). Returned by `listGlassViews` and included in view events; never
+   * affects rendering. At most 4 KiB once serialized.
+   */
+  metadata?: unknown;
+}
+
+/**
+ * Partial update of the current window's glass config
+ *
+ * Only fields that are present change. `null` clears a field that is
+ * optional in the config (e.g. `tintColor`); a missing field keeps its
+ * value. Lengths are in the patch's `units`, points if unset.
+ */
+export type LiquidGlassPatch = {
+  [K in Exclude<keyof LiquidGlassConfig, "version">]?: LiquidGlassConfig[K] | null;
+};
+
+/**
+ * Pacing of a config transition, after Core Animation's named curves
+ */
+export type TimingFunction =
+  | "linear"
+  | "easeIn"
+  | "easeOut"
+  | "easeInOut"
+  | "default";
+
+/**
+ * Transition used when a config is applied to an existing view
+ */
+export interface AnimationConfig {
+  /** Length of the transition in milliseconds; 0 applies instantly (default: 250) */
+  durationMs?: number;
+  /** Pacing of the transition (default: `"easeInOut"`) */
+  timing?: TimingFunction;
+}
+
+/** Where a glass view is stacked relative to the webview */
+export type GlassPlacement = "below" | "above";
+
+/**
+ * Windows 11 system backdrop material
+ *
+ * - `mica`: tinted with the desktop wallpaper, for long-lived main windows
+ * - `acrylic`: a blur of what is behind the window, for transient surfaces
+ * - `tabbed`: Mica Alt, a stronger wallpaper tint, for tabbed title bars
+ */
+export type WindowsBackdrop = "mica" | "acrylic" | "tabbed";
+
+/**
+ * Silhouette of a custom-shaped (usually borderless) window
+ *
+ * - `auto`: rounded rectangle using the window's own corner radius
+ * - `rounded`: rounded rectangle covering the content view
+ * - `path`: SVG path data (no arcs) in content view points, top-left origin,
+ *   optionally scaled from `viewBox` ([width, height]) to the content size
+ */
+export type WindowShape =
+  | { type: "auto" }
+  | { type: "rounded"; radius: number }
+  | { type: "path"; d: string; viewBox?: [number, number] };
+
+/** NSVisualEffectView material of the fallback backend */
+export type FallbackMaterial =
+  | "titlebar"
+  | "selection"
+  | "menu"
+  | "popover"
+  | "sidebar"
+  | "headerView"
+  | "sheet"
+  | "windowBackground"
+  | "hudWindow"
+  | "fullScreenUi"
+  | "tooltip"
+  | "contentBackground"
+  | "underWindowBackground"
+  | "underPageBackground";
+
+/** Curve of rounded corners */
+export type CornerCurve = "circular" | "continuous";
+
+/**
+ * Radius of each corner of a glass view, in the config's `units` (default: 0)
+ */
+export interface CornerRadii {
+  topLeft?: number;
+  topRight?: number;
+  bottomLeft?: number;
+  bottomRight?: number;
+}
+
+/**
+ * Shape a glass view is clipped to, in the view's own coordinates
+ *
+ * - `roundedRect`: rounded rectangle filling the view
+ * - `capsule`: pill with half the shorter side as radius
+ * - `circle`: largest circle centered in the view
+ * - `path`: SVG path data (no arcs) in the view's points, top-left origin,
+ *   optionally scaled from `viewBox` ([width, height]) to the view's size
+ */
+export type GlassMask =
+  | { type: "roundedRect"; radius: number }
+  | { type: "capsule" }
+  | { type: "circle" }
+  | { type: "path"; d: string; viewBox?: [number, number] };
+
+/**
+ * Content view size range in points, used for responsive glass
+ *
+ * All bounds are inclusive; unset bounds are unconstrained. Evaluated natively
+ * on every resize.
+ */
+export interface SizeCondition {
+  minWidth?: number;
+  maxWidth?: number;
+  minHeight?: number;
+  maxHeight?: number;
+}
+
+/**
+ * A length in points, or a percentage of the content view such as `"50%"`
+ */
+export type Length = number | `${number}%`;
+
+/**
+ * Edge a framed glass view is pinned to
+ */
+export type Anchor = "top" | "bottom" | "leading" | "trailing" | "center";
+
+/**
+ * Frame of a glass view within the window's content view
+ *
+ * Works like CSS absolute positioning: set both insets of an axis to stretch
+ * between them, or one inset plus the size to pin to that edge. Unset insets
+ * default to 0 and an unset size fills the remaining space. With an `anchor`,
+ * the view is pinned to that edge and centered along it, and insets act as
+ * margins. Resolved natively, including on window resize.
+ *
+ * @example
+ * ```typescript
+ * // Toolbar strip
+ * { top: 0, left: 0, right: 0, height: 52 }
+ * // Sidebar covering a quarter of the window
+ * { top: 0, bottom: 0, left: 0, width: "25%" }
+ * // Dock pinned to the bottom
+ * { anchor: "bottom", bottom: 12, width: "60%", maxWidth: 720, height: 64 }
+ * ```
+ */
+export interface GlassFrame {
+  /** Edge to pin to; "leading"/"trailing" are left/right */
+  anchor?: Anchor;
+  left?: Length;
+  top?: Length;
+  right?: Length;
+  bottom?: Length;
+  width?: Length;
+  height?: Length;
+  minWidth?: Length;
+  maxWidth?: Length;
+  minHeight?: Length;
+  maxHeight?: Length;
+  /**
+   * Position and size the view within the content view's safe area, e.g.
+   * below the camera housing of a notched display in fullscreen (default: false)
+   */
+  safeArea?: boolean;
+}
+
+/**
+ * Distances from each edge of the content view to the display's safe area, in points
+ */
+export interface SafeAreaInsets {
+  top: number;
+  left: number;
+  bottom: number;
+  right: number;
+}
+
+/**
+ * Blend modes for tint layers
+ */
+export type BlendMode =
+  | "normal"
+  | "multiply"
+  | "screen"
+  | "overlay"
+  | "softLight"
+  | "hardLight"
+  | "colorDodge"
+  | "colorBurn"
+  | "darken"
+  | "lighten"
+  | "difference"
+  | "exclusion"
+  | "hue"
+  | "saturation"
+  | "color"
+  | "luminosity";
+
+/**
+ * Hairline border drawn along the glass edge, following its corner radius
+ */
+export interface GlassRim {
+  /** Border color as a CSS color string (default: "#FFFFFF33") */
+  color?: string;
+  /** Border width, in the config's units (default: 1) */
+  width?: number;
+}
+
+/**
+ * Drop shadow cast by the glass
+ *
+ * Cut out under the glass, so it never shows through the material.
+ */
+export interface GlassShadow {
+  /** Shadow color as a CSS color string; its alpha sets the opacity (default: "#00000040") */
+  color?: string;
+  /** Blur radius, in the config's units (default: 12) */
+  radius?: number;
+  /** Horizontal offset, positive moves right (default: 0) */
+  offsetX?: number;
+  /** Vertical offset, positive moves down (default: 4) */
+  offsetY?: number;
+}
+
+/**
+ * A tint layer composed above the glass
+ */
+export interface TintLayer {
+  /** Layer color as a CSS color string */
+  color: string;
+  /** How the layer blends with the content beneath it (default: "normal") */
+  blendMode?: BlendMode;
+  /** Layer opacity from 0 to 1, applied on top of the color's alpha (default: 1) */
+  opacity?: number;
 }
 
 /**
@@ -50,3 +408,950 @@ export const GlassMaterialVariant = {
 
 export type GlassMaterialVariant =
   (typeof GlassMaterialVariant)[keyof typeof GlassMaterialVariant];
+
+/**
+ * Detailed runtime support information
+ *
+ * Different macOS 26 builds honor different material variants, so use
+ * `supportedVariants` to hide options that would have no effect.
+ */
+export interface SupportDetails {
+  /** Whether NSGlassEffectView is available */
+  supported: boolean;
+  /** Variants verified to be honored by the running OS build (empty on fallback, or when they can't be read back) */
+  supportedVariants: GlassMaterialVariant[];
+  /** Experimental properties the running OS build implements (empty on fallback) */
+  experimentalProperties: ExperimentalProperty[];
+}
+
+/**
+ * Native glass features usable on the running system, from `getCapabilities()`
+ *
+ * Unlike `GlassCapabilities` (what the webview can render with CSS), this
+ * reports what the native side can do. Everything is false or empty off macOS.
+ */
+export interface NativeCapabilities {
+  /** Backend new glass views are created with, accounting for safe mode; `null` off macOS */
+  backend: GlassBackendKind | null;
+  /** Whether NSGlassEffectView is available */
+  nativeGlass: boolean;
+  /** Whether NSGlassEffectView implements `setTintColor:` */
+  tintColor: boolean;
+  /** Whether NSGlassEffectContainerView is available, so grouped glass merges */
+  glassContainer: boolean;
+  /** Variants verified to be honored by the running OS build */
+  supportedVariants: GlassMaterialVariant[];
+  /** Whether the private `scrimState` setter exists */
+  scrim: boolean;
+  /** Whether the private `subduedState` setter exists */
+  subdued: boolean;
+  /** Whether the private `interactive` setter exists */
+  interactive: boolean;
+  /** Running macOS version, `null` off macOS */
+  macosVersion: MacOSVersion | null;
+}
+
+/**
+ * Which NSGlassEffectView selectors the running OS build responds to
+ *
+ * Empty off macOS or when NSGlassEffectView is missing.
+ */
+export interface PrivateApiReport {
+  /** Whether NSGlassEffectView is available */
+  classAvailable: boolean;
+  /** One probe per property the plugin sets by selector lookup */
+  properties: SelectorProbe[];
+}
+
+/**
+ * Selectors found for one NSGlassEffectView property
+ */
+export interface SelectorProbe {
+  /** Property key, e.g. `scrimState` */
+  key: string;
+  /** Responding setter (`set_<key>:` preferred over `setKey:`); `null` means setting it has no effect */
+  setter: string | null;
+  /** Responding getter (`_<key>` preferred over `<key>`) */
+  getter: string | null;
+}
+
+/**
+ * Experimental NSGlassEffectView properties, set through private selectors
+ */
+export type ExperimentalProperty = "scrim" | "subdued" | "interactive";
+
+/**
+ * Version of the running macOS
+ */
+export interface MacOSVersion {
+  major: number;
+  minor: number;
+  patch: number;
+  /** Whether this is macOS 26 (Tahoe) or later, where NSGlassEffectView ships */
+  isTahoeOrLater: boolean;
+}
+
+/**
+ * A single changed configuration field in an audit entry
+ */
+export interface ConfigChange {
+  /** Field name (camelCase, as in LiquidGlassConfig) */
+  field: string;
+  /** Previous value, null if the window had no recorded config */
+  from: unknown;
+  /** New value */
+  to: unknown;
+}
+
+/**
+ * A recorded glass effect operation
+ */
+export interface AuditEntry {
+  /** Milliseconds since the Unix epoch */
+  timestamp: number;
+  /** Label of the affected window */
+  window: string;
+  /** Id of the affected named view, null for the full-window glass */
+  region: string | null;
+  /**
+   * Fields that differ from the last successfully applied config; a removal
+   * shows as `enabled` changing to `false`
+   */
+  changes: ConfigChange[];
+  /** Error message if the operation failed */
+  error: string | null;
+}
+
+/**
+ * A window and optionally one of its named views, see `setGlass`,
+ * `getGlass` and `removeGlass`
+ */
+export interface GlassTarget {
+  /** Label of the window (default: the current window) */
+  label?: string;
+  /**
+   * Id of a named view; omit for the full-window glass (or, for
+   * `removeGlass`, all of the window's glass)
+   */
+  id?: string;
+}
+
+/**
+ * What `removeGlass` removes
+ */
+export type RemoveGlassOptions = GlassTarget;
+
+/**
+ * An element tracked by a glass view, see `bindGlassRegion`
+ */
+export interface GlassRegionBinding {
+  /** Id of the glass view */
+  readonly id: string;
+  /** Replace the glass configuration; the element's box stays the frame */
+  setConfig(config: LiquidGlassConfig): void;
+  /** Stop tracking the element and remove its glass view */
+  unbind(): void;
+}
+
+/**
+ * Binding returned by `bindOwnedGlassRegion`
+ */
+export interface OwnedGlassRegionBinding {
+  /** Resolves to the claimed handle, which is the view id */
+  readonly handle: Promise<string>;
+  /** Replace the glass configuration; the element's box stays the frame */
+  setConfig(config: LiquidGlassConfig): void;
+  /** Stop tracking the element, remove its glass view and release the handle */
+  unbind(): void;
+}
+
+/**
+ * Rect stream returned by `openGlassRectChannel`
+ */
+export interface GlassRectChannel {
+  /** Event the rects are emitted on */
+  readonly event: string;
+  /** Move the view to a rect in points, relative to the window's content */
+  send(rect: ViewRect): void;
+  /** Close the stream; resolves to whether it was still open */
+  close(): Promise<boolean>;
+}
+
+/**
+ * Message streamed over a channel attached with `attachRegionToChannel`
+ */
+export type RegionMessage =
+  | {
+      type: "frame";
+      /** The view's new frame */
+      frame: GlassViewFrame;
+    }
+  | {
+      /** The view was removed; no more messages follow */
+      type: "removed";
+    };
+
+/**
+ * Counters of the main-thread flood guard, see `getMainThreadStats`
+ */
+export interface MainThreadStats {
+  /** Updates dispatched to the main thread, counting parked ones once applied */
+  dispatched: number;
+  /** Updates over the per-frame budget, parked until the next frame */
+  deferred: number;
+  /** Parked updates replaced by a newer update to the same target */
+  merged: number;
+  /** Updates rejected because too many targets were parked */
+  dropped: number;
+}
+
+/**
+ * NSWindow level for overlay glass windows
+ */
+export type WindowLevel =
+  | "normal"
+  | "floating"
+  | "modalPanel"
+  | "mainMenu"
+  | "status"
+  | "popUpMenu"
+  | "screenSaver";
+
+/**
+ * Window level and collection behavior for overlay glass windows
+ *
+ * To keep a glass overlay above full-screen apps, combine a high `level`
+ * with `fullScreenAuxiliary` and `canJoinAllSpaces`.
+ */
+export interface WindowLevelConfig {
+  /** Window level (default: "normal") */
+  level?: WindowLevel;
+  /** Show the window on every Space (default: false) */
+  canJoinAllSpaces?: boolean;
+  /** Allow the window to appear over full-screen windows (default: false) */
+  fullScreenAuxiliary?: boolean;
+  /** Keep the window in place during Mission Control (default: false) */
+  stationary?: boolean;
+  /** Exclude the window from the Cmd+` window cycle (default: false) */
+  ignoresCycle?: boolean;
+}
+
+/**
+ * Configuration for a fullscreen media-controls overlay window
+ */
+export interface MediaControlsConfig {
+  /** Width of the control bar in logical pixels (default: 60% of the monitor width) */
+  width?: number;
+  /** Height of the control bar in logical pixels (default: 64) */
+  height?: number;
+  /** Distance from the bottom edge of the monitor in logical pixels (default: 24) */
+  bottomMargin?: number;
+  /** Hide after this many milliseconds without a reveal; null disables auto-hide (default: 3000) */
+  autoHideAfterMs?: number | null;
+  /** Glass configuration for the control bar (default: Avplayer variant, 16px corners) */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://media-controls-visibility` event
+ */
+export interface MediaControlsVisibility {
+  /** Label of the media-controls window */
+  window: string;
+  /** Whether the controls are now visible */
+  visible: boolean;
+}
+
+/**
+ * Configuration for a glass context menu window
+ */
+export interface ContextMenuConfig {
+  /** Width of the menu in logical pixels (default: 220) */
+  width?: number;
+  /** Height of the menu in logical pixels (default: 240) */
+  height?: number;
+  /** Corner radius of the menu window (default: 10) */
+  cornerRadius?: number;
+  /** Gap between the cursor and the menu in logical pixels (default: 2) */
+  offset?: number;
+  /** Glass configuration for the menu (default: the Popover preset) */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Why a context menu was dismissed
+ * - `blur`: the menu lost focus, e.g. the user clicked elsewhere
+ * - `dismissed`: the app dismissed it with `dismissContextMenu`
+ */
+export type ContextMenuDismissReason = "blur" | "dismissed";
+
+/**
+ * Payload of the `liquid-glass://context-menu-dismissed` event
+ */
+export interface ContextMenuDismissed {
+  /** Label of the context menu window */
+  window: string;
+  /** Why the menu was dismissed */
+  reason: ContextMenuDismissReason;
+}
+
+/**
+ * Configuration for a menubar panel window
+ */
+export interface MenubarPanelConfig {
+  /** Width of the panel in logical pixels (default: 360) */
+  width?: number;
+  /** Height of the panel in logical pixels (default: 420) */
+  height?: number;
+  /** Gap between the menu bar and the panel in logical pixels (default: 4) */
+  gap?: number;
+  /** Corner radius of the panel window (default: 12) */
+  cornerRadius?: number;
+  /** Hide the panel when it loses focus (default: true) */
+  hideOnBlur?: boolean;
+  /** Glass configuration for the panel (default: the Popover preset) */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://menubar-panel-visibility` event
+ */
+export interface MenubarPanelVisibility {
+  /** Label of the panel window */
+  window: string;
+  /** Id of the tray item the panel is attached to */
+  tray: string;
+  /** Whether the panel is now visible */
+  visible: boolean;
+}
+
+/**
+ * What a toast shows: body HTML rendered in a plugin-created window, or the
+ * label of an app-created window
+ */
+export type ToastContent = { html: string } | { window: string };
+
+/**
+ * Corner of a monitor's work area, for toasts and picture-in-picture windows
+ */
+export type ScreenCorner = "topLeft" | "topRight" | "bottomLeft" | "bottomRight";
+
+/**
+ * Configuration for a glass sheet
+ */
+export interface SheetConfig {
+  /** Width of the sheet in logical pixels (default: the window's current width) */
+  width?: number;
+  /** Height of the sheet in logical pixels (default: the window's current height) */
+  height?: number;
+  /** Corner radius of the sheet window (default: 16) */
+  cornerRadius?: number;
+  /** Glass configuration for the sheet */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://sheet-visibility` event
+ */
+export interface SheetVisibility {
+  /** Label of the sheet window */
+  window: string;
+  /** Label of the window the sheet is attached to */
+  parent: string;
+  /** Whether the sheet is now presented */
+  visible: boolean;
+}
+
+/**
+ * Configuration for a picture-in-picture window
+ */
+export interface PipConfig {
+  /** Width of the window in logical pixels (default: 320) */
+  width?: number;
+  /** Height of the window in logical pixels (default: 180) */
+  height?: number;
+  /** Corner the window starts in (default: "bottomRight") */
+  corner?: ScreenCorner;
+  /** Distance from the edges of the work area in logical pixels (default: 16) */
+  margin?: number;
+  /** Corner radius of the window (default: 12) */
+  cornerRadius?: number;
+  /** Duration of the snap animation in milliseconds (default: 180) */
+  snapAnimationMs?: number;
+  /** Window level and collection behavior (default: floating on every Space and over full-screen apps) */
+  level?: WindowLevelConfig;
+  /** Glass configuration for the window (default: the Hud preset) */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://pip-snapped` event
+ */
+export interface PipSnapped {
+  /** Label of the picture-in-picture window */
+  window: string;
+  /** Corner the window snapped to */
+  corner: ScreenCorner;
+}
+
+/**
+ * Options for a glass toast
+ */
+export interface ToastOptions {
+  /** HTML or window to show */
+  content: ToastContent;
+  /** Dismiss the toast after this many milliseconds; 0 keeps it until dismissed (default: 4000) */
+  durationMs?: number;
+  /** Corner of the primary monitor's work area (default: "topRight") */
+  position?: ScreenCorner;
+  /** Width of the toast in logical pixels (default: 340) */
+  width?: number;
+  /** Height of the toast in logical pixels (default: 76) */
+  height?: number;
+  /** Distance from the edges of the work area in logical pixels (default: 16) */
+  margin?: number;
+  /** Gap between stacked toasts in logical pixels (default: 8) */
+  spacing?: number;
+  /** Corner radius of the toast window (default: 16) */
+  cornerRadius?: number;
+  /** Duration of the enter, exit and restacking animations in milliseconds (default: 200) */
+  animationMs?: number;
+  /** Glass configuration for the toast (default: the Popover preset) */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Why a toast was dismissed
+ */
+export type ToastDismissReason = "timeout" | "dismissed";
+
+/**
+ * Payload of the `liquid-glass://toast-dismissed` event
+ */
+export interface ToastDismissed {
+  /** Label of the toast window */
+  window: string;
+  /** Why the toast was dismissed */
+  reason: ToastDismissReason;
+}
+
+/**
+ * Configuration for a Spotlight-style launcher window
+ */
+export interface LauncherConfig {
+  /** Fixed width of the palette in logical pixels (default: 680) */
+  width?: number;
+  /** Smallest height the palette shrinks to, e.g. just the search field (default: 56) */
+  minHeight?: number;
+  /** Largest height the palette grows to with its content (default: 480) */
+  maxHeight?: number;
+  /** Distance of the top edge below the top of the work area, as a fraction of its height (default: 0.22) */
+  topFraction?: number;
+  /** Corner radius of the palette window (default: 20) */
+  cornerRadius?: number;
+  /** Dismiss the palette when it loses focus (default: true) */
+  dismissOnBlur?: boolean;
+  /** Hide the app's Dock icon so summoning the palette activates nothing else; app-wide (default: true) */
+  hideFromDock?: boolean;
+  /** Global shortcut that toggles the palette, e.g. `CmdOrCtrl+Space`; needs the plugin's `launcher-shortcut` feature */
+  shortcut?: string;
+  /** Glass configuration for the palette */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://launcher-visibility` event
+ */
+export interface LauncherVisibility {
+  /** Label of the launcher window */
+  window: string;
+  /** Whether the launcher is now visible */
+  visible: boolean;
+}
+
+/**
+ * Ready-made glass configurations
+ */
+export type GlassPreset =
+  | "regular"
+  | "clear"
+  | "sidebar"
+  | "inspector"
+  | "popover"
+  | "widget"
+  | "controlCenter"
+  | "hud"
+  | "mediaControls";
+
+/**
+ * Apple components with complete, tuned glass configurations
+ *
+ * - `finderSidebar`: floating source-list sidebar of a Finder window
+ * - `safariToolbar`: toolbar strip across the top of a Safari window
+ * - `controlCenterModule`: rounded module tile of Control Center
+ * - `dock`: the Dock, as a bar floating above the bottom edge
+ */
+export type HigComponent =
+  | "finderSidebar"
+  | "safariToolbar"
+  | "controlCenterModule"
+  | "dock";
+
+/**
+ * A named glass region in a {@link GlassLayout}
+ */
+export interface GlassRegion {
+  /** Unique id of the region within the window, e.g. "sidebar" */
+  id: string;
+  /** Frame of the region within the content view (default: fill) */
+  frame?: GlassFrame;
+  /** Preset used when `config` is not set (default: "regular") */
+  preset?: GlassPreset;
+  /** Full glass configuration; its `frame` is replaced by the region's */
+  config?: LiquidGlassConfig;
+  /** Only show the region while the window matches this condition, e.g. `{ minWidth: 700 }` */
+  when?: SizeCondition;
+  /** Metadata for the region's view, overriding `config.metadata` */
+  metadata?: unknown;
+}
+
+/**
+ * Declarative description of all glass regions in a window
+ *
+ * Regions are stacked in list order, first is bottom-most.
+ */
+export interface GlassLayout {
+  regions: GlassRegion[];
+}
+
+/**
+ * Region ids affected by applying a {@link GlassLayout}
+ */
+export interface LayoutDiff {
+  added: string[];
+  updated: string[];
+  removed: string[];
+  unchanged: string[];
+}
+
+/**
+ * Configuration for the scroll-edge effect
+ *
+ * A glass strip along the top of the window that fades in once content
+ * scrolls under it.
+ */
+export interface ScrollEdgeConfig {
+  /** Height of the strip in points (default: 52) */
+  height?: number;
+  /** Scroll offset at which the strip fades in (default: 8) */
+  showAfter?: number;
+  /** Scroll offset at or below which the strip fades out again (default: 2) */
+  hideBelow?: number;
+  /** Fade duration in milliseconds (default: 150) */
+  fadeDurationMs?: number;
+  /**
+   * Glass configuration for the strip. A `frame` replaces the top-edge
+   * pinning and `height`; it is resolved when set, not on window resize.
+   * `placement` is always `"above"`, so the strip lets clicks through.
+   */
+  glass?: LiquidGlassConfig;
+}
+
+/**
+ * Native view type backing a glass view
+ *
+ * `"visualEffect"` is the NSVisualEffectView fallback, which ignores variants
+ * and may need CSS contrast tweaks.
+ */
+export type GlassBackendKind = "native" | "visualEffect";
+
+/**
+ * Description of a glass view tracked by the plugin
+ */
+export interface GlassViewInfo {
+  /** Label of the window hosting the view */
+  window: string;
+  /** Id of a named view (layout region or `setGlassView`), null for the full-window glass */
+  region: string | null;
+  /** Backend the view was created with */
+  backend: GlassBackendKind;
+  /** Why the view fell back to NSVisualEffectView, null for native views */
+  fallbackReason: FallbackReason | null;
+  /** Configuration currently applied to the view */
+  config: LiquidGlassConfig;
+}
+
+/**
+ * Snapshot of every window's glass views, e.g. to save as a theme file or
+ * attach to a bug report
+ */
+export interface GlassStateDocument {
+  /** Format version (currently 1) */
+  version: number;
+  /** macOS version the state was captured on, null on other platforms */
+  macosVersion: MacOSVersion | null;
+  /** Windows that have glass, ordered by label */
+  windows: WindowGlassState[];
+}
+
+/**
+ * Glass views of one window in a GlassStateDocument
+ */
+export interface WindowGlassState {
+  /** Label of the window */
+  label: string;
+  /** The full-window glass first, if any, then named views in stacking order */
+  views: GlassViewState[];
+}
+
+/**
+ * One glass view in a WindowGlassState
+ */
+export interface GlassViewState {
+  /** Id of a named view, null for the full-window glass */
+  region?: string | null;
+  /** Backend the view was created with */
+  backend?: GlassBackendKind;
+  /** Configuration applied to the view */
+  config: LiquidGlassConfig;
+}
+
+/**
+ * Options for importGlassState
+ */
+export interface GlassStateImportOptions {
+  /** Apply a document window's state to a differently labelled window, keyed by the label in the document */
+  windowLabels?: Record<string, string>;
+  /** Skip document windows that don't exist instead of failing (default: false) */
+  skipMissingWindows?: boolean;
+}
+
+/**
+ * System appearance a glass view is rendered with
+ */
+export type Appearance = "light" | "dark";
+
+/**
+ * Suggested foreground color for content drawn over a glass view
+ *
+ * Estimated from the tint, variant and appearance; the content behind the
+ * glass is unknown, so treat the ratio as approximate.
+ */
+export interface ForegroundRecommendation {
+  /** `#ffffff` or `#000000` */
+  color: string;
+  /** Estimated WCAG contrast ratio of `color` against the glass (1 to 21) */
+  contrastRatio: number;
+  /** Appearance the estimate was made for */
+  appearance: Appearance;
+}
+
+/**
+ * User-chosen system colors, resolved for the app's appearance as `#rrggbbaa`
+ */
+export interface SystemColors {
+  /** Accent color */
+  accent: string;
+  /** Text highlight color */
+  highlight: string;
+  /** Keyboard focus ring color */
+  focusRing: string;
+}
+
+/**
+ * Payload of the system colors changed event
+ */
+export interface SystemColorsChanged {
+  /** The newly resolved colors */
+  colors: SystemColors;
+}
+
+/**
+ * Payload of the effects suspended event
+ */
+export interface EffectsSuspended {
+  /** Whether glass effects are now suspended */
+  suspended: boolean;
+}
+
+/**
+ * Why glass was created with NSVisualEffectView instead of NSGlassEffectView
+ *
+ * - `classUnavailable`: NSGlassEffectView does not exist on this macOS version
+ * - `initFailed`: NSGlassEffectView exists but failed to initialize
+ * - `safeMode`: the plugin was built with `safe_mode(true)`
+ */
+export type FallbackReason = "classUnavailable" | "initFailed" | "safeMode";
+
+/**
+ * Payload of the `liquid-glass://applied` event
+ */
+export interface GlassApplied {
+  /** Label of the window */
+  window: string;
+  /** Id of the named view, `null` for the full-window glass */
+  region: string | null;
+  /** Backend rendering the view, `null` off macOS */
+  backend: GlassBackendKind | null;
+  /** Why the view fell back to NSVisualEffectView, `null` for native views */
+  fallbackReason: FallbackReason | null;
+  /** Configuration now applied, in points */
+  config: LiquidGlassConfig;
+}
+
+/**
+ * Payload of the `liquid-glass://removed` event
+ */
+export interface GlassRemoved {
+  /** Label of the window */
+  window: string;
+  /** Id of the named view, `null` for the full-window glass */
+  region: string | null;
+}
+
+/**
+ * Payload of the `liquid-glass://fallback` event
+ */
+export interface GlassFallback {
+  /** Label of the window the glass was applied to */
+  window: string;
+  /** Why NSVisualEffectView was used */
+  reason: FallbackReason;
+}
+
+/**
+ * Payload of the key state changed event
+ */
+export interface KeyStateChanged {
+  /** Label of the window whose key status changed */
+  window: string;
+  /** Whether the window is now the key window */
+  key: boolean;
+  /** Duration of the native crossfade in milliseconds */
+  durationMs: number;
+}
+
+/**
+ * Payload of the `liquid-glass://foreground-changed` event
+ */
+export interface ForegroundChanged {
+  /** Label of the window hosting the glass view */
+  window: string;
+  /** Region id, or null for the full-window glass */
+  region: string | null;
+  /** Metadata attached to the view, if any */
+  metadata: unknown;
+  recommendation: ForegroundRecommendation;
+}
+
+/**
+ * Payload of the `liquid-glass://appearance-changed` event
+ */
+export interface AppearanceChanged {
+  /** Label of the window whose appearance changed */
+  window: string;
+  /** The new appearance */
+  appearance: Appearance;
+}
+
+/**
+ * A rectangle with a top-left origin
+ */
+export interface ViewRect {
+  x: number;
+  y: number;
+  width: number;
+  height: number;
+}
+
+/**
+ * Core Animation state of a view's backing layer
+ */
+export interface LayerInfo {
+  /** Objective-C class of the layer */
+  class: string;
+  opacity: number;
+  /** Corner radius in points */
+  cornerRadius: number;
+  masksToBounds: boolean;
+  /** Whether the layer has a mask layer, e.g. a window shape or glass mask */
+  hasMask: boolean;
+  /** Whether the layer is marked opaque */
+  opaque: boolean;
+}
+
+/**
+ * A node of a window's view hierarchy, as returned by `dumpViewHierarchy`
+ */
+export interface ViewNode {
+  /** Objective-C class of the view, e.g. `NSGlassEffectView` or `WKWebView` */
+  class: string;
+  /** Frame within the superview in points, with a top-left origin */
+  frame: ViewRect;
+  hidden: boolean;
+  alpha: number;
+  /** Whether the view is a glass view registered by the plugin */
+  glass: boolean;
+  /** Backing layer, null for views without one */
+  layer: LayerInfo | null;
+  /** Subviews, back to front */
+  subviews: ViewNode[];
+}
+
+/**
+ * Current native frame of a glass view, as returned by `getGlassFrame`
+ */
+export interface GlassViewFrame {
+  /** Frame in points, relative to the window's content view */
+  points: ViewRect;
+  /** Frame in CSS pixels relative to the viewport, comparable with `getBoundingClientRect()` */
+  css: ViewRect;
+  /** Physical pixels per point */
+  scaleFactor: number;
+  /** Whether the view is currently hidden, e.g. by `visibleWhen` */
+  hidden: boolean;
+}
+
+/**
+ * Payload of the `liquid-glass://frame-changed` event
+ */
+export interface FrameChanged {
+  /** Label of the window hosting the glass view */
+  window: string;
+  /** Region id, or null for the full-window glass */
+  region: string | null;
+  /** Metadata attached to the view, if any */
+  metadata: unknown;
+  /** The new frame */
+  frame: GlassViewFrame;
+  /** Whether the origin changed */
+  moved: boolean;
+  /** Whether the size changed */
+  resized: boolean;
+}
+
+/**
+ * Glass view property watched for changes made outside the plugin
+ */
+export type ObservedProperty = "tintColor" | "variant" | "cornerRadius";
+
+/**
+ * Payload of the `liquid-glass://glass-externally-modified` event
+ */
+export interface GlassExternallyModified {
+  /** Label of the window hosting the glass view */
+  window: string;
+  /** Region id, or null for the full-window glass */
+  region: string | null;
+  /** Metadata attached to the view, if any */
+  metadata: unknown;
+  /** Properties that no longer matched the stored config */
+  properties: ObservedProperty[];
+  /** The stored config, updated to the view's actual state */
+  config: LiquidGlassConfig;
+}
+
+/**
+ * Why a registered glass view was considered orphaned
+ */
+export type OrphanReason = "windowClosed" | "noSuperview" | "noWindow";
+
+/**
+ * A registry entry pruned by `validateGlassViews`
+ */
+export interface OrphanedView {
+  /** Label of the window the view was registered for */
+  window: string;
+  /** Region id, or null for the full-window glass */
+  region: string | null;
+  reason: OrphanReason;
+}
+
+/**
+ * Result of validating the glass view registry
+ */
+export interface GlassHealthReport {
+  /** Number of registered views that were checked */
+  checked: number;
+  /** Orphaned views that were pruned */
+  orphans: OrphanedView[];
+}
+
+/**
+ * Title bar setup of a window, which decides whether glass reaches the top
+ * edge
+ */
+export interface TitlebarStyle {
+  /** Whether the window has a title bar at all */
+  titled: boolean;
+  /** Whether the content view extends under the title bar */
+  fullSizeContentView: boolean;
+  /** Whether the title bar draws no background of its own */
+  transparent: boolean;
+  /** Whether the title text is hidden */
+  titleHidden: boolean;
+}
+
+/**
+ * Everything that decides whether glass shows in a window, as returned by
+ * `liquidGlassDoctor`
+ */
+export interface GlassDoctorReport {
+  /** Label of the diagnosed window */
+  window: string;
+  /** macOS version, glass class availability and the backend new views get */
+  capabilities: NativeCapabilities;
+  /** Whether all effects are suspended */
+  suspended: boolean;
+  /** Whether the window is opaque, null off macOS */
+  windowOpaque: boolean | null;
+  /** Alpha of the window's background color, null off macOS */
+  backgroundAlpha: number | null;
+  /** Whether the webview draws its own background, null off macOS or without a webview */
+  webviewDrawsBackground: boolean | null;
+  /** Title bar setup, null off macOS */
+  titlebar: TitlebarStyle | null;
+  /** Glass views registered for the window, full-window view first */
+  views: GlassViewInfo[];
+  /** Problems found, in plain language. Empty when nothing looks wrong. */
+  problems: string[];
+}
+
+/**
+ * How glass can be rendered in the current webview, best first
+ *
+ * - `native`: a native material behind a transparent webview (macOS)
+ * - `frosted`: CSS `backdrop-filter` over the page
+ * - `solid`: an opaque fill
+ */
+export type GlassTier = "native" | "frosted" | "solid";
+
+/**
+ * What the current webview can render, as read by `detectGlassCapabilities`
+ */
+export interface GlassCapabilities {
+  /** Whether NSGlassEffectView is available */
+  nativeGlass: boolean;
+  /**
+   * Whether any native material is available (glass, the fallback, a
+   * Windows 11 backdrop or Linux compositor blur)
+   */
+  nativeMaterial: boolean;
+  /** Whether the webview supports CSS `backdrop-filter` */
+  backdropFilter: boolean;
+  /** Whether the user asked for reduced transparency */
+  reducedTransparency: boolean;
+  /** Whether glass effects are suspended app-wide */
+  suspended: boolean;
+}
+
+/**
+ * CSS recommended for a set of capabilities
+ */
+export interface GlassCssRecommendation {
+  tier: GlassTier;
+  /** Class for the tier, e.g. `liquid-glass-frosted` */
+  className: string;
+  /** Custom property values keyed by name, e.g. `--liquid-glass-blur` */
+  properties: Record<string, string>;
+}
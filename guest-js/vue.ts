@@ -0,0 +1,72 @@
+/**
+ * Vue bindings
+ *
+ * The `vLiquidGlass` directive backs an element with a native glass view
+ * that follows its box, like `bindGlassRegion()`. Each element claims its
+ * own handle from the plugin and releases it when unmounted, and the view
+ * is streamed over a channel, so it is put back on the element after native
+ * relayouts and recreated if removed elsewhere:
+ *
+ * ```vue
+ * <script setup>
+ * import { vLiquidGlass } from "tauri-plugin-liquid-glass-api/vue";
+ * </script>
+ *
+ * <template>
+ *   <nav v-liquid-glass="{ variant: 'sidebar', cornerRadius: 16 }">...</nav>
+ * </template>
+ * ```
+ *
+ * Register it app-wide with `app.directive("liquid-glass", vLiquidGlass)`.
+ * Where `usesCssGlassPolyfill()` is true the element is styled with
+ * `applyCssGlass()` instead, so the same markup works on every platform.
+ *
+ * @module
+ */
+
+import { bindOwnedGlassRegion } from "./index";
+import { resolveGlassBindingConfig, GlassBindingOptions } from "./binding";
+import { OwnedGlassRegionBinding } from "./types";
+
+export { GlassBindingOptions } from "./binding";
+
+/** The part of Vue's `DirectiveBinding` the directive reads */
+interface LiquidGlassDirectiveBinding {
+  value: GlassBindingOptions | undefined;
+  oldValue: GlassBindingOptions | undefined | null;
+}
+
+/** Bindings of mounted elements */
+const bindings = new WeakMap<HTMLElement, OwnedGlassRegionBinding>();
+
+/**
+ * Vue directive backing an element with a native glass view
+ *
+ * Structurally an `ObjectDirective<HTMLElement, GlassBindingOptions>`, so it
+ * works with `<script setup>` auto-registration and `app.directive()`.
+ *
+ * @example
+ * ```vue
+ * <header v-liquid-glass="{ variant: 'regular', cornerRadius: 12 }" />
+ * ```
+ */
+export const vLiquidGlass = {
+  mounted(element: HTMLElement, binding: LiquidGlassDirectiveBinding) {
+    bindings.set(
+      element,
+      bindOwnedGlassRegion(element, resolveGlassBindingConfig(binding.value))
+    );
+  },
+  updated(element: HTMLElement, binding: LiquidGlassDirectiveBinding) {
+    if (JSON.stringify(binding.value) === JSON.stringify(binding.oldValue)) {
+      return;
+    }
+    bindings
+      .get(element)
+      ?.setConfig(resolveGlassBindingConfig(binding.value));
+  },
+  unmounted(element: HTMLElement) {
+    bindings.get(element)?.unbind();
+    bindings.delete(element);
+  },
+};
@@ -0,0 +1,22 @@
+//! Appearance change events
+//!
+//! Every webview window is observed from creation, so frontends can follow
+//! the appearance the glass is actually rendered with, including per-window
+//! overrides that CSS media queries don't see.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::Appearance;
+
+/// Event emitted when a window's effective appearance changes
+pub const APPEARANCE_CHANGED_EVENT: &str = "liquid-glass://appearance-changed";
+
+/// Payload of [`APPEARANCE_CHANGED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AppearanceChanged {
+    /// Label of the window whose appearance changed
+    pub window: String,
+    /// The new appearance
+    pub appearance: Appearance,
+}
@@ -0,0 +1,157 @@
+//! In-memory audit log of applied glass effect changes
+//!
+//! Enabled via [`Builder::audit_log`](crate::Builder::audit_log). Keeps a ring
+//! buffer of recent operations so apps with many callers can find out which
+//! one changed a window's effect. Every applied, updated or removed glass
+//! view is recorded, full-window or named, along with failed attempts;
+//! frame-only moves (`sync_glass_rect`, rect streams) are not.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::LiquidGlassConfig;
+
+/// A single changed configuration field
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConfigChange {
+    /// Field name as seen by the frontend (camelCase)
+    pub field: String,
+    /// Previous value, `None` if the window had no recorded config
+    pub from: Option<Value>,
+    /// New value
+    pub to: Value,
+}
+
+/// A recorded glass effect operation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    /// Milliseconds since the Unix epoch
+    pub timestamp: u64,
+    /// Label of the affected window
+    pub window: String,
+    /// Id of the affected named view, `None` for the full-window glass
+    pub region: Option<String>,
+    /// Fields that differ from the last successfully applied config; a
+    /// removal shows as `enabled` changing to `false`
+    pub changes: Vec<ConfigChange>,
+    /// Error message if the operation failed
+    pub error: Option<String>,
+}
+
+#[derive(Default)]
+struct AuditState {
+    entries: VecDeque<AuditEntry>,
+    /// Last applied config per window label and view id
+    last_configs: HashMap<(String, Option<String>), Value>,
+}
+
+/// Ring buffer of recent glass effect operations
+pub(crate) struct AuditLog {
+    capacity: usize,
+    state: Mutex<AuditState>,
+}
+
+impl AuditLog {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: Mutex::new(AuditState::default()),
+        }
+    }
+
+    /// Record an operation and its outcome
+    ///
+    /// `config` is the config applied to the view, `None` if it was removed.
+    pub fn record(
+        &self,
+        window: &str,
+        region: Option<&str>,
+        config: Option<&LiquidGlassConfig>,
+        error: Option<String>,
+    ) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+
+        let key = (window.to_string(), region.map(str::to_string));
+        let old = state.last_configs.get(&key);
+        let new = config.map(|config| serde_json::to_value(config).unwrap_or(Value::Null));
+        let changes = match &new {
+            Some(new) => diff(old, new),
+            None => vec![ConfigChange {
+                field: "enabled".to_string(),
+                from: old.and_then(|old| old.get("enabled")).cloned(),
+                to: Value::Bool(false),
+            }],
+        };
+
+        if error.is_none() {
+            match new {
+                Some(new) => state.last_configs.insert(key, new),
+                None => state.last_configs.remove(&key),
+            };
+        }
+
+        if self.capacity == 0 {
+            return;
+        }
+        if state.entries.len() == self.capacity {
+            state.entries.pop_front();
+        }
+        state.entries.push_back(AuditEntry {
+            timestamp: now_millis(),
+            window: window.to_string(),
+            region: region.map(str::to_string),
+            changes,
+            error,
+        });
+    }
+
+    /// Drop the last applied configs of a destroyed window, so a new window
+    /// reusing its label is diffed from scratch
+    pub fn forget(&self, window: &str) {
+        if let Ok(mut state) = self.state.lock() {
+            state.last_configs.retain(|(label, _), _| label != window);
+        }
+    }
+
+    /// Get all recorded entries, oldest first
+    pub fn entries(&self) -> Vec<AuditEntry> {
+        self.state
+            .lock()
+            .map(|state| state.entries.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Compute the fields of `new` that differ from `old`
+fn diff(old: Option<&Value>, new: &Value) -> Vec<ConfigChange> {
+    let Value::Object(new_fields) = new else {
+        return Vec::new();
+    };
+
+    new_fields
+        .iter()
+        .filter_map(|(field, to)| {
+            let from = old.and_then(|old| old.get(field)).cloned();
+            (from.as_ref() != Some(to)).then(|| ConfigChange {
+                field: field.clone(),
+                from,
+                to: to.clone(),
+            })
+        })
+        .collect()
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
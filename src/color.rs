@@ -0,0 +1,466 @@
+//! CSS color string parsing
+//!
+//! Supports the color formats frontend code naturally produces:
+//! - Hex: `#RGB`, `#RGBA`, `#RRGGBB`, `#RRGGBBAA`
+//! - `rgb()` / `rgba()` in legacy comma and modern space syntax (`rgb(255 255 255 / 0.2)`)
+//! - `hsl()` / `hsla()` with `deg`, `rad`, `grad` and `turn` hue units;
+//!   saturation and lightness are percentages with or without the `%`
+//! - CSS named colors, including `transparent`
+
+/// A color with red, green, blue and alpha components in the 0.0-1.0 range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rgba {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl Rgba {
+    fn from_u8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self {
+            r: r as f64 / 255.0,
+            g: g as f64 / 255.0,
+            b: b as f64 / 255.0,
+            a: a as f64 / 255.0,
+        }
+    }
+}
+
+/// Parse a CSS color string
+///
+/// Returns `None` if the string is not a supported color format.
+pub fn parse_css_color(input: &str) -> Option<Rgba> {
+    let input = input.trim().to_ascii_lowercase();
+
+    if let Some(hex) = input.strip_prefix('#') {
+        return parse_hex(hex);
+    }
+
+    if let Some((name, args)) = split_function(&input) {
+        return match name {
+            "rgb" | "rgba" => parse_rgb(args),
+            "hsl" | "hsla" => parse_hsl(args),
+            _ => None,
+        };
+    }
+
+    named_color(&input)
+}
+
+// ============================================================================
+// Hex
+// ============================================================================
+
+fn parse_hex(hex: &str) -> Option<Rgba> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let value = u32::from_str_radix(hex, 16).ok()?;
+    let nibble = |shift: u32| (((value >> shift) & 0xF) * 0x11) as u8;
+    let byte = |shift: u32| ((value >> shift) & 0xFF) as u8;
+
+    match hex.len() {
+        3 => Some(Rgba::from_u8(nibble(8), nibble(4), nibble(0), 0xFF)),
+        4 => Some(Rgba::from_u8(nibble(12), nibble(8), nibble(4), nibble(0))),
+        6 => Some(Rgba::from_u8(byte(16), byte(8), byte(0), 0xFF)),
+        8 => Some(Rgba::from_u8(byte(24), byte(16), byte(8), byte(0))),
+        _ => None,
+    }
+}
+
+// ============================================================================
+// Functional Notation
+// ============================================================================
+
+/// Split `name(args)` into its name and argument string
+fn split_function(input: &str) -> Option<(&str, &str)> {
+    let open = input.find('(')?;
+    let args = input[open + 1..].strip_suffix(')')?;
+    Some((input[..open].trim(), args))
+}
+
+/// Split function arguments into color components and an optional alpha
+///
+/// Accepts both `a, b, c[, alpha]` and `a b c[ / alpha]`.
+fn split_args(args: &str) -> Option<(Vec<&str>, Option<&str>)> {
+    if args.contains(',') {
+        let mut parts: Vec<&str> = args.split(',').map(str::trim).collect();
+        let alpha = match parts.len() {
+            3 => None,
+            4 => parts.pop(),
+            _ => return None,
+        };
+        return Some((parts, alpha));
+    }
+
+    let (channels, alpha) = match args.split_once('/') {
+        Some((channels, alpha)) => (channels, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let parts: Vec<&str> = channels.split_whitespace().collect();
+    (parts.len() == 3).then_some((parts, alpha))
+}
+
+/// Parse a number or percentage, scaling percentages to `percent_scale`
+fn parse_number(value: &str, percent_scale: f64) -> Option<f64> {
+    let number = match value.strip_suffix('%') {
+        Some(percent) => percent.trim().parse::<f64>().ok()? / 100.0 * percent_scale,
+        None => value.parse::<f64>().ok()?,
+    };
+    number.is_finite().then_some(number)
+}
+
+/// Parse a percentage into the 0.0-1.0 range; bare numbers count as
+/// percentages, as in the modern `hsl(120 50 50)` syntax
+fn parse_percentage(value: &str) -> Option<f64> {
+    let percent = value.strip_suffix('%').unwrap_or(value).trim();
+    parse_number(percent, 1.0).map(|percent| percent / 100.0)
+}
+
+fn parse_alpha(alpha: Option<&str>) -> Option<f64> {
+    match alpha {
+        Some(alpha) => parse_number(alpha, 1.0).map(|a| a.clamp(0.0, 1.0)),
+        None => Some(1.0),
+    }
+}
+
+fn parse_rgb(args: &str) -> Option<Rgba> {
+    let (channels, alpha) = split_args(args)?;
+    let channel = |value: &str| parse_number(value, 255.0).map(|c| c.clamp(0.0, 255.0) / 255.0);
+
+    Some(Rgba {
+        r: channel(channels[0])?,
+        g: channel(channels[1])?,
+        b: channel(channels[2])?,
+        a: parse_alpha(alpha)?,
+    })
+}
+
+fn parse_hsl(args: &str) -> Option<Rgba> {
+    let (channels, alpha) = split_args(args)?;
+
+    let hue = parse_hue(channels[0])?;
+    let saturation = parse_percentage(channels[1])?.clamp(0.0, 1.0);
+    let lightness = parse_percentage(channels[2])?.clamp(0.0, 1.0);
+    let (r, g, b) = hsl_to_rgb(hue, saturation, lightness);
+
+    Some(Rgba {
+        r,
+        g,
+        b,
+        a: parse_alpha(alpha)?,
+    })
+}
+
+/// Parse a hue angle into degrees
+fn parse_hue(value: &str) -> Option<f64> {
+    let degrees = if let Some(v) = value.strip_suffix("deg") {
+        v.parse::<f64>().ok()?
+    } else if let Some(v) = value.strip_suffix("grad") {
+        v.parse::<f64>().ok()? * 0.9
+    } else if let Some(v) = value.strip_suffix("rad") {
+        v.parse::<f64>().ok()?.to_degrees()
+    } else if let Some(v) = value.strip_suffix("turn") {
+        v.parse::<f64>().ok()? * 360.0
+    } else {
+        value.parse::<f64>().ok()?
+    };
+    degrees.is_finite().then(|| degrees.rem_euclid(360.0))
+}
+
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    let m = lightness - chroma / 2.0;
+    (r + m, g + m, b + m)
+}
+
+// ============================================================================
+// Named Colors
+// ============================================================================
+
+fn named_color(name: &str) -> Option<Rgba> {
+    if name == "transparent" {
+        return Some(Rgba::from_u8(0, 0, 0, 0));
+    }
+
+    let rgb = NAMED_COLORS
+        .iter()
+        .find(|(candidate, _)| *candidate == name)
+        .map(|(_, rgb)| *rgb)?;
+
+    Some(Rgba::from_u8(
+        (rgb >> 16) as u8,
+        (rgb >> 8) as u8,
+        rgb as u8,
+        0xFF,
+    ))
+}
+
+/// CSS Color Module Level 4 named colors
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expected color from 0-255 channels and a 0.0-1.0 alpha
+    fn rgba(r: u8, g: u8, b: u8, a: f64) -> Rgba {
+        Rgba {
+            a,
+            ..Rgba::from_u8(r, g, b, 0xFF)
+        }
+    }
+
+    fn assert_parses(cases: &[(&str, Rgba)]) {
+        for (input, expected) in cases {
+            let color = parse_css_color(input).unwrap_or_else(|| panic!("{input} did not parse"));
+            for (channel, actual, expected) in [
+                ("r", color.r, expected.r),
+                ("g", color.g, expected.g),
+                ("b", color.b, expected.b),
+                ("a", color.a, expected.a),
+            ] {
+                assert!(
+                    (actual - expected).abs() < 0.002,
+                    "{input}: {channel} is {actual}, expected {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn hex() {
+        assert_parses(&[
+            ("#f00", rgba(255, 0, 0, 1.0)),
+            ("#0f08", rgba(0, 255, 0, 0x88 as f64 / 255.0)),
+            ("#3366CC", rgba(0x33, 0x66, 0xCC, 1.0)),
+            ("#ffffff80", rgba(255, 255, 255, 0x80 as f64 / 255.0)),
+            ("  #000  ", rgba(0, 0, 0, 1.0)),
+        ]);
+    }
+
+    #[test]
+    fn rgb_comma_and_space_syntax() {
+        assert_parses(&[
+            ("rgb(255, 128, 0)", rgba(255, 128, 0, 1.0)),
+            ("rgb(255 128 0)", rgba(255, 128, 0, 1.0)),
+            ("RGB(100%, 50%, 0%)", rgba(255, 128, 0, 1.0)),
+            ("rgb(100% 50% 0%)", rgba(255, 128, 0, 1.0)),
+            ("rgb(300, -5, 0)", rgba(255, 0, 0, 1.0)),
+        ]);
+    }
+
+    #[test]
+    fn hsl_comma_and_space_syntax() {
+        assert_parses(&[
+            ("hsl(120, 100%, 50%)", rgba(0, 255, 0, 1.0)),
+            ("hsl(120 100% 50%)", rgba(0, 255, 0, 1.0)),
+            ("hsl(120 100 50)", rgba(0, 255, 0, 1.0)),
+            ("hsl(120, 50, 50)", rgba(64, 191, 64, 1.0)),
+            ("hsl(0 0% 100%)", rgba(255, 255, 255, 1.0)),
+            ("hsl(240deg 100% 50%)", rgba(0, 0, 255, 1.0)),
+            ("hsl(0.5turn 100% 50%)", rgba(0, 255, 255, 1.0)),
+            ("hsl(-120 100% 50%)", rgba(0, 0, 255, 1.0)),
+        ]);
+    }
+
+    #[test]
+    fn alpha() {
+        assert_parses(&[
+            ("rgba(0, 0, 0, 0.5)", rgba(0, 0, 0, 0.5)),
+            ("rgb(0 0 0 / 0.25)", rgba(0, 0, 0, 0.25)),
+            ("rgb(0 0 0 / 40%)", rgba(0, 0, 0, 0.4)),
+            ("rgba(0, 0, 0, 2)", rgba(0, 0, 0, 1.0)),
+            ("hsla(120, 100%, 50%, 0.3)", rgba(0, 255, 0, 0.3)),
+            ("hsl(120 100 50 / 30%)", rgba(0, 255, 0, 0.3)),
+            ("transparent", rgba(0, 0, 0, 0.0)),
+            ("rebeccapurple", rgba(0x66, 0x33, 0x99, 1.0)),
+        ]);
+    }
+
+    #[test]
+    fn invalid_input() {
+        for input in [
+            "",
+            "#",
+            "#12",
+            "#12345",
+            "#ggg",
+            "rgb(1, 2)",
+            "rgb(1 2 3 4)",
+            "rgb(a, b, c)",
+            "rgb(1, 2, 3",
+            "rgb(1, 2, 3, 4, 5)",
+            "rgb(nan, 0, 0)",
+            "hsl(120deg, 50%)",
+            "hsl(foo 50% 50%)",
+            "hwb(0 0% 0%)",
+            "notacolor",
+        ] {
+            assert_eq!(parse_css_color(input), None, "{input} should not parse");
+        }
+    }
+}
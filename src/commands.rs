@@ -1,9 +1,38 @@
 //! Tauri commands for the liquid-glass plugin
+//!
+//! Commands taking a [`Caller`] are guarded: they fail with
+//! [`Error::RemoteOriginDenied`] when invoked from a remote origin that the
+//! command scope does not allow (see [`crate::scope`]).
 
-use tauri::{command, AppHandle, Runtime, WebviewWindow};
+use std::time::Duration;
 
-use crate::error::Result;
-use crate::models::LiquidGlassConfig;
+use tauri::{
+    command, ipc::Channel, AppHandle, LogicalPosition, Manager, Runtime, WebviewUrl, WebviewWindow,
+};
+
+use crate::audit::AuditEntry;
+use crate::context_menu::ContextMenuConfig;
+use crate::error::{Error, Result};
+use crate::fade::DEFAULT_FADE_MS;
+use crate::hig_presets::HigComponent;
+use crate::launcher::LauncherConfig;
+use crate::media_controls::MediaControlsConfig;
+use crate::menubar_panel::MenubarPanelConfig;
+#[cfg(feature = "devtools")]
+use crate::models::ViewNode;
+use crate::models::{
+    ExperimentalProperty, ForegroundRecommendation, GlassCapabilities, GlassDoctorReport,
+    GlassHealthReport, GlassLayout, GlassStateDocument, GlassStateImportOptions, GlassViewFrame,
+    GlassViewInfo, LayoutDiff, LiquidGlassConfig, LiquidGlassPatch, MacOSVersion, PrivateApiReport,
+    SafeAreaInsets, ScreenCorner, ScrollEdgeConfig, SupportDetails, SystemColors, ViewRect,
+    WindowLevelConfig,
+};
+use crate::pip::PipConfig;
+use crate::region_channel::RegionMessage;
+use crate::scope::{ensure_origin_allowed, ensure_target_allowed, Caller};
+use crate::sheet::SheetConfig;
+use crate::throttle::{MainThreadStats, Target};
+use crate::toast::ToastOptions;
 use crate::LiquidGlassExt;
 
 /// Check if liquid glass effect is supported on the current platform
@@ -14,6 +43,36 @@ pub fn is_glass_supported<R: Runtime>(app: AppHandle<R>) -> bool {
     app.liquid_glass().is_supported()
 }
 
+/// Get detailed support information, including which variants are honored
+#[command]
+pub fn get_support_details<R: Runtime>(app: AppHandle<R>) -> SupportDetails {
+    app.liquid_glass().support_details()
+}
+
+/// Get which glass features are usable at runtime
+#[command]
+pub fn get_capabilities<R: Runtime>(app: AppHandle<R>) -> GlassCapabilities {
+    app.liquid_glass().capabilities()
+}
+
+/// Get which NSGlassEffectView selectors respond on the running OS build
+#[command]
+pub fn get_private_api_report<R: Runtime>(app: AppHandle<R>) -> PrivateApiReport {
+    app.liquid_glass().private_api_report()
+}
+
+/// Get the running macOS version, `null` on other platforms
+#[command]
+pub fn get_macos_version<R: Runtime>(app: AppHandle<R>) -> Option<MacOSVersion> {
+    app.liquid_glass().macos_version()
+}
+
+/// Get the configuration replicating an Apple component on the running build
+#[command]
+pub fn get_hig_preset<R: Runtime>(app: AppHandle<R>, component: HigComponent) -> LiquidGlassConfig {
+    app.liquid_glass().hig_preset(component)
+}
+
 /// Set liquid glass effect on a window
 ///
 /// - If `config.enabled` is true: creates or updates the glass effect with the given configuration
@@ -22,10 +81,998 @@ pub fn is_glass_supported<R: Runtime>(app: AppHandle<R>) -> bool {
 /// All configuration options have sensible defaults, so you can pass an empty object
 /// to enable the effect with default settings.
 #[command]
-pub fn set_liquid_glass_effect<R: Runtime>(
+pub async fn set_liquid_glass_effect<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::Effect, move |glass, window| {
+            glass.set_effect(window, config)
+        })
+        .await
+}
+
+/// Change some settings of the calling window's glass, keeping the rest
+#[command]
+pub async fn update_liquid_glass_effect<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    patch: LiquidGlassPatch,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::effect_patch(), move |glass, window| {
+            glass.update_effect(window, patch)
+        })
+        .await
+}
+
+/// Set liquid glass effects on several windows at once, keyed by label
+///
+/// Every window is resolved before any changes, and on macOS all native
+/// updates run in one main-thread dispatch.
+#[command]
+pub fn set_liquid_glass_effects<R: Runtime>(
+    app: AppHandle<R>,
+    effects: Vec<(String, LiquidGlassConfig)>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().set_effects(effects)
+}
+
+/// Set an experimental glass property on the calling window's glass views
+///
+/// Fails with `UnsupportedCapability` when the running OS lacks the property.
+#[command]
+pub fn set_experimental_glass_property<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    property: ExperimentalProperty,
+    enabled: bool,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .set_experimental_property(&window, property, enabled)
+}
+
+/// Create or update a named glass view in the calling window
+#[command]
+pub async fn set_glass_view<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    id: String,
+    config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::View(id.clone()), move |glass, window| {
+            glass.set_glass_view(window, &id, config)
+        })
+        .await
+}
+
+/// Add a glass view at a fixed rectangle of the calling window
+///
+/// Returns the generated id of the new view.
+#[command]
+pub fn add_glass_region<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    rect: ViewRect,
+    config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<String> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().add_glass_region(&window, rect, config)
+}
+
+/// Move a named glass view of the calling window to a new rectangle
+///
+/// Runs through the main-thread budget, for streaming element bounds.
+#[command]
+pub async fn sync_glass_rect<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    id: String,
+    rect: ViewRect,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::Frame(id.clone()), move |glass, window| {
+            glass.sync_glass_rect(window, &id, rect)
+        })
+        .await
+}
+
+/// Remove a named glass view from the calling window
+#[command]
+pub fn remove_glass_view<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    id: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().remove_glass_view(&window, &id)
+}
+
+/// Claim a handle for a frontend component's glass view in the calling window
+#[command]
+pub fn claim_glass_handle<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<String> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().claim_glass_handle(&window)
+}
+
+/// Release a component handle of the calling window and remove its glass view
+#[command]
+pub fn release_glass_handle<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    handle: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().release_glass_handle(&window, &handle)
+}
+
+/// Create or update a named glass view in the calling window and stream its
+/// frame changes and removal over `channel`
+#[command]
+pub fn attach_region_to_channel<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    id: String,
+    config: LiquidGlassConfig,
+    channel: Channel<RegionMessage>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .attach_region_to_channel(&window, &id, config, channel)
+}
+
+/// Open a rect stream for a named glass view in the calling window
+///
+/// Returns the event name to emit rects on.
+#[command]
+pub fn open_glass_rect_channel<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    region_id: String,
+    caller: Caller<R>,
+) -> Result<String> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .open_glass_rect_channel(&window, &region_id)
+}
+
+/// Close a rect stream of the calling window
+#[command]
+pub fn close_glass_rect_channel<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    channel: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .close_glass_rect_channel(&window, &channel)
+}
+
+/// Remove glass from the calling window, or the window labelled `label`
+///
+/// With `id`, removes that named view (failing if it doesn't exist);
+/// without, removes all of the window's glass. Returns the number of views
+/// removed. Only local content may address another window.
+#[command]
+pub fn remove_glass<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: Option<String>,
+    id: Option<String>,
+    caller: Caller<R>,
+) -> Result<usize> {
+    ensure_origin_allowed(&caller)?;
+    let target = match label {
+        Some(label) => webview_window(&app, &label)?,
+        None => window,
+    };
+    ensure_target_allowed(&caller, &target)?;
+    app.liquid_glass().remove_glass(&target, id.as_deref())
+}
+
+/// Set glass on a window, addressed by window label, view id or both
+///
+/// With `id`, creates or updates that named view; without, sets the
+/// window's full-window glass. Like `remove_glass`, only local content may
+/// address another window.
+#[command]
+pub async fn set_glass<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: Option<String>,
+    id: Option<String>,
+    config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let target = match label {
+        Some(label) => webview_window(&app, &label)?,
+        None => window,
+    };
+    ensure_target_allowed(&caller, &target)?;
+    let throttle_target = id.clone().map_or(Target::Effect, Target::View);
+    app.liquid_glass()
+        .throttled(&target, throttle_target, move |glass, window| {
+            glass.set_glass(window, id.as_deref(), config)
+        })
+        .await
+}
+
+/// Get one glass view of a window, addressed like `set_glass`
+#[command]
+pub fn get_glass<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: Option<String>,
+    id: Option<String>,
+    caller: Caller<R>,
+) -> Result<Option<GlassViewInfo>> {
+    ensure_origin_allowed(&caller)?;
+    let target = match label {
+        Some(label) => webview_window(&app, &label)?,
+        None => window,
+    };
+    ensure_target_allowed(&caller, &target)?;
+    app.liquid_glass().glass(&target, id.as_deref())
+}
+
+/// Remove all glass from every window, returning the number of views removed
+#[command]
+pub fn remove_all_effects<R: Runtime>(app: AppHandle<R>, caller: Caller<R>) -> Result<usize> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().remove_all()
+}
+
+/// Group named glass views of the calling window so their shapes merge
+#[command]
+pub fn create_glass_group<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    group: String,
+    regions: Vec<String>,
+    spacing: f64,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let regions: Vec<&str> = regions.iter().map(String::as_str).collect();
+    app.liquid_glass()
+        .create_glass_group(&window, &group, &regions, spacing)
+}
+
+/// Dissolve a glass group of the calling window
+#[command]
+pub fn remove_glass_group<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    group: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().remove_glass_group(&window, &group)
+}
+
+/// Apply a declarative layout of named glass regions to the calling window
+#[command]
+pub fn apply_glass_layout<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    layout: GlassLayout,
+    caller: Caller<R>,
+) -> Result<LayoutDiff> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().apply_layout(&window, layout)
+}
+
+/// Tag the calling window into a theme group
+#[command]
+pub fn join_theme_group<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    group: String,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().join_theme_group(&window, &group)
+}
+
+/// Remove the calling window from its theme group
+#[command]
+pub fn leave_theme_group<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().leave_theme_group(&window)
+}
+
+/// Apply a configuration to every window in a theme group
+#[command]
+pub fn set_theme_group_effect<R: Runtime>(
+    app: AppHandle<R>,
+    group: String,
+    config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().set_theme_group_effect(&group, config)
+}
+
+/// Set the window level and collection behavior of the calling window
+#[command]
+pub fn set_glass_window_level<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    config: WindowLevelConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().set_window_level(&window, config)
+}
+
+/// Turn the calling window into a rounded glass window
+#[command]
+pub fn make_rounded_glass_window<R: Runtime>(
     app: AppHandle<R>,
     window: WebviewWindow<R>,
+    radius: f64,
     config: LiquidGlassConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .make_rounded_glass_window(&window, radius, config)
+}
+
+/// Set the calling window's background color, or clear it with `null`
+#[command]
+pub fn set_window_background<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    color: Option<String>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .set_window_background(&window, color.as_deref())
+}
+
+/// Toggle whether the calling window's webview draws its own opaque background
+#[command]
+pub fn set_webview_draws_background<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    draws_background: bool,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .set_webview_draws_background(&window, draws_background)
+}
+
+/// Switch the window with the given label between transparent and opaque
+#[command]
+pub fn set_window_transparent<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    transparent: bool,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let target = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &target)?;
+    app.liquid_glass()
+        .set_window_transparent(&target, transparent)
+}
+
+/// Switch the webview of the window with the given label between transparent
+/// and opaque
+#[command]
+pub fn set_webview_transparent<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    transparent: bool,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let target = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &target)?;
+    app.liquid_glass()
+        .set_webview_transparent(&target, transparent)
+}
+
+/// Turn the window with the given label into a fullscreen media-controls overlay
+#[command]
+pub fn attach_media_controls<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    config: MediaControlsConfig,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let controls = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &controls)?;
+    app.liquid_glass().attach_media_controls(&controls, config)
+}
+
+/// Show a media-controls window and restart its auto-hide timer
+#[command]
+pub fn reveal_media_controls<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let controls = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &controls)?;
+    app.liquid_glass().reveal_media_controls(&controls)
+}
+
+/// Stop auto-hiding a media-controls window
+#[command]
+pub fn detach_media_controls<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let controls = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &controls)?;
+    app.liquid_glass().detach_media_controls(&controls)
+}
+
+/// Show the window with the given label as a glass context menu
+///
+/// `x` and `y` are relative to the calling window's content, e.g. a
+/// `contextmenu` event's `clientX`/`clientY`; without them the menu opens at
+/// the cursor.
+#[command]
+pub fn show_context_menu<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: String,
+    x: Option<f64>,
+    y: Option<f64>,
+    config: Option<ContextMenuConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let menu = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &menu)?;
+    let at = match (x, y) {
+        (Some(x), Some(y)) => {
+            let origin = window
+                .inner_position()?
+                .to_logical::<f64>(window.scale_factor()?);
+            Some(LogicalPosition::new(origin.x + x, origin.y + y))
+        }
+        _ => None,
+    };
+    app.liquid_glass()
+        .show_context_menu(&menu, at, config.unwrap_or_default())
+}
+
+/// Hide a context menu window
+#[command]
+pub fn dismiss_context_menu<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    let menu = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &menu)?;
+    app.liquid_glass().dismiss_context_menu(&menu)
+}
+
+/// Create a Spotlight-style launcher window (or configure an existing one)
+///
+/// Async because creating windows from a synchronous command can deadlock.
+#[command]
+pub async fn create_launcher<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    url: WebviewUrl,
+    config: Option<LauncherConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    if let Some(existing) = app.get_webview_window(&label) {
+        ensure_target_allowed(&caller, &existing)?;
+    }
+    app.liquid_glass()
+        .create_launcher(&label, url, config.unwrap_or_default())
+        .map(|_| ())
+}
+
+/// Show a launcher, or dismiss it if it is already visible
+#[command]
+pub fn toggle_launcher<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let launcher = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &launcher)?;
+    app.liquid_glass().toggle_launcher(&launcher)
+}
+
+/// Hide a launcher window
+#[command]
+pub fn dismiss_launcher<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    let launcher = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &launcher)?;
+    app.liquid_glass().dismiss_launcher(&launcher)
+}
+
+/// Fit a launcher window to its content height
+#[command]
+pub fn resize_launcher<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    height: f64,
+    caller: Caller<R>,
+) -> Result<f64> {
+    ensure_origin_allowed(&caller)?;
+    let launcher = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &launcher)?;
+    app.liquid_glass().resize_launcher(&launcher, height)
+}
+
+/// Swap every glass view for a flat fill
+#[command]
+pub fn suspend_all<R: Runtime>(app: AppHandle<R>, caller: Caller<R>) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().suspend_all()
+}
+
+/// Restore every suspended glass view
+#[command]
+pub fn resume_all<R: Runtime>(app: AppHandle<R>, caller: Caller<R>) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().resume_all()
+}
+
+/// Whether glass effects are currently suspended
+#[command]
+pub fn is_suspended<R: Runtime>(app: AppHandle<R>, caller: Caller<R>) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    Ok(app.liquid_glass().is_suspended())
+}
+
+/// Show the window with the given label by fading it in
+#[command]
+pub fn show_with_fade<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    duration_ms: Option<u64>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let target = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &target)?;
+    let duration = Duration::from_millis(duration_ms.unwrap_or(DEFAULT_FADE_MS));
+    app.liquid_glass().show_with_fade(&target, duration)
+}
+
+/// Hide the window with the given label by fading it out
+#[command]
+pub fn hide_with_fade<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    duration_ms: Option<u64>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let target = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &target)?;
+    let duration = Duration::from_millis(duration_ms.unwrap_or(DEFAULT_FADE_MS));
+    app.liquid_glass().hide_with_fade(&target, duration)
+}
+
+/// Present the window with the given label as a glass sheet on `parent`
+/// (the calling window by default)
+#[command]
+pub fn present_sheet<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: String,
+    parent: Option<String>,
+    config: Option<SheetConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let sheet = webview_window(&app, &label)?;
+    let parent = match parent {
+        Some(parent) => webview_window(&app, &parent)?,
+        None => window,
+    };
+    ensure_target_allowed(&caller, &sheet)?;
+    ensure_target_allowed(&caller, &parent)?;
+    app.liquid_glass()
+        .present_sheet(&sheet, &parent, config.unwrap_or_default())
+}
+
+/// Dismiss a glass sheet
+#[command]
+pub fn dismiss_sheet<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    let sheet = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &sheet)?;
+    app.liquid_glass().dismiss_sheet(&sheet)
+}
+
+/// Turn the window with the given label into a picture-in-picture window
+#[command]
+pub fn attach_pip<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    config: Option<PipConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    let pip = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &pip)?;
+    app.liquid_glass()
+        .attach_pip(&pip, config.unwrap_or_default())
+}
+
+/// Stop snapping a picture-in-picture window
+#[command]
+pub fn detach_pip<R: Runtime>(app: AppHandle<R>, label: String, caller: Caller<R>) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    let pip = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &pip)?;
+    app.liquid_glass().detach_pip(&pip)
+}
+
+/// Move a picture-in-picture window into a corner, or the nearest one
+#[command]
+pub fn snap_pip<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    corner: Option<ScreenCorner>,
+    caller: Caller<R>,
+) -> Result<ScreenCorner> {
+    ensure_origin_allowed(&caller)?;
+    let pip = webview_window(&app, &label)?;
+    ensure_target_allowed(&caller, &pip)?;
+    app.liquid_glass().snap_pip(&pip, corner)
+}
+
+/// Show a transient glass toast, returning the label of its window
+///
+/// Async because HTML toasts create a window.
+#[command]
+pub async fn show_glass_toast<R: Runtime>(
+    app: AppHandle<R>,
+    options: ToastOptions,
+    caller: Caller<R>,
+) -> Result<String> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().show_glass_toast(options)
+}
+
+/// Fade out a toast before its duration elapses
+#[command]
+pub fn dismiss_glass_toast<R: Runtime>(
+    app: AppHandle<R>,
+    label: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().dismiss_glass_toast(&label)
+}
+
+/// Show the window with the given label as a glass panel under a tray item
+#[command]
+pub fn attach_menubar_panel<R: Runtime>(
+    app: AppHandle<R>,
+    tray_id: String,
+    label: String,
+    config: Option<MenubarPanelConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    #[cfg(feature = "tray-icon")]
+    {
+        app.liquid_glass()
+            .attach_menubar_panel(&tray_id, &label, config.unwrap_or_default())
+    }
+    #[cfg(not(feature = "tray-icon"))]
+    {
+        let _ = (app, tray_id, label, config);
+        Err(Error::FeatureDisabled("tray-icon".to_string()))
+    }
+}
+
+/// Show or hide the panel of a tray item
+#[command]
+pub fn toggle_menubar_panel<R: Runtime>(
+    app: AppHandle<R>,
+    tray_id: String,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    #[cfg(feature = "tray-icon")]
+    {
+        app.liquid_glass().toggle_menubar_panel(&tray_id)
+    }
+    #[cfg(not(feature = "tray-icon"))]
+    {
+        let _ = (app, tray_id);
+        Err(Error::FeatureDisabled("tray-icon".to_string()))
+    }
+}
+
+/// Stop toggling a window from a tray item
+#[command]
+pub fn detach_menubar_panel<R: Runtime>(
+    app: AppHandle<R>,
+    tray_id: String,
+    caller: Caller<R>,
+) -> Result<bool> {
+    ensure_origin_allowed(&caller)?;
+    #[cfg(feature = "tray-icon")]
+    {
+        app.liquid_glass().detach_menubar_panel(&tray_id)
+    }
+    #[cfg(not(feature = "tray-icon"))]
+    {
+        let _ = (app, tray_id);
+        Err(Error::FeatureDisabled("tray-icon".to_string()))
+    }
+}
+
+/// Install, update or (with `null`) remove the calling window's scroll-edge strip
+#[command]
+pub async fn set_scroll_edge_effect<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    config: Option<ScrollEdgeConfig>,
+    caller: Caller<R>,
+) -> Result<()> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::ScrollEdge, move |glass, window| {
+            glass.set_scroll_edge_effect(window, config)
+        })
+        .await
+}
+
+/// Report the calling window's scroll offset to drive its scroll-edge strip
+#[command]
+pub async fn report_scroll_offset<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    offset: f64,
+    caller: Caller<R>,
 ) -> Result<()> {
-    app.liquid_glass().set_effect(&window, config)
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .throttled(&window, Target::ScrollOffset, move |glass, window| {
+            glass.report_scroll_offset(window, offset)
+        })
+        .await
+}
+
+/// Get the calling window's full-window glass, including its backend
+#[command]
+pub fn get_liquid_glass_effect<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<Option<GlassViewInfo>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().glass(&window, None)
+}
+
+/// Get the glass views of the calling window, including their backend
+#[command]
+pub fn get_glass_state<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<Vec<GlassViewInfo>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().glass_state(&window)
+}
+
+/// Get the insets of the calling window's content view outside the display's safe area
+#[command]
+pub fn get_safe_area_insets<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<SafeAreaInsets> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().safe_area_insets(&window)
+}
+
+/// Get the recommended foreground color for text over the calling window's glass
+#[command]
+pub fn get_recommended_foreground<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    region: Option<String>,
+    caller: Caller<R>,
+) -> Result<Option<ForegroundRecommendation>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .recommended_foreground(&window, region.as_deref())
+}
+
+/// Get the on-screen frame of one of the calling window's glass views
+#[command]
+pub fn get_glass_frame<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    region: Option<String>,
+    caller: Caller<R>,
+) -> Result<Option<GlassViewFrame>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().glass_frame(&window, region.as_deref())
+}
+
+/// Get the user's accent, highlight and focus ring colors
+#[command]
+pub fn get_system_colors<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<Option<SystemColors>> {
+    ensure_origin_allowed(&caller)?;
+    Ok(app.liquid_glass().system_colors())
+}
+
+/// List every glass view tracked by the plugin, across all windows
+///
+/// Exposes other windows' configs, so it is only in the `diagnostics`
+/// permission set.
+#[command]
+pub fn list_glass_views<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<Vec<GlassViewInfo>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().list_glass_views()
+}
+
+/// Diagnose why glass may not show in a window, the calling one by default
+///
+/// Only in the `diagnostics` permission set.
+#[command]
+pub fn liquid_glass_doctor<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    label: Option<String>,
+    caller: Caller<R>,
+) -> Result<GlassDoctorReport> {
+    ensure_origin_allowed(&caller)?;
+    let target = match label {
+        Some(label) => webview_window(&app, &label)?,
+        None => window,
+    };
+    ensure_target_allowed(&caller, &target)?;
+    app.liquid_glass().doctor(&target)
+}
+
+/// Dump the view hierarchy of the calling window
+///
+/// Only registered with the `devtools` feature.
+#[cfg(feature = "devtools")]
+#[command]
+pub fn dump_view_hierarchy<R: Runtime>(
+    app: AppHandle<R>,
+    window: WebviewWindow<R>,
+    caller: Caller<R>,
+) -> Result<ViewNode> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().dump_view_hierarchy(&window)
+}
+
+/// List the full-window glass config of every window, keyed by label
+///
+/// Only in the `diagnostics` permission set.
+#[command]
+pub fn list_effects<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<Vec<(String, LiquidGlassConfig)>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().list()
+}
+
+/// Describe every window's glass views and configs as a versioned document
+///
+/// Only in the `diagnostics` permission set.
+#[command]
+pub fn export_glass_state<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<GlassStateDocument> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().export_glass_state()
+}
+
+/// Apply a glass state document produced by `export_glass_state`
+#[command]
+pub fn import_glass_state<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+    document: String,
+    options: Option<GlassStateImportOptions>,
+) -> Result<Vec<String>> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass()
+        .import_glass_state(&document, options.unwrap_or_default())
+}
+
+/// Check every registered glass view and prune orphans
+#[command]
+pub fn validate_glass_views<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<GlassHealthReport> {
+    ensure_origin_allowed(&caller)?;
+    app.liquid_glass().validate_glass_views()
+}
+
+/// Get the recorded glass effect operations, oldest first
+///
+/// Returns an empty list unless the audit log was enabled on the plugin builder.
+#[command]
+pub fn get_glass_audit_log<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<Vec<AuditEntry>> {
+    ensure_origin_allowed(&caller)?;
+    Ok(app.liquid_glass().audit_log())
+}
+
+/// Get the counters of the main-thread flood guard
+#[command]
+pub fn get_main_thread_stats<R: Runtime>(
+    app: AppHandle<R>,
+    caller: Caller<R>,
+) -> Result<MainThreadStats> {
+    ensure_origin_allowed(&caller)?;
+    Ok(app.liquid_glass().main_thread_stats())
+}
+
+fn webview_window<R: Runtime>(app: &AppHandle<R>, label: &str) -> Result<WebviewWindow<R>> {
+    app.get_webview_window(label)
+        .ok_or_else(|| Error::WindowNotFound(label.to_string()))
 }
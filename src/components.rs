@@ -0,0 +1,66 @@
+//! Glass views owned by frontend components
+//!
+//! Framework components (the React `<LiquidGlass>` in guest-js) claim a
+//! handle when they mount, use it as the id of a named glass view, and
+//! release it when they unmount. Handles are unique for the life of the
+//! process, so a remounted component or a reloaded page never adopts a view
+//! left behind by an earlier instance. Views of components whose page went
+//! away without unmounting them (reloads, HMR) are removed when the webview
+//! starts loading its next page.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+
+/// Prefix of component handles
+pub(crate) const COMPONENT_HANDLE_PREFIX: &str = "component-";
+
+/// Claimed handles per window label
+#[derive(Default)]
+pub(crate) struct ComponentHandles {
+    next_id: AtomicU64,
+    claimed: Mutex<HashMap<String, Vec<String>>>,
+}
+
+impl ComponentHandles {
+    /// Claim a new handle for a component in `label`
+    pub fn claim(&self, label: &str) -> Result<String> {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let handle = format!("{COMPONENT_HANDLE_PREFIX}{id}");
+        self.claimed
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .entry(label.to_string())
+            .or_default()
+            .push(handle.clone());
+        Ok(handle)
+    }
+
+    /// Release a handle, returning false if `label` didn't claim it
+    pub fn release(&self, label: &str, handle: &str) -> Result<bool> {
+        let mut claimed = self.claimed.lock().map_err(|_| Error::RegistryLockFailed)?;
+        let Some(handles) = claimed.get_mut(label) else {
+            return Ok(false);
+        };
+        let Some(index) = handles.iter().position(|claimed| claimed == handle) else {
+            return Ok(false);
+        };
+        handles.swap_remove(index);
+        if handles.is_empty() {
+            claimed.remove(label);
+        }
+        Ok(true)
+    }
+
+    /// Release every handle of `label`, returning them
+    pub fn take(&self, label: &str) -> Result<Vec<String>> {
+        Ok(self
+            .claimed
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label)
+            .unwrap_or_default())
+    }
+}
@@ -0,0 +1,159 @@
+//! Plugin configuration read from `tauri.conf.json`
+//!
+//! Lets apps declare glass for their windows without setup code:
+//!
+//! ```json
+//! {
+//!   "plugins": {
+//!     "liquid-glass": {
+//!       "windows": {
+//!         "main": { "cornerRadius": 16 },
+//!         "settings-*": { "variant": "sidebar" }
+//!       }
+//!     }
+//!   }
+//! }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::LiquidGlassConfig;
+
+/// The `plugins.liquid-glass` block of `tauri.conf.json`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Config {
+    /// Glass applied to windows as soon as their webview is created, keyed
+    /// by window label or by a pattern where `*` matches any run of
+    /// characters and `?` a single one. See [`Config::window_effect`] for
+    /// which entry a label gets.
+    pub windows: BTreeMap<String, LiquidGlassConfig>,
+}
+
+impl Config {
+    /// Get the config for a window label
+    ///
+    /// An entry whose key equals the label wins. Otherwise the matching
+    /// patterns are tried in the byte order of their keys and the first one
+    /// is used, so of `"settings-*"` and `"*"` the label `settings-general`
+    /// gets `"*"` (`*` sorts before letters). Configs are not merged.
+    pub fn window_effect(&self, label: &str) -> Option<&LiquidGlassConfig> {
+        self.windows.get(label).or_else(|| {
+            self.windows
+                .iter()
+                .find(|(pattern, _)| pattern_matches(pattern, label))
+                .map(|(_, config)| config)
+        })
+    }
+}
+
+/// Match a label against a pattern with `*` and `?` wildcards
+fn pattern_matches(pattern: &str, label: &str) -> bool {
+    let (pattern, label): (Vec<char>, Vec<char>) =
+        (pattern.chars().collect(), label.chars().collect());
+    // Position after the last `*` and the label position it was tried at
+    let mut star: Option<(usize, usize)> = None;
+    let (mut p, mut l) = (0, 0);
+
+    while l < label.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                star = Some((p + 1, l));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == label[l] => {
+                p += 1;
+                l += 1;
+            }
+            _ => match star {
+                // Let the last `*` swallow one more character
+                Some((after, tried)) => {
+                    p = after;
+                    l = tried + 1;
+                    star = Some((after, tried + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_matching() {
+        for (pattern, label, matches) in [
+            ("main", "main", true),
+            ("main", "mains", false),
+            ("main", "mai", false),
+            ("", "", true),
+            ("", "main", false),
+            ("*", "", true),
+            ("*", "anything", true),
+            ("settings-*", "settings-", true),
+            ("settings-*", "settings-general", true),
+            ("settings-*", "settings", false),
+            ("*-panel", "left-panel", true),
+            ("*-panel", "left-panel-2", false),
+            ("a*b*c", "abc", true),
+            ("a*b*c", "aXbYbZc", true),
+            ("a*b*c", "aXcYb", false),
+            ("**", "x", true),
+            ("?", "x", true),
+            ("?", "", false),
+            ("?", "xy", false),
+            ("tab-?", "tab-1", true),
+            ("tab-?", "tab-12", false),
+            ("tab-?*", "tab-12", true),
+            ("*?", "", false),
+            ("ä?", "äö", true),
+        ] {
+            assert_eq!(
+                pattern_matches(pattern, label),
+                matches,
+                "{pattern:?} against {label:?}"
+            );
+        }
+    }
+
+    fn config(windows: &[(&str, f64)]) -> Config {
+        Config {
+            windows: windows
+                .iter()
+                .map(|&(key, corner_radius)| {
+                    let config = LiquidGlassConfig {
+                        corner_radius,
+                        ..Default::default()
+                    };
+                    (key.to_string(), config)
+                })
+                .collect(),
+        }
+    }
+
+    fn radius(config: &Config, label: &str) -> Option<f64> {
+        config
+            .window_effect(label)
+            .map(|config| config.corner_radius)
+    }
+
+    #[test]
+    fn exact_label_wins_over_patterns() {
+        let config = config(&[("*", 1.0), ("main", 2.0), ("m*", 3.0)]);
+        assert_eq!(radius(&config, "main"), Some(2.0));
+        assert_eq!(radius(&config, "menu"), Some(1.0));
+    }
+
+    #[test]
+    fn first_matching_pattern_in_key_order_wins() {
+        let config = config(&[("settings-*", 1.0), ("settings-?", 2.0), ("z*", 3.0)]);
+        assert_eq!(radius(&config, "settings-a"), Some(1.0));
+        assert_eq!(radius(&config, "zoom"), Some(3.0));
+        assert_eq!(radius(&config, "main"), None);
+    }
+}
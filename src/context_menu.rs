@@ -0,0 +1,171 @@
+//! Glass context menu window helper
+//!
+//! Turns a small app-created window into an HTML-rendered context menu: a
+//! rounded glass popup with a shadow, placed next to the cursor and kept on
+//! screen, that dismisses itself when it loses focus.
+
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, LogicalPosition, LogicalSize, Runtime, WebviewWindow};
+
+use crate::error::{Error, Result};
+use crate::models::{GlassPreset, LiquidGlassConfig};
+
+/// Event emitted when a context menu window is dismissed
+pub const CONTEXT_MENU_DISMISSED_EVENT: &str = "liquid-glass://context-menu-dismissed";
+
+/// Configuration for a context menu window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ContextMenuConfig {
+    /// Width of the menu in logical pixels
+    pub width: f64,
+
+    /// Height of the menu in logical pixels
+    pub height: f64,
+
+    /// Corner radius of the menu window
+    pub corner_radius: f64,
+
+    /// Gap between the cursor and the menu in logical pixels
+    pub offset: f64,
+
+    /// Glass configuration for the menu
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for ContextMenuConfig {
+    fn default() -> Self {
+        Self {
+            width: 220.0,
+            height: 240.0,
+            corner_radius: 10.0,
+            offset: 2.0,
+            glass: GlassPreset::Popover.into(),
+        }
+    }
+}
+
+/// Why a context menu was dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ContextMenuDismissReason {
+    /// The menu window lost focus, e.g. the user clicked elsewhere
+    Blur,
+    /// The app dismissed the menu, e.g. after an item was chosen or Escape
+    Dismissed,
+}
+
+/// Payload of [`CONTEXT_MENU_DISMISSED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContextMenuDismissed {
+    /// Label of the context menu window
+    pub window: String,
+    /// Why the menu was dismissed
+    pub reason: ContextMenuDismissReason,
+}
+
+/// Tracks context menu windows that are currently shown
+#[derive(Default)]
+pub(crate) struct ContextMenus {
+    open: Mutex<HashSet<String>>,
+}
+
+impl ContextMenus {
+    /// Record `window` as an open context menu
+    pub fn open<R: Runtime>(&self, window: &WebviewWindow<R>) -> Result<()> {
+        self.open
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .insert(window.label().to_string());
+        Ok(())
+    }
+
+    /// Hide `window` if it is an open context menu and emit the dismissal
+    ///
+    /// Returns false if the window was not an open context menu.
+    pub fn dismiss<R: Runtime>(
+        &self,
+        window: &WebviewWindow<R>,
+        reason: ContextMenuDismissReason,
+    ) -> Result<bool> {
+        let was_open = self
+            .open
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(window.label());
+        if !was_open {
+            return Ok(false);
+        }
+
+        window.hide()?;
+        let payload = ContextMenuDismissed {
+            window: window.label().to_string(),
+            reason,
+        };
+        if let Err(e) = window.emit(CONTEXT_MENU_DISMISSED_EVENT, payload) {
+            warn!("Failed to emit context menu dismissal: {}", e);
+        }
+        Ok(true)
+    }
+
+    /// Stop tracking a destroyed window
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.open
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label);
+        Ok(())
+    }
+}
+
+/// Size the menu and place it next to `at` (the cursor if `None`), in screen
+/// coordinates
+///
+/// Like native menus, the menu opens below and to the right of the point and
+/// flips to the other side of any monitor edge it would cross.
+pub(crate) fn position_near<R: Runtime>(
+    window: &WebviewWindow<R>,
+    at: Option<LogicalPosition<f64>>,
+    config: &ContextMenuConfig,
+) -> Result<()> {
+    let cursor = window.cursor_position()?;
+    let monitor = match at {
+        Some(at) => {
+            let scale = window.scale_factor()?;
+            let physical = at.to_physical::<f64>(scale);
+            window.monitor_from_point(physical.x, physical.y)?
+        }
+        None => window.monitor_from_point(cursor.x, cursor.y)?,
+    };
+    let Some(monitor) = monitor.or(window.current_monitor()?) else {
+        return Err(Error::MonitorNotFound(window.label().to_string()));
+    };
+
+    let scale = monitor.scale_factor();
+    let anchor = at.unwrap_or_else(|| cursor.to_logical(scale));
+    let area = monitor.work_area();
+    let origin = area.position.to_logical::<f64>(scale);
+    let size = area.size.to_logical::<f64>(scale);
+
+    let place = |anchor: f64, length: f64, start: f64, extent: f64| {
+        let after = anchor + config.offset;
+        let before = anchor - config.offset - length;
+        let position = if after + length <= start + extent || before < start {
+            after
+        } else {
+            before
+        };
+        position.min(start + extent - length).max(start)
+    };
+    let x = place(anchor.x, config.width, origin.x, size.width);
+    let y = place(anchor.y, config.height, origin.y, size.height);
+
+    window.set_size(LogicalSize::new(config.width, config.height))?;
+    window.set_position(LogicalPosition::new(x, y))?;
+    Ok(())
+}
@@ -2,13 +2,65 @@
 //!
 //! This module provides the `LiquidGlass` struct that exposes the plugin's Rust API.
 
-use tauri::{AppHandle, Runtime, WebviewWindow};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
 
-use crate::error::Result;
-use crate::models::LiquidGlassConfig;
+use log::{debug, warn};
+use tauri::ipc::Channel;
+use tauri::{
+    AppHandle, Emitter, Listener, LogicalPosition, LogicalSize, Manager, Runtime, WebviewUrl,
+    WebviewWindow, WebviewWindowBuilder,
+};
+
+use crate::audit::{AuditEntry, AuditLog};
+use crate::components::ComponentHandles;
+use crate::context_menu::{self, ContextMenuConfig, ContextMenuDismissReason, ContextMenus};
+use crate::error::{Error, Result};
+use crate::events::{GlassEvent, GlassEvents};
+use crate::fade::Fades;
+use crate::fallback::{GlassFallback, FALLBACK_EVENT};
+use crate::foreground::{ForegroundTracker, FOREGROUND_CHANGED_EVENT};
+use crate::groups::ThemeGroups;
+use crate::handle::GlassEffectHandle;
+use crate::hig_presets::HigComponent;
+use crate::launcher::{self, LauncherConfig, Launchers};
+use crate::lifecycle::{GlassApplied, GlassRemoved, APPLIED_EVENT, REMOVED_EVENT};
+use crate::media_controls::{self, MediaControls, MediaControlsConfig};
+#[cfg(feature = "tray-icon")]
+use crate::menubar_panel::{self, MenubarPanelConfig, MenubarPanels};
+#[cfg(feature = "devtools")]
+use crate::models::ViewNode;
+use crate::models::{
+    migrate_glass_state, ExperimentalProperty, ForegroundRecommendation, GlassCapabilities,
+    GlassDoctorReport, GlassHealthReport, GlassLayout, GlassStateDocument, GlassStateImportOptions,
+    GlassViewFrame, GlassViewInfo, GlassViewState, LayoutDiff, LiquidGlassConfig, LiquidGlassPatch,
+    MacOSVersion, PrivateApiReport, SafeAreaInsets, ScreenCorner, ScrollEdgeConfig, SupportDetails,
+    SystemColors, ViewRect, WindowGlassState, WindowLevel, WindowLevelConfig, WindowOptions,
+    WindowShape, GLASS_STATE_VERSION,
+};
+use crate::pip::{self, PipConfig, Pips};
+use crate::rect_stream::RectStreams;
+use crate::region_channel::{RegionChannels, RegionMessage};
+use crate::sheet::{self, SheetConfig, Sheets};
+use crate::suspend::{EffectsSuspended, EFFECTS_SUSPENDED_EVENT};
+use crate::throttle::{MainThreadGuard, MainThreadStats, Target};
+use crate::toast::{
+    self, Toast, ToastContent, ToastDismissReason, ToastDismissed, ToastOptions, Toasts,
+    TOAST_DISMISSED_EVENT,
+};
+use crate::LiquidGlassExt;
 
 #[cfg(target_os = "macos")]
-use crate::glass_effect;
+use crate::{
+    appearance::{AppearanceChanged, APPEARANCE_CHANGED_EVENT},
+    external::{GlassExternallyModified, GLASS_EXTERNALLY_MODIFIED_EVENT},
+    frame::{FrameChanged, FRAME_CHANGED_EVENT},
+    glass_effect,
+    key_state::{KeyStateChanged, KEY_STATE_CHANGED_EVENT},
+    system_colors::{SystemColorsChanged, SYSTEM_COLORS_CHANGED_EVENT},
+};
 
 /// Liquid Glass plugin API
 ///
@@ -24,18 +76,96 @@ use crate::glass_effect;
 /// }
 /// ```
 pub struct LiquidGlass<R: Runtime> {
-    #[allow(dead_code)]
     app: AppHandle<R>,
+    audit: Option<AuditLog>,
+    media_controls: MediaControls,
+    context_menus: ContextMenus,
+    launchers: Launchers,
+    toasts: Toasts,
+    pips: Pips,
+    sheets: Sheets,
+    fades: Fades,
+    #[cfg(feature = "tray-icon")]
+    menubar_panels: MenubarPanels,
+    groups: ThemeGroups,
+    foreground: ForegroundTracker,
+    events: GlassEvents,
+    main_thread: MainThreadGuard,
+    next_region: AtomicU64,
+    components: ComponentHandles,
+    region_channels: RegionChannels,
+    rect_streams: RectStreams,
 }
 
+/// Prefix of the ids generated by [`LiquidGlass::add_glass_region`]
+const GLASS_REGION_ID_PREFIX: &str = "region-";
+
 impl<R: Runtime> LiquidGlass<R> {
-    pub(crate) fn new(app: AppHandle<R>) -> Self {
-        Self { app }
+    pub(crate) fn new(
+        app: AppHandle<R>,
+        audit_log_capacity: Option<usize>,
+        ops_per_frame: usize,
+    ) -> Self {
+        Self {
+            app,
+            audit: audit_log_capacity.map(AuditLog::new),
+            media_controls: MediaControls::default(),
+            context_menus: ContextMenus::default(),
+            launchers: Launchers::default(),
+            toasts: Toasts::default(),
+            pips: Pips::default(),
+            sheets: Sheets::default(),
+            fades: Fades::default(),
+            #[cfg(feature = "tray-icon")]
+            menubar_panels: MenubarPanels::default(),
+            groups: ThemeGroups::default(),
+            foreground: ForegroundTracker::default(),
+            events: GlassEvents::default(),
+            main_thread: MainThreadGuard::new(ops_per_frame),
+            next_region: AtomicU64::new(1),
+            components: ComponentHandles::default(),
+            region_channels: RegionChannels::default(),
+            rect_streams: RectStreams::default(),
+        }
+    }
+
+    /// Subscribe to glass events from Rust
+    ///
+    /// Receives the same changes the webview events report, including
+    /// [`GlassEvent::Applied`] and [`GlassEvent::Removed`] for every
+    /// successful change to a window's glass (emitted to the webview as
+    /// [`APPLIED_EVENT`](crate::APPLIED_EVENT) and
+    /// [`REMOVED_EVENT`](crate::REMOVED_EVENT)). Each receiver buffers up to 64
+    /// events; a receiver that falls further behind gets
+    /// [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged)
+    /// and resumes with the oldest retained event.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassEvent, LiquidGlassExt};
+    ///
+    /// fn watch_fallbacks(app: tauri::AppHandle) {
+    ///     let mut events = app.liquid_glass().subscribe();
+    ///     tauri::async_runtime::spawn(async move {
+    ///         while let Ok(event) = events.recv().await {
+    ///             if let GlassEvent::Fallback(fallback) = event {
+    ///                 println!("{} fell back: {:?}", fallback.window, fallback.reason);
+    ///             }
+    ///         }
+    ///     });
+    /// }
+    /// ```
+    pub fn subscribe(&self) -> tokio::sync::broadcast::Receiver<GlassEvent> {
+        self.events.subscribe()
     }
 
     /// Check if liquid glass effect is supported on the current platform
     ///
-    /// Returns true if running on macOS 26+ with NSGlassEffectView available.
+    /// Returns true if running on macOS 26+ with NSGlassEffectView available,
+    /// on Windows 11 22H2+ where [`set_effect`](Self::set_effect) applies a
+    /// system backdrop, or on Linux sessions whose compositor blurs behind
+    /// windows (KWin on X11, Hyprland).
     ///
     /// # Example
     ///
@@ -52,17 +182,167 @@ impl<R: Runtime> LiquidGlass<R> {
         {
             glass_effect::is_glass_supported()
         }
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "windows")]
+        {
+            crate::windows::is_backdrop_supported()
+        }
+        #[cfg(target_os = "linux")]
+        {
+            crate::linux::is_blur_supported()
+        }
+        #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
         {
             false
         }
     }
 
+    /// Get detailed support information for the current platform
+    ///
+    /// In addition to [`is_supported`](Self::is_supported), this reports which
+    /// [`GlassMaterialVariant`](crate::GlassMaterialVariant)s the running macOS
+    /// build actually honors, so UIs can hide options that would have no effect.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn list_variants(app: tauri::AppHandle) {
+    ///     let details = app.liquid_glass().support_details();
+    ///     println!("Honored variants: {:?}", details.supported_variants);
+    /// }
+    /// ```
+    pub fn support_details(&self) -> SupportDetails {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::support_details()
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            SupportDetails::default()
+        }
+    }
+
+    /// Get which glass features are usable at runtime
+    ///
+    /// Reports the backend new views get, whether NSGlassEffectView and its
+    /// container exist, which private setters (tint, variants, scrim,
+    /// subdued, interactive) respond, and the macOS version. Everything is
+    /// false or empty off macOS.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn scrim_toggle_visible(app: tauri::AppHandle) -> bool {
+    ///     app.liquid_glass().capabilities().scrim
+    /// }
+    /// ```
+    pub fn capabilities(&self) -> GlassCapabilities {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::capabilities()
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            GlassCapabilities::default()
+        }
+    }
+
+    /// Get which NSGlassEffectView setters and getters respond on the
+    /// running OS build
+    ///
+    /// Variant, scrim, subdued and interactive rely on private selectors that
+    /// change between betas; setting one without a responding setter is
+    /// ignored with a warning. Empty off macOS.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn log_missing_setters(app: tauri::AppHandle) {
+    ///     for probe in app.liquid_glass().private_api_report().properties {
+    ///         if probe.setter.is_none() {
+    ///             println!("{} can't be set", probe.key);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn private_api_report(&self) -> PrivateApiReport {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::probe_private_api()
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            PrivateApiReport::default()
+        }
+    }
+
+    /// Get the running macOS version, or `None` on other platforms
+    ///
+    /// Useful for explaining to users why glass options are unavailable.
+    /// The version is read once and cached.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn explain(app: tauri::AppHandle) {
+    ///     if let Some(version) = app.liquid_glass().macos_version() {
+    ///         if !version.is_tahoe_or_later {
+    ///             println!("Liquid Glass requires macOS 26, running {}.{}", version.major, version.minor);
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn macos_version(&self) -> Option<MacOSVersion> {
+        #[cfg(target_os = "macos")]
+        {
+            Some(glass_effect::macos_version())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            None
+        }
+    }
+
+    /// Get the configuration replicating an Apple component, validated
+    /// against the running macOS build
+    ///
+    /// The variant falls back along the component's chain when the build
+    /// doesn't honor the preferred one, and builds without NSGlassEffectView
+    /// get the flatter pre-Tahoe appearance.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{HigComponent, LiquidGlassExt};
+    ///
+    /// fn add_sidebar(window: tauri::WebviewWindow) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     let config = window.liquid_glass().hig_preset(HigComponent::FinderSidebar);
+    ///     window.liquid_glass().set_glass_view(&window, "sidebar", config)
+    /// }
+    /// ```
+    pub fn hig_preset(&self, component: HigComponent) -> LiquidGlassConfig {
+        component.config_for(&self.support_details())
+    }
+
     /// Set liquid glass effect on a window
     ///
     /// - If `config.enabled` is true: creates or updates the glass effect with the given configuration
     /// - If `config.enabled` is false: removes the glass effect if present
     ///
+    /// On Windows 11 22H2+ the whole window gets the system backdrop chosen by
+    /// [`windows_backdrop`](LiquidGlassConfig::windows_backdrop) instead; the
+    /// other settings only apply on macOS. On Linux the compositor blurs behind
+    /// the whole window where it supports it. Older Windows builds and Linux
+    /// sessions without blur fail with [`Error::UnsupportedPlatform`], as do
+    /// all other platforms.
+    ///
     /// # Example
     ///
     /// ```rust,no_run
@@ -88,14 +368,3247 @@ impl<R: Runtime> LiquidGlass<R> {
     /// }
     /// ```
     pub fn set_effect(&self, window: &WebviewWindow<R>, config: LiquidGlassConfig) -> Result<()> {
+        config.validate_metadata().map_err(Error::InvalidMetadata)?;
+        config
+            .validate_colors()
+            .map_err(Error::InvalidColorFormat)?;
+        let config = config.into_points(window.scale_factor()?);
+        let applied = config.clone();
+
+        #[cfg(target_os = "macos")]
+        let result = glass_effect::set_liquid_glass_effect(&self.app, window, config);
+        #[cfg(target_os = "windows")]
+        let result = crate::windows::set_backdrop(window, &config);
+        #[cfg(target_os = "linux")]
+        let result = crate::linux::set_blur(window, &config);
+        #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+        let result = {
+            let _ = config;
+            Err(Error::UnsupportedPlatform)
+        };
+
+        self.effect_set(window, applied, &result);
+        result
+    }
+
+    /// Change some settings of a window's full-window glass
+    ///
+    /// The patch is applied to the config currently applied (or the default
+    /// config if the window has no glass) and the result set with
+    /// [`set_effect`](Self::set_effect), so unmentioned settings such as the
+    /// tint or variant are kept. Off macOS, where applied configs are not
+    /// tracked, the patch always starts from the default config.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, LiquidGlassPatch};
+    ///
+    /// fn square_corners(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let patch = LiquidGlassPatch {
+    ///         corner_radius: Some(0.0),
+    ///         ..Default::default()
+    ///     };
+    ///     app.liquid_glass().update_effect(&window, patch).unwrap();
+    /// }
+    /// ```
+    pub fn update_effect(&self, window: &WebviewWindow<R>, patch: LiquidGlassPatch) -> Result<()> {
+        let mut config = self.get_effect(window)?.unwrap_or_default();
+        patch.apply_to(&mut config, window.scale_factor()?);
+        self.set_effect(window, config)
+    }
+
+    /// Apply glass effects to several windows at once, keyed by window label
+    ///
+    /// Every window is resolved and every config validated before any window
+    /// changes. On macOS all native updates then run in one main-thread
+    /// dispatch and Core Animation transaction, so theming many windows
+    /// doesn't stagger; elsewhere each window is set in turn. Windows are
+    /// handled as by [`set_effect`](Self::set_effect); a failing window
+    /// doesn't stop the rest, and the first error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, LiquidGlassConfig};
+    ///
+    /// fn tint_all(app: tauri::AppHandle) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     let config = LiquidGlassConfig {
+    ///         tint_color: Some("#ff000020".into()),
+    ///         ..Default::default()
+    ///     };
+    ///     app.liquid_glass().set_effects(vec![
+    ///         ("main".into(), config.clone()),
+    ///         ("settings".into(), config),
+    ///     ])
+    /// }
+    /// ```
+    pub fn set_effects(&self, batch: Vec<(String, LiquidGlassConfig)>) -> Result<()> {
+        let batch = batch
+            .into_iter()
+            .map(|(label, config)| {
+                let window = self
+                    .app
+                    .get_webview_window(&label)
+                    .ok_or(Error::WindowNotFound(label))?;
+                config.validate_metadata().map_err(Error::InvalidMetadata)?;
+                config
+                    .validate_colors()
+                    .map_err(Error::InvalidColorFormat)?;
+                Ok((window, config))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        // Parked single-window updates must not land over the batch
+        for (window, _) in &batch {
+            self.main_thread.cancel(window.label(), |target| {
+                matches!(target, Target::Effect | Target::EffectPatch(_))
+            });
+        }
+
         #[cfg(target_os = "macos")]
         {
-            glass_effect::set_liquid_glass_effect(&self.app, window, config)
+            let batch = batch
+                .into_iter()
+                .map(|(window, config)| {
+                    let config = config.into_points(window.scale_factor()?);
+                    Ok((window, config))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let results = glass_effect::set_liquid_glass_effects(&self.app, batch.clone());
+
+            batch
+                .into_iter()
+                .zip(results)
+                .map(|((window, config), result)| {
+                    self.effect_set(&window, config, &result);
+                    result
+                })
+                .fold(Ok(()), Result::and)
         }
         #[cfg(not(target_os = "macos"))]
         {
-            let _ = (window, config);
-            Ok(()) // No-op on non-macOS
+            batch
+                .into_iter()
+                .map(|(window, config)| self.set_effect(&window, config))
+                .fold(Ok(()), Result::and)
+        }
+    }
+
+    /// Announce a full-window effect change and refresh the window's
+    /// observers, or audit the failure
+    fn effect_set(
+        &self,
+        window: &WebviewWindow<R>,
+        config: LiquidGlassConfig,
+        result: &Result<()>,
+    ) {
+        if let Err(e) = result {
+            self.audit_failure(window.label(), None, Some(&config), e);
+            return;
+        }
+
+        let (label, region) = (window.label().to_string(), None);
+        let event = if config.enabled {
+            GlassEvent::Applied {
+                window: label,
+                region,
+                config: Box::new(config),
+            }
+        } else {
+            GlassEvent::Removed {
+                window: label,
+                region,
+            }
+        };
+        self.announce(window, event);
+        self.refresh_foreground(window);
+        self.observe_frames(window);
+        self.observe_external_changes(window);
+        self.report_fallback(window, |view| view.region.is_none());
+    }
+
+    /// Apply a declarative layout of named glass regions to a window
+    ///
+    /// The layout is validated and diffed against the window's current
+    /// regions: new regions are created, changed ones updated and missing
+    /// ones removed. Pass an empty layout to remove all regions. Regions are
+    /// independent of the full-window effect set by [`set_effect`](Self::set_effect).
+    /// Off macOS only an empty layout succeeds; any other fails with
+    /// [`Error::UnsupportedPlatform`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{
+    ///     GlassFrame, GlassLayout, GlassPreset, GlassRegion, Length, LiquidGlassExt,
+    /// };
+    ///
+    /// fn apply_shell(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let layout = GlassLayout {
+    ///         regions: vec![GlassRegion {
+    ///             id: "sidebar".into(),
+    ///             preset: Some(GlassPreset::Sidebar),
+    ///             frame: GlassFrame {
+    ///                 width: Some(Length::Points(240.0)),
+    ///                 ..Default::default()
+    ///             },
+    ///             ..Default::default()
+    ///         }],
+    ///     };
+    ///     let diff = app.liquid_glass().apply_layout(&window, layout).unwrap();
+    ///     println!("added {:?}", diff.added);
+    /// }
+    /// ```
+    pub fn apply_layout(
+        &self,
+        window: &WebviewWindow<R>,
+        layout: GlassLayout,
+    ) -> Result<LayoutDiff> {
+        layout.validate().map_err(Error::InvalidLayout)?;
+        for region in &layout.regions {
+            region
+                .resolved_config()
+                .validate_colors()
+                .map_err(Error::InvalidColorFormat)?;
+        }
+        // Parked updates to named views would re-add them over the new layout
+        self.main_thread.cancel(window.label(), |target| {
+            matches!(target, Target::View(_) | Target::Frame(_))
+        });
+        let layout = layout.into_points(window.scale_factor()?);
+        let regions = layout.regions.clone();
+
+        #[cfg(target_os = "macos")]
+        let diff = match glass_effect::apply_glass_layout(&self.app, window, layout) {
+            Ok(diff) => diff,
+            Err(e) => {
+                for region in &regions {
+                    let config = region.resolved_config();
+                    self.audit_failure(window.label(), Some(&region.id), Some(&config), &e);
+                }
+                return Err(e);
+            }
+        };
+        // Nothing to remove without views, so only an empty layout succeeds
+        #[cfg(not(target_os = "macos"))]
+        let diff = match layout.regions.is_empty() {
+            true => LayoutDiff::default(),
+            false => return Err(Error::UnsupportedPlatform),
+        };
+
+        let label = window.label();
+        for region in regions {
+            if diff.added.contains(&region.id) || diff.updated.contains(&region.id) {
+                let event = GlassEvent::Applied {
+                    window: label.to_string(),
+                    config: Box::new(region.resolved_config()),
+                    region: Some(region.id),
+                };
+                self.announce(window, event);
+            }
+        }
+        for id in &diff.removed {
+            let event = GlassEvent::Removed {
+                window: label.to_string(),
+                region: Some(id.clone()),
+            };
+            self.announce(window, event);
+        }
+
+        self.refresh_foreground(window);
+        self.observe_frames(window);
+        self.observe_external_changes(window);
+        self.report_fallback(window, |view| view.region.is_some());
+        Ok(diff)
+    }
+
+    /// Create or update a glass view with a caller-chosen id (e.g. `"sidebar"`)
+    ///
+    /// Named views are layout regions: they share ids with
+    /// [`apply_layout`](Self::apply_layout), so a later layout that doesn't
+    /// list the id removes the view. New views are stacked above the window's
+    /// existing regions; the full-window effect is unaffected. Fails with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{
+    ///     GlassFrame, GlassPreset, Length, LiquidGlassConfig, LiquidGlassExt,
+    /// };
+    ///
+    /// fn add_toolbar(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let config = LiquidGlassConfig {
+    ///         frame: Some(GlassFrame {
+    ///             height: Some(Length::Points(52.0)),
+    ///             ..Default::default()
+    ///         }),
+    ///         ..GlassPreset::Regular.into()
+    ///     };
+    ///     app.liquid_glass().set_glass_view(&window, "toolbar", config).unwrap();
+    /// }
+    /// ```
+    pub fn set_glass_view(
+        &self,
+        window: &WebviewWindow<R>,
+        id: &str,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        if id.is_empty() {
+            return Err(Error::InvalidLayout("view id must not be empty".into()));
+        }
+        let config = config.into();
+        config.validate_metadata().map_err(Error::InvalidMetadata)?;
+        config
+            .validate_colors()
+            .map_err(Error::InvalidColorFormat)?;
+        let config = config.into_points(window.scale_factor()?);
+        let event = GlassEvent::Applied {
+            window: window.label().to_string(),
+            region: Some(id.to_string()),
+            config: Box::new(config.clone()),
+        };
+
+        #[cfg(target_os = "macos")]
+        let result = glass_effect::set_glass_region(&self.app, window, id, Some(config.clone()));
+        #[cfg(not(target_os = "macos"))]
+        let result: Result<()> = Err(Error::UnsupportedPlatform);
+        if let Err(e) = result {
+            self.audit_failure(window.label(), Some(id), Some(&config), &e);
+            return Err(e);
+        }
+
+        self.announce(window, event);
+
+        self.refresh_foreground(window);
+        self.observe_frames(window);
+        self.observe_external_changes(window);
+        self.report_fallback(window, |view| view.region.as_deref() == Some(id));
+        Ok(())
+    }
+
+    /// Add a glass view at a fixed rectangle and get its generated id
+    ///
+    /// `rect` is in the config's [`units`](LiquidGlassConfig::units),
+    /// measured from the top-left corner of the content view, and replaces
+    /// any `frame` in the config. The view is a named view like those of
+    /// [`set_glass_view`](Self::set_glass_view): update it by passing the
+    /// returned id there and remove it with
+    /// [`remove_glass_view`](Self::remove_glass_view).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassPreset, LiquidGlassExt, ViewRect};
+    ///
+    /// fn add_card(app: tauri::AppHandle, window: tauri::WebviewWindow) -> String {
+    ///     let rect = ViewRect { x: 24.0, y: 80.0, width: 320.0, height: 200.0 };
+    ///     app.liquid_glass()
+    ///         .add_glass_region(&window, rect, GlassPreset::Widget)
+    ///         .unwrap()
+    /// }
+    /// ```
+    pub fn add_glass_region(
+        &self,
+        window: &WebviewWindow<R>,
+        rect: ViewRect,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<String> {
+        let taken: HashSet<String> = self
+            .glass_state(window)?
+            .into_iter()
+            .filter_map(|view| view.region)
+            .collect();
+        let id = loop {
+            let n = self.next_region.fetch_add(1, Ordering::Relaxed);
+            let id = format!("{GLASS_REGION_ID_PREFIX}{n}");
+            if !taken.contains(&id) {
+                break id;
+            }
+        };
+
+        let config = LiquidGlassConfig {
+            frame: Some(rect.into()),
+            ..config.into()
+        };
+        self.set_glass_view(window, &id, config)?;
+        Ok(id)
+    }
+
+    /// Move a named glass view to `rect` without re-applying its config
+    ///
+    /// `rect` is in points, measured from the top-left corner of the content
+    /// view, and becomes the view's `frame`. Meant for streaming the bounding
+    /// box of a DOM element: only the frame is touched on the main thread and
+    /// no [`GlassEvent`] is published. Fails with [`Error::ViewNotFound`] if
+    /// the window has no view by that id, and with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    pub fn sync_glass_rect(
+        &self,
+        window: &WebviewWindow<R>,
+        id: &str,
+        rect: ViewRect,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        let exists = glass_effect::set_glass_region_frame(&self.app, window, id, rect.into());
+        #[cfg(not(target_os = "macos"))]
+        let exists = {
+            let _ = (window, rect);
+            Err(Error::UnsupportedPlatform)
+        };
+
+        if !exists? {
+            return Err(Error::ViewNotFound(id.to_string()));
+        }
+        Ok(())
+    }
+
+    /// Remove a named glass view created by [`set_glass_view`](Self::set_glass_view)
+    /// or a layout
+    ///
+    /// Returns whether a view with this id existed.
+    pub fn remove_glass_view(&self, window: &WebviewWindow<R>, id: &str) -> Result<bool> {
+        self.main_thread.cancel(
+            window.label(),
+            |target| matches!(target, Target::View(view) | Target::Frame(view) if view == id),
+        );
+
+        #[cfg(target_os = "macos")]
+        let existed = glass_effect::set_glass_region(&self.app, window, id, None)?;
+        #[cfg(not(target_os = "macos"))]
+        let existed = {
+            let _ = id;
+            false
+        };
+
+        if existed {
+            let event = GlassEvent::Removed {
+                window: window.label().to_string(),
+                region: Some(id.to_string()),
+            };
+            self.announce(window, event);
+        }
+        self.refresh_foreground(window);
+        Ok(existed)
+    }
+
+    /// Create or update a named glass view and stream its state over a channel
+    ///
+    /// Applies `config` like [`set_glass_view`](Self::set_glass_view), then
+    /// sends the view's frame over `channel` right away and whenever it
+    /// changes natively, and a final [`RegionMessage::Removed`] when the view
+    /// is removed by any means. Attaching again replaces the channel.
+    pub fn attach_region_to_channel(
+        &self,
+        window: &WebviewWindow<R>,
+        id: &str,
+        config: impl Into<LiquidGlassConfig>,
+        channel: Channel<RegionMessage>,
+    ) -> Result<()> {
+        self.set_glass_view(window, id, config)?;
+        if let Some(frame) = self.glass_frame(window, Some(id))? {
+            channel.send(RegionMessage::Frame { frame })?;
+        }
+        self.region_channels.attach(window.label(), id, channel)
+    }
+
+    /// Open a rect stream for a named glass view
+    ///
+    /// Returns the name of an event the webview emits [`ViewRect`]s on, in
+    /// the units of [`sync_glass_rect`](Self::sync_glass_rect). Emitting
+    /// doesn't wait on the main thread: the latest rect per view is queued
+    /// and all queued views are moved in one main-thread pass, inside a
+    /// CATransaction without implicit animations, so scroll-synced glass can
+    /// update at the display's rate. The stream closes with
+    /// [`close_glass_rect_channel`](Self::close_glass_rect_channel), when the
+    /// view is removed, or when the page reloads. Fails with
+    /// [`Error::ViewNotFound`] if the window has no view by that id.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn stream_header(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let event = app.liquid_glass().open_glass_rect_channel(&window, "header").unwrap();
+    ///     // Hand `event` to the page, which emits `{ x, y, width, height }` on it
+    /// }
+    /// ```
+    pub fn open_glass_rect_channel(&self, window: &WebviewWindow<R>, id: &str) -> Result<String> {
+        #[cfg(target_os = "macos")]
+        if self.glass_frame(window, Some(id))?.is_none() {
+            return Err(Error::ViewNotFound(id.to_string()));
+        }
+
+        let event = self.rect_streams.event_name();
+        let app = self.app.clone();
+        let label = window.label().to_string();
+        let region = id.to_string();
+        let listener =
+            self.app.listen_any(event.clone(), move |event| {
+                match serde_json::from_str::<ViewRect>(event.payload()) {
+                    Ok(rect) => app.liquid_glass().stream_rect(&label, &region, rect),
+                    Err(e) => warn!("Ignoring malformed rect for glass view '{}': {}", region, e),
+                }
+            });
+        if let Err(e) = self
+            .rect_streams
+            .insert(event.clone(), window.label(), id, listener)
+        {
+            self.app.unlisten(listener);
+            return Err(e);
+        }
+        Ok(event)
+    }
+
+    /// Close a rect stream opened by [`open_glass_rect_channel`](Self::open_glass_rect_channel)
+    ///
+    /// Returns whether `window` had a stream open under `event`. Rects still
+    /// queued for it are dropped.
+    pub fn close_glass_rect_channel(&self, window: &WebviewWindow<R>, event: &str) -> Result<bool> {
+        self.rect_streams.close(&self.app, window.label(), event)
+    }
+
+    /// Queue a streamed rect, scheduling a flush if none is pending
+    fn stream_rect(&self, label: &str, region: &str, rect: ViewRect) {
+        if !self.rect_streams.push(label, region, rect) {
+            return;
+        }
+        let app = self.app.clone();
+        if let Err(e) = self
+            .app
+            .run_on_main_thread(move || app.liquid_glass().flush_rects())
+        {
+            warn!("Failed to schedule streamed glass rects: {}", e);
+            self.rect_streams.take();
+        }
+    }
+
+    /// Move every view with a queued rect, in one CATransaction
+    fn flush_rects(&self) {
+        let rects = self.rect_streams.take();
+        #[cfg(target_os = "macos")]
+        {
+            let frames = rects
+                .into_iter()
+                .map(|(label, region, rect)| (label, region, rect.into()))
+                .collect();
+            if let Err(e) = glass_effect::set_glass_region_frames(&self.app, frames) {
+                warn!("Failed to apply streamed glass rects: {}", e);
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        let _ = rects;
+    }
+
+    /// Claim a handle for a frontend component that owns a glass view
+    ///
+    /// The handle is unique for the life of the process and is meant as the
+    /// id of the component's named view (see
+    /// [`set_glass_view`](Self::set_glass_view)). Release it with
+    /// [`release_glass_handle`](Self::release_glass_handle) on unmount; views
+    /// of handles still claimed when the webview starts loading another page
+    /// are removed then.
+    pub fn claim_glass_handle(&self, window: &WebviewWindow<R>) -> Result<String> {
+        self.components.claim(window.label())
+    }
+
+    /// Release a component handle and remove its glass view
+    ///
+    /// Returns false if the window didn't claim the handle; its view is then
+    /// left alone.
+    pub fn release_glass_handle(&self, window: &WebviewWindow<R>, handle: &str) -> Result<bool> {
+        if !self.components.release(window.label(), handle)? {
+            return Ok(false);
+        }
+        self.remove_glass_view(window, handle)?;
+        Ok(true)
+    }
+
+    /// Remove the views of components that the page being unloaded never
+    /// unmounted
+    pub(crate) fn page_loading(&self, label: &str) {
+        if let Err(e) = self.rect_streams.close_window(&self.app, label) {
+            warn!("Failed to close rect streams of '{}': {}", label, e);
+        }
+        let handles = match self.components.take(label) {
+            Ok(handles) => handles,
+            Err(e) => {
+                warn!("Failed to release component handles of '{}': {}", label, e);
+                return;
+            }
+        };
+        if handles.is_empty() {
+            return;
+        }
+        let Some(window) = self.app.get_webview_window(label) else {
+            return;
+        };
+        for handle in handles {
+            if let Err(e) = self.remove_glass_view(&window, &handle) {
+                warn!("Failed to remove glass of component '{}': {}", handle, e);
+            }
+        }
+    }
+
+    /// Set glass on a window: one named view, or its full-window glass
+    ///
+    /// With `id`, this is [`set_glass_view`](Self::set_glass_view); without,
+    /// [`set_effect`](Self::set_effect). Together with
+    /// [`glass`](Self::glass) and [`remove_glass`](Self::remove_glass) it
+    /// addresses every glass view of the app by window and optional view id.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassPreset, LiquidGlassExt};
+    ///
+    /// fn glass_sidebar(app: tauri::AppHandle) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     if let Some(window) = tauri::Manager::get_webview_window(&app, "main") {
+    ///         app.liquid_glass().set_glass(&window, Some("sidebar"), GlassPreset::Sidebar)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn set_glass(
+        &self,
+        window: &WebviewWindow<R>,
+        id: Option<&str>,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        match id {
+            Some(id) => self.set_glass_view(window, id, config),
+            None => self.set_effect(window, config.into()),
         }
     }
+
+    /// Get one glass view of a window, addressed like [`set_glass`](Self::set_glass)
+    ///
+    /// Returns `None` if the window has no such view.
+    pub fn glass(
+        &self,
+        window: &WebviewWindow<R>,
+        id: Option<&str>,
+    ) -> Result<Option<GlassViewInfo>> {
+        let views = self.glass_state(window)?;
+        Ok(views.into_iter().find(|view| view.region.as_deref() == id))
+    }
+
+    /// Remove glass from a window: one named view, or all of its glass
+    ///
+    /// With `id`, removes that named view and fails with
+    /// [`Error::ViewNotFound`] if the window has none by that id. Without,
+    /// removes the full-window glass and every named view, along with any
+    /// updates to them still waiting for the main thread. The scroll-edge
+    /// strip is left alone.
+    ///
+    /// Returns the number of views removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn clear_glass(app: tauri::AppHandle) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     if let Some(window) = tauri::Manager::get_webview_window(&app, "inspector") {
+    ///         app.liquid_glass().remove_glass(&window, None)?;
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn remove_glass(&self, window: &WebviewWindow<R>, id: Option<&str>) -> Result<usize> {
+        if let Some(id) = id {
+            if !self.remove_glass_view(window, id)? {
+                return Err(Error::ViewNotFound(id.to_string()));
+            }
+            return Ok(1);
+        }
+
+        self.main_thread.cancel(window.label(), |_| true);
+        let has_window_glass = self
+            .glass_state(window)?
+            .iter()
+            .any(|view| view.region.is_none());
+        if has_window_glass {
+            let config = LiquidGlassConfig {
+                enabled: false,
+                ..Default::default()
+            };
+            self.set_effect(window, config)?;
+        }
+
+        let diff = self.apply_layout(window, GlassLayout::default())?;
+        Ok(usize::from(has_window_glass) + diff.removed.len())
+    }
+
+    /// Remove all glass from every window
+    ///
+    /// Handles each window with glass as [`remove_glass`](Self::remove_glass)
+    /// without an id, e.g. to tear down a theme or honor a "disable
+    /// transparency" setting. A failing window doesn't stop the rest; the
+    /// first error is returned. Returns the number of views removed. Off
+    /// macOS, where no glass views are tracked, it removes nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn disable_transparency(app: tauri::AppHandle) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     let removed = app.liquid_glass().remove_all()?;
+    ///     println!("removed {removed} glass views");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn remove_all(&self) -> Result<usize> {
+        #[cfg(target_os = "macos")]
+        let labels = glass_effect::glass_windows(&self.app)?;
+        #[cfg(not(target_os = "macos"))]
+        let labels: Vec<String> = Vec::new();
+
+        let mut removed = 0;
+        let mut first_error = None;
+        for label in labels {
+            // Windows being destroyed are cleaned up by `forget_window`
+            let Some(window) = self.app.get_webview_window(&label) else {
+                continue;
+            };
+            match self.remove_glass(&window, None) {
+                Ok(count) => removed += count,
+                Err(e) => {
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        first_error.map_or(Ok(removed), Err)
+    }
+
+    /// Group named glass views so nearby shapes merge and morph into each other
+    ///
+    /// Hosts the views `regions` in one `NSGlassEffectContainerView`; shapes
+    /// closer than `spacing` points render as one piece of glass, like
+    /// toolbar buttons in Apple's apps. Calling it again for the same `group`
+    /// updates the members and spacing; a view leaving the group, or
+    /// joining another, is moved out of it. Views keep their ids, configs
+    /// and frames. Fails with [`Error::ViewNotFound`] if the window has no
+    /// named view for one of the ids. With NSVisualEffectView fallback glass
+    /// the views are grouped but don't merge. Fails with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn merge_controls(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .create_glass_group(&window, "controls", &["back", "forward"], 8.0)
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn create_glass_group(
+        &self,
+        window: &WebviewWindow<R>,
+        group: &str,
+        regions: &[&str],
+        spacing: f64,
+    ) -> Result<()> {
+        if group.is_empty() {
+            return Err(Error::InvalidLayout("group id must not be empty".into()));
+        }
+        if !spacing.is_finite() || spacing < 0.0 {
+            return Err(Error::InvalidLayout(format!(
+                "group spacing must be a non-negative number, got {spacing}"
+            )));
+        }
+
+        #[cfg(target_os = "macos")]
+        {
+            let regions = regions.iter().map(|id| id.to_string()).collect();
+            glass_effect::create_glass_group(&self.app, window, group, regions, spacing)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, regions);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Dissolve a group created by [`create_glass_group`](Self::create_glass_group)
+    ///
+    /// The views stay, rendered separately again. Returns whether a group
+    /// with this id existed.
+    pub fn remove_glass_group(&self, window: &WebviewWindow<R>, group: &str) -> Result<bool> {
+        #[cfg(target_os = "macos")]
+        return glass_effect::remove_glass_group(&self.app, window, group);
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, group);
+            Ok(false)
+        }
+    }
+
+    /// Get the recommended foreground color for text over a window's glass
+    ///
+    /// `region` selects a layout region; `None` selects the full-window glass.
+    /// Returns `None` if there is no such glass view. The recommendation is
+    /// also pushed as [`FOREGROUND_CHANGED_EVENT`](crate::FOREGROUND_CHANGED_EVENT)
+    /// whenever it changes.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn text_color(app: tauri::AppHandle, window: tauri::WebviewWindow) -> String {
+    ///     app.liquid_glass()
+    ///         .recommended_foreground(&window, None)
+    ///         .unwrap()
+    ///         .map_or_else(|| "#000000".into(), |r| r.color)
+    /// }
+    /// ```
+    pub fn recommended_foreground(
+        &self,
+        window: &WebviewWindow<R>,
+        region: Option<&str>,
+    ) -> Result<Option<ForegroundRecommendation>> {
+        Ok(self
+            .foregrounds(window)?
+            .into_iter()
+            .find(|(id, _)| id.as_deref() == region)
+            .map(|(_, recommendation)| recommendation))
+    }
+
+    fn foregrounds(
+        &self,
+        window: &WebviewWindow<R>,
+    ) -> Result<Vec<(Option<String>, ForegroundRecommendation)>> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::recommended_foregrounds(&self.app, window)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+            Ok(Vec::new())
+        }
+    }
+
+    /// Emit [`FRAME_CHANGED_EVENT`](crate::FRAME_CHANGED_EVENT) when any of
+    /// the window's glass views is moved or resized natively
+    ///
+    /// Called after views are created; views already observed are skipped.
+    fn observe_frames(&self, window: &WebviewWindow<R>) {
+        #[cfg(target_os = "macos")]
+        {
+            let app = self.app.clone();
+            let label = window.label().to_string();
+            let result =
+                glass_effect::observe_frames(&self.app, window, move |region, previous, frame| {
+                    let Some(window) = app.get_webview_window(&label) else {
+                        return;
+                    };
+                    let metadata = app
+                        .liquid_glass()
+                        .glass_state(&window)
+                        .unwrap_or_default()
+                        .into_iter()
+                        .find(|view| view.region == region)
+                        .and_then(|view| view.config.metadata);
+                    let (moved, resized) = previous.map_or((true, true), |previous| {
+                        let (old, new) = (&previous.points, &frame.points);
+                        (
+                            old.x != new.x || old.y != new.y,
+                            old.width != new.width || old.height != new.height,
+                        )
+                    });
+                    let payload_region = region.clone();
+                    let payload = FrameChanged {
+                        window: label.clone(),
+                        region,
+                        metadata,
+                        frame: frame.clone(),
+                        moved,
+                        resized,
+                    };
+                    if let Err(e) = window.emit(FRAME_CHANGED_EVENT, payload) {
+                        warn!("Failed to emit frame change: {}", e);
+                    }
+                    if let Some(region) = &payload_region {
+                        app.liquid_glass()
+                            .region_channels
+                            .frame(&label, region, frame);
+                    }
+                });
+            if let Err(e) = result {
+                warn!(
+                    "Failed to observe glass frames of '{}': {}",
+                    window.label(),
+                    e
+                );
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+        }
+    }
+
+    /// Emit [`GLASS_EXTERNALLY_MODIFIED_EVENT`](crate::GLASS_EXTERNALLY_MODIFIED_EVENT)
+    /// when other code changes the tint, variant or corner radius of one of
+    /// the window's glass views
+    ///
+    /// The stored config is corrected first, so `glass_state` and diffs stay
+    /// accurate. Called after views are created; views already observed are
+    /// skipped.
+    fn observe_external_changes(&self, window: &WebviewWindow<R>) {
+        #[cfg(target_os = "macos")]
+        {
+            let app = self.app.clone();
+            let label = window.label().to_string();
+            let result = glass_effect::observe_external_changes(
+                &self.app,
+                window,
+                move |region, properties, config| {
+                    let Some(window) = app.get_webview_window(&label) else {
+                        return;
+                    };
+                    let payload = GlassExternallyModified {
+                        window: label.clone(),
+                        region,
+                        metadata: config.metadata.clone(),
+                        properties,
+                        config,
+                    };
+                    if let Err(e) = window.emit(GLASS_EXTERNALLY_MODIFIED_EVENT, payload) {
+                        warn!("Failed to emit external glass change: {}", e);
+                    }
+                    app.liquid_glass().refresh_foreground(&window);
+                },
+            );
+            if let Err(e) = result {
+                warn!(
+                    "Failed to observe external changes of '{}': {}",
+                    window.label(),
+                    e
+                );
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+        }
+    }
+
+    /// Emit [`APPEARANCE_CHANGED_EVENT`](crate::APPEARANCE_CHANGED_EVENT)
+    /// whenever the window's effective appearance changes
+    ///
+    /// Foreground recommendations are refreshed at the same time. Called for
+    /// every webview window when it is created.
+    pub(crate) fn observe_appearance(&self, window: &WebviewWindow<R>) {
+        #[cfg(target_os = "macos")]
+        {
+            let app = self.app.clone();
+            let label = window.label().to_string();
+            let result = glass_effect::observe_appearance(window, move |appearance| {
+                let Some(window) = app.get_webview_window(&label) else {
+                    return;
+                };
+                let payload = AppearanceChanged {
+                    window: label.clone(),
+                    appearance,
+                };
+                app.liquid_glass()
+                    .events
+                    .publish(GlassEvent::AppearanceChanged(payload.clone()));
+                if let Err(e) = window.emit(APPEARANCE_CHANGED_EVENT, payload) {
+                    warn!("Failed to emit appearance change: {}", e);
+                }
+                app.liquid_glass().refresh_foreground(&window);
+            });
+            if let Err(e) = result {
+                warn!(
+                    "Failed to observe appearance of '{}': {}",
+                    window.label(),
+                    e
+                );
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+        }
+    }
+
+    /// Emit [`KEY_STATE_CHANGED_EVENT`](crate::KEY_STATE_CHANGED_EVENT) when
+    /// the window becomes or resigns key and crossfades fallback glass
+    ///
+    /// Called for every webview window when it is created; the event is only
+    /// emitted while the window has views with `key_state_transition_ms` set.
+    pub(crate) fn observe_key_state(&self, window: &WebviewWindow<R>) {
+        #[cfg(target_os = "macos")]
+        {
+            let app = self.app.clone();
+            let label = window.label().to_string();
+            let result =
+                glass_effect::observe_key_state(&self.app, window, move |key, duration_ms| {
+                    let Some(window) = app.get_webview_window(&label) else {
+                        return;
+                    };
+                    let payload = KeyStateChanged {
+                        window: label.clone(),
+                        key,
+                        duration_ms,
+                    };
+                    app.liquid_glass()
+                        .events
+                        .publish(GlassEvent::KeyStateChanged(payload.clone()));
+                    if let Err(e) = window.emit(KEY_STATE_CHANGED_EVENT, payload) {
+                        warn!("Failed to emit key state change: {}", e);
+                    }
+                });
+            if let Err(e) = result {
+                warn!(
+                    "Failed to observe key status of '{}': {}",
+                    window.label(),
+                    e
+                );
+            }
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+        }
+    }
+
+    /// Emit [`SYSTEM_COLORS_CHANGED_EVENT`](crate::SYSTEM_COLORS_CHANGED_EVENT)
+    /// to every window when the user changes their accent or highlight color
+    ///
+    /// Called once from plugin setup.
+    #[cfg(target_os = "macos")]
+    pub(crate) fn observe_system_colors(&self) {
+        let app = self.app.clone();
+        glass_effect::observe_system_colors(move |colors| {
+            let payload = SystemColorsChanged {
+                colors: colors.clone(),
+            };
+            app.liquid_glass()
+                .events
+                .publish(GlassEvent::SystemColorsChanged(payload.clone()));
+            if let Err(e) = app.emit(SYSTEM_COLORS_CHANGED_EVENT, payload) {
+                warn!("Failed to emit system colors change: {}", e);
+            }
+        });
+    }
+
+    /// Recompute a window's foreground recommendations and emit the changes
+    fn refresh_foreground(&self, window: &WebviewWindow<R>) {
+        let changed = self
+            .foregrounds(window)
+            .and_then(|current| self.foreground.update(window.label(), current));
+
+        match changed {
+            Ok(changed) => {
+                let views = if changed.is_empty() {
+                    Vec::new()
+                } else {
+                    self.glass_state(window).unwrap_or_default()
+                };
+                for mut payload in changed {
+                    payload.metadata = views
+                        .iter()
+                        .find(|view| view.region == payload.region)
+                        .and_then(|view| view.config.metadata.clone());
+                    if let Err(e) = window.emit(FOREGROUND_CHANGED_EVENT, payload) {
+                        warn!("Failed to emit foreground change: {}", e);
+                    }
+                }
+            }
+            Err(e) => warn!("Failed to recommend foreground colors: {}", e),
+        }
+    }
+
+    /// Float a component window (media controls, menus, pips, toasts, ...)
+    ///
+    /// Sets the full window level on macOS; elsewhere the level maps to
+    /// always-on-top and `can_join_all_spaces` to all workspaces, so the
+    /// components keep working where [`set_window_level`](Self::set_window_level)
+    /// is unsupported.
+    fn raise_component(&self, window: &WebviewWindow<R>, config: WindowLevelConfig) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            self.set_window_level(window, config)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            window.set_always_on_top(config.level != WindowLevel::Normal)?;
+            window.set_visible_on_all_workspaces(config.can_join_all_spaces)?;
+            Ok(())
+        }
+    }
+
+    /// Give a component window its rounded glass
+    ///
+    /// Uses [`make_rounded_glass_window`](Self::make_rounded_glass_window) on
+    /// macOS; elsewhere applies the glass as the window's effect when the
+    /// platform has one and otherwise leaves the window as created.
+    fn round_component(
+        &self,
+        window: &WebviewWindow<R>,
+        radius: f64,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            self.make_rounded_glass_window(window, radius, config)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let config = LiquidGlassConfig {
+                corner_radius: radius,
+                ..config.into()
+            };
+            match self.is_supported() {
+                true => self.set_effect(window, config),
+                false => Ok(()),
+            }
+        }
+    }
+
+    /// Record a failed glass operation in the audit log, if enabled
+    fn audit_failure(
+        &self,
+        label: &str,
+        region: Option<&str>,
+        config: Option<&LiquidGlassConfig>,
+        error: &Error,
+    ) {
+        if let Some(audit) = &self.audit {
+            audit.record(label, region, config, Some(error.to_string()));
+        }
+    }
+
+    /// Publish an applied or removed event to Rust subscribers, emit it to
+    /// the webview as [`APPLIED_EVENT`] or [`REMOVED_EVENT`] and audit it
+    ///
+    /// Every successful glass change goes through here, which makes it the
+    /// one place the audit log records them.
+    fn announce(&self, window: &WebviewWindow<R>, event: GlassEvent) {
+        let emitted = match &event {
+            GlassEvent::Applied {
+                window: label,
+                region,
+                config,
+            } => {
+                if let Some(audit) = &self.audit {
+                    audit.record(label, region.as_deref(), Some(config), None);
+                }
+                let view = self
+                    .glass_state(window)
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|view| &view.region == region);
+                let payload = GlassApplied {
+                    window: label.clone(),
+                    region: region.clone(),
+                    backend: view.as_ref().map(|view| view.backend),
+                    fallback_reason: view.and_then(|view| view.fallback_reason),
+                    config: (**config).clone(),
+                };
+                window.emit(APPLIED_EVENT, payload)
+            }
+            GlassEvent::Removed {
+                window: label,
+                region,
+            } => {
+                if let Some(audit) = &self.audit {
+                    audit.record(label, region.as_deref(), None, None);
+                }
+                if let Some(id) = region {
+                    self.region_channels.removed(label, id);
+                    self.rect_streams.close_region(&self.app, label, id);
+                }
+                let payload = GlassRemoved {
+                    window: label.clone(),
+                    region: region.clone(),
+                };
+                window.emit(REMOVED_EVENT, payload)
+            }
+            _ => Ok(()),
+        };
+        if let Err(e) = emitted {
+            warn!("Failed to emit glass lifecycle event: {}", e);
+        }
+        self.events.publish(event);
+    }
+
+    /// Emit [`FALLBACK_EVENT`] if one of the `applied` views of a window
+    /// renders with NSVisualEffectView
+    fn report_fallback(&self, window: &WebviewWindow<R>, applied: impl Fn(&GlassViewInfo) -> bool) {
+        let reason = self
+            .glass_state(window)
+            .unwrap_or_default()
+            .iter()
+            .filter(|view| applied(view))
+            .find_map(|view| view.fallback_reason);
+        let Some(reason) = reason else {
+            return;
+        };
+
+        let payload = GlassFallback {
+            window: window.label().to_string(),
+            reason,
+        };
+        self.events.publish(GlassEvent::Fallback(payload.clone()));
+        if let Err(e) = window.emit(FALLBACK_EVENT, payload) {
+            warn!("Failed to emit glass fallback: {}", e);
+        }
+    }
+
+    /// Tag a window into a theme group
+    ///
+    /// A window belongs to at most one group; joining a group leaves the
+    /// previous one. If the group already has an effect, it is applied to the
+    /// window right away.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassPreset, LiquidGlassExt};
+    ///
+    /// fn open_document(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass().join_theme_group(&window, "documents").unwrap();
+    ///     // Later: restyle every document window at once
+    ///     app.liquid_glass().set_theme_group_effect("documents", GlassPreset::Clear).unwrap();
+    /// }
+    /// ```
+    pub fn join_theme_group(&self, window: &WebviewWindow<R>, group: &str) -> Result<()> {
+        match self.groups.join(group, window.label())? {
+            Some(config) => self.set_effect(window, config),
+            None => Ok(()),
+        }
+    }
+
+    /// Remove a window from its theme group
+    ///
+    /// The window keeps its current effect.
+    pub fn leave_theme_group(&self, window: &WebviewWindow<R>) -> Result<()> {
+        self.groups.leave(window.label())
+    }
+
+    /// Apply a configuration to every window in a theme group
+    ///
+    /// The configuration is remembered and applied to windows that join the
+    /// group later. All members are updated even if one fails; the first
+    /// error is returned.
+    pub fn set_theme_group_effect(
+        &self,
+        group: &str,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        let config = config.into();
+        let mut result = Ok(());
+
+        for label in self.groups.set_config(group, config.clone())? {
+            let Some(window) = self.app.get_webview_window(&label) else {
+                // Window was closed without leaving the group
+                self.groups.leave(&label)?;
+                continue;
+            };
+            let applied = self.set_effect(&window, config.clone());
+            if result.is_ok() {
+                result = applied;
+            }
+        }
+
+        result
+    }
+
+    /// Labels of the windows in a theme group
+    pub fn theme_group_members(&self, group: &str) -> Result<Vec<String>> {
+        self.groups.members(group)
+    }
+
+    /// Set the window level and collection behavior of a glass window
+    ///
+    /// Lets overlay glass windows float above other windows, or above full-screen
+    /// apps when combined with `full_screen_auxiliary` and `can_join_all_spaces`.
+    /// Fails with [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, WindowLevel, WindowLevelConfig};
+    ///
+    /// fn float_overlay(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass().set_window_level(&window, WindowLevelConfig {
+    ///         level: WindowLevel::Status,
+    ///         can_join_all_spaces: true,
+    ///         full_screen_auxiliary: true,
+    ///         ..Default::default()
+    ///     }).unwrap();
+    /// }
+    /// ```
+    pub fn set_window_level(
+        &self,
+        window: &WebviewWindow<R>,
+        config: WindowLevelConfig,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_window_level(window, &config)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, config);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Set the NSWindow background color, or clear it with `None`
+    ///
+    /// Useful for managing the window's own background alongside the glass,
+    /// e.g. a faint base color under a clear variant. Accepts the same CSS
+    /// color strings as `tint_color`. Fails with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn set_background(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass().set_window_background(&window, Some("#ffffff10")).unwrap();
+    ///     app.liquid_glass().set_window_background(&window, None).unwrap();
+    /// }
+    /// ```
+    pub fn set_window_background(
+        &self,
+        window: &WebviewWindow<R>,
+        color: Option<&str>,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_window_background(window, color)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, color);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Turn a window into a rounded glass window in one call
+    ///
+    /// Makes the window and its webview transparent, hides the title bar
+    /// chrome, rounds the window to `radius`, applies `config` with a matching
+    /// corner radius and window shape, and refreshes the window shadow so it
+    /// follows the rounded corners. Works best on windows created with
+    /// `transparent(true)`. Fails with [`Error::UnsupportedPlatform`] on
+    /// other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassPreset, LiquidGlassExt};
+    ///
+    /// fn round(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .make_rounded_glass_window(&window, 16.0, GlassPreset::Hud)
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn make_rounded_glass_window(
+        &self,
+        window: &WebviewWindow<R>,
+        radius: f64,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        let config = LiquidGlassConfig {
+            corner_radius: radius,
+            window_shape: Some(WindowShape::Rounded { radius }),
+            ..config.into()
+        };
+
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_webview_draws_background(window, false)?;
+            self.set_effect(window, config)?;
+            // Last, so the shadow is computed from the final window content
+            glass_effect::round_window(window, radius)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, config);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Create a window that is transparent all the way down and attach glass
+    /// to it
+    ///
+    /// The window gets a clear, non-opaque background, a transparent title
+    /// bar over a full-size content view, and a webview that draws no
+    /// background, so the glass fills the whole window. It stays hidden until
+    /// the glass is attached and is then shown if `options.visible` is set.
+    /// On other platforms the window is created with Tauri's transparency and
+    /// the config applied as with [`set_effect`](Self::set_effect).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassConfig, LiquidGlassExt, WindowOptions};
+    ///
+    /// fn open_inspector(app: tauri::AppHandle) {
+    ///     let options = WindowOptions {
+    ///         url: tauri::WebviewUrl::App("inspector.html".into()),
+    ///         width: 320.0,
+    ///         ..Default::default()
+    ///     };
+    ///     app.liquid_glass()
+    ///         .create_glass_window("inspector", options, LiquidGlassConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn create_glass_window(
+        &self,
+        label: &str,
+        options: WindowOptions,
+        config: LiquidGlassConfig,
+    ) -> Result<WebviewWindow<R>> {
+        let mut builder = WebviewWindowBuilder::new(&self.app, label, options.url)
+            .title(&options.title)
+            .inner_size(options.width, options.height)
+            .resizable(options.resizable)
+            .visible(false);
+        if options.center {
+            builder = builder.center();
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            builder = builder.transparent(true);
+        }
+        let window = builder.build()?;
+
+        let result = (|| {
+            #[cfg(target_os = "macos")]
+            {
+                glass_effect::make_window_transparent(&window, options.hidden_title)?;
+                glass_effect::set_webview_draws_background(&window, false)?;
+            }
+            self.set_effect(&window, config)?;
+            if options.visible {
+                window.show()?;
+            }
+            Ok(())
+        })();
+        if let Err(e) = result {
+            let _ = window.destroy();
+            return Err(e);
+        }
+        Ok(window)
+    }
+
+    /// Switch a window between transparent and opaque at runtime
+    ///
+    /// Transparent makes the NSWindow non-opaque with a clear background so
+    /// glass behind the webview shows through; opaque restores the system
+    /// window background color. Pair with
+    /// [`set_webview_transparent`](Self::set_webview_transparent), e.g. to
+    /// honor a "reduce transparency" preference. Fails with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn use_opaque_mode(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let glass = app.liquid_glass();
+    ///     glass.set_window_transparent(&window, false).unwrap();
+    ///     glass.set_webview_transparent(&window, false).unwrap();
+    /// }
+    /// ```
+    pub fn set_window_transparent(
+        &self,
+        window: &WebviewWindow<R>,
+        transparent: bool,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_window_transparent(window, transparent)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, transparent);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Switch a window's webview between transparent and opaque at runtime
+    ///
+    /// Transparent stops the WKWebView from drawing its background and
+    /// clears its under-page color (macOS 12+); opaque restores both
+    /// defaults. Fails with [`Error::UnsupportedPlatform`] on other platforms.
+    pub fn set_webview_transparent(
+        &self,
+        window: &WebviewWindow<R>,
+        transparent: bool,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_webview_transparent(window, transparent)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, transparent);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Toggle whether the window's webview draws its own opaque background
+    ///
+    /// Pass `false` for glass-transparent mode and `true` for opaque content,
+    /// e.g. to honor a "reduce transparency" user setting at runtime. Fails
+    /// with [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn make_transparent(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass().set_webview_draws_background(&window, false).unwrap();
+    /// }
+    /// ```
+    pub fn set_webview_draws_background(
+        &self,
+        window: &WebviewWindow<R>,
+        draws_background: bool,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_webview_draws_background(window, draws_background)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, draws_background);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Turn a window into a fullscreen media-controls overlay
+    ///
+    /// Sizes the window into a strip anchored to the bottom center of its
+    /// monitor, floats it above full-screen content, applies the glass and
+    /// shows it. The window hides itself after `auto_hide_after_ms` unless
+    /// [`reveal_media_controls`](Self::reveal_media_controls) is called again;
+    /// visibility changes are emitted as
+    /// [`MEDIA_CONTROLS_VISIBILITY_EVENT`](crate::MEDIA_CONTROLS_VISIBILITY_EVENT).
+    ///
+    /// The window should be created transparent, undecorated and hidden.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, MediaControlsConfig};
+    ///
+    /// fn show_controls(app: tauri::AppHandle, controls: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .attach_media_controls(&controls, MediaControlsConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn attach_media_controls(
+        &self,
+        window: &WebviewWindow<R>,
+        config: MediaControlsConfig,
+    ) -> Result<()> {
+        media_controls::position_at_bottom(window, &config)?;
+        self.raise_component(
+            window,
+            WindowLevelConfig {
+                level: WindowLevel::Status,
+                can_join_all_spaces: true,
+                full_screen_auxiliary: true,
+                ignores_cycle: true,
+                ..Default::default()
+            },
+        )?;
+        self.set_effect(window, config.glass)?;
+
+        self.media_controls
+            .attach(window, config.auto_hide_after_ms.map(Duration::from_millis))
+    }
+
+    /// Show a media-controls window and restart its auto-hide timer
+    ///
+    /// Call this on user activity such as mouse movement over the video.
+    pub fn reveal_media_controls(&self, window: &WebviewWindow<R>) -> Result<()> {
+        self.media_controls.reveal(window)
+    }
+
+    /// Stop auto-hiding a media-controls window
+    ///
+    /// The window keeps its glass and level; pending hide timers are cancelled.
+    pub fn detach_media_controls(&self, window: &WebviewWindow<R>) -> Result<()> {
+        self.media_controls.detach(window.label())
+    }
+
+    /// Show a window as a glass context menu
+    ///
+    /// Sizes the window, places it next to `at` (screen coordinates, the
+    /// cursor if `None`) flipped to stay on screen, floats it at pop-up menu
+    /// level, rounds it with glass and a shadow, then shows and focuses it.
+    /// The menu hides itself when it loses focus; dismissals are emitted as
+    /// [`CONTEXT_MENU_DISMISSED_EVENT`](crate::CONTEXT_MENU_DISMISSED_EVENT).
+    ///
+    /// The window should be created transparent, undecorated, hidden and
+    /// skipped from the taskbar. Calling this again while the menu is shown
+    /// moves it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{ContextMenuConfig, LiquidGlassExt};
+    ///
+    /// fn open_menu(app: tauri::AppHandle, menu: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .show_context_menu(&menu, None, ContextMenuConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn show_context_menu(
+        &self,
+        window: &WebviewWindow<R>,
+        at: Option<LogicalPosition<f64>>,
+        config: ContextMenuConfig,
+    ) -> Result<()> {
+        context_menu::position_near(window, at, &config)?;
+        self.raise_component(
+            window,
+            WindowLevelConfig {
+                level: WindowLevel::PopUpMenu,
+                full_screen_auxiliary: true,
+                ignores_cycle: true,
+                ..Default::default()
+            },
+        )?;
+        self.round_component(window, config.corner_radius, config.glass)?;
+
+        self.context_menus.open(window)?;
+        window.show()?;
+        window.set_focus()?;
+        Ok(())
+    }
+
+    /// Hide a context menu window, e.g. after an item was chosen
+    ///
+    /// Returns false if the window is not a shown context menu.
+    pub fn dismiss_context_menu(&self, window: &WebviewWindow<R>) -> Result<bool> {
+        self.context_menus
+            .dismiss(window, ContextMenuDismissReason::Dismissed)
+    }
+
+    /// Hide a context menu or menubar panel window that lost focus
+    pub(crate) fn window_blurred(&self, label: &str) {
+        let Some(window) = self.app.get_webview_window(label) else {
+            return;
+        };
+        if let Err(e) = self
+            .context_menus
+            .dismiss(&window, ContextMenuDismissReason::Blur)
+        {
+            warn!("Failed to dismiss context menu '{}': {}", label, e);
+        }
+        if let Err(e) = self.launcher_blurred(&window) {
+            warn!("Failed to dismiss launcher '{}': {}", label, e);
+        }
+        #[cfg(feature = "tray-icon")]
+        if let Err(e) = self.menubar_panels.window_blurred(&window) {
+            warn!("Failed to hide menubar panel '{}': {}", label, e);
+        }
+    }
+
+    /// Show a window by fading its alpha in over `duration`
+    ///
+    /// A window that is already visible (e.g. mid fade-out) fades back in
+    /// from its current alpha. On other platforms the window is just shown.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn reveal(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .show_with_fade(&window, Duration::from_millis(200))
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn show_with_fade(&self, window: &WebviewWindow<R>, duration: Duration) -> Result<()> {
+        self.fades.start(window.label())?;
+
+        #[cfg(target_os = "macos")]
+        {
+            if !window.is_visible()? {
+                glass_effect::set_window_alpha(window, 0.0)?;
+                window.show()?;
+            }
+            glass_effect::animate_window(window, None, Some(1.0), duration)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = duration;
+            window.show().map_err(Into::into)
+        }
+    }
+
+    /// Hide a window by fading its alpha out over `duration`
+    ///
+    /// The window is hidden once the fade completes and its alpha restored,
+    /// unless [`show_with_fade`](Self::show_with_fade) was called meanwhile.
+    /// On other platforms the window is just hidden.
+    pub fn hide_with_fade(&self, window: &WebviewWindow<R>, duration: Duration) -> Result<()> {
+        let generation = self.fades.start(window.label())?;
+
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::animate_window(window, None, Some(0.0), duration)?;
+
+            let app = self.app.clone();
+            let window = window.clone();
+            thread::spawn(move || {
+                thread::sleep(duration);
+                if !app
+                    .liquid_glass()
+                    .fades
+                    .is_current(window.label(), generation)
+                {
+                    return; // faded back in
+                }
+                let result = window
+                    .hide()
+                    .map_err(Into::into)
+                    .and_then(|_| glass_effect::set_window_alpha(&window, 1.0));
+                if let Err(e) = result {
+                    warn!("Failed to hide '{}' after fading: {}", window.label(), e);
+                }
+            });
+            Ok(())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (duration, generation);
+            window.hide().map_err(Into::into)
+        }
+    }
+
+    /// Present a window as a glass sheet attached to `parent`
+    ///
+    /// The sheet is sized, made transparent with rounded glass while still
+    /// hidden, then attached with `beginSheet` so AppKit animates it out of
+    /// the parent's title bar and keeps it attached while the parent moves.
+    /// The parent stays blocked until
+    /// [`dismiss_sheet`](Self::dismiss_sheet) is called. On other platforms
+    /// the window is simply shown. Emits
+    /// [`SHEET_VISIBILITY_EVENT`](crate::SHEET_VISIBILITY_EVENT); presenting
+    /// a sheet that is already presented does nothing.
+    ///
+    /// Create the sheet window hidden and undecorated.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, SheetConfig};
+    ///
+    /// fn ask(app: tauri::AppHandle, dialog: tauri::WebviewWindow, main: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .present_sheet(&dialog, &main, SheetConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn present_sheet(
+        &self,
+        sheet: &WebviewWindow<R>,
+        parent: &WebviewWindow<R>,
+        config: SheetConfig,
+    ) -> Result<()> {
+        if !self.sheets.present(sheet.label(), parent.label())? {
+            return Ok(());
+        }
+
+        let result = (|| {
+            if config.width.is_some() || config.height.is_some() {
+                let current = sheet.inner_size()?.to_logical::<f64>(sheet.scale_factor()?);
+                sheet.set_size(LogicalSize::new(
+                    config.width.unwrap_or(current.width),
+                    config.height.unwrap_or(current.height),
+                ))?;
+            }
+            self.round_component(sheet, config.corner_radius, config.glass.clone())?;
+
+            #[cfg(target_os = "macos")]
+            glass_effect::begin_sheet(parent, sheet)?;
+            #[cfg(not(target_os = "macos"))]
+            {
+                sheet.show()?;
+                sheet.set_focus()?;
+            }
+            Ok(())
+        })();
+        if let Err(e) = result {
+            self.sheets.dismiss(sheet.label())?;
+            return Err(e);
+        }
+
+        sheet::emit_visibility(sheet, parent.label(), true);
+        Ok(())
+    }
+
+    /// Dismiss a sheet presented with [`present_sheet`](Self::present_sheet)
+    ///
+    /// Returns false if the window is not a presented sheet.
+    pub fn dismiss_sheet(&self, sheet: &WebviewWindow<R>) -> Result<bool> {
+        let Some(parent) = self.sheets.dismiss(sheet.label())? else {
+            return Ok(false);
+        };
+
+        #[cfg(target_os = "macos")]
+        if !glass_effect::end_sheet(sheet)? {
+            sheet.hide()?;
+        }
+        #[cfg(not(target_os = "macos"))]
+        sheet.hide()?;
+
+        sheet::emit_visibility(sheet, &parent, false);
+        Ok(true)
+    }
+
+    /// Turn a window into a picture-in-picture mini window
+    ///
+    /// Sizes the window, floats it according to `config.level` (above other
+    /// windows on every Space by default), applies rounded glass, places it in
+    /// `config.corner` and shows it. Whenever the user drops the window after
+    /// dragging it (e.g. by a `data-tauri-drag-region`), it animates to the
+    /// nearest corner of its monitor and
+    /// [`PIP_SNAPPED_EVENT`](crate::PIP_SNAPPED_EVENT) is emitted.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, PipConfig, ScreenCorner};
+    ///
+    /// fn show_mini_player(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .attach_pip(&window, PipConfig {
+    ///             corner: ScreenCorner::TopRight,
+    ///             ..Default::default()
+    ///         })
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn attach_pip(&self, window: &WebviewWindow<R>, config: PipConfig) -> Result<()> {
+        self.raise_component(window, config.level.clone())?;
+        self.round_component(window, config.corner_radius, config.glass.clone())?;
+        window.set_size(LogicalSize::new(config.width, config.height))?;
+
+        let corner = config.corner;
+        self.pips.attach(window.label(), config)?;
+        self.snap_pip(window, Some(corner))?;
+        window.show()?;
+        Ok(())
+    }
+
+    /// Stop snapping a picture-in-picture window; the window keeps its glass
+    ///
+    /// Returns false if the window was not attached.
+    pub fn detach_pip(&self, window: &WebviewWindow<R>) -> Result<bool> {
+        self.pips.detach(window.label())
+    }
+
+    /// Animate a picture-in-picture window into `corner`, or the nearest
+    /// corner with `None`, and emit the snap
+    pub fn snap_pip(
+        &self,
+        window: &WebviewWindow<R>,
+        corner: Option<ScreenCorner>,
+    ) -> Result<ScreenCorner> {
+        let (origin, area) = pip::work_area(window)?;
+        let corner = match corner {
+            Some(corner) => corner,
+            None => pip::nearest_corner(window, origin, area)?,
+        };
+        let config = self
+            .pips
+            .snapping(window.label())?
+            .ok_or_else(|| Error::WindowNotFound(window.label().to_string()))?;
+
+        let position = pip::corner_position(corner, &config, origin, area);
+        #[cfg(target_os = "macos")]
+        glass_effect::animate_window(
+            window,
+            Some((position, LogicalSize::new(config.width, config.height))),
+            None,
+            Duration::from_millis(config.snap_animation_ms),
+        )?;
+        #[cfg(not(target_os = "macos"))]
+        window.set_position(position)?;
+
+        pip::emit_snapped(window, corner);
+        Ok(corner)
+    }
+
+    /// Snap a picture-in-picture window once the user stops dragging it
+    pub(crate) fn window_moved(&self, label: &str) {
+        match self.pips.moved(label) {
+            Ok(true) => {}
+            Ok(false) => return,
+            Err(e) => {
+                warn!("Failed to track pip move '{}': {}", label, e);
+                return;
+            }
+        }
+
+        let app = self.app.clone();
+        let label = label.to_string();
+        thread::spawn(move || {
+            let pips = &app.liquid_glass().pips;
+            let mut seen = pips.generation(&label).ok().flatten();
+            loop {
+                thread::sleep(pip::SNAP_DEBOUNCE);
+                let current = match pips.generation(&label) {
+                    Ok(Some(current)) => current,
+                    _ => return, // detached
+                };
+                if seen != Some(current) {
+                    seen = Some(current);
+                    continue; // still moving
+                }
+                #[cfg(target_os = "macos")]
+                if glass_effect::primary_mouse_button_down() {
+                    continue; // held still mid-drag
+                }
+                break;
+            }
+
+            let result = pips
+                .settled(&label)
+                .and_then(|_| {
+                    app.get_webview_window(&label)
+                        .ok_or_else(|| Error::WindowNotFound(label.clone()))
+                })
+                .and_then(|window| app.liquid_glass().snap_pip(&window, None));
+            if let Err(e) = result {
+                warn!("Failed to snap pip '{}': {}", label, e);
+            }
+        });
+    }
+
+    /// Create a Spotlight-style launcher window
+    ///
+    /// The window is an undecorated, hidden glass palette with a fixed width
+    /// that floats over every Space and full-screen app. Summon it with
+    /// [`toggle_launcher`](Self::toggle_launcher) from a global shortcut; it is
+    /// centered on the monitor under the cursor and dismissed when it loses
+    /// focus. Let it grow with its content through
+    /// [`resize_launcher`](Self::resize_launcher) (`observeLauncher()` in
+    /// guest-js does this and handles Escape). Visibility changes are emitted
+    /// as [`LAUNCHER_VISIBILITY_EVENT`](crate::LAUNCHER_VISIBILITY_EVENT).
+    ///
+    /// If a window labelled `label` already exists it is configured instead
+    /// and `url` is ignored.
+    ///
+    /// With the `launcher-shortcut` feature, a `shortcut` in the config is
+    /// registered as a global shortcut toggling the launcher; a shortcut that
+    /// can't be registered fails with [`Error::ShortcutFailed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri::WebviewUrl;
+    /// use tauri_plugin_liquid_glass::{LauncherConfig, LiquidGlassExt};
+    ///
+    /// fn setup_launcher(app: tauri::AppHandle) {
+    ///     let url = WebviewUrl::App("launcher.html".into());
+    ///     app.liquid_glass()
+    ///         .create_launcher("launcher", url, LauncherConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn create_launcher(
+        &self,
+        label: &str,
+        url: WebviewUrl,
+        config: LauncherConfig,
+    ) -> Result<WebviewWindow<R>> {
+        let window = match self.app.get_webview_window(label) {
+            Some(window) => window,
+            None => WebviewWindowBuilder::new(&self.app, label, url)
+                .title("")
+                .inner_size(config.width, config.min_height)
+                .decorations(false)
+                .resizable(false)
+                .skip_taskbar(true)
+                .visible(false)
+                .build()?,
+        };
+
+        self.raise_component(
+            &window,
+            WindowLevelConfig {
+                level: WindowLevel::Status,
+                can_join_all_spaces: true,
+                full_screen_auxiliary: true,
+                ignores_cycle: true,
+                ..Default::default()
+            },
+        )?;
+        self.round_component(&window, config.corner_radius, config.glass.clone())?;
+        #[cfg(target_os = "macos")]
+        if config.hide_from_dock {
+            self.app
+                .set_activation_policy(tauri::ActivationPolicy::Accessory)?;
+        }
+        #[cfg(feature = "launcher-shortcut")]
+        if let Some(shortcut) = &config.shortcut {
+            launcher::register_shortcut(&self.app, label, shortcut)?;
+        }
+
+        self.launchers.insert(label, config)?;
+        Ok(window)
+    }
+
+    /// Center a launcher on the monitor under the cursor, show and focus it
+    pub fn show_launcher(&self, window: &WebviewWindow<R>) -> Result<()> {
+        let config = self.launcher_config(window)?;
+        launcher::position_centered(window, &config)?;
+        window.show()?;
+        window.set_focus()?;
+        launcher::emit_visibility(window, true);
+        Ok(())
+    }
+
+    /// Show a launcher, or dismiss it if it is already visible
+    ///
+    /// Call this from the app's global shortcut handler.
+    pub fn toggle_launcher(&self, window: &WebviewWindow<R>) -> Result<()> {
+        if window.is_visible()? {
+            self.dismiss_launcher(window).map(|_| ())
+        } else {
+            self.show_launcher(window)
+        }
+    }
+
+    /// Hide a launcher, e.g. on Escape or after an item was chosen
+    ///
+    /// Returns false if the launcher was not visible.
+    pub fn dismiss_launcher(&self, window: &WebviewWindow<R>) -> Result<bool> {
+        self.launcher_config(window)?;
+        if !window.is_visible()? {
+            return Ok(false);
+        }
+        window.hide()?;
+        launcher::emit_visibility(window, false);
+        Ok(true)
+    }
+
+    /// Fit a launcher to `content_height`, keeping its top edge in place
+    ///
+    /// The height is clamped to the launcher's `min_height`/`max_height`;
+    /// returns the height applied.
+    pub fn resize_launcher(&self, window: &WebviewWindow<R>, content_height: f64) -> Result<f64> {
+        let height = self.launcher_config(window)?.clamp_height(content_height);
+        launcher::resize_keeping_top(window, height)?;
+        Ok(height)
+    }
+
+    fn launcher_config(&self, window: &WebviewWindow<R>) -> Result<LauncherConfig> {
+        self.launchers
+            .get(window.label())?
+            .ok_or_else(|| Error::WindowNotFound(window.label().to_string()))
+    }
+
+    /// Dismiss a launcher that lost focus, if it dismisses on blur
+    fn launcher_blurred(&self, window: &WebviewWindow<R>) -> Result<()> {
+        match self.launchers.get(window.label())? {
+            Some(config) if config.dismiss_on_blur => self.dismiss_launcher(window).map(|_| ()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Show a transient glass toast in a corner of the primary monitor
+    ///
+    /// With [`ToastContent::Html`] the plugin creates a small window rendering
+    /// the HTML and closes it when the toast ends; with
+    /// [`ToastContent::Window`] an app-created window is shown and hidden
+    /// again. The toast slides in without taking focus, pushes older toasts in
+    /// the same corner aside, and fades out after `duration_ms` or on
+    /// [`dismiss_glass_toast`](Self::dismiss_glass_toast). Dismissals are
+    /// emitted as [`TOAST_DISMISSED_EVENT`](crate::TOAST_DISMISSED_EVENT).
+    ///
+    /// Returns the label of the toast window.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, ToastContent, ToastOptions};
+    ///
+    /// fn notify(app: tauri::AppHandle) {
+    ///     app.liquid_glass()
+    ///         .show_glass_toast(ToastOptions {
+    ///             content: ToastContent::Html("<p>Export finished</p>".into()),
+    ///             ..Default::default()
+    ///         })
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn show_glass_toast(&self, options: ToastOptions) -> Result<String> {
+        let size = LogicalSize::new(options.width, options.height);
+        let (window, owned) = match &options.content {
+            ToastContent::Html(html) => {
+                let window = WebviewWindowBuilder::new(
+                    &self.app,
+                    self.toasts.next_label(),
+                    WebviewUrl::External(toast::html_url(html)?),
+                )
+                .title("")
+                .inner_size(size.width, size.height)
+                .decorations(false)
+                .resizable(false)
+                .focused(false)
+                .skip_taskbar(true)
+                .visible(false)
+                .build()?;
+                (window, true)
+            }
+            ToastContent::Window(label) => (
+                self.app
+                    .get_webview_window(label)
+                    .ok_or_else(|| Error::WindowNotFound(label.clone()))?,
+                false,
+            ),
+        };
+
+        self.raise_component(
+            &window,
+            WindowLevelConfig {
+                level: WindowLevel::Status,
+                can_join_all_spaces: true,
+                full_screen_auxiliary: true,
+                ignores_cycle: true,
+                ..Default::default()
+            },
+        )?;
+        self.round_component(&window, options.corner_radius, options.glass.clone())?;
+
+        let toast = Toast {
+            label: window.label().to_string(),
+            position: options.position,
+            size,
+            margin: options.margin,
+            spacing: options.spacing,
+            animation_ms: options.animation_ms,
+            owned,
+            generation: self.toasts.next_generation(),
+        };
+        let generation = toast.generation;
+        self.toasts.push(toast.clone())?;
+
+        // Start next to the slot, transparent, and let the restack slide it in
+        let (origin, area) = self.toast_area()?;
+        let stack = self.toasts.stack(toast.position)?;
+        let index = stack
+            .iter()
+            .position(|t| t.label == toast.label)
+            .unwrap_or(0);
+        let slot = toast::slots(&stack, origin, area)[index];
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_window_frame(
+                &window,
+                toast::enter_from(&toast, slot),
+                size,
+                Some(0.0),
+            )?;
+            glass_effect::order_front_without_focus(&window)?;
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            window.set_size(size)?;
+            window.set_position(slot)?;
+            window.show()?;
+        }
+        self.restack_toasts(toast.position);
+
+        if options.duration_ms > 0 {
+            let app = self.app.clone();
+            let label = toast.label.clone();
+            let delay = Duration::from_millis(options.duration_ms);
+            thread::spawn(move || {
+                thread::sleep(delay);
+                let result = app.liquid_glass().end_toast(
+                    &label,
+                    Some(generation),
+                    ToastDismissReason::Timeout,
+                );
+                if let Err(e) = result {
+                    warn!("Failed to expire toast '{}': {}", label, e);
+                }
+            });
+        }
+
+        Ok(toast.label)
+    }
+
+    /// Fade out a toast before its duration elapses
+    ///
+    /// Returns false if `label` is not a shown toast.
+    pub fn dismiss_glass_toast(&self, label: &str) -> Result<bool> {
+        self.end_toast(label, None, ToastDismissReason::Dismissed)
+    }
+
+    /// Fade out a toast (if `generation` is still the shown one), then close
+    /// or hide its window and restack the rest
+    fn end_toast(
+        &self,
+        label: &str,
+        generation: Option<u64>,
+        reason: ToastDismissReason,
+    ) -> Result<bool> {
+        let Some(toast) = self.toasts.remove(label, generation)? else {
+            return Ok(false);
+        };
+        self.restack_toasts(toast.position);
+
+        let Some(window) = self.app.get_webview_window(label) else {
+            return Ok(true);
+        };
+        let animation = Duration::from_millis(toast.animation_ms);
+        #[cfg(target_os = "macos")]
+        glass_effect::animate_window(&window, None, Some(0.0), animation)?;
+
+        let app = self.app.clone();
+        thread::spawn(move || {
+            thread::sleep(animation);
+            // Shown again while fading out
+            if let Ok(stack) = app.liquid_glass().toasts.stack(toast.position) {
+                if stack.iter().any(|t| t.label == toast.label) {
+                    return;
+                }
+            }
+
+            let result = if toast.owned {
+                window.close()
+            } else {
+                window.hide()
+            };
+            if let Err(e) = result {
+                warn!("Failed to close toast '{}': {}", toast.label, e);
+            }
+            // Restore the alpha so the app can show its window normally again
+            #[cfg(target_os = "macos")]
+            if !toast.owned {
+                if let Err(e) = glass_effect::set_window_alpha(&window, 1.0) {
+                    warn!("Failed to reset toast '{}': {}", toast.label, e);
+                }
+            }
+
+            let payload = ToastDismissed {
+                window: toast.label.clone(),
+                reason,
+            };
+            if let Err(e) = app.emit(TOAST_DISMISSED_EVENT, payload) {
+                warn!("Failed to emit toast dismissal: {}", e);
+            }
+        });
+        Ok(true)
+    }
+
+    /// Work area of the primary monitor, in logical pixels
+    fn toast_area(&self) -> Result<(LogicalPosition<f64>, LogicalSize<f64>)> {
+        let Some(monitor) = self.app.primary_monitor()? else {
+            return Err(Error::UnsupportedCapability(
+                "no primary monitor".to_string(),
+            ));
+        };
+        let scale = monitor.scale_factor();
+        let area = monitor.work_area();
+        Ok((area.position.to_logical(scale), area.size.to_logical(scale)))
+    }
+
+    /// Animate every toast in a corner to its slot
+    fn restack_toasts(&self, position: ScreenCorner) {
+        let result = self.toast_area().and_then(|(origin, area)| {
+            let stack = self.toasts.stack(position)?;
+            for (toast, slot) in stack.iter().zip(toast::slots(&stack, origin, area)) {
+                let Some(window) = self.app.get_webview_window(&toast.label) else {
+                    continue;
+                };
+                #[cfg(target_os = "macos")]
+                glass_effect::animate_window(
+                    &window,
+                    Some((slot, toast.size)),
+                    Some(1.0),
+                    Duration::from_millis(toast.animation_ms),
+                )?;
+                #[cfg(not(target_os = "macos"))]
+                window.set_position(slot)?;
+            }
+            Ok(())
+        });
+        if let Err(e) = result {
+            warn!("Failed to restack toasts: {}", e);
+        }
+    }
+
+    /// Show a window as a glass panel under a tray item, toggled by clicking it
+    ///
+    /// Floats the window above other windows on every Space, rounds it with
+    /// glass and a shadow, and from then on shows it centered under the tray
+    /// item on left click (kept on the item's monitor) and hides it on the
+    /// next click or, with `hide_on_blur`, when it loses focus. Visible panels
+    /// follow the tray item when displays change. Visibility changes are
+    /// emitted as
+    /// [`MENUBAR_PANEL_VISIBILITY_EVENT`](crate::MENUBAR_PANEL_VISIBILITY_EVENT).
+    ///
+    /// The window should be created transparent, undecorated, hidden and
+    /// skipped from the taskbar. Requires the `tray-icon` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, MenubarPanelConfig};
+    ///
+    /// fn menubar_app(app: tauri::AppHandle) {
+    ///     app.liquid_glass()
+    ///         .attach_menubar_panel("main-tray", "panel", MenubarPanelConfig::default())
+    ///         .unwrap();
+    /// }
+    /// ```
+    #[cfg(feature = "tray-icon")]
+    pub fn attach_menubar_panel(
+        &self,
+        tray_id: &str,
+        window_label: &str,
+        config: MenubarPanelConfig,
+    ) -> Result<()> {
+        let window = self
+            .app
+            .get_webview_window(window_label)
+            .ok_or_else(|| Error::WindowNotFound(window_label.to_string()))?;
+        if self.app.tray_by_id(tray_id).is_none() {
+            return Err(Error::TrayNotFound(tray_id.to_string()));
+        }
+
+        self.raise_component(
+            &window,
+            WindowLevelConfig {
+                level: WindowLevel::Status,
+                can_join_all_spaces: true,
+                full_screen_auxiliary: true,
+                ignores_cycle: true,
+                ..Default::default()
+            },
+        )?;
+        self.round_component(&window, config.corner_radius, config.glass.clone())?;
+
+        if self.menubar_panels.attach(tray_id, window_label, config)? {
+            self.app.on_tray_icon_event(|app, event| {
+                let Some((tray_id, rect)) = menubar_panel::left_click(&event) else {
+                    return;
+                };
+                if let Err(e) = app
+                    .liquid_glass()
+                    .menubar_panels
+                    .toggle(app, &tray_id, rect)
+                {
+                    warn!("Failed to toggle menubar panel of '{}': {}", tray_id, e);
+                }
+            });
+            #[cfg(target_os = "macos")]
+            {
+                let app = self.app.clone();
+                glass_effect::observe_screen_changes(move || {
+                    if let Err(e) = app.liquid_glass().menubar_panels.reposition(&app) {
+                        warn!("Failed to reposition menubar panels: {}", e);
+                    }
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Show or hide the panel of a tray item, as if the item was clicked
+    #[cfg(feature = "tray-icon")]
+    pub fn toggle_menubar_panel(&self, tray_id: &str) -> Result<()> {
+        let rect = self
+            .app
+            .tray_by_id(tray_id)
+            .ok_or_else(|| Error::TrayNotFound(tray_id.to_string()))?
+            .rect()?
+            .ok_or_else(|| Error::TrayNotFound(tray_id.to_string()))?;
+        self.menubar_panels.toggle(&self.app, tray_id, rect)
+    }
+
+    /// Stop toggling a window from a tray item
+    ///
+    /// The window keeps its glass and level. Returns false if the tray item
+    /// had no panel.
+    #[cfg(feature = "tray-icon")]
+    pub fn detach_menubar_panel(&self, tray_id: &str) -> Result<bool> {
+        self.menubar_panels.detach(tray_id)
+    }
+
+    /// Install, update or (with `None`) remove a window's scroll-edge strip
+    ///
+    /// The strip is a glass band along the top of the window that starts
+    /// hidden and fades in once content scrolls under it. Drive it with
+    /// [`report_scroll_offset`](Self::report_scroll_offset), or from the
+    /// frontend with `observeScrollEdge()`. Fails with
+    /// [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, ScrollEdgeConfig};
+    ///
+    /// fn enable_scroll_edge(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     app.liquid_glass()
+    ///         .set_scroll_edge_effect(&window, Some(ScrollEdgeConfig::default()))
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn set_scroll_edge_effect(
+        &self,
+        window: &WebviewWindow<R>,
+        config: Option<ScrollEdgeConfig>,
+    ) -> Result<()> {
+        if let Some(config) = &config {
+            config
+                .glass
+                .validate_colors()
+                .map_err(Error::InvalidColorFormat)?;
+        }
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::set_scroll_edge_effect(&self.app, window, config)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, config);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Report the content's scroll offset for a window's scroll-edge strip
+    ///
+    /// The strip fades in once `offset` reaches `show_after` and fades out at
+    /// or below `hide_below`. Does nothing if the window has no strip.
+    pub fn report_scroll_offset(&self, window: &WebviewWindow<R>, offset: f64) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::report_scroll_offset(&self.app, window, offset)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, offset);
+            Ok(())
+        }
+    }
+
+    /// Set an experimental NSGlassEffectView property on a window's glass views
+    ///
+    /// Returns [`Error::UnsupportedCapability`] when the running OS lacks the
+    /// property (see [`SupportDetails::experimental_properties`]) and
+    /// [`Error::UnsupportedPlatform`] off macOS. The value is stored in each
+    /// view's config, so it survives later updates and shows up in exports;
+    /// a `glass-applied` event is emitted for every view that changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{ExperimentalProperty, LiquidGlassExt};
+    ///
+    /// fn dim(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     if let Err(e) = app.liquid_glass().set_experimental_property(
+    ///         &window,
+    ///         ExperimentalProperty::Scrim,
+    ///         true,
+    ///     ) {
+    ///         println!("Scrim unavailable: {}", e);
+    ///     }
+    /// }
+    /// ```
+    pub fn set_experimental_property(
+        &self,
+        window: &WebviewWindow<R>,
+        property: ExperimentalProperty,
+        enabled: bool,
+    ) -> Result<()> {
+        #[cfg(target_os = "macos")]
+        {
+            let stored =
+                glass_effect::set_experimental_property(&self.app, window, property, enabled)?;
+            for (region, config) in stored {
+                self.announce(
+                    window,
+                    GlassEvent::Applied {
+                        window: window.label().to_string(),
+                        region,
+                        config: Box::new(config),
+                    },
+                );
+            }
+            Ok(())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, property, enabled);
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// List every glass view tracked by the plugin, across all windows
+    ///
+    /// Each entry reports the backend the view was created with, so apps can
+    /// apply contrast tweaks when running on the NSVisualEffectView fallback.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassBackendKind, LiquidGlassExt};
+    ///
+    /// fn uses_fallback(app: tauri::AppHandle) -> bool {
+    ///     app.liquid_glass()
+    ///         .list_glass_views()
+    ///         .unwrap()
+    ///         .iter()
+    ///         .any(|view| view.backend == GlassBackendKind::VisualEffect)
+    /// }
+    /// ```
+    pub fn list_glass_views(&self) -> Result<Vec<GlassViewInfo>> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::list_glass_views(&self.app)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Diagnose why glass may not show in a window
+    ///
+    /// Reports the OS support, window opacity and background, whether the
+    /// webview draws its background, the title bar style, the window's
+    /// registered views, and a plain-language list of problems found, e.g.
+    /// an opaque window or a view that left the hierarchy. Off macOS only
+    /// the window label is filled in.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn explain(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     for problem in app.liquid_glass().doctor(&window).unwrap().problems {
+    ///         eprintln!("{problem}");
+    ///     }
+    /// }
+    /// ```
+    pub fn doctor(&self, window: &WebviewWindow<R>) -> Result<GlassDoctorReport> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::diagnose(&self.app, window)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Ok(GlassDoctorReport {
+                window: window.label().to_string(),
+                ..Default::default()
+            })
+        }
+    }
+
+    /// Dump the view hierarchy under a window's content view
+    ///
+    /// Each node has the view's class, frame (top-left origin, in its
+    /// superview), visibility, backing layer properties and whether it is a
+    /// glass view registered by the plugin, which shows where the glass sits
+    /// relative to the WKWebView. Only available with the `devtools` feature;
+    /// fails with [`Error::UnsupportedPlatform`] on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn print_tree(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     let tree = app.liquid_glass().dump_view_hierarchy(&window).unwrap();
+    ///     println!("{}", serde_json::to_string_pretty(&tree).unwrap());
+    /// }
+    /// ```
+    #[cfg(feature = "devtools")]
+    pub fn dump_view_hierarchy(&self, window: &WebviewWindow<R>) -> Result<ViewNode> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::dump_view_hierarchy(&self.app, window)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+            Err(Error::UnsupportedPlatform)
+        }
+    }
+
+    /// Describe every window's glass views and configs as a versioned document
+    ///
+    /// The document serializes to JSON suitable for saving as a theme file
+    /// or attaching to a bug report.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn save_theme(app: tauri::AppHandle) {
+    ///     let state = app.liquid_glass().export_glass_state().unwrap();
+    ///     std::fs::write("theme.json", serde_json::to_string_pretty(&state).unwrap()).unwrap();
+    /// }
+    /// ```
+    pub fn export_glass_state(&self) -> Result<GlassStateDocument> {
+        let mut windows: BTreeMap<String, Vec<GlassViewState>> = BTreeMap::new();
+        for view in self.list_glass_views()? {
+            windows
+                .entry(view.window)
+                .or_default()
+                .push(GlassViewState {
+                    region: view.region,
+                    backend: Some(view.backend),
+                    config: view.config,
+                });
+        }
+
+        Ok(GlassStateDocument {
+            version: GLASS_STATE_VERSION,
+            macos_version: self.macos_version(),
+            windows: windows
+                .into_iter()
+                .map(|(label, views)| WindowGlassState { label, views })
+                .collect(),
+        })
+    }
+
+    /// Apply a glass state document produced by
+    /// [`export_glass_state`](Self::export_glass_state), e.g. a theme file
+    ///
+    /// The document is validated and every target window resolved before
+    /// anything changes. Each window ends up with exactly the described
+    /// views: its full-window glass is removed if the document has none, and
+    /// its regions are replaced as by [`apply_layout`](Self::apply_layout).
+    /// If applying fails part way, windows already changed are restored to
+    /// their previous state. Returns the labels of the windows changed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use std::collections::HashMap;
+    /// use tauri_plugin_liquid_glass::{GlassStateImportOptions, LiquidGlassExt};
+    ///
+    /// fn load_theme(app: tauri::AppHandle) {
+    ///     let json = std::fs::read_to_string("theme.json").unwrap();
+    ///     let options = GlassStateImportOptions {
+    ///         window_labels: HashMap::from([("main".into(), "editor".into())]),
+    ///         ..Default::default()
+    ///     };
+    ///     app.liquid_glass().import_glass_state(&json, options).unwrap();
+    /// }
+    /// ```
+    pub fn import_glass_state(
+        &self,
+        document: &str,
+        options: GlassStateImportOptions,
+    ) -> Result<Vec<String>> {
+        let document = serde_json::from_str(document)
+            .map_err(|e| e.to_string())
+            .and_then(migrate_glass_state)
+            .and_then(|value| {
+                serde_json::from_value::<GlassStateDocument>(value).map_err(|e| e.to_string())
+            })
+            .map_err(Error::InvalidGlassState)?;
+        document.validate().map_err(Error::InvalidGlassState)?;
+
+        let mut labels = HashSet::new();
+        let mut targets = Vec::new();
+        for state in &document.windows {
+            let label = options
+                .window_labels
+                .get(&state.label)
+                .unwrap_or(&state.label);
+            if !labels.insert(label) {
+                return Err(Error::InvalidGlassState(format!(
+                    "more than one window maps to {:?}",
+                    label
+                )));
+            }
+            match self.app.get_webview_window(label) {
+                Some(window) => targets.push((window, state)),
+                None if options.skip_missing_windows => {}
+                None => return Err(Error::WindowNotFound(label.clone())),
+            }
+        }
+
+        let previous = self.export_glass_state()?;
+        for (index, (window, state)) in targets.iter().enumerate() {
+            let Err(e) = self.apply_window_state(window, state) else {
+                continue;
+            };
+            for (window, _) in &targets[..=index] {
+                let restored = previous
+                    .windows
+                    .iter()
+                    .find(|previous| previous.label == window.label())
+                    .cloned()
+                    .unwrap_or_else(|| WindowGlassState {
+                        label: window.label().to_string(),
+                        views: Vec::new(),
+                    });
+                if let Err(e) = self.apply_window_state(window, &restored) {
+                    warn!(
+                        "Failed to restore glass state of '{}': {}",
+                        window.label(),
+                        e
+                    );
+                }
+            }
+            return Err(e);
+        }
+
+        Ok(targets
+            .into_iter()
+            .map(|(window, _)| window.label().to_string())
+            .collect())
+    }
+
+    /// Give `window` exactly the views described by `state`
+    fn apply_window_state(
+        &self,
+        window: &WebviewWindow<R>,
+        state: &WindowGlassState,
+    ) -> Result<()> {
+        let effect = state.effect().unwrap_or(LiquidGlassConfig {
+            enabled: false,
+            ..Default::default()
+        });
+        self.set_effect(window, effect)?;
+        self.apply_layout(window, state.layout())?;
+        Ok(())
+    }
+
+    /// Check every registered glass view and prune orphans
+    ///
+    /// A view is orphaned when its Tauri window no longer exists, or when it
+    /// was detached from its superview or window by other code. Orphans are
+    /// removed from the registry (and from any view hierarchy they are still
+    /// in) and reported.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn after_hot_reload(app: tauri::AppHandle) {
+    ///     let report = app.liquid_glass().validate_glass_views().unwrap();
+    ///     for orphan in report.orphans {
+    ///         println!("pruned {:?} of '{}': {:?}", orphan.region, orphan.window, orphan.reason);
+    ///     }
+    /// }
+    /// ```
+    pub fn validate_glass_views(&self) -> Result<GlassHealthReport> {
+        #[cfg(target_os = "macos")]
+        {
+            let report = glass_effect::validate_glass_views(&self.app)?;
+            for orphan in &report.orphans {
+                if let Some(id) = &orphan.region {
+                    self.region_channels.removed(&orphan.window, id);
+                }
+            }
+            Ok(report)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Ok(GlassHealthReport::default())
+        }
+    }
+
+    /// Drop all state kept for a destroyed window
+    ///
+    /// Called when a window is destroyed, so apps that churn windows don't
+    /// accumulate registry entries. Every cleanup runs even if an earlier one
+    /// fails; failures are logged one by one.
+    pub(crate) fn forget_window(&self, label: &str) {
+        self.main_thread.cancel(label, |_| true);
+        if let Some(audit) = &self.audit {
+            audit.forget(label);
+        }
+
+        let results = [
+            #[cfg(target_os = "macos")]
+            (
+                "glass views",
+                glass_effect::forget_window(&self.app, label).map(|_| ()),
+            ),
+            #[cfg(feature = "tray-icon")]
+            ("menubar panel", self.menubar_panels.forget(label)),
+            ("media controls", self.media_controls.detach(label)),
+            ("context menu", self.context_menus.forget(label)),
+            ("launcher", self.launchers.forget(label)),
+            ("picture-in-picture", self.pips.detach(label).map(|_| ())),
+            ("sheet", self.sheets.forget(label)),
+            ("fades", self.fades.forget(label)),
+            ("theme group", self.groups.leave(label)),
+            ("foreground", self.foreground.forget(label)),
+            ("component handles", self.components.take(label).map(|_| ())),
+            ("region channels", self.region_channels.forget(label)),
+            (
+                "rect streams",
+                self.rect_streams.close_window(&self.app, label),
+            ),
+        ];
+        for (state, result) in results {
+            if let Err(e) = result {
+                warn!(
+                    "Failed to forget {} of destroyed window '{}': {}",
+                    state, label, e
+                );
+            }
+        }
+
+        match self.toasts.remove(label, None) {
+            Ok(Some(toast)) => self.restack_toasts(toast.position),
+            Ok(None) => {}
+            Err(e) => warn!("Failed to forget toast '{}': {}", label, e),
+        }
+    }
+
+    /// Prune orphaned glass views in the background, logging what was removed
+    pub(crate) fn sweep_orphans(&self) {
+        match self.validate_glass_views() {
+            Ok(report) => {
+                for orphan in report.orphans {
+                    debug!(
+                        "Pruned orphaned glass view {:?} of '{}': {:?}",
+                        orphan.region, orphan.window, orphan.reason
+                    );
+                }
+            }
+            Err(e) => warn!("Failed to sweep orphaned glass views: {}", e),
+        }
+    }
+
+    /// Get the config of a window's full-window glass, `None` without one
+    ///
+    /// Returns the config as applied, in points. Use
+    /// [`glass_state`](Self::glass_state) to also learn the backend.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn log_tint(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     if let Ok(Some(config)) = app.liquid_glass().get_effect(&window) {
+    ///         println!("tint: {:?}", config.tint_color);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_effect(&self, window: &WebviewWindow<R>) -> Result<Option<LiquidGlassConfig>> {
+        let views = self.glass_state(window)?;
+        Ok(views
+            .into_iter()
+            .find(|view| view.region.is_none())
+            .map(|view| view.config))
+    }
+
+    /// Get the config of every window's full-window glass, keyed by label
+    ///
+    /// Configs are returned as applied, in points. Regions are not included;
+    /// use [`list_glass_views`](Self::list_glass_views) for those. Empty off
+    /// macOS.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn glass_windows(app: tauri::AppHandle) -> Vec<String> {
+    ///     let effects = app.liquid_glass().list().unwrap_or_default();
+    ///     effects.into_iter().map(|(label, _)| label).collect()
+    /// }
+    /// ```
+    pub fn list(&self) -> Result<Vec<(String, LiquidGlassConfig)>> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::list_effects(&self.app)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            Ok(Vec::new())
+        }
+    }
+
+    /// Get the glass views of a single window: its full-window glass and regions
+    pub fn glass_state(&self, window: &WebviewWindow<R>) -> Result<Vec<GlassViewInfo>> {
+        let mut views = self.list_glass_views()?;
+        views.retain(|view| view.window == window.label());
+        Ok(views)
+    }
+
+    /// Get the insets of a window's content view that lie outside the
+    /// display's safe area
+    ///
+    /// Non-zero in fullscreen on displays with a camera housing, where the top
+    /// of the content view extends beside the notch. Glass frames with
+    /// `safe_area` set stay within these insets on their own; use this for
+    /// web content that should avoid the housing too.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn toolbar_offset(app: tauri::AppHandle, window: tauri::WebviewWindow) -> f64 {
+    ///     app.liquid_glass().safe_area_insets(&window).unwrap().top
+    /// }
+    /// ```
+    pub fn safe_area_insets(&self, window: &WebviewWindow<R>) -> Result<SafeAreaInsets> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::content_safe_area_insets(window)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+            Ok(SafeAreaInsets::default())
+        }
+    }
+
+    /// Read the on-screen frame of a window's glass view
+    ///
+    /// `region` selects a named view; `None` selects the full-window glass.
+    /// The frame is reported in content view points and in the webview's CSS
+    /// pixels, so it can be compared with the DOM element it should cover.
+    /// Returns `None` if there is no such view.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn check_sidebar(app: tauri::AppHandle, window: tauri::WebviewWindow) {
+    ///     if let Some(frame) = app.liquid_glass().glass_frame(&window, Some("sidebar")).unwrap() {
+    ///         println!("sidebar is {}pt wide", frame.points.width);
+    ///     }
+    /// }
+    /// ```
+    pub fn glass_frame(
+        &self,
+        window: &WebviewWindow<R>,
+        region: Option<&str>,
+    ) -> Result<Option<GlassViewFrame>> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::glass_frame(&self.app, window, region)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = (window, region);
+            Ok(None)
+        }
+    }
+
+    /// Get the user's accent, highlight and focus ring colors
+    ///
+    /// Resolved for the app's effective appearance as `#rrggbbaa` strings.
+    /// Changes are emitted as
+    /// [`SYSTEM_COLORS_CHANGED_EVENT`](crate::SYSTEM_COLORS_CHANGED_EVENT).
+    /// Returns `None` on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn accent(app: tauri::AppHandle) {
+    ///     if let Some(colors) = app.liquid_glass().system_colors() {
+    ///         println!("accent is {}", colors.accent);
+    ///     }
+    /// }
+    /// ```
+    pub fn system_colors(&self) -> Option<SystemColors> {
+        #[cfg(target_os = "macos")]
+        {
+            Some(glass_effect::system_colors())
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            None
+        }
+    }
+
+    /// Temporarily swap every glass view for a flat fill
+    ///
+    /// Each view is hidden behind its opaque base (`opaque_color`, or the
+    /// window background), which suits screen recordings, presentations or a
+    /// "reduce effects" preference. Configs are remembered: changes made
+    /// while suspended are stored and take effect on
+    /// [`resume_all`](Self::resume_all). Emits
+    /// [`EFFECTS_SUSPENDED_EVENT`](crate::EFFECTS_SUSPENDED_EVENT).
+    ///
+    /// Returns false if effects were already suspended, or on other platforms.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn start_recording(app: tauri::AppHandle) {
+    ///     app.liquid_glass().suspend_all().unwrap();
+    /// }
+    /// ```
+    pub fn suspend_all(&self) -> Result<bool> {
+        self.set_suspended(true)
+    }
+
+    /// Restore every glass view suspended by [`suspend_all`](Self::suspend_all)
+    ///
+    /// Returns false if effects were not suspended.
+    pub fn resume_all(&self) -> Result<bool> {
+        self.set_suspended(false)
+    }
+
+    /// Whether glass effects are currently suspended
+    pub fn is_suspended(&self) -> bool {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::is_suspended()
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            false
+        }
+    }
+
+    fn set_suspended(&self, suspended: bool) -> Result<bool> {
+        #[cfg(target_os = "macos")]
+        let changed = glass_effect::set_suspended(&self.app, suspended)?;
+        #[cfg(not(target_os = "macos"))]
+        let changed = false;
+
+        if changed {
+            let payload = EffectsSuspended { suspended };
+            self.events
+                .publish(GlassEvent::EffectsSuspended(payload.clone()));
+            if let Err(e) = self.app.emit(EFFECTS_SUSPENDED_EVENT, payload) {
+                warn!("Failed to emit effects suspension: {}", e);
+            }
+        }
+        Ok(changed)
+    }
+
+    /// Get the configuration currently applied to a window
+    pub(crate) fn current_config(
+        &self,
+        window: &WebviewWindow<R>,
+    ) -> Result<Option<LiquidGlassConfig>> {
+        #[cfg(target_os = "macos")]
+        {
+            glass_effect::current_config(&self.app, window)
+        }
+        #[cfg(not(target_os = "macos"))]
+        {
+            let _ = window;
+            Ok(None)
+        }
+    }
+
+    /// Apply a glass effect that is removed when the returned handle is dropped
+    ///
+    /// Useful for temporary effects in Rust code: the effect cannot leak when an
+    /// early return skips cleanup. Call [`GlassEffectHandle::detach`] to keep it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{LiquidGlassExt, LiquidGlassConfig};
+    ///
+    /// fn show_modal(app: tauri::AppHandle, window: tauri::WebviewWindow) -> tauri_plugin_liquid_glass::Result<()> {
+    ///     let _glass = app.liquid_glass().apply(&window, LiquidGlassConfig::default())?;
+    ///     // ... effect stays applied until `_glass` goes out of scope
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn apply(
+        &self,
+        window: &WebviewWindow<R>,
+        config: LiquidGlassConfig,
+    ) -> Result<GlassEffectHandle<R>> {
+        self.set_effect(window, config)?;
+        Ok(GlassEffectHandle::new(window.clone()))
+    }
+
+    /// Async variant of [`set_effect`](Self::set_effect)
+    ///
+    /// The synchronous API blocks its thread until the main thread has run
+    /// the AppKit work. These variants run it on the async runtime's blocking
+    /// pool instead and resolve once it completes, so async commands and
+    /// background tasks don't stall a runtime worker. Don't block the main
+    /// thread on the returned futures: they need it to make progress.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::{GlassPreset, LiquidGlassExt};
+    ///
+    /// #[tauri::command]
+    /// async fn enable_glass(window: tauri::WebviewWindow) -> Result<(), String> {
+    ///     window
+    ///         .liquid_glass()
+    ///         .set_effect_async(&window, GlassPreset::Sidebar.into())
+    ///         .await
+    ///         .map_err(|e| e.to_string())
+    /// }
+    /// ```
+    pub async fn set_effect_async(
+        &self,
+        window: &WebviewWindow<R>,
+        config: LiquidGlassConfig,
+    ) -> Result<()> {
+        let window = window.clone();
+        self.run_blocking(move |glass| glass.set_effect(&window, config))
+            .await
+    }
+
+    /// Async variant of [`set_effect`](Self::set_effect) with `enabled: false`,
+    /// removing the window's full-window glass
+    pub async fn remove_async(&self, window: &WebviewWindow<R>) -> Result<()> {
+        let config = LiquidGlassConfig {
+            enabled: false,
+            ..Default::default()
+        };
+        self.set_effect_async(window, config).await
+    }
+
+    /// Async variant of [`apply_layout`](Self::apply_layout)
+    pub async fn apply_layout_async(
+        &self,
+        window: &WebviewWindow<R>,
+        layout: GlassLayout,
+    ) -> Result<LayoutDiff> {
+        let window = window.clone();
+        self.run_blocking(move |glass| glass.apply_layout(&window, layout))
+            .await
+    }
+
+    /// Async variant of [`set_glass_view`](Self::set_glass_view)
+    pub async fn set_glass_view_async(
+        &self,
+        window: &WebviewWindow<R>,
+        id: &str,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Result<()> {
+        let (window, id, config) = (window.clone(), id.to_string(), config.into());
+        self.run_blocking(move |glass| glass.set_glass_view(&window, &id, config))
+            .await
+    }
+
+    /// Async variant of [`remove_glass_view`](Self::remove_glass_view)
+    pub async fn remove_glass_view_async(
+        &self,
+        window: &WebviewWindow<R>,
+        id: &str,
+    ) -> Result<bool> {
+        let (window, id) = (window.clone(), id.to_string());
+        self.run_blocking(move |glass| glass.remove_glass_view(&window, &id))
+            .await
+    }
+
+    /// Async variant of [`import_glass_state`](Self::import_glass_state)
+    pub async fn import_glass_state_async(
+        &self,
+        document: &str,
+        options: GlassStateImportOptions,
+    ) -> Result<Vec<String>> {
+        let document = document.to_string();
+        self.run_blocking(move |glass| glass.import_glass_state(&document, options))
+            .await
+    }
+
+    /// Run a synchronous API call on the blocking pool and await its result
+    async fn run_blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&LiquidGlass<R>) -> Result<T> + Send + 'static,
+    ) -> Result<T> {
+        let app = self.app.clone();
+        tauri::async_runtime::spawn_blocking(move || f(app.liquid_glass())).await?
+    }
+
+    /// Get the recorded glass effect operations, oldest first
+    ///
+    /// Returns an empty list unless the audit log was enabled with
+    /// [`Builder::audit_log`](crate::Builder::audit_log).
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use tauri_plugin_liquid_glass::LiquidGlassExt;
+    ///
+    /// fn print_audit_log(app: tauri::AppHandle) {
+    ///     for entry in app.liquid_glass().audit_log() {
+    ///         println!("{} {}: {:?}", entry.timestamp, entry.window, entry.changes);
+    ///     }
+    /// }
+    /// ```
+    pub fn audit_log(&self) -> Vec<AuditEntry> {
+        self.audit
+            .as_ref()
+            .map(AuditLog::entries)
+            .unwrap_or_default()
+    }
+
+    /// Get the counters of the main-thread flood guard
+    ///
+    /// Commands that set state from the frontend share a budget of main-thread
+    /// operations per frame, set with
+    /// [`Builder::main_thread_budget`](crate::Builder::main_thread_budget). Updates
+    /// over budget are parked until the next frame and merged per target;
+    /// steadily growing `deferred` or `merged` counts point at a frontend
+    /// loop sending updates faster than frames are drawn.
+    pub fn main_thread_stats(&self) -> MainThreadStats {
+        self.main_thread.stats()
+    }
+
+    /// Run a frontend-driven update through the main-thread budget
+    ///
+    /// Resolves once the update, or a newer one merged over it, was applied.
+    pub(crate) async fn throttled(
+        &self,
+        window: &WebviewWindow<R>,
+        target: Target,
+        op: impl FnOnce(&LiquidGlass<R>, &WebviewWindow<R>) -> Result<()> + Send + 'static,
+    ) -> Result<()> {
+        let (label, target_window) = (window.label().to_string(), window.clone());
+        self.run_blocking(move |glass| {
+            let app = glass.app.clone();
+            Ok(glass.main_thread.submit(&label, target, move || {
+                op(app.liquid_glass(), &target_window)
+            }))
+        })
+        .await?
+        .wait()
+        .await
+    }
 }
@@ -0,0 +1,149 @@
+//! Developer hotkey for trying out glass looks
+//!
+//! With the `dev-hotkey` feature, a global shortcut cycles the focused window's
+//! full-window glass through every preset, then through every variant the
+//! running OS honors (applied over the window's current config), and logs
+//! which one is showing. Meant for tuning a design; leave it out of release
+//! builds.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+use tauri_plugin_global_shortcut::{GlobalShortcut, GlobalShortcutExt, ShortcutState};
+
+use crate::error::{Error, Result};
+use crate::models::{GlassMaterialVariant, GlassPreset, LiquidGlassConfig};
+use crate::LiquidGlassExt;
+
+/// Shortcut used when the builder does not configure one
+pub(crate) const DEFAULT_SHORTCUT: &str = "CmdOrCtrl+Alt+Shift+G";
+
+/// Presets in the order the hotkey cycles through them
+const PRESETS: [GlassPreset; 9] = [
+    GlassPreset::Regular,
+    GlassPreset::Clear,
+    GlassPreset::Sidebar,
+    GlassPreset::Inspector,
+    GlassPreset::Popover,
+    GlassPreset::Widget,
+    GlassPreset::ControlCenter,
+    GlassPreset::Hud,
+    GlassPreset::MediaControls,
+];
+
+/// One look in the cycle
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Preset(GlassPreset),
+    Variant(GlassMaterialVariant),
+}
+
+/// Position of each window in the cycle, by label
+#[derive(Default)]
+struct Cycle {
+    positions: Mutex<HashMap<String, usize>>,
+}
+
+impl Cycle {
+    /// Advance `label` to its next position in a cycle of `len` steps
+    fn advance(&self, label: &str, len: usize) -> Result<usize> {
+        let mut positions = self
+            .positions
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?;
+        let position = positions
+            .entry(label.to_string())
+            .and_modify(|position| *position = (*position + 1) % len)
+            .or_insert(0);
+        Ok(*position)
+    }
+}
+
+/// Register `shortcut` to cycle the focused window's glass
+///
+/// Installs the global-shortcut plugin first if the app has not.
+pub(crate) fn register<R: Runtime>(app: &AppHandle<R>, shortcut: &str) -> Result<()> {
+    if app.try_state::<GlobalShortcut<R>>().is_none() {
+        app.plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
+    }
+    app.manage(Cycle::default());
+
+    let registered = app
+        .global_shortcut()
+        .on_shortcut(shortcut, |app, _, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            if let Err(e) = cycle_focused(app) {
+                warn!("Failed to cycle glass effect: {}", e);
+            }
+        });
+    match registered {
+        Ok(()) => info!("Liquid glass dev hotkey: {} cycles glass effects", shortcut),
+        Err(e) => warn!("Failed to register dev hotkey '{}': {}", shortcut, e),
+    }
+    Ok(())
+}
+
+/// Apply the next step of the cycle to the focused window
+fn cycle_focused<R: Runtime>(app: &AppHandle<R>) -> Result<()> {
+    let Some(window) = app
+        .webview_windows()
+        .into_values()
+        .find(|window| window.is_focused().unwrap_or(false))
+    else {
+        return Ok(());
+    };
+
+    let mut steps: Vec<Step> = PRESETS.into_iter().map(Step::Preset).collect();
+    steps.extend(
+        app.liquid_glass()
+            .support_details()
+            .supported_variants
+            .into_iter()
+            .map(Step::Variant),
+    );
+
+    let position = app.state::<Cycle>().advance(window.label(), steps.len())?;
+    let step = steps[position];
+    let config = match step {
+        Step::Preset(preset) => preset.into(),
+        Step::Variant(variant) => LiquidGlassConfig {
+            variant,
+            ..current_config(&window)?
+        },
+    };
+    app.liquid_glass().set_effect(&window, config)?;
+
+    match step {
+        Step::Preset(preset) => info!(
+            "Liquid glass dev hotkey: '{}' [{}/{}] preset {:?}",
+            window.label(),
+            position + 1,
+            steps.len(),
+            preset
+        ),
+        Step::Variant(variant) => info!(
+            "Liquid glass dev hotkey: '{}' [{}/{}] variant {:?} ({})",
+            window.label(),
+            position + 1,
+            steps.len(),
+            variant,
+            variant as i64
+        ),
+    }
+    Ok(())
+}
+
+/// Full-window glass config of `window`, or the default if it has none
+fn current_config<R: Runtime>(window: &WebviewWindow<R>) -> Result<LiquidGlassConfig> {
+    Ok(window
+        .liquid_glass()
+        .glass_state(window)?
+        .into_iter()
+        .find(|view| view.region.is_none())
+        .map(|view| view.config)
+        .unwrap_or_default())
+}
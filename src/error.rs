@@ -15,6 +15,24 @@ pub enum Error {
     #[error("Window not found: {0}")]
     WindowNotFound(String),
 
+    /// No monitor could be found for the window
+    #[error("No monitor found for window: {0}")]
+    MonitorNotFound(String),
+
+    /// The window has no WKWebView in its view hierarchy
+    #[error("Webview not found in window: {0}")]
+    WebviewNotFound(String),
+
+    /// The running OS does not implement an experimental glass property, or
+    /// the compositor cannot do what was asked (disabling blur under Hyprland)
+    #[error("Unsupported capability: {0}")]
+    UnsupportedCapability(String),
+
+    /// The running OS build has no setter for a requested glass property, so
+    /// setting it had no effect
+    #[error("Property not supported on this macOS build: {0}")]
+    UnsupportedProperty(String),
+
     /// Failed to create glass effect view
     #[error("Failed to create glass effect view")]
     ViewCreationFailed,
@@ -27,6 +45,63 @@ pub enum Error {
     #[error("Invalid color format: {0}")]
     InvalidColorFormat(String),
 
+    /// A glass layout failed validation
+    #[error("Invalid glass layout: {0}")]
+    InvalidLayout(String),
+
+    /// A glass state document failed to parse or validate
+    #[error("Invalid glass state: {0}")]
+    InvalidGlassState(String),
+
+    /// Metadata attached to a glass view is too large
+    #[error("Invalid metadata: {0}")]
+    InvalidMetadata(String),
+
+    /// No tray item has the given id
+    #[error("Tray item not found: {0}")]
+    TrayNotFound(String),
+
+    /// The operation needs a crate feature that is not enabled
+    #[error("Feature not enabled: {0}")]
+    FeatureDisabled(String),
+
+    /// The command was invoked from a remote origin not allowed by the capability scope
+    #[error("Remote origin not allowed: {0}")]
+    RemoteOriginDenied(String),
+
+    /// The command was invoked from a plugin-created HTML toast window
+    #[error("Toast window '{0}' may not invoke glass commands")]
+    ToastCallerDenied(String),
+
+    /// The window has no named glass view with this id
+    #[error("Glass view not found: {0}")]
+    ViewNotFound(String),
+
+    /// A closure dispatched to the main thread panicked or never replied
+    #[error("Failed to run on the main thread")]
+    MainThreadDispatchFailed,
+
+    /// Too many glass updates are waiting for the main thread
+    #[error("Too many glass updates queued for the main thread")]
+    Throttled,
+
+    /// A parked update was merged into a newer update to the same target,
+    /// which failed with this message when applied
+    #[error("{0}")]
+    MergedUpdateFailed(String),
+
+    /// DWM rejected a Windows system backdrop change
+    #[error("Failed to set window backdrop (HRESULT {0:#010x})")]
+    BackdropFailed(i32),
+
+    /// The compositor rejected a Linux blur request
+    #[error("Failed to request compositor blur: {0}")]
+    BlurFailed(String),
+
+    /// A launcher's global shortcut could not be registered
+    #[error("Failed to register shortcut '{0}': {1}")]
+    ShortcutFailed(String, String),
+
     /// Tauri error
     #[error("Tauri error: {0}")]
     Tauri(#[from] tauri::Error),
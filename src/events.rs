@@ -0,0 +1,81 @@
+//! Rust-side event channel
+//!
+//! Webview events only reach the frontend. [`LiquidGlass::subscribe`](crate::LiquidGlass::subscribe)
+//! hands out broadcast receivers so Rust code can react to the same changes,
+//! e.g. to update native menus or tray icons when glass falls back.
+
+use serde::Serialize;
+use tokio::sync::broadcast;
+
+use crate::appearance::AppearanceChanged;
+use crate::fallback::GlassFallback;
+use crate::key_state::KeyStateChanged;
+use crate::models::LiquidGlassConfig;
+use crate::suspend::EffectsSuspended;
+use crate::system_colors::SystemColorsChanged;
+
+/// Events buffered per receiver before slow receivers start lagging
+const CHANNEL_CAPACITY: usize = 64;
+
+/// Event delivered to [`subscribe`](crate::LiquidGlass::subscribe) receivers
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[non_exhaustive]
+pub enum GlassEvent {
+    /// Glass was created or updated on a window
+    #[serde(rename_all = "camelCase")]
+    Applied {
+        /// Label of the window
+        window: String,
+        /// Id of the named view, `None` for the full-window glass
+        region: Option<String>,
+        /// Configuration now applied, in points
+        config: Box<LiquidGlassConfig>,
+    },
+    /// Glass was removed from a window
+    #[serde(rename_all = "camelCase")]
+    Removed {
+        /// Label of the window
+        window: String,
+        /// Id of the named view, `None` for the full-window glass
+        region: Option<String>,
+    },
+    /// Applied glass renders with NSVisualEffectView, see [`FALLBACK_EVENT`](crate::FALLBACK_EVENT)
+    Fallback(GlassFallback),
+    /// A window's appearance switched between light and dark, see
+    /// [`APPEARANCE_CHANGED_EVENT`](crate::APPEARANCE_CHANGED_EVENT)
+    AppearanceChanged(AppearanceChanged),
+    /// Fallback glass followed its window's key status, see
+    /// [`KEY_STATE_CHANGED_EVENT`](crate::KEY_STATE_CHANGED_EVENT)
+    KeyStateChanged(KeyStateChanged),
+    /// Effects were suspended or resumed, see
+    /// [`EFFECTS_SUSPENDED_EVENT`](crate::EFFECTS_SUSPENDED_EVENT)
+    EffectsSuspended(EffectsSuspended),
+    /// The user's accent or highlight colors changed, see
+    /// [`SYSTEM_COLORS_CHANGED_EVENT`](crate::SYSTEM_COLORS_CHANGED_EVENT)
+    SystemColorsChanged(SystemColorsChanged),
+}
+
+/// Broadcast sender behind [`LiquidGlass::subscribe`](crate::LiquidGlass::subscribe)
+pub(crate) struct GlassEvents {
+    sender: broadcast::Sender<GlassEvent>,
+}
+
+impl Default for GlassEvents {
+    fn default() -> Self {
+        Self {
+            sender: broadcast::channel(CHANNEL_CAPACITY).0,
+        }
+    }
+}
+
+impl GlassEvents {
+    pub fn subscribe(&self) -> broadcast::Receiver<GlassEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Send an event to current receivers; without receivers it is dropped
+    pub fn publish(&self, event: GlassEvent) {
+        let _ = self.sender.send(event);
+    }
+}
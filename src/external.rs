@@ -0,0 +1,28 @@
+//! External modification events
+//!
+//! Emitted when something other than the plugin changes a property of one of
+//! its glass views, after the stored config has been brought back in line.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::{LiquidGlassConfig, ObservedProperty};
+
+/// Event emitted when a glass view is modified outside the plugin
+pub const GLASS_EXTERNALLY_MODIFIED_EVENT: &str = "liquid-glass://glass-externally-modified";
+
+/// Payload of [`GLASS_EXTERNALLY_MODIFIED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassExternallyModified {
+    /// Label of the window hosting the glass view
+    pub window: String,
+    /// Region id, or `None` for the window's full-window glass
+    pub region: Option<String>,
+    /// Metadata attached to the view, if any
+    pub metadata: Option<Value>,
+    /// Properties that no longer matched the stored config
+    pub properties: Vec<ObservedProperty>,
+    /// The stored config, updated to the view's actual state
+    pub config: LiquidGlassConfig,
+}
@@ -0,0 +1,49 @@
+//! Fade-in/out of whole windows
+//!
+//! Popping a transparent glass window in or out without a transition reads
+//! as a glitch, so windows can be shown and hidden by animating their alpha.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+
+/// Duration of a fade when none is given
+pub(crate) const DEFAULT_FADE_MS: u64 = 200;
+
+/// Tracks the latest fade per window, so a fade-out's delayed hide is
+/// skipped if the window was faded back in meanwhile
+#[derive(Default)]
+pub(crate) struct Fades {
+    generations: Mutex<HashMap<String, u64>>,
+}
+
+impl Fades {
+    /// Start a fade of `label`, superseding any running one
+    pub fn start(&self, label: &str) -> Result<u64> {
+        let mut generations = self
+            .generations
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?;
+        let generation = generations.entry(label.to_string()).or_default();
+        *generation += 1;
+        Ok(*generation)
+    }
+
+    /// Whether `generation` is still the latest fade of `label`
+    #[cfg(target_os = "macos")]
+    pub fn is_current(&self, label: &str, generation: u64) -> bool {
+        self.generations
+            .lock()
+            .is_ok_and(|generations| generations.get(label) == Some(&generation))
+    }
+
+    /// Stop tracking a destroyed window
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.generations
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label);
+        Ok(())
+    }
+}
@@ -0,0 +1,22 @@
+//! Fallback notifications
+//!
+//! Glass applied while NSGlassEffectView is unavailable, fails to initialize
+//! or is disabled by safe mode renders with NSVisualEffectView instead; the
+//! event lets web content switch to a higher-contrast stylesheet right away.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::FallbackReason;
+
+/// Event emitted when applying glass ends up on NSVisualEffectView
+pub const FALLBACK_EVENT: &str = "liquid-glass://fallback";
+
+/// Payload of [`FALLBACK_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassFallback {
+    /// Label of the window the glass was applied to
+    pub window: String,
+    /// Why NSVisualEffectView was used
+    pub reason: FallbackReason,
+}
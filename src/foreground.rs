@@ -0,0 +1,75 @@
+//! Foreground recommendation change tracking
+//!
+//! Remembers the last recommendation per glass view so an event is only
+//! emitted when the suggested foreground actually changes.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::error::{Error, Result};
+use crate::models::ForegroundRecommendation;
+
+/// Event emitted when the recommended foreground of a glass view changes
+pub const FOREGROUND_CHANGED_EVENT: &str = "liquid-glass://foreground-changed";
+
+/// Payload of [`FOREGROUND_CHANGED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForegroundChanged {
+    /// Label of the window hosting the glass view
+    pub window: String,
+    /// Region id, or `None` for the window's full-window glass
+    pub region: Option<String>,
+    /// Metadata attached to the view, if any
+    pub metadata: Option<Value>,
+    /// The new recommendation
+    pub recommendation: ForegroundRecommendation,
+}
+
+/// Last recommendation per window, keyed by region (`None` for full-window glass)
+#[derive(Default)]
+pub(crate) struct ForegroundTracker {
+    last: Mutex<HashMap<String, HashMap<Option<String>, ForegroundRecommendation>>>,
+}
+
+impl ForegroundTracker {
+    /// Forget the recommendations of a destroyed window
+    pub fn forget(&self, window: &str) -> Result<()> {
+        self.last
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(window);
+        Ok(())
+    }
+
+    /// Replace a window's recommendations and return the ones that changed
+    ///
+    /// Views that no longer exist are forgotten without an event.
+    pub fn update(
+        &self,
+        window: &str,
+        current: Vec<(Option<String>, ForegroundRecommendation)>,
+    ) -> Result<Vec<ForegroundChanged>> {
+        let mut last = self.last.lock().map_err(|_| Error::RegistryLockFailed)?;
+        let previous = last.remove(window).unwrap_or_default();
+
+        let changed = current
+            .iter()
+            .filter(|(region, recommendation)| previous.get(region) != Some(recommendation))
+            .map(|(region, recommendation)| ForegroundChanged {
+                window: window.to_string(),
+                region: region.clone(),
+                metadata: None,
+                recommendation: recommendation.clone(),
+            })
+            .collect();
+
+        if !current.is_empty() {
+            last.insert(window.to_string(), current.into_iter().collect());
+        }
+        Ok(changed)
+    }
+}
@@ -0,0 +1,31 @@
+//! Frame change events
+//!
+//! Glass views can move or resize without the frontend asking, e.g. through
+//! autoresizing or a relayout after a display change. Every plugin-owned view
+//! reports its new frame so JS-driven layouts can follow along.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::GlassViewFrame;
+
+/// Event emitted when the native frame of a glass view changes
+pub const FRAME_CHANGED_EVENT: &str = "liquid-glass://frame-changed";
+
+/// Payload of [`FRAME_CHANGED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FrameChanged {
+    /// Label of the window hosting the glass view
+    pub window: String,
+    /// Region id, or `None` for the window's full-window glass
+    pub region: Option<String>,
+    /// Metadata attached to the view, if any
+    pub metadata: Option<Value>,
+    /// The new frame
+    pub frame: GlassViewFrame,
+    /// Whether the origin changed
+    pub moved: bool,
+    /// Whether the size changed
+    pub resized: bool,
+}
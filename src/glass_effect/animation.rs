@@ -0,0 +1,58 @@
+//! Animated config transitions
+//!
+//! Updates to an existing view whose config sets
+//! [`animation`](crate::LiquidGlassConfig::animation) run inside an
+//! NSAnimationContext group that allows implicit animation, plus a
+//! CATransaction with the same duration and curve. Frame, corner radius and
+//! tint changes then morph instead of snapping, as far as AppKit and Core
+//! Animation animate the property implicitly. New views are never animated.
+
+use cocoa::base::{id, YES};
+use objc::{class, msg_send, sel, sel_impl};
+
+use crate::models::{AnimationConfig, TimingFunction};
+
+/// Run `apply` inside an animation group for `animation`, if set
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn animated<T>(animation: Option<&AnimationConfig>, apply: impl FnOnce() -> T) -> T {
+    let Some(animation) = animation.filter(|animation| animation.duration_ms > 0) else {
+        return apply();
+    };
+
+    let duration = animation.duration_ms as f64 / 1000.0;
+    let timing = timing_function(animation.timing);
+
+    let _: () = msg_send![class!(NSAnimationContext), beginGrouping];
+    let context: id = msg_send![class!(NSAnimationContext), currentContext];
+    let _: () = msg_send![context, setDuration: duration];
+    let _: () = msg_send![context, setTimingFunction: timing];
+    let _: () = msg_send![context, setAllowsImplicitAnimation: YES];
+
+    let _: () = msg_send![class!(CATransaction), begin];
+    let _: () = msg_send![class!(CATransaction), setAnimationDuration: duration];
+    let _: () = msg_send![class!(CATransaction), setAnimationTimingFunction: timing];
+
+    let result = apply();
+
+    let _: () = msg_send![class!(CATransaction), commit];
+    let _: () = msg_send![class!(NSAnimationContext), endGrouping];
+    result
+}
+
+/// Get the CAMediaTimingFunction for a timing function
+///
+/// # Safety
+/// Must be called on the main thread
+unsafe fn timing_function(timing: TimingFunction) -> id {
+    let name = match timing {
+        TimingFunction::Linear => c"linear",
+        TimingFunction::EaseIn => c"easeIn",
+        TimingFunction::EaseOut => c"easeOut",
+        TimingFunction::EaseInOut => c"easeInEaseOut",
+        TimingFunction::Default => c"default",
+    };
+    let name: id = msg_send![class!(NSString), stringWithUTF8String: name.as_ptr()];
+    msg_send![class!(CAMediaTimingFunction), functionWithName: name]
+}
@@ -0,0 +1,50 @@
+//! Forward NSWindow `effectiveAppearance` changes to Rust callbacks
+
+use std::cell::Cell;
+
+use cocoa::base::nil;
+use objc::{msg_send, sel, sel_impl};
+use tauri::{Runtime, WebviewWindow};
+
+use super::observer::observe_key_path;
+use super::utils::{
+    associated_object, effective_appearance, run_on_main_sync, set_associated_object,
+};
+use super::window::ns_window_handle;
+use crate::error::Result;
+use crate::models::Appearance;
+
+/// Address used as the associated-object key holding a window's observer
+static OBSERVER_KEY: u8 = 0;
+
+/// Invoke `on_change` whenever the window's effective appearance changes
+///
+/// KVO may report a change without the light/dark match changing (e.g. an
+/// accessibility variant), so only actual transitions are forwarded. The
+/// observer is owned by the window, and observing a window twice is a no-op.
+pub fn observe_appearance<R: Runtime>(
+    window: &WebviewWindow<R>,
+    on_change: impl Fn(Appearance) + Send + 'static,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        if associated_object(ns_window, &OBSERVER_KEY) != nil {
+            return;
+        }
+
+        let last = Cell::new(effective_appearance(ns_window));
+        let observer = observe_key_path(ns_window, c"effectiveAppearance", move |ns_window| {
+            let appearance = effective_appearance(ns_window);
+            if last.replace(appearance) != appearance {
+                on_change(appearance);
+            }
+        });
+
+        set_associated_object(ns_window, &OBSERVER_KEY, observer);
+        let _: () = msg_send![observer, release];
+    });
+
+    Ok(())
+}
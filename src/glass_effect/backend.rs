@@ -4,14 +4,23 @@ use cocoa::appkit::{
     NSViewHeightSizable, NSViewWidthSizable, NSVisualEffectBlendingMode, NSVisualEffectMaterial,
     NSVisualEffectState,
 };
+use std::sync::atomic::{AtomicBool, Ordering};
+
 use cocoa::base::{id, nil, NO, YES};
 use cocoa::foundation::NSRect;
+use log::warn;
 use objc::runtime::{Class, Object, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
 use super::registry::ViewHandle;
-use super::utils::glass_class_available;
+use super::utils::{
+    getter_names, glass_class_available, probe_private_api, setter_names, warn_unapplied,
+};
 use crate::error::{Error, Result};
+use crate::models::{
+    ExperimentalProperty, FallbackBlendingMode, FallbackMaterial, FallbackReason, GlassBackendKind,
+    GlassMaterialVariant, LiquidGlassConfig,
+};
 
 // ============================================================================
 // Constants
@@ -34,6 +43,9 @@ fn autoresize_mask() -> u64 {
 /// # Safety
 /// All methods must be called on the main thread.
 pub trait GlassBackend {
+    /// Which backend this is
+    fn kind(&self) -> GlassBackendKind;
+
     /// Create a new glass effect view with the given bounds
     ///
     /// # Safety
@@ -68,6 +80,33 @@ pub trait GlassBackend {
     /// - Must be called on the main thread
     /// - `view` must be a valid Objective-C object
     unsafe fn set_variant(&self, view: id, variant: i64);
+
+    /// Set the NSVisualEffectView material and blending mode
+    ///
+    /// # Safety
+    /// - Must be called on the main thread
+    /// - `view` must be a valid Objective-C object
+    unsafe fn configure_fallback(
+        &self,
+        view: id,
+        material: FallbackMaterial,
+        blending_mode: FallbackBlendingMode,
+    );
+
+    /// Set an experimental glass property
+    ///
+    /// # Safety
+    /// - Must be called on the main thread
+    /// - `view` must be a valid Objective-C object
+    unsafe fn set_experimental(&self, view: id, property: ExperimentalProperty, enabled: bool);
+
+    /// Create a container that hosts glass views as one group
+    ///
+    /// Returns the container and the view grouped glass views are added to.
+    ///
+    /// # Safety
+    /// Must be called on the main thread
+    unsafe fn create_container(&self, bounds: NSRect, spacing: f64) -> Result<(id, id)>;
 }
 
 // ============================================================================
@@ -78,11 +117,18 @@ pub trait GlassBackend {
 struct NativeGlassBackend;
 
 impl GlassBackend for NativeGlassBackend {
+    fn kind(&self) -> GlassBackendKind {
+        GlassBackendKind::Native
+    }
+
     unsafe fn create_view(&self, bounds: NSRect) -> Result<id> {
         let glass_class = Class::get("NSGlassEffectView").ok_or(Error::ViewCreationFailed)?;
 
         let glass: id = msg_send![glass_class, alloc];
         let glass: id = msg_send![glass, initWithFrame: bounds];
+        if glass == nil {
+            return Err(Error::ViewCreationFailed);
+        }
         let _: () = msg_send![glass, setAutoresizingMask: autoresize_mask()];
 
         Ok(glass)
@@ -96,16 +142,62 @@ impl GlassBackend for NativeGlassBackend {
         _existing_overlay: Option<ViewHandle>,
     ) -> Option<ViewHandle> {
         // NSGlassEffectView has native tint support
-        let _: () = msg_send![view, setTintColor: color];
+        if responds_to(view, sel!(setTintColor:)) {
+            let _: () = msg_send![view, setTintColor: color];
+        } else {
+            warn_unapplied("tintColor");
+        }
         None
     }
 
     unsafe fn clear_tint(&self, view: id, _existing_overlay: Option<ViewHandle>) {
-        let _: () = msg_send![view, setTintColor: nil];
+        if responds_to(view, sel!(setTintColor:)) {
+            let _: () = msg_send![view, setTintColor: nil];
+        }
     }
 
     unsafe fn set_variant(&self, view: id, variant: i64) {
-        set_view_property(view, "variant", variant);
+        let applied = set_view_property(view, "variant", variant).is_ok();
+        if !applied && variant != GlassMaterialVariant::Regular as i64 {
+            warn_unapplied("variant");
+        }
+    }
+
+    unsafe fn configure_fallback(
+        &self,
+        _view: id,
+        _material: FallbackMaterial,
+        _blending_mode: FallbackBlendingMode,
+    ) {
+        // NSGlassEffectView has no materials - no-op
+    }
+
+    unsafe fn set_experimental(&self, view: id, property: ExperimentalProperty, enabled: bool) {
+        if set_experimental_property(view, property, enabled).is_err() && enabled {
+            warn_unapplied(experimental_key(property));
+        }
+    }
+
+    unsafe fn create_container(&self, bounds: NSRect, spacing: f64) -> Result<(id, id)> {
+        let container_class =
+            Class::get("NSGlassEffectContainerView").ok_or(Error::ViewCreationFailed)?;
+
+        let container: id = msg_send![container_class, alloc];
+        let container: id = msg_send![container, initWithFrame: bounds];
+        if container == nil {
+            return Err(Error::ViewCreationFailed);
+        }
+        let _: () = msg_send![container, setAutoresizingMask: autoresize_mask()];
+        let _: () = msg_send![container, setSpacing: spacing];
+
+        // Glass views merge only as descendants of the container's content view
+        let content: id = msg_send![class!(NSView), alloc];
+        let content: id = msg_send![content, initWithFrame: bounds];
+        let _: () = msg_send![content, setAutoresizingMask: autoresize_mask()];
+        let _: () = msg_send![container, setContentView: content];
+        let _: () = msg_send![content, release];
+
+        Ok((container, content))
     }
 }
 
@@ -117,6 +209,10 @@ impl GlassBackend for NativeGlassBackend {
 struct VisualEffectBackend;
 
 impl GlassBackend for VisualEffectBackend {
+    fn kind(&self) -> GlassBackendKind {
+        GlassBackendKind::VisualEffect
+    }
+
     unsafe fn create_view(&self, bounds: NSRect) -> Result<id> {
         let visual: id = msg_send![class!(NSVisualEffectView), alloc];
         let visual: id = msg_send![visual, initWithFrame: bounds];
@@ -137,9 +233,9 @@ impl GlassBackend for VisualEffectBackend {
         existing_overlay: Option<ViewHandle>,
     ) -> Option<ViewHandle> {
         // NSVisualEffectView doesn't support tint - use overlay subview
-        let overlay: id = if let Some(handle) = existing_overlay {
+        let handle = if let Some(handle) = existing_overlay {
             // Reuse existing overlay
-            handle.as_id()
+            handle
         } else {
             // Create new overlay view
             let bounds: NSRect = msg_send![view, bounds];
@@ -148,8 +244,9 @@ impl GlassBackend for VisualEffectBackend {
             let _: () = msg_send![overlay, setAutoresizingMask: autoresize_mask()];
             let _: () = msg_send![overlay, setWantsLayer: YES];
             let _: () = msg_send![view, addSubview: overlay];
-            overlay
+            ViewHandle::owned(overlay)
         };
+        let overlay = handle.as_id();
 
         // Apply color to overlay layer (CGColor preserves alpha for transparency)
         let overlay_layer: id = msg_send![overlay, layer];
@@ -165,31 +262,295 @@ impl GlassBackend for VisualEffectBackend {
             }
         }
 
-        Some(ViewHandle::new(overlay))
+        Some(handle)
     }
 
     unsafe fn clear_tint(&self, _view: id, existing_overlay: Option<ViewHandle>) {
         if let Some(handle) = existing_overlay {
             let overlay = handle.as_id();
             let _: () = msg_send![overlay, removeFromSuperview];
+            handle.retire();
         }
     }
 
     unsafe fn set_variant(&self, _view: id, _variant: i64) {
         // NSVisualEffectView doesn't support variants - no-op
     }
+
+    unsafe fn configure_fallback(
+        &self,
+        view: id,
+        material: FallbackMaterial,
+        blending_mode: FallbackBlendingMode,
+    ) {
+        let blending_mode = match blending_mode {
+            FallbackBlendingMode::BehindWindow => NSVisualEffectBlendingMode::BehindWindow,
+            FallbackBlendingMode::WithinWindow => NSVisualEffectBlendingMode::WithinWindow,
+        };
+        let _: () = msg_send![view, setMaterial: visual_effect_material(material)];
+        let _: () = msg_send![view, setBlendingMode: blending_mode];
+    }
+
+    unsafe fn set_experimental(&self, _view: id, _property: ExperimentalProperty, _enabled: bool) {
+        // NSVisualEffectView has no experimental properties - no-op
+    }
+
+    unsafe fn create_container(&self, bounds: NSRect, _spacing: f64) -> Result<(id, id)> {
+        // NSVisualEffectViews don't merge; a plain view keeps the grouping
+        let container: id = msg_send![class!(NSView), alloc];
+        let container: id = msg_send![container, initWithFrame: bounds];
+        if container == nil {
+            return Err(Error::ViewCreationFailed);
+        }
+        let _: () = msg_send![container, setAutoresizingMask: autoresize_mask()];
+
+        Ok((container, container))
+    }
+}
+
+/// AppKit material of a [`FallbackMaterial`]
+fn visual_effect_material(material: FallbackMaterial) -> NSVisualEffectMaterial {
+    match material {
+        FallbackMaterial::Titlebar => NSVisualEffectMaterial::Titlebar,
+        FallbackMaterial::Selection => NSVisualEffectMaterial::Selection,
+        FallbackMaterial::Menu => NSVisualEffectMaterial::Menu,
+        FallbackMaterial::Popover => NSVisualEffectMaterial::Popover,
+        FallbackMaterial::Sidebar => NSVisualEffectMaterial::Sidebar,
+        FallbackMaterial::HeaderView => NSVisualEffectMaterial::HeaderView,
+        FallbackMaterial::Sheet => NSVisualEffectMaterial::Sheet,
+        FallbackMaterial::WindowBackground => NSVisualEffectMaterial::WindowBackground,
+        FallbackMaterial::HudWindow => NSVisualEffectMaterial::HudWindow,
+        FallbackMaterial::FullScreenUi => NSVisualEffectMaterial::FullScreenUI,
+        FallbackMaterial::Tooltip => NSVisualEffectMaterial::Tooltip,
+        FallbackMaterial::ContentBackground => NSVisualEffectMaterial::ContentBackground,
+        FallbackMaterial::UnderWindowBackground => NSVisualEffectMaterial::UnderWindowBackground,
+        FallbackMaterial::UnderPageBackground => NSVisualEffectMaterial::UnderPageBackground,
+    }
 }
 
 // ============================================================================
 // Backend Selection
 // ============================================================================
 
-/// Get the appropriate glass backend for the current macOS version
-pub fn get_backend() -> Box<dyn GlassBackend> {
-    if glass_class_available() {
-        Box::new(NativeGlassBackend)
+/// Whether new views are forced onto NSVisualEffectView
+static SAFE_MODE: AtomicBool = AtomicBool::new(false);
+
+/// Create every new glass view with NSVisualEffectView, even where
+/// NSGlassEffectView is available
+pub fn set_safe_mode(enabled: bool) {
+    SAFE_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// Whether new views are forced onto NSVisualEffectView
+pub fn is_safe_mode() -> bool {
+    SAFE_MODE.load(Ordering::Relaxed)
+}
+
+/// Create a glass view with the best available backend
+///
+/// Falls back to NSVisualEffectView in safe mode, when NSGlassEffectView is
+/// missing or when it fails to initialize. Returns the view, its backend and
+/// the reason it fell back, if it did.
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn create_view(
+    bounds: NSRect,
+) -> Result<(id, Box<dyn GlassBackend>, Option<FallbackReason>)> {
+    let reason = if SAFE_MODE.load(Ordering::Relaxed) {
+        FallbackReason::SafeMode
+    } else if !glass_class_available() {
+        FallbackReason::ClassUnavailable
     } else {
+        match NativeGlassBackend.create_view(bounds) {
+            Ok(view) => return Ok((view, Box::new(NativeGlassBackend), None)),
+            Err(e) => {
+                warn!(
+                    "NSGlassEffectView failed to initialize, falling back: {}",
+                    e
+                );
+                FallbackReason::InitFailed
+            }
+        }
+    };
+
+    let view = VisualEffectBackend.create_view(bounds)?;
+    Ok((view, Box::new(VisualEffectBackend), Some(reason)))
+}
+
+/// Create a glass group container with the best available backend
+///
+/// Uses NSGlassEffectContainerView where NSGlassEffectView would be used,
+/// else a plain view that groups without merging. Grouped glass views go
+/// into [`container_content`].
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn create_container(bounds: NSRect, spacing: f64) -> Result<id> {
+    if !SAFE_MODE.load(Ordering::Relaxed) && Class::get("NSGlassEffectContainerView").is_some() {
+        match NativeGlassBackend.create_container(bounds, spacing) {
+            Ok((container, _)) => return Ok(container),
+            Err(e) => warn!(
+                "NSGlassEffectContainerView failed to initialize, falling back: {}",
+                e
+            ),
+        }
+    }
+
+    let (container, _) = VisualEffectBackend.create_container(bounds, spacing)?;
+    Ok(container)
+}
+
+/// Set the merge distance of a group container
+///
+/// A no-op for containers without NSGlassEffectContainerView.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `container` must be a valid NSView
+pub unsafe fn set_container_spacing(container: id, spacing: f64) {
+    let responds: BOOL = msg_send![container, respondsToSelector: sel!(setSpacing:)];
+    if responds != NO {
+        let _: () = msg_send![container, setSpacing: spacing];
+    }
+}
+
+/// Get the view grouped glass views are added to
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `container` must be a valid NSView created by [`create_container`]
+pub unsafe fn container_content(container: id) -> id {
+    let responds: BOOL = msg_send![container, respondsToSelector: sel!(contentView)];
+    if responds != NO {
+        msg_send![container, contentView]
+    } else {
+        container
+    }
+}
+
+/// Get the backend that created an existing glass view
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn backend_of(view: id) -> Box<dyn GlassBackend> {
+    let is_fallback: BOOL = msg_send![view, isKindOfClass: class!(NSVisualEffectView)];
+    if is_fallback != NO {
         Box::new(VisualEffectBackend)
+    } else {
+        Box::new(NativeGlassBackend)
+    }
+}
+
+// ============================================================================
+// Variant Probing
+// ============================================================================
+
+/// Determine which material variants the running OS build actually honors
+///
+/// Creates a throwaway NSGlassEffectView, sets each variant and reads it back.
+/// Variants the view rejects or clamps to another value are omitted. If the
+/// view exposes no getter nothing can be verified, so no variant is reported.
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn probe_supported_variants() -> Vec<GlassMaterialVariant> {
+    let Some(glass_class) = Class::get("NSGlassEffectView") else {
+        return Vec::new();
+    };
+
+    let view: id = msg_send![glass_class, alloc];
+    let view: id = msg_send![view, init];
+    if view == nil {
+        return Vec::new();
+    }
+
+    let supported = GlassMaterialVariant::ALL
+        .into_iter()
+        .filter(|&variant| {
+            if set_view_property(view, "variant", variant as i64).is_err() {
+                return false;
+            }
+            get_view_property(view, "variant").is_some_and(|value| value == variant as i64)
+        })
+        .collect();
+
+    let _: () = msg_send![view, release];
+
+    supported
+}
+
+/// Whether NSGlassEffectView implements `setTintColor:`
+///
+/// Asks the class, so no view is created.
+pub fn probe_tint_color() -> bool {
+    Class::get("NSGlassEffectView").is_some_and(|glass_class| {
+        let responds: BOOL =
+            unsafe { msg_send![glass_class, instancesRespondToSelector: sel!(setTintColor:)] };
+        responds != NO
+    })
+}
+
+/// Determine which experimental properties the running OS build implements
+///
+/// Creates a throwaway NSGlassEffectView and checks whether it responds to
+/// each property's setter.
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn probe_experimental_properties() -> Vec<ExperimentalProperty> {
+    let Some(glass_class) = Class::get("NSGlassEffectView") else {
+        return Vec::new();
+    };
+
+    let view: id = msg_send![glass_class, alloc];
+    let view: id = msg_send![view, init];
+    if view == nil {
+        return Vec::new();
+    }
+
+    let supported = ExperimentalProperty::ALL
+        .into_iter()
+        .filter(|&property| responds_to_setter(view, experimental_key(property)))
+        .collect();
+
+    let _: () = msg_send![view, release];
+
+    supported
+}
+
+/// Set an experimental property on a glass view
+///
+/// Fails with [`Error::UnsupportedProperty`] if the view does not implement
+/// the property.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid Objective-C object
+pub unsafe fn set_experimental_property(
+    view: id,
+    property: ExperimentalProperty,
+    enabled: bool,
+) -> Result<()> {
+    set_view_property(view, experimental_key(property), enabled as i64)
+}
+
+/// Read the material variant a glass view currently renders with
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid Objective-C object
+pub unsafe fn current_variant(view: id) -> Option<i64> {
+    get_view_property(view, "variant")
+}
+
+/// Key of the NSGlassEffectView property backing an experimental property
+fn experimental_key(property: ExperimentalProperty) -> &'static str {
+    match property {
+        ExperimentalProperty::Scrim => "scrimState",
+        ExperimentalProperty::Subdued => "subduedState",
+        ExperimentalProperty::Interactive => "interactive",
     }
 }
 
@@ -199,25 +560,87 @@ pub fn get_backend() -> Box<dyn GlassBackend> {
 
 /// Set property on view using selector lookup
 ///
+/// Fails with [`Error::UnsupportedProperty`] if the view responds to neither
+/// setter.
+///
 /// # Safety
 /// - Must be called on the main thread
 /// - `view` must be a valid Objective-C object
-unsafe fn set_view_property(view: id, key: &str, value: i64) {
-    let obj = view;
+unsafe fn set_view_property(view: id, key: &str, value: i64) -> Result<()> {
+    if setter_names(key)
+        .iter()
+        .any(|name| try_send_i64(view, Sel::register(name), value))
+    {
+        Ok(())
+    } else {
+        Err(Error::UnsupportedProperty(key.to_string()))
+    }
+}
 
-    // Try private setter: set_<key>:
-    let private_sel = Sel::register(&format!("set_{}:", key));
-    if try_send_i64(obj, private_sel, value) {
-        return;
+/// Check that NSGlassEffectView has a setter for every property `config`
+/// asks for
+///
+/// Properties left at their default are not checked. Fails with
+/// [`Error::UnsupportedProperty`] naming the first property whose setter is
+/// missing, which the native backend would skip when applying the config.
+pub fn ensure_applicable(config: &LiquidGlassConfig) -> Result<()> {
+    let report = probe_private_api();
+    let requested = [
+        ("variant", config.variant != GlassMaterialVariant::Regular),
+        ("scrimState", config.scrim == Some(true)),
+        ("subduedState", config.subdued == Some(true)),
+        ("interactive", config.interactive),
+        ("tintColor", config.tint_color.is_some()),
+    ];
+
+    let missing = requested
+        .into_iter()
+        .filter(|&(_, requested)| requested)
+        .map(|(key, _)| key)
+        .find(|&key| {
+            !report
+                .properties
+                .iter()
+                .any(|probe| probe.key == key && probe.setter.is_some())
+        });
+
+    match missing {
+        Some(key) => Err(Error::UnsupportedProperty(key.to_string())),
+        None => Ok(()),
     }
+}
+
+/// Check whether a view responds to the private or public setter of a property
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid Objective-C object
+unsafe fn responds_to_setter(view: id, key: &str) -> bool {
+    setter_names(key)
+        .iter()
+        .any(|name| responds_to(view, Sel::register(name)))
+}
 
-    // Try public setter: setKey:
-    let public_sel = Sel::register(&format!(
-        "set{}{}:",
-        key.chars().next().unwrap().to_uppercase(),
-        &key[1..]
-    ));
-    try_send_i64(obj, public_sel, value);
+/// Check whether an object responds to a selector
+///
+/// # Safety
+/// `obj` must be a valid Objective-C object
+unsafe fn responds_to(obj: id, sel: Sel) -> bool {
+    let responds: BOOL = msg_send![obj, respondsToSelector: sel];
+    responds != NO
+}
+
+/// Read property from view using selector lookup
+///
+/// Returns `None` if the view responds to neither the private nor the public getter.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid Objective-C object
+unsafe fn get_view_property(view: id, key: &str) -> Option<i64> {
+    getter_names(key)
+        .iter()
+        .find_map(|name| try_get_i64(view, Sel::register(name)))
 }
 
 /// Try to send an i64 message to an object
@@ -230,3 +653,13 @@ unsafe fn try_send_i64(obj: *mut Object, sel: Sel, value: i64) -> bool {
         false
     }
 }
+
+/// Try to read an i64 value from an object
+unsafe fn try_get_i64(obj: *mut Object, sel: Sel) -> Option<i64> {
+    let responds: BOOL = msg_send![obj, respondsToSelector: sel];
+    if responds != NO {
+        objc::__send_message(&*obj, sel, ()).ok()
+    } else {
+        None
+    }
+}
@@ -0,0 +1,102 @@
+//! Foreground color recommendation for text over glass
+//!
+//! The backdrop behind glass is unknown, so this is a heuristic: the glass is
+//! approximated by a flat base color for the current appearance, the tint and
+//! tint layers are composited over it, and the foreground with the higher
+//! WCAG contrast ratio against the result is recommended.
+
+use crate::color::{parse_css_color, Rgba};
+use crate::models::{
+    Appearance, ForegroundRecommendation, GlassMaterialVariant, LiquidGlassConfig,
+};
+
+/// Approximate rendered color of untinted glass per appearance
+const LIGHT_GLASS: Rgb = Rgb(0.93, 0.93, 0.94);
+const DARK_GLASS: Rgb = Rgb(0.16, 0.16, 0.18);
+
+/// Unknown content seen through the clear variant
+const MID_GRAY: Rgb = Rgb(0.5, 0.5, 0.5);
+
+#[derive(Debug, Clone, Copy)]
+struct Rgb(f64, f64, f64);
+
+impl Rgb {
+    /// Composite `top` with opacity `alpha` over this color
+    fn over(self, top: Rgb, alpha: f64) -> Rgb {
+        let mix = |bottom: f64, top: f64| top * alpha + bottom * (1.0 - alpha);
+        Rgb(mix(self.0, top.0), mix(self.1, top.1), mix(self.2, top.2))
+    }
+
+    /// WCAG relative luminance
+    fn luminance(self) -> f64 {
+        let channel = |c: f64| {
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(self.0) + 0.7152 * channel(self.1) + 0.0722 * channel(self.2)
+    }
+
+    fn from_css(css: &str) -> Option<(Rgb, f64)> {
+        let Rgba { r, g, b, a } = parse_css_color(css)?;
+        Some((Rgb(r, g, b), a))
+    }
+}
+
+/// WCAG contrast ratio between two luminances
+fn contrast_ratio(a: f64, b: f64) -> f64 {
+    let (light, dark) = if a > b { (a, b) } else { (b, a) };
+    (light + 0.05) / (dark + 0.05)
+}
+
+/// Recommend a foreground color for glass rendered with `config`
+pub fn recommend_foreground(
+    config: &LiquidGlassConfig,
+    appearance: Appearance,
+) -> ForegroundRecommendation {
+    let glass = match appearance {
+        Appearance::Light => LIGHT_GLASS,
+        Appearance::Dark => DARK_GLASS,
+    };
+
+    let mut surface = if config.opaque {
+        config
+            .opaque_color
+            .as_deref()
+            .and_then(Rgb::from_css)
+            .map_or(glass, |(color, _)| color)
+    } else if config.variant == GlassMaterialVariant::Clear {
+        // Clear glass shows much of the content behind it
+        MID_GRAY.over(glass, 0.5)
+    } else {
+        glass
+    };
+
+    let tints = config
+        .tint_color
+        .iter()
+        .chain(config.tint_layers.iter().map(|layer| &layer.color));
+    for css in tints {
+        if let Some((color, alpha)) = Rgb::from_css(css) {
+            surface = surface.over(color, alpha);
+        }
+    }
+
+    let background = surface.luminance();
+    let on_white = contrast_ratio(background, 1.0);
+    let on_black = contrast_ratio(background, 0.0);
+
+    let (color, contrast_ratio) = if on_white >= on_black {
+        ("#ffffff", on_white)
+    } else {
+        ("#000000", on_black)
+    };
+
+    ForegroundRecommendation {
+        color: color.to_string(),
+        contrast_ratio: (contrast_ratio * 100.0).round() / 100.0,
+        appearance,
+    }
+}
@@ -0,0 +1,218 @@
+//! Window diagnostics
+//!
+//! Glass that is applied but invisible almost always comes down to an opaque
+//! window or webview, a title bar the content doesn't reach under, or a view
+//! that left the hierarchy. [`diagnose`] reads all of it in one main-thread
+//! pass and spells out what looks wrong.
+
+use cocoa::base::{id, nil, NO};
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::operations::find_webview;
+use super::registry::{GlassViewRegistry, ViewHandle};
+use super::suspend::is_suspended;
+use super::utils::run_on_main_sync;
+use super::window::ns_window_handle;
+use crate::error::Result;
+use crate::models::{GlassBackendKind, GlassDoctorReport, GlassViewInfo, TitlebarStyle};
+
+/// NSWindowStyleMaskTitled
+const STYLE_TITLED: u64 = 1 << 0;
+
+/// NSWindowStyleMaskFullSizeContentView
+const STYLE_FULL_SIZE_CONTENT_VIEW: u64 = 1 << 15;
+
+/// NSWindowTitleHidden
+const TITLE_HIDDEN: i64 = 1;
+
+/// Native state read from the window and its glass views
+struct NativeState {
+    opaque: bool,
+    background_alpha: f64,
+    webview_found: bool,
+    draws_background: Option<bool>,
+    titlebar: TitlebarStyle,
+    detached: Vec<Option<String>>,
+    hidden: Vec<Option<String>>,
+}
+
+/// Diagnose why glass may not show in a window
+pub fn diagnose<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+) -> Result<GlassDoctorReport> {
+    let label = window.label().to_string();
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut views = Vec::new();
+    let mut handles = Vec::new();
+    let full_window = registry
+        .entries()?
+        .into_iter()
+        .filter(|(window, _)| *window == label)
+        .map(|(window, entry)| (window, None, entry));
+    let regions = registry
+        .region_entries()?
+        .into_iter()
+        .filter(|(window, _, _)| *window == label)
+        .map(|(window, region, entry)| (window, Some(region), entry));
+    for (window, region, entry) in full_window.chain(regions) {
+        handles.push((region.clone(), entry.glass_view));
+        views.push(GlassViewInfo {
+            window,
+            region,
+            backend: entry.backend,
+            fallback_reason: entry.fallback,
+            config: entry.config,
+        });
+    }
+
+    let ns_window = ns_window_handle(window)?;
+    let native = run_on_main_sync(move || unsafe { inspect(ns_window.as_id(), &handles) });
+
+    let mut report = GlassDoctorReport {
+        window: label,
+        capabilities: super::capabilities(),
+        suspended: is_suspended(),
+        window_opaque: Some(native.opaque),
+        background_alpha: Some(native.background_alpha),
+        webview_draws_background: native.draws_background,
+        titlebar: Some(native.titlebar),
+        views,
+        problems: Vec::new(),
+    };
+    report.problems = problems(&report, &native);
+    Ok(report)
+}
+
+/// Read the window, webview and glass view state
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be a valid NSWindow
+unsafe fn inspect(ns_window: id, views: &[(Option<String>, ViewHandle)]) -> NativeState {
+    let opaque: BOOL = msg_send![ns_window, isOpaque];
+    let background: id = msg_send![ns_window, backgroundColor];
+    let background_alpha: f64 = if background == nil {
+        0.0
+    } else {
+        msg_send![background, alphaComponent]
+    };
+
+    let content_view: id = msg_send![ns_window, contentView];
+    let webview = find_webview(content_view);
+    let draws_background = webview.and_then(|webview| {
+        let key: id =
+            msg_send![class!(NSString), stringWithUTF8String: c"drawsBackground".as_ptr()];
+        let value: id = msg_send![webview, valueForKey: key];
+        (value != nil).then(|| {
+            let draws: BOOL = msg_send![value, boolValue];
+            draws != NO
+        })
+    });
+
+    let style: u64 = msg_send![ns_window, styleMask];
+    let transparent: BOOL = msg_send![ns_window, titlebarAppearsTransparent];
+    let title_visibility: i64 = msg_send![ns_window, titleVisibility];
+
+    let mut detached = Vec::new();
+    let mut hidden = Vec::new();
+    for (region, view) in views {
+        let host: id = msg_send![view.as_id(), window];
+        if host != ns_window {
+            detached.push(region.clone());
+            continue;
+        }
+        let is_hidden: BOOL = msg_send![view.as_id(), isHiddenOrHasHiddenAncestor];
+        if is_hidden != NO {
+            hidden.push(region.clone());
+        }
+    }
+
+    NativeState {
+        opaque: opaque != NO,
+        background_alpha,
+        webview_found: webview.is_some(),
+        draws_background,
+        titlebar: TitlebarStyle {
+            titled: style & STYLE_TITLED != 0,
+            full_size_content_view: style & STYLE_FULL_SIZE_CONTENT_VIEW != 0,
+            transparent: transparent != NO,
+            title_hidden: title_visibility == TITLE_HIDDEN,
+        },
+        detached,
+        hidden,
+    }
+}
+
+/// Describe what keeps glass from showing
+fn problems(report: &GlassDoctorReport, native: &NativeState) -> Vec<String> {
+    let capabilities = &report.capabilities;
+    let mut problems = Vec::new();
+
+    if !capabilities.native_glass {
+        problems.push(
+            "NSGlassEffectView is unavailable on this macOS version, so glass falls back to \
+             NSVisualEffectView"
+                .to_string(),
+        );
+    } else if capabilities.backend == Some(GlassBackendKind::VisualEffect) {
+        problems.push("Safe mode is on, so new views use NSVisualEffectView".to_string());
+    }
+    if report.suspended {
+        problems.push("Effects are suspended, so glass is replaced by an opaque base".to_string());
+    }
+    if native.opaque {
+        problems.push(
+            "The window is opaque. Set `transparent: true` in tauri.conf.json, `autoTransparency` \
+             in the config or call setWindowTransparent()"
+                .to_string(),
+        );
+    }
+    if native.background_alpha >= 1.0 {
+        problems.push("The window's background color is opaque".to_string());
+    }
+    if !native.webview_found {
+        problems.push("No webview was found in the window's content view".to_string());
+    } else if native.draws_background == Some(true) {
+        problems.push(
+            "The webview draws its own background. Call setWebviewTransparent() or use \
+             `autoTransparency`, and make the page background transparent"
+                .to_string(),
+        );
+    }
+    if native.titlebar.titled && !native.titlebar.full_size_content_view && !report.views.is_empty()
+    {
+        problems.push(
+            "The content view doesn't extend under the title bar, so glass stops below it"
+                .to_string(),
+        );
+    }
+    if report.views.is_empty() {
+        problems.push("No glass view is registered for this window".to_string());
+    }
+    for region in &native.detached {
+        problems.push(format!(
+            "{} is no longer in the window's view hierarchy; validate_glass_views() prunes it",
+            view_name(region.as_deref())
+        ));
+    }
+    for region in &native.hidden {
+        problems.push(format!(
+            "{} is hidden, e.g. by its `visibleWhen` condition",
+            view_name(region.as_deref())
+        ));
+    }
+
+    problems
+}
+
+/// Name a view in a problem description
+fn view_name(region: Option<&str>) -> String {
+    match region {
+        Some(region) => format!("Glass view '{region}'"),
+        None => "The full-window glass view".to_string(),
+    }
+}
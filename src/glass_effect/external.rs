@@ -0,0 +1,176 @@
+//! Detect changes other code makes to the properties of our glass views
+//!
+//! Another component (or the OS) can set `tintColor`, `variant` or the layer
+//! corner radius of a glass view directly, leaving the registry's stored
+//! config stale. Each native glass view is observed through KVO; changes made
+//! while the plugin itself applies a config are ignored.
+
+use std::cell::Cell;
+use std::ffi::CStr;
+
+use cocoa::base::{id, nil};
+use objc::{msg_send, sel, sel_impl};
+
+use super::backend::current_variant;
+use super::observer::{add_key_path, observe_key_path, remove_key_path};
+use super::registry::ViewHandle;
+use super::utils::{associated_object, css_from_color, set_associated_object};
+use crate::color::parse_css_color;
+use crate::models::{GlassMaterialVariant, LiquidGlassConfig, ObservedProperty};
+
+/// Address used as the associated-object key holding a view's KVO observer
+static OBSERVER_KEY: u8 = 0;
+
+/// Key paths observed on the glass view
+const VIEW_KEY_PATHS: [&CStr; 2] = [c"tintColor", c"variant"];
+
+/// Key paths observed on the glass view's layer
+const LAYER_KEY_PATHS: [&CStr; 1] = [c"cornerRadius"];
+
+thread_local! {
+    /// Number of live [`ApplyingGuard`]s on the main thread
+    static APPLYING: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Suppresses change reports while the plugin applies a config itself
+pub struct ApplyingGuard(());
+
+impl ApplyingGuard {
+    pub fn new() -> Self {
+        APPLYING.with(|depth| depth.set(depth.get() + 1));
+        Self(())
+    }
+}
+
+impl Drop for ApplyingGuard {
+    fn drop(&mut self) {
+        APPLYING.with(|depth| depth.set(depth.get() - 1));
+    }
+}
+
+/// Invoke `on_change` whenever a KVO-observed property of `view` changes
+/// while no [`ApplyingGuard`] is alive
+///
+/// Observing a view twice is a no-op. The observer is owned by the view.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSGlassEffectView
+pub unsafe fn observe_external_changes(view: ViewHandle, on_change: impl Fn() + 'static) {
+    let glass = view.as_id();
+    if associated_object(glass, &OBSERVER_KEY) != nil {
+        return;
+    }
+
+    let callback = move |_| {
+        if APPLYING.with(Cell::get) == 0 {
+            on_change();
+        }
+    };
+
+    let observer = observe_key_path(glass, VIEW_KEY_PATHS[0], callback);
+    for key_path in &VIEW_KEY_PATHS[1..] {
+        add_key_path(observer, glass, key_path);
+    }
+    let layer: id = msg_send![glass, layer];
+    if layer != nil {
+        for key_path in LAYER_KEY_PATHS {
+            add_key_path(observer, layer, key_path);
+        }
+    }
+
+    set_associated_object(glass, &OBSERVER_KEY, observer);
+    let _: () = msg_send![observer, release];
+}
+
+/// Run `change` with the view's KVO registrations temporarily removed
+///
+/// KVO works by swapping the class of the observed object, which would be
+/// undone by anything else that swaps the class, such as the focus subclass.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView
+pub unsafe fn without_observers<T>(glass: id, change: impl FnOnce() -> T) -> T {
+    let observer = associated_object(glass, &OBSERVER_KEY);
+    if observer == nil {
+        return change();
+    }
+
+    let layer: id = msg_send![glass, layer];
+    for key_path in VIEW_KEY_PATHS {
+        remove_key_path(observer, glass, key_path);
+    }
+    if layer != nil {
+        for key_path in LAYER_KEY_PATHS {
+            remove_key_path(observer, layer, key_path);
+        }
+    }
+
+    let result = change();
+
+    for key_path in VIEW_KEY_PATHS {
+        add_key_path(observer, glass, key_path);
+    }
+    if layer != nil {
+        for key_path in LAYER_KEY_PATHS {
+            add_key_path(observer, layer, key_path);
+        }
+    }
+    result
+}
+
+/// Bring `config` in line with the view's native state
+///
+/// Returns the properties that differed.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSGlassEffectView
+pub unsafe fn sync_config(glass: id, config: &mut LiquidGlassConfig) -> Vec<ObservedProperty> {
+    let mut changed = Vec::new();
+
+    let tint: id = msg_send![glass, tintColor];
+    let native_tint = css_from_color(tint);
+    let same_tint = match (&native_tint, &config.tint_color) {
+        (None, None) => true,
+        (Some(native), Some(stored)) => colors_match(native, stored),
+        _ => false,
+    };
+    if !same_tint {
+        config.tint_color = native_tint;
+        changed.push(ObservedProperty::TintColor);
+    }
+
+    if let Some(value) = current_variant(glass) {
+        let variant = GlassMaterialVariant::ALL
+            .into_iter()
+            .find(|&variant| variant as i64 == value);
+        if let Some(variant) = variant.filter(|&variant| variant != config.variant) {
+            config.variant = variant;
+            changed.push(ObservedProperty::Variant);
+        }
+    }
+
+    let layer: id = msg_send![glass, layer];
+    if layer != nil {
+        let radius: f64 = msg_send![layer, cornerRadius];
+        if (radius - config.layer_corner_radius()).abs() > 0.01 {
+            config.corner_radius = radius;
+            config.corner_radii = None;
+            changed.push(ObservedProperty::CornerRadius);
+        }
+    }
+
+    changed
+}
+
+/// Whether two CSS colors are equal at 8-bit precision
+fn colors_match(a: &str, b: &str) -> bool {
+    match (parse_css_color(a), parse_css_color(b)) {
+        (Some(a), Some(b)) => [a.r - b.r, a.g - b.g, a.b - b.b, a.a - b.a]
+            .into_iter()
+            .all(|delta| delta.abs() < 1.0 / 255.0),
+        _ => a == b,
+    }
+}
@@ -0,0 +1,193 @@
+//! Key view loop participation and click-through for glass views
+//!
+//! Glass views sit in the same window as the webview, so a view that accepts
+//! first responder can swallow Tab presses or steal focus from web content.
+//! Each glass view is moved to a runtime subclass of its own class that
+//! either refuses first responder outright or joins the key view loop and
+//! draws a focus ring matching its corner radius. Glass placed above the
+//! webview also overrides `hitTest:`, so clicks fall through to the web
+//! content (NSView has no `ignoresMouseEvents` of its own).
+
+use std::sync::Mutex;
+
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect};
+use objc::runtime::{Class, Object, Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::external::without_observers;
+use super::operations::find_webview;
+use super::utils::declare_class;
+
+extern "C" {
+    fn object_setClass(obj: id, cls: *const Class) -> *const Class;
+}
+
+/// Prefix of the runtime subclasses, used to recover the original class
+const CLASS_PREFIX: &str = "LiquidGlassFocus";
+
+/// NSFocusRingType
+const NS_FOCUS_RING_TYPE_DEFAULT: u64 = 0;
+
+/// Make a glass view refuse first responder, or join the key view loop, and
+/// let clicks through if `click_through` is set
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView
+pub unsafe fn apply_focus_behavior(glass: id, focusable: bool, click_through: bool) {
+    let current: *const Class = msg_send![glass, class];
+    let target = focus_class(base_class(&*current), focusable, click_through);
+    swap_class(glass, current, target);
+
+    let window: id = msg_send![glass, window];
+    if window == nil {
+        return;
+    }
+
+    if focusable {
+        let _: () = msg_send![glass, setFocusRingType: NS_FOCUS_RING_TYPE_DEFAULT];
+        let _: () = msg_send![window, setAutorecalculatesKeyViewLoop: YES];
+        let _: () = msg_send![window, recalculateKeyViewLoop];
+    } else {
+        // Hand focus back to the web content if the glass was holding it
+        let responder: id = msg_send![window, firstResponder];
+        if responder == glass {
+            let content_view: id = msg_send![window, contentView];
+            let webview = find_webview(content_view).unwrap_or(nil);
+            let _: BOOL = msg_send![window, makeFirstResponder: webview];
+        }
+    }
+}
+
+/// Let clicks on an auxiliary view (opaque base, shadow) fall through
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn apply_click_through(view: id, click_through: bool) {
+    let current: *const Class = msg_send![view, class];
+    let base = base_class(&*current);
+    let target = if click_through {
+        focus_class(base, false, true)
+    } else {
+        base
+    };
+    swap_class(view, current, target);
+}
+
+/// Move a view to `target` unless it already has that class
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `target` must be `current`'s base class or one of our subclasses of it
+unsafe fn swap_class(view: id, current: *const Class, target: &Class) {
+    if !std::ptr::eq(current, target) {
+        // Swapping the class would silently drop KVO registrations
+        without_observers(view, || object_setClass(view, target));
+    }
+}
+
+/// The AppKit class a glass view was created with, skipping our subclasses
+fn base_class(class: &Class) -> &Class {
+    if class.name().starts_with(CLASS_PREFIX) {
+        class.superclass().unwrap_or(class)
+    } else {
+        class
+    }
+}
+
+/// Get (or declare) the focus subclass of `base`
+fn focus_class(base: &Class, focusable: bool, click_through: bool) -> &'static Class {
+    static CLASSES: Mutex<Vec<(String, usize)>> = Mutex::new(Vec::new());
+
+    let name = format!(
+        "{}{}{}{}",
+        CLASS_PREFIX,
+        if focusable { "Key" } else { "None" },
+        if click_through { "Through" } else { "" },
+        base.name()
+    );
+
+    let mut classes = CLASSES.lock().unwrap_or_else(|e| e.into_inner());
+    let class = match classes.iter().find(|(existing, _)| *existing == name) {
+        Some(&(_, class)) => class,
+        None => {
+            let class = declare_focus_class(&name, base, focusable, click_through);
+            classes.push((name, class));
+            class
+        }
+    };
+
+    unsafe { &*(class as *const Class) }
+}
+
+/// Declare a subclass overriding the first responder and focus ring methods,
+/// and `hitTest:` for click-through
+fn declare_focus_class(name: &str, base: &Class, focusable: bool, click_through: bool) -> usize {
+    // Another copy of the plugin may already have registered the class
+    let mut decl = match declare_class(name, base) {
+        Ok(decl) => decl,
+        Err(existing) => return existing as *const Class as usize,
+    };
+
+    extern "C" fn accepts(_this: &Object, _sel: Sel) -> BOOL {
+        YES
+    }
+    extern "C" fn refuses(_this: &Object, _sel: Sel) -> BOOL {
+        NO
+    }
+    extern "C" fn hit_nothing(_this: &Object, _sel: Sel, _point: NSPoint) -> id {
+        nil
+    }
+    extern "C" fn focus_ring_mask_bounds(this: &Object, _sel: Sel) -> NSRect {
+        unsafe { msg_send![this, bounds] }
+    }
+    extern "C" fn draw_focus_ring_mask(this: &Object, _sel: Sel) {
+        unsafe {
+            let bounds: NSRect = msg_send![this, bounds];
+            let layer: id = msg_send![this, layer];
+            let radius: f64 = if layer == nil {
+                0.0
+            } else {
+                msg_send![layer, cornerRadius]
+            };
+            let path: id = msg_send![
+                class!(NSBezierPath),
+                bezierPathWithRoundedRect: bounds
+                xRadius: radius
+                yRadius: radius
+            ];
+            let _: () = msg_send![path, fill];
+        }
+    }
+
+    let answer = if focusable {
+        accepts as extern "C" fn(&Object, Sel) -> BOOL
+    } else {
+        refuses as extern "C" fn(&Object, Sel) -> BOOL
+    };
+
+    unsafe {
+        decl.add_method(sel!(acceptsFirstResponder), answer);
+        decl.add_method(sel!(canBecomeKeyView), answer);
+        if focusable {
+            decl.add_method(
+                sel!(focusRingMaskBounds),
+                focus_ring_mask_bounds as extern "C" fn(&Object, Sel) -> NSRect,
+            );
+            decl.add_method(
+                sel!(drawFocusRingMask),
+                draw_focus_ring_mask as extern "C" fn(&Object, Sel),
+            );
+        }
+        if click_through {
+            decl.add_method(
+                sel!(hitTest:),
+                hit_nothing as extern "C" fn(&Object, Sel, NSPoint) -> id,
+            );
+        }
+    }
+
+    decl.register() as *const Class as usize
+}
@@ -0,0 +1,133 @@
+//! Read back and observe the on-screen frame of glass views
+
+use std::cell::RefCell;
+
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::NSRect;
+use objc::runtime::BOOL;
+use objc::{msg_send, sel, sel_impl};
+
+use super::observer::notification_observer;
+use super::operations::find_webview;
+use super::registry::ViewHandle;
+use super::utils::{associated_object, set_associated_object};
+use crate::models::{GlassViewFrame, ViewRect};
+
+/// Address used as the associated-object key holding a view's frame observer
+static OBSERVER_KEY: u8 = 0;
+
+/// Invoke `on_change` with the previous and new frame whenever the view's
+/// frame changes
+///
+/// Posted by autoresizing and relayouts alike, so this also reports changes
+/// the frontend did not initiate. Changes that leave the measured frame
+/// untouched are dropped. The observer is owned by the view, and observing a
+/// view twice is a no-op.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSView
+pub unsafe fn observe_frame(
+    view: ViewHandle,
+    on_change: impl Fn(Option<&GlassViewFrame>, &GlassViewFrame) + 'static,
+) {
+    let view = view.as_id();
+    if associated_object(view, &OBSERVER_KEY) != nil {
+        return;
+    }
+
+    let _: () = msg_send![view, setPostsFrameChangedNotifications: YES];
+
+    let last = RefCell::new(measure_view(view));
+    let observer = notification_observer(c"NSViewFrameDidChangeNotification", view, move |_| {
+        let Some(frame) = measure_view(view) else {
+            return;
+        };
+        let previous = last.replace(Some(frame.clone()));
+        if previous.as_ref() != Some(&frame) {
+            on_change(previous.as_ref(), &frame);
+        }
+    });
+
+    set_associated_object(view, &OBSERVER_KEY, observer);
+    let _: () = msg_send![observer, release];
+}
+
+/// Measure a glass view in content view points and webview CSS pixels
+///
+/// Returns `None` if the view is not attached to a window.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn measure_view(view: id) -> Option<GlassViewFrame> {
+    let window: id = msg_send![view, window];
+    if window == nil {
+        return None;
+    }
+    let content_view: id = msg_send![window, contentView];
+    if content_view == nil {
+        return None;
+    }
+
+    let bounds: NSRect = msg_send![view, bounds];
+    let points = top_left_rect(view, bounds, content_view);
+
+    // Without a webview, CSS pixels and content view points coincide
+    let css = match find_webview(content_view) {
+        Some(webview) => {
+            let rect = top_left_rect(view, bounds, webview);
+            let zoom = page_zoom(webview);
+            ViewRect {
+                x: rect.x / zoom,
+                y: rect.y / zoom,
+                width: rect.width / zoom,
+                height: rect.height / zoom,
+            }
+        }
+        None => points,
+    };
+
+    let scale_factor: f64 = msg_send![window, backingScaleFactor];
+    let hidden: BOOL = msg_send![view, isHiddenOrHasHiddenAncestor];
+
+    Some(GlassViewFrame {
+        points,
+        css,
+        scale_factor,
+        hidden: hidden != NO,
+    })
+}
+
+/// Convert `rect` from `view` into `target`, with a top-left origin
+unsafe fn top_left_rect(view: id, rect: NSRect, target: id) -> ViewRect {
+    let converted: NSRect = msg_send![view, convertRect: rect toView: target];
+    let flipped: BOOL = msg_send![target, isFlipped];
+    let y = if flipped != NO {
+        converted.origin.y
+    } else {
+        let target_bounds: NSRect = msg_send![target, bounds];
+        target_bounds.size.height - converted.origin.y - converted.size.height
+    };
+
+    ViewRect {
+        x: converted.origin.x,
+        y,
+        width: converted.size.width,
+        height: converted.size.height,
+    }
+}
+
+/// The webview's page zoom (`WKWebView.pageZoom`, macOS 11+), 1.0 if unknown
+unsafe fn page_zoom(webview: id) -> f64 {
+    let responds: BOOL = msg_send![webview, respondsToSelector: sel!(pageZoom)];
+    if responds == NO {
+        return 1.0;
+    }
+    let zoom: f64 = msg_send![webview, pageZoom];
+    if zoom > 0.0 {
+        zoom
+    } else {
+        1.0
+    }
+}
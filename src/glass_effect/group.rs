@@ -0,0 +1,185 @@
+//! Glass groups: regions hosted in one NSGlassEffectContainerView
+//!
+//! Glass views inside the same container render together, so shapes closer
+//! than the container's spacing merge and morph into each other. Grouping
+//! keeps region ids and configs; it only moves the region views (with their
+//! opaque base and shadow siblings) into the container's content view, and
+//! back below the webview when they leave the group.
+
+use cocoa::base::{id, nil};
+use cocoa::foundation::NSRect;
+use objc::{msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::backend::{self, container_content, set_container_spacing};
+use super::operations::{insert_below_webview, layout_glass_view, place_views};
+use super::registry::{GlassGroupEntry, GlassViewEntry, GlassViewRegistry, ViewHandle};
+use super::utils::run_on_main_sync;
+use super::window::ns_window_handle;
+use crate::error::{Error, Result};
+
+/// NSWindowOrderingMode
+const NS_WINDOW_ABOVE: i64 = 1;
+
+/// Create or update a group hosting the regions `members`
+///
+/// Regions already in another group move to this one; regions that were in
+/// this group but are no longer listed move back below the webview. Every
+/// member must be an existing region of the window.
+pub fn create_glass_group<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    group: &str,
+    members: Vec<String>,
+    spacing: f64,
+) -> Result<()> {
+    let registry = app.state::<GlassViewRegistry>();
+    let label = window.label();
+    let ns_window = ns_window_handle(window)?;
+
+    let regions = registry.regions(label)?;
+    let entry_of = |member: &String| {
+        regions
+            .iter()
+            .find(|(id, _)| id == member)
+            .map(|(_, entry)| entry.clone())
+    };
+    let joining = members
+        .iter()
+        .map(|member| entry_of(member).ok_or_else(|| Error::ViewNotFound(member.clone())))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut groups = registry.groups(label)?;
+    for (id, entry) in &mut groups {
+        if id != group {
+            entry.members.retain(|member| !members.contains(member));
+        }
+    }
+    let existing = groups.iter().position(|(id, _)| id == group);
+    let leaving: Vec<_> = existing
+        .map(|index| &groups[index].1.members)
+        .into_iter()
+        .flatten()
+        .filter(|member| !members.contains(member))
+        .filter_map(entry_of)
+        .collect();
+    let container = existing.map(|index| groups[index].1.container);
+
+    let container = run_on_main_sync(move || unsafe {
+        let content_view: id = msg_send![ns_window.as_id(), contentView];
+        if content_view == nil {
+            return Err(Error::ViewCreationFailed);
+        }
+
+        for entry in &leaving {
+            unhost_region(entry, content_view);
+        }
+
+        let container = match container {
+            Some(container) => {
+                set_container_spacing(container.as_id(), spacing);
+                container
+            }
+            None => {
+                let bounds: NSRect = msg_send![content_view, bounds];
+                let container = backend::create_container(bounds, spacing)?;
+                insert_below_webview(content_view, container);
+                ViewHandle::owned(container)
+            }
+        };
+
+        let content = container_content(container.as_id());
+        for entry in &joining {
+            host_region(entry, content);
+        }
+        Ok(container)
+    })?;
+
+    let entry = GlassGroupEntry { container, members };
+    match existing {
+        Some(index) => groups[index].1 = entry,
+        None => groups.push((group.to_string(), entry)),
+    }
+    registry.set_groups(label, groups)
+}
+
+/// Dissolve a group, moving its regions back below the webview
+///
+/// Returns whether a group with this id existed.
+pub fn remove_glass_group<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    group: &str,
+) -> Result<bool> {
+    let registry = app.state::<GlassViewRegistry>();
+    let label = window.label();
+    let ns_window = ns_window_handle(window)?;
+
+    let mut groups = registry.groups(label)?;
+    let Some(index) = groups.iter().position(|(id, _)| id == group) else {
+        return Ok(false);
+    };
+    let (_, entry) = groups.remove(index);
+
+    let regions = registry.regions(label)?;
+    let members: Vec<_> = regions
+        .into_iter()
+        .filter(|(id, _)| entry.members.contains(id))
+        .map(|(_, region)| region)
+        .collect();
+
+    run_on_main_sync(move || unsafe {
+        let content_view: id = msg_send![ns_window.as_id(), contentView];
+        if content_view != nil {
+            for region in &members {
+                unhost_region(region, content_view);
+            }
+        }
+        let _: () = msg_send![entry.container.as_id(), removeFromSuperview];
+        entry.container.retire();
+    });
+
+    registry.set_groups(label, groups)?;
+    Ok(true)
+}
+
+/// Views of a region from the bottom up: shadow, opaque base, glass
+fn region_views(entry: &GlassViewEntry) -> impl Iterator<Item = ViewHandle> + '_ {
+    entry
+        .overlays
+        .shadow
+        .iter()
+        .chain(entry.overlays.opaque_base.iter())
+        .copied()
+        .chain(std::iter::once(entry.glass_view))
+}
+
+/// Move a region to the top of `content`, a group container's content view
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `content` must be a valid NSView
+unsafe fn host_region(entry: &GlassViewEntry, content: id) {
+    for view in region_views(entry) {
+        let view = view.as_id();
+        if view != nil {
+            let _: () =
+                msg_send![content, addSubview: view positioned: NS_WINDOW_ABOVE relativeTo: nil];
+        }
+    }
+    layout_glass_view(entry.glass_view, &entry.config, &entry.overlays);
+}
+
+/// Move a region out of its group, back to where its placement puts it
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `content_view` must be the window's content view
+unsafe fn unhost_region(entry: &GlassViewEntry, content_view: id) {
+    let views: Vec<id> = region_views(entry)
+        .map(|view| view.as_id())
+        .filter(|&view| view != nil)
+        .collect();
+    place_views(content_view, &views, entry.config.placement, true);
+    layout_glass_view(entry.glass_view, &entry.config, &entry.overlays);
+}
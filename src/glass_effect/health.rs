@@ -0,0 +1,119 @@
+//! Registry health checks
+//!
+//! The registry only learns about views going away through the plugin's own
+//! API. Views torn down behind its back (a window closed mid-session, a
+//! webview reloaded by HMR into a fresh window) stay registered forever
+//! unless they are detected and pruned here.
+
+use cocoa::base::{id, nil};
+use objc::{msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::operations::remove_glass_view;
+use super::registry::{GlassViewRegistry, ViewHandle};
+use super::scroll_edge::ScrollEdgeRegistry;
+use super::utils::run_on_main_sync;
+use crate::error::Result;
+use crate::models::{GlassHealthReport, OrphanReason, OrphanedView};
+
+/// Check every registered view and prune the orphaned ones
+pub fn validate_glass_views<R: Runtime>(app: &AppHandle<R>) -> Result<GlassHealthReport> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut views: Vec<_> = registry
+        .entries()?
+        .into_iter()
+        .map(|(label, entry)| (label, None, entry.glass_view))
+        .collect();
+    views.extend(
+        registry
+            .region_entries()?
+            .into_iter()
+            .map(|(label, region, entry)| (label, Some(region), entry.glass_view)),
+    );
+    let checked = views.len();
+
+    // Views of windows Tauri no longer knows about are orphans regardless of
+    // their native state
+    let (closed, open): (Vec<_>, Vec<_>) = views
+        .into_iter()
+        .partition(|(label, _, _)| app.get_webview_window(label).is_none());
+
+    let mut orphans: Vec<_> = closed
+        .into_iter()
+        .map(|(label, region, view)| (label, region, view, OrphanReason::WindowClosed))
+        .collect();
+    orphans.extend(run_on_main_sync(move || {
+        open.into_iter()
+            .filter_map(|(label, region, view)| {
+                let reason = unsafe { detached_reason(view) }?;
+                Some((label, region, view, reason))
+            })
+            .collect::<Vec<_>>()
+    }));
+
+    let mut pruned = Vec::new();
+    for (label, region, view, reason) in orphans {
+        if let Some(entry) = registry.remove_view(&label, region.as_deref(), view)? {
+            run_on_main_sync(move || unsafe { remove_glass_view(&entry) });
+            pruned.push(OrphanedView {
+                window: label,
+                region,
+                reason,
+            });
+        }
+    }
+
+    Ok(GlassHealthReport {
+        checked,
+        orphans: pruned,
+    })
+}
+
+/// Drop every entry of a destroyed window
+///
+/// The views go away with the window, so only the registries are updated.
+/// Returns the number of glass views that were registered for the window.
+pub fn forget_window<R: Runtime>(app: &AppHandle<R>, label: &str) -> Result<usize> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let window_glass = registry.remove(label)?;
+    let regions = registry.regions(label)?;
+    registry.set_regions(label, Vec::new())?;
+    for (_, group) in registry.groups(label)? {
+        group.container.retire();
+    }
+    registry.set_groups(label, Vec::new())?;
+    app.state::<ScrollEdgeRegistry>().forget(label)?;
+
+    let entries: Vec<_> = window_glass
+        .into_iter()
+        .chain(regions.into_iter().map(|(_, entry)| entry))
+        .collect();
+    for entry in &entries {
+        for view in entry.overlays.views() {
+            view.retire();
+        }
+        entry.glass_view.retire();
+    }
+
+    Ok(entries.len())
+}
+
+/// Why a view is no longer part of a live view hierarchy, if it isn't
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to an NSView that has not been deallocated
+unsafe fn detached_reason(view: ViewHandle) -> Option<OrphanReason> {
+    let view = view.as_id();
+    let superview: id = msg_send![view, superview];
+    if superview == nil {
+        return Some(OrphanReason::NoSuperview);
+    }
+    let window: id = msg_send![view, window];
+    if window == nil {
+        return Some(OrphanReason::NoWindow);
+    }
+    None
+}
@@ -0,0 +1,124 @@
+//! View hierarchy dumps
+//!
+//! Walks a window's content view so users can see where the glass view sits
+//! relative to the WKWebView, e.g. stacked above it or left behind in
+//! another superview. Only built with the `devtools` feature.
+
+use cocoa::base::{id, nil, NO};
+use cocoa::foundation::NSRect;
+use objc::runtime::BOOL;
+use objc::{msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::registry::{GlassViewRegistry, ViewHandle};
+use super::utils::run_on_main_sync;
+use super::window::ns_window_handle;
+use crate::error::{Error, Result};
+use crate::models::{LayerInfo, ViewNode, ViewRect};
+
+/// Dump the view tree under the window's content view
+pub fn dump_view_hierarchy<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+) -> Result<ViewNode> {
+    let label = window.label();
+    let registry = app.state::<GlassViewRegistry>();
+    let mut glass: Vec<ViewHandle> = registry
+        .entries()?
+        .into_iter()
+        .filter(|(window, _)| window == label)
+        .map(|(_, entry)| entry.glass_view)
+        .collect();
+    glass.extend(
+        registry
+            .region_entries()?
+            .into_iter()
+            .filter(|(window, _, _)| window == label)
+            .map(|(_, _, entry)| entry.glass_view),
+    );
+
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        let content_view: id = msg_send![ns_window.as_id(), contentView];
+        if content_view == nil {
+            return Err(Error::ViewNotFound("contentView".to_string()));
+        }
+        Ok(describe(content_view, &glass))
+    })
+}
+
+/// Describe a view and its subviews
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+unsafe fn describe(view: id, glass: &[ViewHandle]) -> ViewNode {
+    let frame: NSRect = msg_send![view, frame];
+    let hidden: BOOL = msg_send![view, isHidden];
+    let alpha: f64 = msg_send![view, alphaValue];
+    let layer: id = msg_send![view, layer];
+
+    let subviews: id = msg_send![view, subviews];
+    let count: usize = msg_send![subviews, count];
+    let subviews = (0..count)
+        .map(|i| {
+            let subview: id = msg_send![subviews, objectAtIndex: i];
+            describe(subview, glass)
+        })
+        .collect();
+
+    ViewNode {
+        class: (*view).class().name().to_string(),
+        frame: top_left_frame(view, frame),
+        hidden: hidden != NO,
+        alpha,
+        glass: glass.iter().any(|handle| handle.as_id() == view),
+        layer: (layer != nil).then(|| describe_layer(layer)),
+        subviews,
+    }
+}
+
+/// Describe a view's backing layer
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `layer` must be a valid CALayer
+unsafe fn describe_layer(layer: id) -> LayerInfo {
+    let opacity: f32 = msg_send![layer, opacity];
+    let corner_radius: f64 = msg_send![layer, cornerRadius];
+    let masks_to_bounds: BOOL = msg_send![layer, masksToBounds];
+    let mask: id = msg_send![layer, mask];
+    let opaque: BOOL = msg_send![layer, isOpaque];
+
+    LayerInfo {
+        class: (*layer).class().name().to_string(),
+        opacity: opacity as f64,
+        corner_radius,
+        masks_to_bounds: masks_to_bounds != NO,
+        has_mask: mask != nil,
+        opaque: opaque != NO,
+    }
+}
+
+/// Convert a view's frame to a top-left origin within its superview
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+unsafe fn top_left_frame(view: id, frame: NSRect) -> ViewRect {
+    let superview: id = msg_send![view, superview];
+    let mut y = frame.origin.y;
+    if superview != nil {
+        let flipped: BOOL = msg_send![superview, isFlipped];
+        if flipped == NO {
+            let bounds: NSRect = msg_send![superview, bounds];
+            y = bounds.size.height - frame.origin.y - frame.size.height;
+        }
+    }
+    ViewRect {
+        x: frame.origin.x,
+        y,
+        width: frame.size.width,
+        height: frame.size.height,
+    }
+}
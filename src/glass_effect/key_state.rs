@@ -0,0 +1,130 @@
+//! Follow window key status on the NSVisualEffectView fallback
+//!
+//! System sidebars desaturate when their window resigns key. NSGlassEffectView
+//! does this on its own; the fallback is pinned to the active state unless
+//! `key_state_transition_ms` is set, in which case it switches between active
+//! and inactive with a crossfade instead of an abrupt swap. An explicit
+//! `fallback_state` overrides both.
+
+use std::time::Duration;
+
+use cocoa::appkit::NSVisualEffectState;
+use cocoa::base::{id, nil, NO, YES};
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{Runtime, WebviewWindow};
+
+use super::observer::notification_observer;
+use super::utils::{associated_object, run_on_main_sync, set_associated_object};
+use super::window::ns_window_handle;
+use crate::error::Result;
+use crate::models::{FallbackState, LiquidGlassConfig};
+
+/// Associated-object keys holding a window's key status observers
+static BECOME_KEY: u8 = 0;
+static RESIGN_KEY: u8 = 0;
+
+/// Key of the crossfade animation on the view's layer
+const ANIMATION_KEY: &std::ffi::CStr = c"liquidGlassKeyState";
+
+/// Set the fallback view's state to `config.fallback_state` or to match its
+/// window, without animating
+///
+/// Views that are not NSVisualEffectViews are left alone.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView
+pub unsafe fn apply_key_state(glass: id, config: &LiquidGlassConfig) {
+    if !is_visual_effect_view(glass) {
+        return;
+    }
+
+    let state = match config.fallback_state {
+        Some(FallbackState::Active) => NSVisualEffectState::Active,
+        Some(FallbackState::Inactive) => NSVisualEffectState::Inactive,
+        Some(FallbackState::FollowsWindowActiveState) => {
+            NSVisualEffectState::FollowsWindowActiveState
+        }
+        None => state(config.key_state_transition_ms.is_none() || window_is_key(glass)),
+    };
+    let _: () = msg_send![glass, setState: state];
+}
+
+/// Crossfade a fallback view to the active (key) or inactive state
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView
+pub unsafe fn transition_key_state(glass: id, key: bool, duration: Duration) {
+    if !is_visual_effect_view(glass) {
+        return;
+    }
+
+    let _: () = msg_send![glass, setWantsLayer: YES];
+    let layer: id = msg_send![glass, layer];
+    if layer != nil && !duration.is_zero() {
+        let transition: id = msg_send![class!(CATransition), animation];
+        let fade: id = msg_send![class!(NSString), stringWithUTF8String: c"fade".as_ptr()];
+        let _: () = msg_send![transition, setType: fade];
+        let _: () = msg_send![transition, setDuration: duration.as_secs_f64()];
+        let name: id = msg_send![class!(NSString), stringWithUTF8String: ANIMATION_KEY.as_ptr()];
+        let _: () = msg_send![layer, addAnimation: transition forKey: name];
+    }
+
+    let _: () = msg_send![glass, setState: state(key)];
+}
+
+/// Invoke `on_change` with the new key status whenever the window becomes or
+/// resigns key
+///
+/// The observers are owned by the window, and observing a window twice is a
+/// no-op.
+pub fn observe_key_status<R: Runtime>(
+    window: &WebviewWindow<R>,
+    on_change: impl Fn(bool) + Clone + Send + 'static,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        if associated_object(ns_window, &BECOME_KEY) != nil {
+            return;
+        }
+
+        let notifications = [
+            (&BECOME_KEY, c"NSWindowDidBecomeKeyNotification", true),
+            (&RESIGN_KEY, c"NSWindowDidResignKeyNotification", false),
+        ];
+        for (slot, name, key) in notifications {
+            let on_change = on_change.clone();
+            let observer = notification_observer(name, ns_window, move |_| on_change(key));
+            set_associated_object(ns_window, slot, observer);
+            let _: () = msg_send![observer, release];
+        }
+    });
+
+    Ok(())
+}
+
+unsafe fn is_visual_effect_view(view: id) -> bool {
+    let is_fallback: BOOL = msg_send![view, isKindOfClass: class!(NSVisualEffectView)];
+    is_fallback != NO
+}
+
+unsafe fn window_is_key(view: id) -> bool {
+    let window: id = msg_send![view, window];
+    if window == nil {
+        return true;
+    }
+    let key: BOOL = msg_send![window, isKeyWindow];
+    key != NO
+}
+
+fn state(key: bool) -> NSVisualEffectState {
+    if key {
+        NSVisualEffectState::Active
+    } else {
+        NSVisualEffectState::Inactive
+    }
+}
@@ -0,0 +1,238 @@
+//! Tint layer composition above the glass view, its rim and corner curve, and
+//! the opaque base beneath it
+
+use cocoa::appkit::{NSViewHeightSizable, NSViewWidthSizable};
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::NSRect;
+use objc::runtime::{Class, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::registry::ViewHandle;
+use super::utils::color_from_css;
+use crate::models::{BlendMode, CornerCurve, GlassRim, LiquidGlassConfig, TintLayer};
+
+/// Replace the tint layer views on a glass view
+///
+/// Existing layer views are removed and one layer-backed overlay view is created
+/// per valid layer, bottom-most first. Layers with unparseable colors are skipped.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView and `layer` its backing layer (or nil)
+///
+/// Returns handles to the created overlay views
+pub unsafe fn apply_tint_layers(
+    glass: id,
+    layer: id,
+    tint_layers: &[TintLayer],
+    existing: Vec<ViewHandle>,
+) -> Vec<ViewHandle> {
+    for view in existing {
+        let _: () = msg_send![view.as_id(), removeFromSuperview];
+        view.retire();
+    }
+
+    let bounds: NSRect = msg_send![glass, bounds];
+    let radius: f64 = if layer != nil {
+        msg_send![layer, cornerRadius]
+    } else {
+        0.0
+    };
+
+    tint_layers
+        .iter()
+        .filter_map(|tint_layer| {
+            let color = color_from_css(&tint_layer.color)?;
+
+            let view: id = msg_send![class!(NSView), alloc];
+            let view: id = msg_send![view, initWithFrame: bounds];
+            let _: () =
+                msg_send![view, setAutoresizingMask: NSViewWidthSizable | NSViewHeightSizable];
+            let _: () = msg_send![view, setWantsLayer: YES];
+
+            let view_layer: id = msg_send![view, layer];
+            if view_layer != nil {
+                let cg_color: id = msg_send![color, CGColor];
+                let _: () = msg_send![view_layer, setBackgroundColor: cg_color];
+                let _: () =
+                    msg_send![view_layer, setOpacity: tint_layer.opacity.clamp(0.0, 1.0) as f32];
+                let _: () = msg_send![view_layer, setCornerRadius: radius];
+                let _: () = msg_send![view_layer, setMasksToBounds: YES];
+                set_blend_mode(view_layer, tint_layer.blend_mode);
+            }
+
+            let _: () = msg_send![glass, addSubview: view];
+            Some(ViewHandle::owned(view))
+        })
+        .collect()
+}
+
+/// Set the curve of a layer's rounded corners
+///
+/// `cornerCurve` needs macOS 10.15; older systems keep circular corners.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `layer` must be a valid CALayer
+pub unsafe fn apply_corner_curve(layer: id, curve: CornerCurve) {
+    let responds: BOOL = msg_send![layer, respondsToSelector: sel!(setCornerCurve:)];
+    if responds == NO {
+        return;
+    }
+    // Values of kCACornerCurveCircular and kCACornerCurveContinuous
+    let name = match curve {
+        CornerCurve::Circular => c"circular",
+        CornerCurve::Continuous => c"continuous",
+    };
+    let name: id = msg_send![class!(NSString), stringWithUTF8String: name.as_ptr()];
+    let _: () = msg_send![layer, setCornerCurve: name];
+}
+
+/// Set or clear the border drawn along the glass edge
+///
+/// The border is composited above the layer's sublayers, so it stays visible
+/// over tint layers and follows the corner radius.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `layer` must be a valid CALayer
+pub unsafe fn apply_rim(layer: id, rim: Option<&GlassRim>) {
+    match rim.and_then(|rim| Some((color_from_css(&rim.color)?, rim.width))) {
+        Some((color, width)) => {
+            let cg_color: id = msg_send![color, CGColor];
+            let _: () = msg_send![layer, setBorderColor: cg_color];
+            let _: () = msg_send![layer, setBorderWidth: width.max(0.0)];
+        }
+        None => {
+            let _: () = msg_send![layer, setBorderWidth: 0.0f64];
+        }
+    }
+}
+
+/// NSWindowOrderingMode
+const NS_WINDOW_BELOW: i64 = -1;
+
+/// Add, update or remove the opaque base view behind a glass view
+///
+/// The base is a sibling placed directly below the glass, filled with
+/// `config.opaque_color` or, if unset, the window's background color at full
+/// opacity. It keeps content legible over bright desktops.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView already inserted into its superview
+///
+/// Returns the base view handle if `config.opaque` is set
+pub unsafe fn apply_opaque_base(
+    glass: id,
+    config: &LiquidGlassConfig,
+    existing: Option<ViewHandle>,
+) -> Option<ViewHandle> {
+    let superview: id = msg_send![glass, superview];
+    if !config.opaque || superview == nil {
+        if let Some(view) = existing {
+            let _: () = msg_send![view.as_id(), removeFromSuperview];
+            view.retire();
+        }
+        return None;
+    }
+
+    let frame: NSRect = msg_send![glass, frame];
+    let handle = match existing {
+        Some(view) => view,
+        None => {
+            let view: id = msg_send![class!(NSView), alloc];
+            let view: id = msg_send![view, initWithFrame: frame];
+            let _: () = msg_send![view, setWantsLayer: YES];
+            let _: () = msg_send![superview, addSubview: view positioned: NS_WINDOW_BELOW relativeTo: glass];
+            ViewHandle::owned(view)
+        }
+    };
+    let base = handle.as_id();
+
+    let mask: u64 = msg_send![glass, autoresizingMask];
+    let hidden: BOOL = msg_send![glass, isHidden];
+    let _: () = msg_send![base, setFrame: frame];
+    let _: () = msg_send![base, setAutoresizingMask: mask];
+    let _: () = msg_send![base, setHidden: hidden];
+
+    let base_layer: id = msg_send![base, layer];
+    if base_layer != nil {
+        let color = base_color(glass, config.opaque_color.as_deref());
+        let cg_color: id = msg_send![color, CGColor];
+        let _: () = msg_send![base_layer, setBackgroundColor: cg_color];
+        let _: () = msg_send![base_layer, setCornerRadius: config.layer_corner_radius()];
+        apply_corner_curve(base_layer, config.corner_curve);
+        let _: () = msg_send![base_layer, setMasksToBounds: YES];
+    }
+
+    Some(handle)
+}
+
+/// Color for the opaque base: the provided CSS color, else the window's
+/// background color, else the system window background color
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView
+unsafe fn base_color(glass: id, css: Option<&str>) -> id {
+    if let Some(color) = css.and_then(color_from_css) {
+        return color;
+    }
+
+    let window: id = msg_send![glass, window];
+    if window != nil {
+        let background: id = msg_send![window, backgroundColor];
+        if background != nil {
+            let alpha: f64 = msg_send![background, alphaComponent];
+            if alpha > 0.0 {
+                return msg_send![background, colorWithAlphaComponent: 1.0f64];
+            }
+        }
+    }
+
+    msg_send![class!(NSColor), windowBackgroundColor]
+}
+
+/// Set the layer's compositing filter to the Core Image blend filter for `mode`
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `layer` must be a valid CALayer
+unsafe fn set_blend_mode(layer: id, mode: BlendMode) {
+    let Some(name) = blend_filter_name(mode) else {
+        return;
+    };
+    let Some(filter_class) = Class::get("CIFilter") else {
+        return;
+    };
+
+    let name: id = msg_send![class!(NSString), stringWithUTF8String: name.as_ptr()];
+    let filter: id = msg_send![filter_class, filterWithName: name];
+    if filter != nil {
+        let _: () = msg_send![layer, setCompositingFilter: filter];
+    }
+}
+
+/// Core Image filter name for a blend mode, `None` for normal compositing
+fn blend_filter_name(mode: BlendMode) -> Option<&'static std::ffi::CStr> {
+    let name = match mode {
+        BlendMode::Normal => return None,
+        BlendMode::Multiply => c"CIMultiplyBlendMode",
+        BlendMode::Screen => c"CIScreenBlendMode",
+        BlendMode::Overlay => c"CIOverlayBlendMode",
+        BlendMode::SoftLight => c"CISoftLightBlendMode",
+        BlendMode::HardLight => c"CIHardLightBlendMode",
+        BlendMode::ColorDodge => c"CIColorDodgeBlendMode",
+        BlendMode::ColorBurn => c"CIColorBurnBlendMode",
+        BlendMode::Darken => c"CIDarkenBlendMode",
+        BlendMode::Lighten => c"CILightenBlendMode",
+        BlendMode::Difference => c"CIDifferenceBlendMode",
+        BlendMode::Exclusion => c"CIExclusionBlendMode",
+        BlendMode::Hue => c"CIHueBlendMode",
+        BlendMode::Saturation => c"CISaturationBlendMode",
+        BlendMode::Color => c"CIColorBlendMode",
+        BlendMode::Luminosity => c"CILuminosityBlendMode",
+    };
+    Some(name)
+}
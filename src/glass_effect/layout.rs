@@ -0,0 +1,238 @@
+//! Named glass regions: declarative layouts diffed against the current state,
+//! and single regions addressed by id
+
+use cocoa::base::YES;
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::animation::animated;
+use super::operations::{
+    create_region_view, layout_glass_view, remove_glass_view, restack_glass_view, restack_regions,
+    update_glass_config,
+};
+use super::registry::{GlassViewEntry, GlassViewRegistry, Regions};
+use super::utils::run_on_main_sync;
+use super::window::ns_window_handle;
+use crate::error::Result;
+use crate::models::{GlassFrame, GlassLayout, LayoutDiff, LiquidGlassConfig};
+
+/// Apply a layout to a window, creating, updating and removing regions
+///
+/// Regions whose resolved config is unchanged are left untouched, apart from
+/// being restacked in layout order, the first bottom-most. The layout must
+/// already be validated.
+pub fn apply_glass_layout<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    layout: GlassLayout,
+) -> Result<LayoutDiff> {
+    let registry = app.state::<GlassViewRegistry>();
+    let label = window.label().to_string();
+    let ns_window = ns_window_handle(window)?;
+
+    let mut current = registry.regions(&label)?;
+    let mut diff = LayoutDiff::default();
+
+    // Remove regions that are no longer part of the layout
+    let (kept, removed): (Regions, Regions) = current
+        .drain(..)
+        .partition(|(id, _)| layout.regions.iter().any(|region| &region.id == id));
+    diff.removed = removed.iter().map(|(id, _)| id.clone()).collect();
+
+    let mut next = Regions::new();
+    let mut to_create = Vec::new();
+    let mut to_update = Vec::new();
+
+    for (position, region) in layout.regions.iter().enumerate() {
+        let config = region.resolved_config();
+        match kept.iter().find(|(id, _)| id == &region.id) {
+            Some((id, entry)) if entry.config == config => {
+                diff.unchanged.push(id.clone());
+                next.push((id.clone(), entry.clone()));
+            }
+            Some((id, entry)) => {
+                diff.updated.push(id.clone());
+                to_update.push((next.len(), entry.clone(), config));
+                next.push((id.clone(), entry.clone()));
+            }
+            None => {
+                diff.added.push(region.id.clone());
+                to_create.push((position, region.id.clone(), config));
+            }
+        }
+    }
+
+    let (next, error) = run_on_main_sync(move || unsafe {
+        for (_, entry) in &removed {
+            remove_glass_view(entry);
+        }
+
+        for (index, entry, config) in to_update {
+            restack_glass_view(&entry, config.placement, true);
+            let overlays = animated(config.animation.as_ref(), || {
+                update_glass_config(entry.glass_view, &entry.config, &config, entry.overlays)
+            });
+            next[index].1 = GlassViewEntry {
+                overlays,
+                config,
+                ..entry
+            };
+        }
+
+        // Insert in ascending layout position so each index is valid; new
+        // views start above all existing regions
+        for (index, id, config) in to_create {
+            match create_region_view(ns_window, config) {
+                Ok(entry) => next.insert(index, (id, entry)),
+                Err(e) => return (next, Some(e)),
+            }
+        }
+
+        // Then put every region in layout order, including reordered ones
+        restack_regions(next.iter().map(|(_, entry)| entry));
+
+        (next, None)
+    });
+
+    // Record whatever was created, even if a later region failed
+    registry.set_regions(&label, next)?;
+
+    match error {
+        Some(e) => Err(e),
+        None => Ok(diff),
+    }
+}
+
+/// Create, update or (with `None`) remove a single named region
+///
+/// New regions are stacked above the window's existing ones. Returns whether
+/// a region with this id existed before.
+pub fn set_glass_region<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    id: &str,
+    config: Option<LiquidGlassConfig>,
+) -> Result<bool> {
+    let registry = app.state::<GlassViewRegistry>();
+    let label = window.label().to_string();
+
+    let mut regions = registry.regions(&label)?;
+    let position = regions.iter().position(|(region_id, _)| region_id == id);
+
+    match (position, config) {
+        (None, None) => Ok(false),
+        (Some(index), None) => {
+            let (_, entry) = regions.remove(index);
+            run_on_main_sync(move || unsafe { remove_glass_view(&entry) });
+            registry.set_regions(&label, regions)?;
+            Ok(true)
+        }
+        (Some(index), Some(config)) => {
+            let entry = regions[index].1.clone();
+            if entry.config != config {
+                let (overlays, config) = run_on_main_sync(move || unsafe {
+                    restack_glass_view(&entry, config.placement, true);
+                    let overlays = animated(config.animation.as_ref(), || {
+                        update_glass_config(
+                            entry.glass_view,
+                            &entry.config,
+                            &config,
+                            entry.overlays,
+                        )
+                    });
+                    (overlays, config)
+                });
+                registry.update_region(&label, id, overlays, config)?;
+            }
+            Ok(true)
+        }
+        (None, Some(config)) => {
+            let ns_window = ns_window_handle(window)?;
+            let entry = run_on_main_sync(move || unsafe { create_region_view(ns_window, config) })?;
+            regions.push((id.to_string(), entry));
+            registry.set_regions(&label, regions)?;
+            Ok(false)
+        }
+    }
+}
+
+/// Move a named region to `frame` without re-applying the rest of its config
+///
+/// Returns whether a region with this id exists.
+pub fn set_glass_region_frame<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    id: &str,
+    frame: GlassFrame,
+) -> Result<bool> {
+    let registry = app.state::<GlassViewRegistry>();
+    let label = window.label();
+
+    let regions = registry.regions(label)?;
+    let Some((_, entry)) = regions.into_iter().find(|(region_id, _)| region_id == id) else {
+        return Ok(false);
+    };
+    if entry.config.frame.as_ref() == Some(&frame) {
+        return Ok(true);
+    }
+
+    let config = LiquidGlassConfig {
+        frame: Some(frame),
+        ..entry.config
+    };
+    let (overlays, config) = run_on_main_sync(move || unsafe {
+        layout_glass_view(entry.glass_view, &config, &entry.overlays);
+        (entry.overlays, config)
+    });
+    registry.update_region(label, id, overlays, config)?;
+    Ok(true)
+}
+
+/// Move several named glass views at once, for streamed rects
+///
+/// `frames` holds `(window label, view id, frame)` triples. The views are
+/// laid out in one main-thread pass inside a CATransaction with implicit
+/// animations disabled, so everything streamed within a frame moves
+/// together. Views that no longer exist are skipped. Returns the number of
+/// views moved.
+pub fn set_glass_region_frames<R: Runtime>(
+    app: &AppHandle<R>,
+    frames: Vec<(String, String, GlassFrame)>,
+) -> Result<usize> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut moves = Vec::with_capacity(frames.len());
+    for (label, id, frame) in frames {
+        let regions = registry.regions(&label)?;
+        let Some((_, entry)) = regions.into_iter().find(|(region_id, _)| *region_id == id) else {
+            continue;
+        };
+        if entry.config.frame.as_ref() == Some(&frame) {
+            continue;
+        }
+        let config = LiquidGlassConfig {
+            frame: Some(frame),
+            ..entry.config
+        };
+        moves.push((label, id, entry.glass_view, entry.overlays, config));
+    }
+    if moves.is_empty() {
+        return Ok(0);
+    }
+
+    let moves = run_on_main_sync(move || unsafe {
+        let _: () = msg_send![class!(CATransaction), begin];
+        let _: () = msg_send![class!(CATransaction), setDisableActions: YES];
+        for (_, _, glass_view, overlays, config) in &moves {
+            layout_glass_view(*glass_view, config, overlays);
+        }
+        let _: () = msg_send![class!(CATransaction), commit];
+        moves
+    });
+
+    let moved = moves.len();
+    for (label, id, _, overlays, config) in moves {
+        registry.update_region(&label, &id, overlays, config)?;
+    }
+    Ok(moved)
+}
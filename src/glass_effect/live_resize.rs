@@ -0,0 +1,105 @@
+//! Cheaper region rendering during live window resizes
+//!
+//! With [`LiveResizeMode::Freeze`], region frames are left alone until the
+//! resize ends. With [`LiveResizeMode::Placeholder`], regions are rendered
+//! from the same flat config as suspension for the length of the drag. Either
+//! way the regions are re-applied when the window posts
+//! `NSWindowDidEndLiveResizeNotification`.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use cocoa::base::{id, nil, NO};
+use objc::runtime::BOOL;
+use objc::{msg_send, sel, sel_impl};
+
+use super::operations::relayout_regions;
+use super::registry::{GlassViewRegistry, ViewHandle};
+use crate::error::Result;
+use crate::models::LiveResizeMode;
+
+/// Mode applied to live resizes, as a `LiveResizeMode` discriminant
+static MODE: AtomicU8 = AtomicU8::new(LiveResizeMode::Full as u8);
+
+/// Region views rendered as placeholders, with the window being resized
+static PLACEHOLDERS: Mutex<Vec<(usize, ViewHandle)>> = Mutex::new(Vec::new());
+
+fn placeholders() -> MutexGuard<'static, Vec<(usize, ViewHandle)>> {
+    PLACEHOLDERS.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Set how region views behave during live resizes
+pub fn set_live_resize_mode(mode: LiveResizeMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+fn mode() -> LiveResizeMode {
+    match MODE.load(Ordering::Relaxed) {
+        m if m == LiveResizeMode::Freeze as u8 => LiveResizeMode::Freeze,
+        m if m == LiveResizeMode::Placeholder as u8 => LiveResizeMode::Placeholder,
+        _ => LiveResizeMode::Full,
+    }
+}
+
+/// Whether `glass` is a region currently rendered as a placeholder
+pub fn is_placeholder(glass: ViewHandle) -> bool {
+    placeholders().iter().any(|(_, view)| *view == glass)
+}
+
+/// Whether a region's frame should be left alone on this resize step
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must point to a valid view
+pub unsafe fn is_frozen(glass: ViewHandle) -> bool {
+    if mode() != LiveResizeMode::Freeze {
+        return false;
+    }
+    let window: id = msg_send![glass.as_id(), window];
+    if window == nil {
+        return false;
+    }
+    let resizing: BOOL = msg_send![window, inLiveResize];
+    resizing != NO
+}
+
+/// Swap the window's regions for placeholders when a live resize starts
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be a valid NSWindow
+pub unsafe fn begin(registry: &GlassViewRegistry, ns_window: id) -> Result<()> {
+    if mode() != LiveResizeMode::Placeholder {
+        return Ok(());
+    }
+
+    let regions = registry
+        .region_entries()?
+        .into_iter()
+        .filter_map(|(_, _, entry)| {
+            let host: id = msg_send![entry.glass_view.as_id(), window];
+            (host == ns_window).then_some((ns_window as usize, entry.glass_view))
+        });
+    placeholders().extend(regions);
+
+    relayout_regions(registry, Some(ns_window))
+}
+
+/// Restore the window's regions to full fidelity when a live resize ends
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be a valid NSWindow
+pub unsafe fn end(registry: &GlassViewRegistry, ns_window: id) -> Result<()> {
+    let had_placeholders = {
+        let mut placeholders = placeholders();
+        let before = placeholders.len();
+        placeholders.retain(|(window, _)| *window != ns_window as usize);
+        placeholders.len() != before
+    };
+
+    if had_placeholders || mode() == LiveResizeMode::Freeze {
+        relayout_regions(registry, Some(ns_window))?;
+    }
+    Ok(())
+}
@@ -4,18 +4,71 @@
 //! On macOS 26 (Tahoe) and later, it uses the private NSGlassEffectView API.
 //! On older macOS versions, it falls back to NSVisualEffectView.
 
+mod animation;
+mod appearance;
 mod backend;
+mod contrast;
+mod doctor;
+mod external;
+mod focus;
+mod frame;
+mod group;
+mod health;
+#[cfg(feature = "devtools")]
+mod hierarchy;
+mod key_state;
+mod layers;
+mod layout;
+mod live_resize;
+mod observer;
 mod operations;
+mod path;
 mod registry;
+mod safe_area;
+mod scroll_edge;
+mod shadow;
+mod shape;
+mod suspend;
+mod system_colors;
 mod utils;
+mod window;
 
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use log::warn;
+use objc::runtime::Class;
 use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
 
-use crate::error::Result;
-use crate::models::LiquidGlassConfig;
+use crate::error::{Error, Result};
+use crate::models::{
+    ExperimentalProperty, ForegroundRecommendation, GlassBackendKind, GlassCapabilities,
+    GlassViewFrame, GlassViewInfo, LiquidGlassConfig, ObservedProperty, SupportDetails,
+    SystemColors,
+};
 
 // Re-export public types
+pub use appearance::observe_appearance;
+pub use backend::set_safe_mode;
+pub use doctor::diagnose;
+pub use group::{create_glass_group, remove_glass_group};
+pub use health::{forget_window, validate_glass_views};
+#[cfg(feature = "devtools")]
+pub use hierarchy::dump_view_hierarchy;
+pub use layout::{
+    apply_glass_layout, set_glass_region, set_glass_region_frame, set_glass_region_frames,
+};
+pub use live_resize::set_live_resize_mode;
 pub use registry::GlassViewRegistry;
+pub use scroll_edge::{report_scroll_offset, set_scroll_edge_effect, ScrollEdgeRegistry};
+pub use suspend::{is_suspended, set_suspended};
+pub use utils::{glass_class_available, macos_version, probe_private_api};
+pub use window::{
+    animate_window, begin_sheet, content_safe_area_insets, end_sheet, make_window_transparent,
+    order_front_without_focus, primary_mouse_button_down, round_window,
+    set_webview_draws_background, set_webview_transparent, set_window_alpha, set_window_background,
+    set_window_frame, set_window_level, set_window_transparent,
+};
 
 // ============================================================================
 // Public API
@@ -23,13 +76,63 @@ pub use registry::GlassViewRegistry;
 
 /// Check if liquid glass (NSGlassEffectView) is supported
 pub fn is_glass_supported() -> bool {
-    utils::run_on_main_sync(utils::glass_class_available)
+    utils::glass_class_available()
+}
+
+/// Get detailed support information, including which variants and
+/// experimental properties are honored
+///
+/// The probe runs once on the main thread and is cached for the
+/// lifetime of the process since the OS build cannot change while running.
+pub fn support_details() -> SupportDetails {
+    static DETAILS: OnceLock<SupportDetails> = OnceLock::new();
+
+    DETAILS
+        .get_or_init(|| {
+            utils::run_on_main_sync(|| SupportDetails {
+                supported: utils::glass_class_available(),
+                supported_variants: unsafe { backend::probe_supported_variants() },
+                experimental_properties: unsafe { backend::probe_experimental_properties() },
+            })
+        })
+        .clone()
+}
+
+/// Report which glass features are usable
+///
+/// Selector probes come from [`support_details`] and are cached; the backend
+/// reflects safe mode at call time. Views whose NSGlassEffectView fails to
+/// initialize still fall back, which can't be known in advance.
+pub fn capabilities() -> GlassCapabilities {
+    let details = support_details();
+    let has = |property| details.experimental_properties.contains(&property);
+    let backend = if details.supported && !backend::is_safe_mode() {
+        GlassBackendKind::Native
+    } else {
+        GlassBackendKind::VisualEffect
+    };
+
+    GlassCapabilities {
+        backend: Some(backend),
+        native_glass: details.supported,
+        tint_color: backend::probe_tint_color(),
+        glass_container: Class::get("NSGlassEffectContainerView").is_some(),
+        scrim: has(ExperimentalProperty::Scrim),
+        subdued: has(ExperimentalProperty::Subdued),
+        interactive: has(ExperimentalProperty::Interactive),
+        supported_variants: details.supported_variants,
+        macos_version: Some(macos_version()),
+    }
 }
 
 /// Set liquid glass effect on a window
 ///
 /// - If `config.enabled` is true: creates or updates the glass effect
 /// - If `config.enabled` is false: removes the glass effect if present
+///
+/// When the window's glass is or will be an NSGlassEffectView without a
+/// setter for a property the config asks for, the call fails with
+/// [`Error::UnsupportedProperty`] before anything is changed.
 pub fn set_liquid_glass_effect<R: Runtime>(
     app: &AppHandle<R>,
     window: &WebviewWindow<R>,
@@ -39,9 +142,8 @@ pub fn set_liquid_glass_effect<R: Runtime>(
     let window_label = window.label().to_string();
 
     if config.enabled {
-        let existing = registry.contains(&window_label)?;
-
-        if existing {
+        ensure_applicable(&registry, &window_label, &config)?;
+        if registry.contains(&window_label)? {
             operations::update_glass_effect(app, window, &config)
         } else {
             operations::create_glass_effect(app, window, &config)
@@ -50,3 +152,500 @@ pub fn set_liquid_glass_effect<R: Runtime>(
         operations::remove_glass_effect(app, &window_label)
     }
 }
+
+/// Set liquid glass effects on several windows in one main-thread dispatch
+///
+/// Each window is handled as by [`set_liquid_glass_effect`]; returns one
+/// result per window, in order.
+pub fn set_liquid_glass_effects<R: Runtime>(
+    app: &AppHandle<R>,
+    batch: Vec<(WebviewWindow<R>, LiquidGlassConfig)>,
+) -> Vec<Result<()>> {
+    let registry = app.state::<GlassViewRegistry>();
+    let mut results = Vec::with_capacity(batch.len());
+    let mut applicable = Vec::with_capacity(batch.len());
+    for (window, config) in batch {
+        let check = if config.enabled {
+            ensure_applicable(&registry, window.label(), &config)
+        } else {
+            Ok(())
+        };
+        if check.is_ok() {
+            applicable.push((window, config));
+        }
+        results.push(check);
+    }
+
+    // Windows that failed the check are left out of the dispatch
+    let mut applied = operations::set_glass_effects(app, applicable).into_iter();
+    for result in results.iter_mut().filter(|result| result.is_ok()) {
+        *result = applied.next().unwrap_or(Ok(()));
+    }
+    results
+}
+
+/// Fail with [`Error::UnsupportedProperty`] if the window's glass is, or
+/// would be created as, native glass that cannot take a property of `config`
+///
+/// The fallback ignores native-only properties by design.
+fn ensure_applicable(
+    registry: &GlassViewRegistry,
+    label: &str,
+    config: &LiquidGlassConfig,
+) -> Result<()> {
+    let native = match registry.get(label)? {
+        Some(entry) => entry.backend == GlassBackendKind::Native,
+        None => !backend::is_safe_mode() && glass_class_available(),
+    };
+    if native {
+        backend::ensure_applicable(config)
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the configuration currently applied to a window, if it has a glass effect
+pub fn current_config<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+) -> Result<Option<LiquidGlassConfig>> {
+    app.state::<GlassViewRegistry>().get_config(window.label())
+}
+
+/// Set an experimental property on a window's glass views, including regions
+///
+/// Fails with [`Error::UnsupportedCapability`] when the running OS does not
+/// implement the property, rather than silently doing nothing. The value is
+/// written into each view's stored config, so later updates, re-applies and
+/// exports keep it. Returns `(region, config)` for every view that changed.
+pub fn set_experimental_property<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    property: ExperimentalProperty,
+    enabled: bool,
+) -> Result<Vec<(Option<String>, LiquidGlassConfig)>> {
+    if !support_details()
+        .experimental_properties
+        .contains(&property)
+    {
+        return Err(Error::UnsupportedCapability(property.as_str().to_string()));
+    }
+
+    let registry = app.state::<GlassViewRegistry>();
+    let mut views: Vec<_> = registry
+        .get(window.label())?
+        .into_iter()
+        .map(|entry| (None, entry.glass_view))
+        .collect();
+    views.extend(
+        registry
+            .regions(window.label())?
+            .into_iter()
+            .map(|(id, entry)| (Some(id), entry.glass_view)),
+    );
+
+    let native = views.clone();
+    utils::try_run_on_main_sync(move || unsafe {
+        native.into_iter().try_for_each(|(_, view)| {
+            backend::set_experimental_property(view.as_id(), property, enabled)
+        })
+    })?;
+
+    let mut stored = Vec::with_capacity(views.len());
+    for (region, view) in views {
+        let config = registry.modify_config(window.label(), region.as_deref(), view, |config| {
+            property.store(config, enabled);
+            config.clone()
+        })?;
+        stored.extend(config.map(|config| (region, config)));
+    }
+    Ok(stored)
+}
+
+/// Recommend a foreground color for each glass view of a window
+///
+/// Returns `(region, recommendation)` pairs, with `None` for the window's
+/// full-window glass, in the window's stacking order.
+pub fn recommended_foregrounds<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+) -> Result<Vec<(Option<String>, ForegroundRecommendation)>> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut entries: Vec<_> = registry
+        .get(window.label())?
+        .into_iter()
+        .map(|entry| (None, entry))
+        .collect();
+    entries.extend(
+        registry
+            .regions(window.label())?
+            .into_iter()
+            .map(|(id, entry)| (Some(id), entry)),
+    );
+
+    Ok(utils::run_on_main_sync(move || {
+        entries
+            .into_iter()
+            .map(|(region, entry)| {
+                let appearance = unsafe { utils::effective_appearance(entry.glass_view.as_id()) };
+                (
+                    region,
+                    contrast::recommend_foreground(&entry.config, appearance),
+                )
+            })
+            .collect()
+    }))
+}
+
+/// Read the on-screen frame of a window's glass view
+///
+/// `region` selects a named view; `None` selects the full-window glass.
+/// Returns `None` if there is no such view or it is not attached to a window.
+pub fn glass_frame<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    region: Option<&str>,
+) -> Result<Option<GlassViewFrame>> {
+    let registry = app.state::<GlassViewRegistry>();
+    let entry = match region {
+        None => registry.get(window.label())?,
+        Some(region) => registry
+            .regions(window.label())?
+            .into_iter()
+            .find(|(id, _)| id == region)
+            .map(|(_, entry)| entry),
+    };
+    let Some(entry) = entry else {
+        return Ok(None);
+    };
+
+    Ok(utils::run_on_main_sync(move || unsafe {
+        frame::measure_view(entry.glass_view.as_id())
+    }))
+}
+
+/// Invoke `on_change` whenever the frame of one of a window's glass views
+/// changes natively
+///
+/// The callback receives the view's region (`None` for the full-window
+/// glass), its previous frame if known, and the new frame. Views that are
+/// already observed are skipped, so call this again after creating views.
+pub fn observe_frames<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    on_change: impl Fn(Option<String>, Option<&GlassViewFrame>, &GlassViewFrame)
+        + Clone
+        + Send
+        + 'static,
+) -> Result<()> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut views: Vec<_> = registry
+        .get(window.label())?
+        .into_iter()
+        .map(|entry| (None, entry.glass_view))
+        .collect();
+    views.extend(
+        registry
+            .regions(window.label())?
+            .into_iter()
+            .map(|(id, entry)| (Some(id), entry.glass_view)),
+    );
+
+    utils::run_on_main_sync(move || unsafe {
+        for (region, view) in views {
+            let on_change = on_change.clone();
+            frame::observe_frame(view, move |previous, frame| {
+                on_change(region.clone(), previous, frame)
+            });
+        }
+    });
+
+    Ok(())
+}
+
+/// Invoke `on_change` when other code changes the tint, variant or corner
+/// radius of one of a window's native glass views
+///
+/// The stored config is updated to match the view before the callback runs,
+/// which receives the view's region (`None` for the full-window glass), the
+/// properties that changed and the updated config. NSVisualEffectView
+/// fallbacks are not observed. Views that are already observed are skipped.
+pub fn observe_external_changes<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    on_change: impl Fn(Option<String>, Vec<ObservedProperty>, LiquidGlassConfig)
+        + Clone
+        + Send
+        + 'static,
+) -> Result<()> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut views: Vec<_> = registry
+        .get(window.label())?
+        .into_iter()
+        .map(|entry| (None, entry))
+        .collect();
+    views.extend(
+        registry
+            .regions(window.label())?
+            .into_iter()
+            .map(|(id, entry)| (Some(id), entry)),
+    );
+    views.retain(|(_, entry)| entry.backend == GlassBackendKind::Native);
+
+    let app = app.clone();
+    let label = window.label().to_string();
+    utils::run_on_main_sync(move || unsafe {
+        for (region, entry) in views {
+            let (app, label, on_change) = (app.clone(), label.clone(), on_change.clone());
+            let view = entry.glass_view;
+            external::observe_external_changes(view, move || {
+                let registry = app.state::<GlassViewRegistry>();
+                // Read the view on a copy, so AppKit isn't called under the registry lock
+                let stored = registry
+                    .modify_config(&label, region.as_deref(), view, |config| config.clone());
+                let mut config = match stored {
+                    Ok(Some(config)) => config,
+                    Ok(None) => return,
+                    Err(e) => {
+                        warn!("Failed to record external glass change: {}", e);
+                        return;
+                    }
+                };
+                let changed = external::sync_config(view.as_id(), &mut config);
+                if changed.is_empty() {
+                    return;
+                }
+                let synced = config.clone();
+                match registry
+                    .modify_config(&label, region.as_deref(), view, |stored| *stored = synced)
+                {
+                    Ok(Some(())) => on_change(region.clone(), changed, config),
+                    Ok(None) => {}
+                    Err(e) => warn!("Failed to record external glass change: {}", e),
+                }
+            });
+        }
+    });
+
+    Ok(())
+}
+
+/// Crossfade a window's opted-in fallback views when it becomes or resigns key
+///
+/// Views with `key_state_transition_ms` set on the NSVisualEffectView
+/// backend switch between the active and inactive state. `on_change`
+/// receives the new key status and the longest transition in milliseconds,
+/// and only runs when at least one view transitioned. Observing a window
+/// twice is a no-op.
+pub fn observe_key_state<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    on_change: impl Fn(bool, u64) + Clone + Send + 'static,
+) -> Result<()> {
+    let app = app.clone();
+    let label = window.label().to_string();
+
+    key_state::observe_key_status(window, move |key| {
+        let registry = app.state::<GlassViewRegistry>();
+        let mut entries: Vec<_> = registry.get(&label).ok().flatten().into_iter().collect();
+        entries.extend(
+            registry
+                .regions(&label)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(_, entry)| entry),
+        );
+
+        let mut longest = None;
+        for entry in entries {
+            if entry.backend != GlassBackendKind::VisualEffect {
+                continue;
+            }
+            let Some(duration_ms) = entry
+                .config
+                .key_state_transition_ms
+                .filter(|_| entry.config.fallback_state.is_none())
+            else {
+                continue;
+            };
+            unsafe {
+                key_state::transition_key_state(
+                    entry.glass_view.as_id(),
+                    key,
+                    Duration::from_millis(duration_ms),
+                );
+            }
+            longest = longest.max(Some(duration_ms));
+        }
+
+        if let Some(duration_ms) = longest {
+            on_change(key, duration_ms);
+        }
+    })
+}
+
+/// Resolve the user's accent, highlight and focus ring colors
+pub fn system_colors() -> SystemColors {
+    utils::run_on_main_sync(|| unsafe { system_colors::system_colors() })
+}
+
+/// Invoke `on_change` with the resolved colors whenever the user changes
+/// their accent or highlight color
+pub fn observe_system_colors(on_change: impl Fn(&SystemColors) + Send + 'static) {
+    utils::run_on_main_sync(move || unsafe { system_colors::observe_system_colors(on_change) });
+}
+
+/// Get the applied config of every window with full-window glass
+pub fn list_effects<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<(String, LiquidGlassConfig)>> {
+    app.state::<GlassViewRegistry>().configs()
+}
+
+/// Get the labels of windows with any glass view
+pub fn glass_windows<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<String>> {
+    app.state::<GlassViewRegistry>().labels()
+}
+
+/// Describe every tracked glass view: full-window views first, then regions
+pub fn list_glass_views<R: Runtime>(app: &AppHandle<R>) -> Result<Vec<GlassViewInfo>> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let windows = registry
+        .entries()?
+        .into_iter()
+        .map(|(window, entry)| GlassViewInfo {
+            window,
+            region: None,
+            backend: entry.backend,
+            fallback_reason: entry.fallback,
+            config: entry.config,
+        });
+    let regions = registry
+        .region_entries()?
+        .into_iter()
+        .map(|(window, region, entry)| GlassViewInfo {
+            window,
+            region: Some(region),
+            backend: entry.backend,
+            fallback_reason: entry.fallback,
+            config: entry.config,
+        });
+
+    Ok(windows.chain(regions).collect())
+}
+
+/// Re-lay out glass views when displays or window sizes change
+///
+/// Observes `NSApplicationDidChangeScreenParametersNotification` (displays
+/// added, removed or reconfigured), `NSWindowDidChangeScreenNotification`
+/// (a window moved to another screen),
+/// `NSWindowDidChangeBackingPropertiesNotification` (a window's backing scale
+/// factor changed, e.g. when dragged from a Retina to a 1x display) and
+/// `NSWindowDidResizeNotification` (glass frames are re-resolved). Live
+/// resize start and end notifications switch regions into and out of the
+/// [`LiveResizeMode`](crate::LiveResizeMode).
+pub fn install_display_observers<R: Runtime>(app: &AppHandle<R>) {
+    let screens_app = app.clone();
+    let window_app = app.clone();
+    let backing_app = app.clone();
+    let resize_app = app.clone();
+    let start_app = app.clone();
+    let end_app = app.clone();
+
+    utils::run_on_main_sync(move || unsafe {
+        observer::observe_notification(
+            c"NSApplicationDidChangeScreenParametersNotification",
+            cocoa::base::nil,
+            move |_| relayout(&screens_app, None),
+        );
+        observer::observe_notification(
+            c"NSWindowDidChangeScreenNotification",
+            cocoa::base::nil,
+            move |notification| {
+                relayout(
+                    &window_app,
+                    Some(observer::notification_object(notification)),
+                )
+            },
+        );
+        observer::observe_notification(
+            c"NSWindowDidChangeBackingPropertiesNotification",
+            cocoa::base::nil,
+            move |notification| {
+                relayout(
+                    &backing_app,
+                    Some(observer::notification_object(notification)),
+                )
+            },
+        );
+        observer::observe_notification(
+            c"NSWindowDidResizeNotification",
+            cocoa::base::nil,
+            move |notification| {
+                let Some(registry) = resize_app.try_state::<GlassViewRegistry>() else {
+                    return;
+                };
+                let ns_window = observer::notification_object(notification);
+                if let Err(e) = operations::layout_glass_views(&registry, Some(ns_window)) {
+                    warn!("Failed to lay out glass views after resize: {}", e);
+                }
+            },
+        );
+        observer::observe_notification(
+            c"NSWindowWillStartLiveResizeNotification",
+            cocoa::base::nil,
+            move |notification| {
+                let Some(registry) = start_app.try_state::<GlassViewRegistry>() else {
+                    return;
+                };
+                let ns_window = observer::notification_object(notification);
+                if let Err(e) = live_resize::begin(&registry, ns_window) {
+                    warn!("Failed to prepare glass views for live resize: {}", e);
+                }
+            },
+        );
+        observer::observe_notification(
+            c"NSWindowDidEndLiveResizeNotification",
+            cocoa::base::nil,
+            move |notification| {
+                let Some(registry) = end_app.try_state::<GlassViewRegistry>() else {
+                    return;
+                };
+                let ns_window = observer::notification_object(notification);
+                if let Err(e) = live_resize::end(&registry, ns_window) {
+                    warn!("Failed to restore glass views after live resize: {}", e);
+                }
+            },
+        );
+    });
+}
+
+/// Invoke `on_change` whenever displays are added, removed or rearranged
+#[cfg(feature = "tray-icon")]
+pub fn observe_screen_changes(on_change: impl Fn() + Send + 'static) {
+    utils::run_on_main_sync(move || unsafe {
+        observer::observe_notification(
+            c"NSApplicationDidChangeScreenParametersNotification",
+            cocoa::base::nil,
+            move |_| on_change(),
+        );
+    });
+}
+
+/// Re-lay out glass views, logging any failure
+///
+/// # Safety
+/// Must be called on the main thread
+unsafe fn relayout<R: Runtime>(app: &AppHandle<R>, ns_window: Option<cocoa::base::id>) {
+    let Some(registry) = app.try_state::<GlassViewRegistry>() else {
+        return;
+    };
+    if let Err(e) = operations::relayout_glass_views(&registry, ns_window) {
+        warn!(
+            "Failed to re-lay out glass views after display change: {}",
+            e
+        );
+    }
+}
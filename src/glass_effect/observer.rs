@@ -0,0 +1,194 @@
+//! NSNotificationCenter and key-value observers backed by Rust closures
+
+use std::ffi::{c_void, CStr};
+use std::sync::OnceLock;
+
+use cocoa::base::{id, nil};
+use objc::runtime::{Class, Object, Sel};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::utils::declare_class;
+
+/// Callback invoked with the posted NSNotification
+type Callback = Box<dyn Fn(id)>;
+
+/// Ivar holding the leaked `Box<Callback>` address
+const CALLBACK_IVAR: &str = "rustCallback";
+
+/// NSKeyValueObservingOptionNew
+const NS_KEY_VALUE_OBSERVING_OPTION_NEW: u64 = 0x01;
+
+/// Get (or declare) the Objective-C class used as notification target
+fn observer_class() -> &'static Class {
+    static CLASS: OnceLock<usize> = OnceLock::new();
+
+    let class = *CLASS.get_or_init(|| {
+        let mut decl = match declare_class("LiquidGlassNotificationObserver", class!(NSObject)) {
+            Ok(decl) => decl,
+            Err(existing) => return existing as *const Class as usize,
+        };
+        decl.add_ivar::<usize>(CALLBACK_IVAR);
+
+        extern "C" fn handle_notification(this: &Object, _sel: Sel, notification: id) {
+            unsafe {
+                let callback = *this.get_ivar::<usize>(CALLBACK_IVAR) as *const Callback;
+                if let Some(callback) = callback.as_ref() {
+                    callback(notification);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(handleNotification:),
+                handle_notification as extern "C" fn(&Object, Sel, id),
+            );
+        }
+
+        decl.register() as *const Class as usize
+    });
+
+    unsafe { &*(class as *const Class) }
+}
+
+/// Observe a notification, invoking `callback` for each post
+///
+/// The observer stays registered for the lifetime of the process. `object`
+/// restricts the observer to notifications posted by that object; pass `nil`
+/// to observe the notification from any sender.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` must be nil or a valid Objective-C object
+pub unsafe fn observe_notification(name: &CStr, object: id, callback: impl Fn(id) + 'static) {
+    // Intentionally leaked, which keeps the registration alive
+    let _ = notification_observer(name, object, callback);
+}
+
+/// Observe a notification through an observer owned by the caller
+///
+/// Returns the observer with a +1 retain count. The notification center does
+/// not retain it, so the registration ends once the observer is deallocated.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` must be nil or a valid Objective-C object
+pub unsafe fn notification_observer(
+    name: &CStr,
+    object: id,
+    callback: impl Fn(id) + 'static,
+) -> id {
+    let callback: Box<Callback> = Box::new(Box::new(callback));
+
+    let observer: id = msg_send![observer_class(), new];
+    (*observer).set_ivar::<usize>(CALLBACK_IVAR, Box::into_raw(callback) as usize);
+
+    let name: id = msg_send![class!(NSString), stringWithUTF8String: name.as_ptr()];
+    let center: id = msg_send![class!(NSNotificationCenter), defaultCenter];
+    let _: () = msg_send![
+        center,
+        addObserver: observer
+        selector: sel!(handleNotification:)
+        name: name
+        object: object
+    ];
+    observer
+}
+
+/// Object that posted a notification
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `notification` must be a valid NSNotification
+pub unsafe fn notification_object(notification: id) -> id {
+    if notification == nil {
+        return nil;
+    }
+    msg_send![notification, object]
+}
+
+/// Get (or declare) the Objective-C class used as key-value observer
+fn key_value_observer_class() -> &'static Class {
+    static CLASS: OnceLock<usize> = OnceLock::new();
+
+    let class = *CLASS.get_or_init(|| {
+        let mut decl = match declare_class("LiquidGlassKeyValueObserver", class!(NSObject)) {
+            Ok(decl) => decl,
+            Err(existing) => return existing as *const Class as usize,
+        };
+        decl.add_ivar::<usize>(CALLBACK_IVAR);
+
+        extern "C" fn observe_value(
+            this: &Object,
+            _sel: Sel,
+            _key_path: id,
+            object: id,
+            _change: id,
+            _context: *mut c_void,
+        ) {
+            unsafe {
+                let callback = *this.get_ivar::<usize>(CALLBACK_IVAR) as *const Callback;
+                if let Some(callback) = callback.as_ref() {
+                    callback(object);
+                }
+            }
+        }
+
+        unsafe {
+            decl.add_method(
+                sel!(observeValueForKeyPath:ofObject:change:context:),
+                observe_value as extern "C" fn(&Object, Sel, id, id, id, *mut c_void),
+            );
+        }
+
+        decl.register() as *const Class as usize
+    });
+
+    unsafe { &*(class as *const Class) }
+}
+
+/// Observe a key path of `object`, invoking `callback` with the object on
+/// every change
+///
+/// Returns the observer with a +1 retain count, owned by the caller. AppKit
+/// drops the registration when `object` is deallocated.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` must be a valid, KVO-compliant Objective-C object
+pub unsafe fn observe_key_path(object: id, key_path: &CStr, callback: impl Fn(id) + 'static) -> id {
+    let callback: Box<Callback> = Box::new(Box::new(callback));
+
+    let observer: id = msg_send![key_value_observer_class(), new];
+    (*observer).set_ivar::<usize>(CALLBACK_IVAR, Box::into_raw(callback) as usize);
+
+    add_key_path(observer, object, key_path);
+    observer
+}
+
+/// Register an existing key-value observer for another key path
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `observer` must come from [`observe_key_path`]
+/// - `object` must be a valid, KVO-compliant Objective-C object
+pub unsafe fn add_key_path(observer: id, object: id, key_path: &CStr) {
+    let key_path: id = msg_send![class!(NSString), stringWithUTF8String: key_path.as_ptr()];
+    let _: () = msg_send![
+        object,
+        addObserver: observer
+        forKeyPath: key_path
+        options: NS_KEY_VALUE_OBSERVING_OPTION_NEW
+        context: std::ptr::null_mut::<c_void>()
+    ];
+}
+
+/// Unregister a key-value observer from a key path
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `observer` must be registered for `key_path` on `object`
+pub unsafe fn remove_key_path(observer: id, object: id, key_path: &CStr) {
+    let key_path: id = msg_send![class!(NSString), stringWithUTF8String: key_path.as_ptr()];
+    let _: () = msg_send![object, removeObserver: observer forKeyPath: key_path];
+}
@@ -1,18 +1,30 @@
 //! Glass effect operations - create, update, remove
 
+use cocoa::appkit::{NSViewHeightSizable, NSViewWidthSizable};
 use cocoa::base::{id, nil, NO, YES};
-use cocoa::foundation::NSRect;
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
 use log::warn;
 use objc::runtime::{Class, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
 use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
 
-use super::backend::get_backend;
-use super::registry::{GlassViewRegistry, ViewHandle};
-use super::utils::{color_from_hex, run_on_main_sync};
+use super::animation::animated;
+use super::backend::{self, backend_of};
+use super::external::ApplyingGuard;
+use super::focus::{apply_click_through, apply_focus_behavior};
+use super::key_state::apply_key_state;
+use super::layers::{apply_corner_curve, apply_opaque_base, apply_rim, apply_tint_layers};
+use super::live_resize::{is_frozen, is_placeholder};
+use super::registry::{GlassViewEntry, GlassViewRegistry, Overlays, ViewHandle};
+use super::safe_area::safe_area_insets;
+use super::shadow::{apply_shadow, layout_shadow};
+use super::shape::apply_shape;
+use super::suspend::{flat_config, is_suspended};
+use super::utils::{color_from_css, try_run_on_main_sync};
+use super::window::{apply_webview_transparency, apply_window_transparency};
 use crate::error::{Error, Result};
-use crate::models::LiquidGlassConfig;
+use crate::models::{ExperimentalProperty, GlassPlacement, LiquidGlassConfig, SafeAreaInsets};
 
 // ============================================================================
 // Constants
@@ -20,6 +32,7 @@ use crate::models::LiquidGlassConfig;
 
 /// NSWindowOrderingMode
 const NS_WINDOW_BELOW: i64 = -1;
+const NS_WINDOW_ABOVE: i64 = 1;
 
 // ============================================================================
 // High-Level Operations
@@ -38,13 +51,13 @@ pub fn create_glass_effect<R: Runtime>(
         .map_err(|_| Error::WindowNotFound(window_label.clone()))?;
 
     let ns_window_handle = ViewHandle::new(ns_window as id);
-    let config = config.clone();
+    let main_config = config.clone();
 
-    let (glass_view, tint_overlay) = run_on_main_sync(move || unsafe {
-        create_and_attach_glass_view(ns_window_handle, &config)
+    let entry = try_run_on_main_sync(move || unsafe {
+        create_and_attach_glass_view(ns_window_handle, main_config)
     })?;
 
-    registry.insert(window_label, glass_view, tint_overlay)?;
+    registry.insert(window_label, entry)?;
 
     Ok(())
 }
@@ -57,17 +70,32 @@ pub fn update_glass_effect<R: Runtime>(
     let registry = app.state::<GlassViewRegistry>();
     let window_label = window.label().to_string();
 
-    let (glass_handle, existing_tint) = registry
+    let entry = registry
         .get(&window_label)?
         .ok_or_else(|| Error::WindowNotFound(window_label.clone()))?;
 
-    let config = config.clone();
+    // The stored config tracks external edits, so an equal config means the
+    // view already shows it, and only fields that differ need to be sent
+    if entry.config == *config {
+        return Ok(());
+    }
 
-    let new_tint = run_on_main_sync(move || unsafe {
-        apply_glass_config(glass_handle, &config, existing_tint)
-    });
+    let main_config = config.clone();
+
+    let overlays = try_run_on_main_sync(move || unsafe {
+        restack_glass_view(&entry, main_config.placement, false);
+        apply_auto_transparency(&entry, &main_config);
+        Ok(animated(main_config.animation.as_ref(), || {
+            update_glass_config(
+                entry.glass_view,
+                &entry.config,
+                &main_config,
+                entry.overlays,
+            )
+        }))
+    })?;
 
-    registry.update_tint(&window_label, new_tint)?;
+    registry.update(&window_label, overlays, config.clone())?;
 
     Ok(())
 }
@@ -78,15 +106,186 @@ pub fn remove_glass_effect<R: Runtime>(app: &AppHandle<R>, window_label: &str) -
     let entry = registry.remove(window_label)?;
 
     // If no entry exists, that's fine - effect was already disabled
-    if let Some((glass_handle, tint_handle)) = entry {
-        run_on_main_sync(move || unsafe {
-            // Remove tint overlay first (if exists)
-            if let Some(tint) = tint_handle {
-                let _: () = msg_send![tint.as_id(), removeFromSuperview];
+    if let Some(entry) = entry {
+        try_run_on_main_sync(move || unsafe {
+            remove_glass_view(&entry);
+            Ok(())
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Native work for one window of a batch
+enum BatchOp {
+    Create(ViewHandle),
+    Update(GlassViewEntry),
+    Remove(Option<GlassViewEntry>),
+}
+
+/// What a batch op did, for the registry
+enum BatchOutcome {
+    Created(Box<GlassViewEntry>),
+    Updated(Overlays),
+    Removed,
+}
+
+/// Create, update or remove the full-window glass of several windows
+///
+/// Registry entries and native windows are looked up first; every view is
+/// then changed in a single main-thread dispatch inside one CATransaction,
+/// so the windows change in the same frame. Returns one result per window,
+/// in order.
+pub fn set_glass_effects<R: Runtime>(
+    app: &AppHandle<R>,
+    batch: Vec<(WebviewWindow<R>, LiquidGlassConfig)>,
+) -> Vec<Result<()>> {
+    let registry = app.state::<GlassViewRegistry>();
+
+    let mut labels = Vec::with_capacity(batch.len());
+    let mut ops = Vec::with_capacity(batch.len());
+    for (window, config) in batch {
+        let label = window.label().to_string();
+        let op = if !config.enabled {
+            registry.remove(&label).map(BatchOp::Remove)
+        } else {
+            match registry.get(&label) {
+                Ok(Some(entry)) => Ok(BatchOp::Update(entry)),
+                Ok(None) => window
+                    .ns_window()
+                    .map(|ns_window| BatchOp::Create(ViewHandle::new(ns_window as id)))
+                    .map_err(|_| Error::WindowNotFound(label.clone())),
+                Err(e) => Err(e),
             }
-            // Remove glass view
-            let _: () = msg_send![glass_handle.as_id(), removeFromSuperview];
-        });
+        };
+        labels.push((label, config.clone()));
+        ops.push(op.map(|op| (op, config)));
+    }
+
+    let count = ops.len();
+    let outcomes = try_run_on_main_sync(move || unsafe {
+        let _: () = msg_send![class!(CATransaction), begin];
+        let outcomes = ops
+            .into_iter()
+            .map(|op| op.and_then(|(op, config)| apply_batch_op(op, config)))
+            .collect::<Vec<_>>();
+        let _: () = msg_send![class!(CATransaction), commit];
+        Ok(outcomes)
+    })
+    .unwrap_or_else(|_| {
+        (0..count)
+            .map(|_| Err(Error::MainThreadDispatchFailed))
+            .collect()
+    });
+
+    labels
+        .into_iter()
+        .zip(outcomes)
+        .map(|((label, config), outcome)| match outcome? {
+            BatchOutcome::Created(entry) => registry.insert(label, *entry),
+            BatchOutcome::Updated(overlays) => registry.update(&label, overlays, config),
+            BatchOutcome::Removed => Ok(()),
+        })
+        .collect()
+}
+
+/// Run one window's part of a batch
+///
+/// # Safety
+/// Must be called on the main thread
+unsafe fn apply_batch_op(op: BatchOp, config: LiquidGlassConfig) -> Result<BatchOutcome> {
+    match op {
+        BatchOp::Create(ns_window_handle) => {
+            let entry = create_and_attach_glass_view(ns_window_handle, config)?;
+            Ok(BatchOutcome::Created(Box::new(entry)))
+        }
+        BatchOp::Update(entry) if entry.config == config => {
+            Ok(BatchOutcome::Updated(entry.overlays))
+        }
+        BatchOp::Update(entry) => {
+            restack_glass_view(&entry, config.placement, false);
+            apply_auto_transparency(&entry, &config);
+            let overlays = animated(config.animation.as_ref(), || {
+                update_glass_config(entry.glass_view, &entry.config, &config, entry.overlays)
+            });
+            Ok(BatchOutcome::Updated(overlays))
+        }
+        BatchOp::Remove(entry) => {
+            if let Some(entry) = entry {
+                remove_glass_view(&entry);
+            }
+            Ok(BatchOutcome::Removed)
+        }
+    }
+}
+
+/// Re-resolve glass view frames after a window resize
+///
+/// Only frames are updated; configs are not re-applied. `ns_window` limits
+/// the layout to glass views hosted in that window. Regions frozen for a live
+/// resize are skipped.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be nil or a valid NSWindow
+pub unsafe fn layout_glass_views(
+    registry: &GlassViewRegistry,
+    ns_window: Option<id>,
+) -> Result<()> {
+    let windows = registry.entries()?.into_iter().map(|(_, entry)| entry);
+    let regions = registry
+        .region_entries()?
+        .into_iter()
+        .map(|(_, _, entry)| entry)
+        .filter(|entry| !is_frozen(entry.glass_view));
+
+    for entry in windows.chain(regions) {
+        if hosted_in(entry.glass_view, ns_window) {
+            layout_glass_view(entry.glass_view, &entry.config, &entry.overlays);
+        }
+    }
+
+    Ok(())
+}
+
+/// Re-sync glass view frames and re-apply their configs
+///
+/// Called when displays change so frames and scale-dependent values are
+/// recomputed for the new screen. `ns_window` limits the re-layout to glass
+/// views hosted in that window; `None` re-lays out every glass view.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be nil or a valid NSWindow
+pub unsafe fn relayout_glass_views(
+    registry: &GlassViewRegistry,
+    ns_window: Option<id>,
+) -> Result<()> {
+    for (label, entry) in registry.entries()? {
+        if !hosted_in(entry.glass_view, ns_window) {
+            continue;
+        }
+
+        let overlays = apply_glass_config(entry.glass_view, &entry.config, entry.overlays);
+        registry.update(&label, overlays, entry.config)?;
+    }
+
+    relayout_regions(registry, ns_window)
+}
+
+/// Re-apply the configs of region views, limited to `ns_window` if set
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be nil or a valid NSWindow
+pub unsafe fn relayout_regions(registry: &GlassViewRegistry, ns_window: Option<id>) -> Result<()> {
+    for (label, id, entry) in registry.region_entries()? {
+        if !hosted_in(entry.glass_view, ns_window) {
+            continue;
+        }
+
+        let overlays = apply_glass_config(entry.glass_view, &entry.config, entry.overlays);
+        registry.update_region(&label, &id, overlays, entry.config)?;
     }
 
     Ok(())
@@ -102,11 +301,11 @@ pub fn remove_glass_effect<R: Runtime>(app: &AppHandle<R>, window_label: &str) -
 /// - Must be called on the main thread
 /// - `ns_window_handle` must point to a valid NSWindow
 ///
-/// Returns (glass_view_handle, tint_overlay_handle)
+/// Returns the registry entry for the new view
 unsafe fn create_and_attach_glass_view(
     ns_window_handle: ViewHandle,
-    config: &LiquidGlassConfig,
-) -> Result<(ViewHandle, Option<ViewHandle>)> {
+    config: LiquidGlassConfig,
+) -> Result<GlassViewEntry> {
     let ns_window = ns_window_handle.as_id();
     let content_view: id = msg_send![ns_window, contentView];
 
@@ -114,25 +313,233 @@ unsafe fn create_and_attach_glass_view(
         return Err(Error::ViewCreationFailed);
     }
 
-    // Check and warn about transparency settings
-    check_window_transparency(ns_window);
-    check_webview_transparency(content_view);
+    // Clear the window and webview backgrounds, or warn if they are opaque
+    if config.auto_transparency {
+        make_transparent(ns_window);
+    } else {
+        check_window_transparency(ns_window);
+        check_webview_transparency(content_view);
+    }
 
     let bounds: NSRect = msg_send![content_view, bounds];
 
     // Create glass view using appropriate backend
-    let backend = get_backend();
-    let glass_view = backend.create_view(bounds)?;
+    let (glass_view, backend, fallback) = backend::create_view(bounds)?;
+
+    // Insert into view hierarchy (before configuring, so the opaque base can
+    // be placed next to the glass)
+    place_views(content_view, &[glass_view], config.placement, false);
 
     // Configure appearance and experimental properties
-    let glass_handle = ViewHandle::new(glass_view);
-    let tint_overlay = apply_glass_config(glass_handle, config, None);
+    let glass_handle = ViewHandle::owned(glass_view);
+    let overlays = apply_glass_config(glass_handle, &config, Overlays::default());
+
+    Ok(GlassViewEntry {
+        glass_view: glass_handle,
+        overlays,
+        config,
+        backend: backend.kind(),
+        fallback,
+    })
+}
+
+/// Create a region glass view stacked directly below the webview, or at the
+/// top with [`GlassPlacement::Above`]
+///
+/// Successive regions are stacked above earlier ones but stay below the
+/// webview; the full-window glass view remains at the very bottom.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window_handle` must point to a valid NSWindow
+pub unsafe fn create_region_view(
+    ns_window_handle: ViewHandle,
+    config: LiquidGlassConfig,
+) -> Result<GlassViewEntry> {
+    let content_view: id = msg_send![ns_window_handle.as_id(), contentView];
+    if content_view == nil {
+        return Err(Error::ViewCreationFailed);
+    }
 
-    // Insert into view hierarchy
-    let _: () =
-        msg_send![content_view, addSubview: glass_view positioned: NS_WINDOW_BELOW relativeTo: nil];
+    if config.auto_transparency {
+        make_transparent(ns_window_handle.as_id());
+    }
 
-    Ok((glass_handle, tint_overlay))
+    let bounds: NSRect = msg_send![content_view, bounds];
+    let (glass_view, backend, fallback) = backend::create_view(bounds)?;
+    place_views(content_view, &[glass_view], config.placement, true);
+
+    let glass_handle = ViewHandle::owned(glass_view);
+    let overlays = apply_glass_config(glass_handle, &config, Overlays::default());
+
+    Ok(GlassViewEntry {
+        glass_view: glass_handle,
+        overlays,
+        config,
+        backend: backend.kind(),
+        fallback,
+    })
+}
+
+/// Add `view` to the content view directly below the webview
+///
+/// If the webview isn't a direct child, the view goes to the top of the
+/// content view's subviews instead. Views inserted later end up above
+/// earlier ones.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `content_view` and `view` must be valid NSViews
+pub unsafe fn insert_below_webview(content_view: id, view: id) {
+    let webview = find_webview(content_view)
+        .filter(|&webview| {
+            let parent: id = msg_send![webview, superview];
+            parent == content_view
+        })
+        .unwrap_or(nil);
+    let ordering = if webview == nil {
+        NS_WINDOW_ABOVE
+    } else {
+        NS_WINDOW_BELOW
+    };
+    let _: () = msg_send![content_view, addSubview: view positioned: ordering relativeTo: webview];
+}
+
+/// Add views to the content view for `placement`, keeping their order
+///
+/// `views` are listed bottom-most first. Below the webview, the full-window
+/// glass goes to the very bottom and regions directly below the webview;
+/// above it, views go to the top of the content view.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `content_view` and `views` must be valid NSViews
+pub unsafe fn place_views(content_view: id, views: &[id], placement: GlassPlacement, region: bool) {
+    match (placement, region) {
+        (GlassPlacement::Below, false) => {
+            for &view in views.iter().rev() {
+                let _: () = msg_send![content_view, addSubview: view positioned: NS_WINDOW_BELOW relativeTo: nil];
+            }
+        }
+        (GlassPlacement::Below, true) => {
+            for &view in views {
+                insert_below_webview(content_view, view);
+            }
+        }
+        (GlassPlacement::Above, _) => {
+            for &view in views {
+                let _: () = msg_send![content_view, addSubview: view positioned: NS_WINDOW_ABOVE relativeTo: nil];
+            }
+        }
+    }
+}
+
+/// Move a glass view and its siblings if `placement` differs from the one
+/// the entry was created or last updated with
+///
+/// Views hosted in a group container keep the group's stacking.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - The entry's views must still be valid
+pub unsafe fn restack_glass_view(entry: &GlassViewEntry, placement: GlassPlacement, region: bool) {
+    if entry.config.placement == placement {
+        return;
+    }
+    let glass = entry.glass_view.as_id();
+    let superview: id = msg_send![glass, superview];
+    let window: id = msg_send![glass, window];
+    let content_view: id = if window == nil {
+        nil
+    } else {
+        msg_send![window, contentView]
+    };
+    if superview == nil || superview != content_view {
+        return;
+    }
+
+    place_views(content_view, &stacked_views(entry), placement, region);
+}
+
+/// Restack region views in list order, the first bottom-most
+///
+/// Each region keeps its placement, so the order is only enforced among
+/// regions of the same placement. Regions hosted in a group container keep
+/// the group's stacking. Nothing moves if the views are already in order.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - The entries' views must still be valid
+pub unsafe fn restack_regions<'a>(entries: impl IntoIterator<Item = &'a GlassViewEntry>) {
+    let mut content_view = nil;
+    let hosted: Vec<&GlassViewEntry> = entries
+        .into_iter()
+        .filter(|entry| {
+            let glass = entry.glass_view.as_id();
+            let superview: id = msg_send![glass, superview];
+            let window: id = msg_send![glass, window];
+            if superview == nil || window == nil {
+                return false;
+            }
+            let window_content: id = msg_send![window, contentView];
+            content_view = window_content;
+            superview == window_content
+        })
+        .collect();
+    if content_view == nil {
+        return;
+    }
+
+    let subviews: id = msg_send![content_view, subviews];
+    let in_order = [GlassPlacement::Below, GlassPlacement::Above]
+        .into_iter()
+        .all(|placement| {
+            let indices: Vec<usize> = hosted
+                .iter()
+                .filter(|entry| entry.config.placement == placement)
+                .map(|entry| msg_send![subviews, indexOfObject: entry.glass_view.as_id()])
+                .collect();
+            indices.windows(2).all(|pair| pair[0] < pair[1])
+        });
+    if in_order {
+        return;
+    }
+
+    for entry in hosted {
+        place_views(
+            content_view,
+            &stacked_views(entry),
+            entry.config.placement,
+            true,
+        );
+    }
+}
+
+/// The views of an entry that move together, bottom-most first
+///
+/// # Safety
+/// - The entry's views must still be valid
+unsafe fn stacked_views(entry: &GlassViewEntry) -> Vec<id> {
+    [entry.overlays.shadow, entry.overlays.opaque_base]
+        .into_iter()
+        .flatten()
+        .map(|view| view.as_id())
+        .chain(std::iter::once(entry.glass_view.as_id()))
+        .collect()
+}
+
+/// Remove a glass view and its overlays from the view hierarchy
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - The entry's views must still be valid
+pub unsafe fn remove_glass_view(entry: &GlassViewEntry) {
+    for overlay in entry.overlays.views() {
+        let _: () = msg_send![overlay.as_id(), removeFromSuperview];
+        overlay.retire();
+    }
+    let _: () = msg_send![entry.glass_view.as_id(), removeFromSuperview];
+    entry.glass_view.retire();
 }
 
 /// Apply all configuration to glass view
@@ -141,47 +548,304 @@ unsafe fn create_and_attach_glass_view(
 /// - Must be called on the main thread
 /// - `glass_handle` must point to a valid glass effect view
 ///
-/// Returns the tint overlay handle if one was created (for NSVisualEffectView fallback)
-unsafe fn apply_glass_config(
+/// Returns the overlay views now attached to the glass view
+pub unsafe fn apply_glass_config(
     glass_handle: ViewHandle,
     config: &LiquidGlassConfig,
-    existing_tint_overlay: Option<ViewHandle>,
-) -> Option<ViewHandle> {
+    existing_overlays: Overlays,
+) -> Overlays {
+    apply_config(glass_handle, None, config, existing_overlays)
+}
+
+/// Apply only the configuration that differs from `previous`, the config the
+/// glass view currently shows
+///
+/// Unchanged tint, tint layers, variant, corners and experimental states
+/// cost no Objective-C calls and keep their overlay views. Frames, the opaque
+/// base and the shadow are always refreshed in place.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass_handle` must point to a valid glass effect view
+///
+/// Returns the overlay views now attached to the glass view
+pub unsafe fn update_glass_config(
+    glass_handle: ViewHandle,
+    previous: &LiquidGlassConfig,
+    config: &LiquidGlassConfig,
+    existing_overlays: Overlays,
+) -> Overlays {
+    apply_config(glass_handle, Some(previous), config, existing_overlays)
+}
+
+/// Whether `field` differs between `previous` and `config`, or everything
+/// is applied because there is no previous config
+fn changed<T: PartialEq>(
+    previous: Option<&LiquidGlassConfig>,
+    config: &LiquidGlassConfig,
+    field: impl Fn(&LiquidGlassConfig) -> T,
+) -> bool {
+    previous.map_or(true, |previous| field(previous) != field(config))
+}
+
+/// Shared body of [`apply_glass_config`] and [`update_glass_config`]
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass_handle` must point to a valid glass effect view
+unsafe fn apply_config(
+    glass_handle: ViewHandle,
+    previous: Option<&LiquidGlassConfig>,
+    config: &LiquidGlassConfig,
+    existing_overlays: Overlays,
+) -> Overlays {
+    // Our own property changes must not be reported as external edits
+    let _applying = ApplyingGuard::new();
+
+    // While suspended, the opaque base stands in for the hidden glass; live
+    // resize placeholders look the same
+    let (flat, flat_previous);
+    let (config, previous) = if is_suspended() || is_placeholder(glass_handle) {
+        flat = flat_config(config);
+        flat_previous = previous.map(flat_config);
+        (&flat, flat_previous.as_ref())
+    } else {
+        (config, previous)
+    };
+
     let glass = glass_handle.as_id();
+
     let _: () = msg_send![glass, setWantsLayer: YES];
     let layer: id = msg_send![glass, layer];
 
     // Apply corner radius
-    if layer != nil {
-        let _: () = msg_send![layer, setCornerRadius: config.corner_radius];
+    let corners_changed = changed(previous, config, |c| {
+        (c.layer_corner_radius(), c.corner_curve, c.rim.clone())
+    });
+    if layer != nil && corners_changed {
+        let _: () = msg_send![layer, setCornerRadius: config.layer_corner_radius()];
+        apply_corner_curve(layer, config.corner_curve);
         let _: () = msg_send![layer, setMasksToBounds: YES];
+        apply_rim(layer, config.rim.as_ref());
     }
 
-    let backend = get_backend();
+    let backend = backend_of(glass);
 
-    // Apply or clear tint color
-    let tint_overlay = if let Some(ref hex) = config.tint_color {
-        if let Some(color) = color_from_hex(hex) {
-            backend.apply_tint(glass, layer, color, existing_tint_overlay)
+    // Apply or clear tint color; overlays follow the corner radius
+    let existing_tint = existing_overlays.tint;
+    let tint = if !corners_changed && !changed(previous, config, |c| c.tint_color.clone()) {
+        existing_tint
+    } else if let Some(ref css) = config.tint_color {
+        if let Some(color) = color_from_css(css) {
+            backend.apply_tint(glass, layer, color, existing_tint)
         } else {
-            backend.clear_tint(glass, existing_tint_overlay);
+            backend.clear_tint(glass, existing_tint);
             None
         }
     } else {
-        backend.clear_tint(glass, existing_tint_overlay);
+        backend.clear_tint(glass, existing_tint);
         None
     };
 
-    // Apply variant
-    backend.set_variant(glass, config.variant as i64);
+    // Compose tint layers above the glass (and above the fallback tint overlay)
+    let tint_layers = if !corners_changed && !changed(previous, config, |c| c.tint_layers.clone()) {
+        existing_overlays.tint_layers
+    } else {
+        apply_tint_layers(
+            glass,
+            layer,
+            &config.tint_layers,
+            existing_overlays.tint_layers,
+        )
+    };
+
+    // Apply variant, or the fallback's material
+    if changed(previous, config, |c| c.variant) {
+        backend.set_variant(glass, config.variant as i64);
+    }
+    if changed(previous, config, |c| {
+        (c.fallback_material, c.fallback_blending_mode)
+    }) {
+        backend.configure_fallback(
+            glass,
+            config.fallback_material.unwrap_or_default(),
+            config.fallback_blending_mode,
+        );
+    }
+
+    // Apply the experimental states the config sets and the view lacks
+    for (property, enabled, shown) in [
+        (
+            ExperimentalProperty::Scrim,
+            config.scrim,
+            previous.map(|previous| previous.scrim),
+        ),
+        (
+            ExperimentalProperty::Subdued,
+            config.subdued,
+            previous.map(|previous| previous.subdued),
+        ),
+        (
+            ExperimentalProperty::Interactive,
+            Some(config.interactive),
+            previous.map(|previous| Some(previous.interactive)),
+        ),
+    ] {
+        if let Some(enabled) = enabled.filter(|&enabled| shown != Some(Some(enabled))) {
+            backend.set_experimental(glass, property, enabled);
+        }
+    }
+
+    // Refuse first responder, or join the key view loop
+    let click_through = config.placement == GlassPlacement::Above;
+    if changed(previous, config, |c| (c.focusable, c.placement)) {
+        apply_focus_behavior(glass, config.focusable, click_through);
+    }
+
+    // Pin the fallback's state, or match the window's key status
+    apply_key_state(glass, config);
+
+    // Add or remove the opaque base behind the glass
+    let opaque_base = apply_opaque_base(glass, config, existing_overlays.opaque_base);
+
+    // Add or remove the shadow below both
+    let below = opaque_base.map_or(glass, |base| base.as_id());
+    let shadow = apply_shadow(glass, below, config, existing_overlays.shadow);
+
+    // Siblings over the webview must not swallow its clicks either
+    for view in opaque_base.iter().chain(shadow.iter()) {
+        apply_click_through(view.as_id(), click_through);
+    }
+
+    let overlays = Overlays {
+        tint,
+        tint_layers,
+        opaque_base,
+        shadow,
+    };
+
+    // Last, so the opaque base and shadow follow the glass frame and shape
+    layout_glass_view(glass_handle, config, &overlays);
 
-    tint_overlay
+    overlays
+}
+
+/// Resolve `config.frame` and `config.visible_when` against the superview
+///
+/// Without a frame the glass fills its superview and follows it through
+/// autoresizing. With a frame, autoresizing is disabled and the frame is
+/// re-resolved by [`layout_glass_views`] on every window resize so
+/// percentages stay accurate. Visibility is re-evaluated on the same path.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass_handle` must point to a valid glass effect view
+pub unsafe fn layout_glass_view(
+    glass_handle: ViewHandle,
+    config: &LiquidGlassConfig,
+    overlays: &Overlays,
+) {
+    let glass = glass_handle.as_id();
+    let superview: id = msg_send![glass, superview];
+    if superview == nil {
+        return;
+    }
+
+    let bounds: NSRect = msg_send![superview, bounds];
+    let (frame, mask) = match &config.frame {
+        None => (bounds, NSViewWidthSizable | NSViewHeightSizable),
+        Some(glass_frame) => {
+            let insets = if glass_frame.safe_area {
+                safe_area_insets(superview)
+            } else {
+                SafeAreaInsets::default()
+            };
+            let (x, top, width, height) =
+                glass_frame.resolve_in_safe_area(bounds.size.width, bounds.size.height, insets);
+            let flipped: BOOL = msg_send![superview, isFlipped];
+            let y = if flipped != NO {
+                top
+            } else {
+                bounds.size.height - top - height
+            };
+            let frame = NSRect::new(NSPoint::new(x, y), NSSize::new(width, height));
+            (frame, 0)
+        }
+    };
+
+    let hidden = match config.visible_when {
+        Some(condition) if !condition.matches(bounds.size.width, bounds.size.height) => YES,
+        _ => NO,
+    };
+
+    let glass_hidden = if is_suspended() || is_placeholder(glass_handle) {
+        YES
+    } else {
+        hidden
+    };
+
+    let _: () = msg_send![glass, setFrame: frame];
+    let _: () = msg_send![glass, setAutoresizingMask: mask];
+    let _: () = msg_send![glass, setHidden: glass_hidden];
+    apply_shape(glass, config);
+
+    // The opaque base is a sibling, so it has to follow the glass
+    if let Some(base) = overlays.opaque_base {
+        let _: () = msg_send![base.as_id(), setFrame: frame];
+        let _: () = msg_send![base.as_id(), setAutoresizingMask: mask];
+        let _: () = msg_send![base.as_id(), setHidden: hidden];
+        apply_shape(base.as_id(), config);
+    }
+    if let Some(shadow) = overlays.shadow {
+        layout_shadow(shadow.as_id(), frame, mask, hidden, config);
+    }
 }
 
 // ============================================================================
 // Utility Functions
 // ============================================================================
 
+/// Whether a glass view is hosted in `ns_window` (always true for `None`)
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must point to a valid view
+unsafe fn hosted_in(glass: ViewHandle, ns_window: Option<id>) -> bool {
+    let host_window: id = msg_send![glass.as_id(), window];
+    ns_window.map_or(true, |ns_window| ns_window == host_window)
+}
+
+/// Clear the window and webview backgrounds when an update turns on
+/// [`auto_transparency`](LiquidGlassConfig::auto_transparency)
+///
+/// # Safety
+/// Must be called on the main thread
+unsafe fn apply_auto_transparency(entry: &GlassViewEntry, config: &LiquidGlassConfig) {
+    if !config.auto_transparency || entry.config.auto_transparency {
+        return;
+    }
+    let ns_window: id = msg_send![entry.glass_view.as_id(), window];
+    if ns_window != nil {
+        make_transparent(ns_window);
+    }
+}
+
+/// Make the window non-opaque with a clear background, and stop its webview
+/// from drawing a background, including the one shown when overscrolling
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must be a valid NSWindow
+unsafe fn make_transparent(ns_window: id) {
+    apply_window_transparency(ns_window, true);
+
+    let content_view: id = msg_send![ns_window, contentView];
+    match find_webview(content_view) {
+        Some(webview) => apply_webview_transparency(webview, true),
+        None => warn!("No webview found to make transparent"),
+    }
+}
+
 /// Check if window has transparency configured and warn if not
 unsafe fn check_window_transparency(ns_window: id) {
     let is_opaque: BOOL = msg_send![ns_window, isOpaque];
@@ -213,7 +877,7 @@ unsafe fn check_webview_transparency(content_view: id) {
 }
 
 /// Find WKWebView in view hierarchy
-unsafe fn find_webview(view: id) -> Option<id> {
+pub unsafe fn find_webview(view: id) -> Option<id> {
     if view == nil {
         return None;
     }
@@ -0,0 +1,461 @@
+//! SVG path data parsing and CGPath construction
+
+use std::ffi::c_void;
+
+use cocoa::foundation::NSRect;
+
+/// Opaque CoreGraphics path
+pub type CGMutablePathRef = *mut c_void;
+
+#[link(name = "CoreGraphics", kind = "framework")]
+extern "C" {
+    fn CGPathCreateMutable() -> CGMutablePathRef;
+    fn CGPathMoveToPoint(path: CGMutablePathRef, m: *const c_void, x: f64, y: f64);
+    fn CGPathAddLineToPoint(path: CGMutablePathRef, m: *const c_void, x: f64, y: f64);
+    fn CGPathAddQuadCurveToPoint(
+        path: CGMutablePathRef,
+        m: *const c_void,
+        cpx: f64,
+        cpy: f64,
+        x: f64,
+        y: f64,
+    );
+    #[allow(clippy::too_many_arguments)]
+    fn CGPathAddCurveToPoint(
+        path: CGMutablePathRef,
+        m: *const c_void,
+        cp1x: f64,
+        cp1y: f64,
+        cp2x: f64,
+        cp2y: f64,
+        x: f64,
+        y: f64,
+    );
+    fn CGPathAddRoundedRect(
+        path: CGMutablePathRef,
+        m: *const c_void,
+        rect: NSRect,
+        corner_width: f64,
+        corner_height: f64,
+    );
+    fn CGPathAddArcToPoint(
+        path: CGMutablePathRef,
+        m: *const c_void,
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        radius: f64,
+    );
+    fn CGPathAddRect(path: CGMutablePathRef, m: *const c_void, rect: NSRect);
+    fn CGPathAddEllipseInRect(path: CGMutablePathRef, m: *const c_void, rect: NSRect);
+    fn CGPathCloseSubpath(path: CGMutablePathRef);
+    pub fn CGPathRelease(path: CGMutablePathRef);
+}
+
+/// A point in path coordinates
+pub type Point = (f64, f64);
+
+/// Absolute path segment
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segment {
+    MoveTo(Point),
+    LineTo(Point),
+    QuadTo(Point, Point),
+    CubicTo(Point, Point, Point),
+    Close,
+}
+
+/// Parse SVG path data into absolute segments
+///
+/// Supports the `M L H V C S Q T Z` commands in absolute and relative form.
+/// Elliptical arcs (`A`) are rejected.
+pub fn parse_svg_path(d: &str) -> Result<Vec<Segment>, String> {
+    let mut tokens = Tokens::new(d);
+    let mut segments = Vec::new();
+
+    let mut current = (0.0, 0.0);
+    let mut start = (0.0, 0.0);
+    // Reflected control point for S/T, reset by any other command
+    let mut last_cubic: Option<Point> = None;
+    let mut last_quad: Option<Point> = None;
+
+    let mut command = None;
+    while let Some(next) = tokens.command_or_repeat(command)? {
+        command = Some(next);
+        let relative = next.is_ascii_lowercase();
+        let offset = |(x, y): Point, current: Point| {
+            if relative {
+                (x + current.0, y + current.1)
+            } else {
+                (x, y)
+            }
+        };
+
+        let (segment, cubic, quad) = match next.to_ascii_uppercase() {
+            'M' => {
+                let point = offset(tokens.point()?, current);
+                start = point;
+                // Subsequent pairs after a moveto are implicit linetos
+                command = Some(if relative { 'l' } else { 'L' });
+                (Segment::MoveTo(point), None, None)
+            }
+            'L' => (
+                Segment::LineTo(offset(tokens.point()?, current)),
+                None,
+                None,
+            ),
+            'H' => {
+                let x = tokens.number()?;
+                let x = if relative { current.0 + x } else { x };
+                (Segment::LineTo((x, current.1)), None, None)
+            }
+            'V' => {
+                let y = tokens.number()?;
+                let y = if relative { current.1 + y } else { y };
+                (Segment::LineTo((current.0, y)), None, None)
+            }
+            'C' => {
+                let c1 = offset(tokens.point()?, current);
+                let c2 = offset(tokens.point()?, current);
+                let end = offset(tokens.point()?, current);
+                (Segment::CubicTo(c1, c2, end), Some(c2), None)
+            }
+            'S' => {
+                let c1 = reflect(last_cubic, current);
+                let c2 = offset(tokens.point()?, current);
+                let end = offset(tokens.point()?, current);
+                (Segment::CubicTo(c1, c2, end), Some(c2), None)
+            }
+            'Q' => {
+                let c = offset(tokens.point()?, current);
+                let end = offset(tokens.point()?, current);
+                (Segment::QuadTo(c, end), None, Some(c))
+            }
+            'T' => {
+                let c = reflect(last_quad, current);
+                let end = offset(tokens.point()?, current);
+                (Segment::QuadTo(c, end), None, Some(c))
+            }
+            'Z' => {
+                command = None;
+                (Segment::Close, None, None)
+            }
+            'A' => return Err("arc commands are not supported".to_string()),
+            other => return Err(format!("unknown path command {other:?}")),
+        };
+
+        current = match segment {
+            Segment::MoveTo(end)
+            | Segment::LineTo(end)
+            | Segment::QuadTo(_, end)
+            | Segment::CubicTo(_, _, end) => end,
+            Segment::Close => start,
+        };
+        last_cubic = cubic;
+        last_quad = quad;
+        segments.push(segment);
+    }
+
+    match segments.first() {
+        None => Err("path is empty".to_string()),
+        Some(Segment::MoveTo(_)) => Ok(segments),
+        Some(_) => Err("path must start with a moveto command".to_string()),
+    }
+}
+
+/// Reflect the previous control point through the current point
+fn reflect(control: Option<Point>, current: Point) -> Point {
+    match control {
+        Some((x, y)) => (2.0 * current.0 - x, 2.0 * current.1 - y),
+        None => current,
+    }
+}
+
+/// Tokenizer over SVG path data
+struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Tokens<'a> {
+    fn new(d: &'a str) -> Self {
+        Self { rest: d }
+    }
+
+    fn skip_separators(&mut self) {
+        self.rest = self
+            .rest
+            .trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    /// Next command letter, or `previous` again if more numbers follow
+    fn command_or_repeat(&mut self, previous: Option<char>) -> Result<Option<char>, String> {
+        self.skip_separators();
+        let Some(c) = self.rest.chars().next() else {
+            return Ok(None);
+        };
+
+        if c.is_ascii_alphabetic() {
+            self.rest = &self.rest[1..];
+            Ok(Some(c))
+        } else {
+            previous
+                .map(Some)
+                .ok_or_else(|| format!("expected a path command, found {c:?}"))
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, String> {
+        self.skip_separators();
+        let bytes = self.rest.as_bytes();
+
+        let mut end = 0;
+        if matches!(bytes.first(), Some(b'+' | b'-')) {
+            end += 1;
+        }
+        let mut seen_dot = false;
+        let mut seen_exp = false;
+        while let Some(&b) = bytes.get(end) {
+            match b {
+                b'0'..=b'9' => {}
+                b'.' if !seen_dot && !seen_exp => seen_dot = true,
+                b'e' | b'E' if !seen_exp && end > 0 => {
+                    seen_exp = true;
+                    if matches!(bytes.get(end + 1), Some(b'+' | b'-')) {
+                        end += 1;
+                    }
+                }
+                _ => break,
+            }
+            end += 1;
+        }
+
+        let (number, rest) = self.rest.split_at(end);
+        let value = number
+            .parse()
+            .map_err(|_| format!("expected a number near {:?}", self.rest))?;
+        self.rest = rest;
+        Ok(value)
+    }
+
+    fn point(&mut self) -> Result<Point, String> {
+        Ok((self.number()?, self.number()?))
+    }
+}
+
+/// Build a CGPath from segments, mapping every point through `map`
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+///
+/// # Safety
+/// `map` must not unwind
+pub unsafe fn build_path(segments: &[Segment], map: impl Fn(Point) -> Point) -> CGMutablePathRef {
+    let path = CGPathCreateMutable();
+    let null = std::ptr::null();
+
+    for segment in segments {
+        match *segment {
+            Segment::MoveTo(p) => {
+                let (x, y) = map(p);
+                CGPathMoveToPoint(path, null, x, y);
+            }
+            Segment::LineTo(p) => {
+                let (x, y) = map(p);
+                CGPathAddLineToPoint(path, null, x, y);
+            }
+            Segment::QuadTo(c, p) => {
+                let ((cx, cy), (x, y)) = (map(c), map(p));
+                CGPathAddQuadCurveToPoint(path, null, cx, cy, x, y);
+            }
+            Segment::CubicTo(c1, c2, p) => {
+                let ((c1x, c1y), (c2x, c2y), (x, y)) = (map(c1), map(c2), map(p));
+                CGPathAddCurveToPoint(path, null, c1x, c1y, c2x, c2y, x, y);
+            }
+            Segment::Close => CGPathCloseSubpath(path),
+        }
+    }
+
+    path
+}
+
+/// Build a rounded-rectangle CGPath, clamping the radius to fit the rect
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+pub unsafe fn rounded_rect_path(rect: NSRect, radius: f64) -> CGMutablePathRef {
+    let radius = clamp_radius(radius, rect);
+
+    let path = CGPathCreateMutable();
+    CGPathAddRoundedRect(path, std::ptr::null(), rect, radius, radius);
+    path
+}
+
+/// Build a rectangle CGPath with its own radius per corner, each clamped to
+/// fit the rect
+///
+/// `radii` are top-left, top-right, bottom-right and bottom-left, where the
+/// top edge is at the maximum y unless `flipped`. The caller owns the
+/// returned path and must release it with [`CGPathRelease`].
+pub unsafe fn corner_radii_path(rect: NSRect, radii: [f64; 4], flipped: bool) -> CGMutablePathRef {
+    let [top_left, top_right, bottom_right, bottom_left] =
+        radii.map(|radius| clamp_radius(radius, rect));
+
+    let (left, right) = (rect.origin.x, rect.origin.x + rect.size.width);
+    let (top, bottom) = if flipped {
+        (rect.origin.y, rect.origin.y + rect.size.height)
+    } else {
+        (rect.origin.y + rect.size.height, rect.origin.y)
+    };
+
+    let path = CGPathCreateMutable();
+    let null = std::ptr::null();
+    CGPathMoveToPoint(path, null, left + top_left, top);
+    CGPathAddArcToPoint(path, null, right, top, right, bottom, top_right);
+    CGPathAddArcToPoint(path, null, right, bottom, left, bottom, bottom_right);
+    CGPathAddArcToPoint(path, null, left, bottom, left, top, bottom_left);
+    CGPathAddArcToPoint(path, null, left, top, right, top, top_left);
+    CGPathCloseSubpath(path);
+    path
+}
+
+/// Clamp a corner radius to fit `rect`: between zero and half its shorter
+/// side
+fn clamp_radius(radius: f64, rect: NSRect) -> f64 {
+    radius
+        .min(rect.size.width / 2.0)
+        .min(rect.size.height / 2.0)
+        .max(0.0)
+}
+
+/// Build an ellipse CGPath inscribed in `rect`
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+pub unsafe fn ellipse_path(rect: NSRect) -> CGMutablePathRef {
+    let path = CGPathCreateMutable();
+    CGPathAddEllipseInRect(path, std::ptr::null(), rect);
+    path
+}
+
+/// Turn `hole` into a path covering `outer` with a hole in that shape
+///
+/// Meant to be filled with the even-odd rule. Takes ownership of `hole`; the
+/// caller owns the returned path and must release it with [`CGPathRelease`].
+pub unsafe fn cutout_path(outer: NSRect, hole: CGMutablePathRef) -> CGMutablePathRef {
+    CGPathAddRect(hole, std::ptr::null(), outer);
+    hole
+}
+
+#[cfg(test)]
+mod tests {
+    use cocoa::foundation::{NSPoint, NSSize};
+
+    use super::*;
+    use Segment::*;
+
+    #[test]
+    fn absolute_and_relative_commands() {
+        for (d, expected) in [
+            (
+                "M 10 20 L 30 40 Z",
+                vec![MoveTo((10.0, 20.0)), LineTo((30.0, 40.0)), Close],
+            ),
+            (
+                "m10,20 l5-5 h10 v-10 H0 V0 z",
+                vec![
+                    MoveTo((10.0, 20.0)),
+                    LineTo((15.0, 15.0)),
+                    LineTo((25.0, 15.0)),
+                    LineTo((25.0, 5.0)),
+                    LineTo((0.0, 5.0)),
+                    LineTo((0.0, 0.0)),
+                    Close,
+                ],
+            ),
+            // Pairs after a moveto are implicit linetos
+            (
+                "M0 0 10 0 10 10",
+                vec![
+                    MoveTo((0.0, 0.0)),
+                    LineTo((10.0, 0.0)),
+                    LineTo((10.0, 10.0)),
+                ],
+            ),
+            ("m1 1 2 2", vec![MoveTo((1.0, 1.0)), LineTo((3.0, 3.0))]),
+            // Numbers may run together
+            (
+                "M.5.5L-1e1+2",
+                vec![MoveTo((0.5, 0.5)), LineTo((-10.0, 2.0))],
+            ),
+        ] {
+            assert_eq!(parse_svg_path(d), Ok(expected), "{d:?}");
+        }
+    }
+
+    #[test]
+    fn curves_reflect_the_previous_control_point() {
+        assert_eq!(
+            parse_svg_path("M0 0 C0 10 10 10 10 0 S20 -10 20 0"),
+            Ok(vec![
+                MoveTo((0.0, 0.0)),
+                CubicTo((0.0, 10.0), (10.0, 10.0), (10.0, 0.0)),
+                CubicTo((10.0, -10.0), (20.0, -10.0), (20.0, 0.0)),
+            ])
+        );
+        assert_eq!(
+            parse_svg_path("M0 0 Q5 10 10 0 T20 0"),
+            Ok(vec![
+                MoveTo((0.0, 0.0)),
+                QuadTo((5.0, 10.0), (10.0, 0.0)),
+                QuadTo((15.0, -10.0), (20.0, 0.0)),
+            ])
+        );
+        // Without a previous curve the control point is the current point
+        assert_eq!(
+            parse_svg_path("M5 5 L10 10 S20 20 30 30"),
+            Ok(vec![
+                MoveTo((5.0, 5.0)),
+                LineTo((10.0, 10.0)),
+                CubicTo((10.0, 10.0), (20.0, 20.0), (30.0, 30.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn close_returns_to_the_subpath_start() {
+        assert_eq!(
+            parse_svg_path("M10 10 l10 0 z l0 10"),
+            Ok(vec![
+                MoveTo((10.0, 10.0)),
+                LineTo((20.0, 10.0)),
+                Close,
+                LineTo((10.0, 20.0)),
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_paths() {
+        for d in [
+            "",
+            "   ",
+            "L 10 10",
+            "M 0 0 A 5 5 0 0 1 10 10",
+            "M 0 0 X 1 1",
+            "M 0",
+            "M 0 0 L 1 x",
+            "10 10",
+        ] {
+            assert!(parse_svg_path(d).is_err(), "{d:?}");
+        }
+    }
+
+    #[test]
+    fn radii_are_clamped_to_the_rect() {
+        let rect = NSRect::new(NSPoint::new(0.0, 0.0), NSSize::new(100.0, 40.0));
+        for (radius, expected) in [(10.0, 10.0), (20.0, 20.0), (30.0, 20.0), (-5.0, 0.0)] {
+            assert_eq!(clamp_radius(radius, rect), expected, "{radius}");
+        }
+    }
+}
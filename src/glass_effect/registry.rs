@@ -1,11 +1,13 @@
 //! Glass view registry for tracking created views by window label
 
 use std::collections::HashMap;
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
 
-use cocoa::base::id;
+use cocoa::base::{id, nil};
 
 use crate::error::{Error, Result};
+use crate::models::{FallbackReason, GlassBackendKind, LiquidGlassConfig};
 
 // ============================================================================
 // View Handle - Type-safe wrapper for raw pointer addresses
@@ -13,24 +15,116 @@ use crate::error::{Error, Result};
 
 /// A thread-safe handle to an NSView stored as a raw pointer address.
 ///
+/// Handles to views the plugin creates ([`ViewHandle::owned`]) carry a
+/// generation that is checked on every dereference: once the view is retired,
+/// the handle resolves to nil (a no-op receiver) instead of a pointer that
+/// may since have been freed or reused by another object. Handles to objects
+/// the plugin doesn't own, such as windows, are not tracked.
+///
 /// # Safety
 /// All actual view operations must be performed on the main thread via `run_on_main_sync`.
-#[derive(Clone, Copy, Debug)]
-pub struct ViewHandle(usize);
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ViewHandle {
+    address: usize,
+    /// 0 for untracked handles
+    generation: u64,
+}
+
+/// Generation of every live plugin-owned view, keyed by address
+static LIVE_VIEWS: Mutex<Option<HashMap<usize, u64>>> = Mutex::new(None);
+
+/// Last generation handed out
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+fn live_views() -> MutexGuard<'static, Option<HashMap<usize, u64>>> {
+    LIVE_VIEWS.lock().unwrap_or_else(|e| e.into_inner())
+}
 
 impl ViewHandle {
-    /// Create a new ViewHandle from an Objective-C id
+    /// Create an untracked handle to an object the plugin doesn't own
     pub fn new(view: id) -> Self {
-        Self(view as usize)
+        Self {
+            address: view as usize,
+            generation: 0,
+        }
+    }
+
+    /// Create a tracked handle to a view the plugin just created
+    ///
+    /// Any earlier handle to the same address stops resolving.
+    pub fn owned(view: id) -> Self {
+        let generation = GENERATION.fetch_add(1, Ordering::Relaxed) + 1;
+        live_views()
+            .get_or_insert_with(HashMap::new)
+            .insert(view as usize, generation);
+        Self {
+            address: view as usize,
+            generation,
+        }
+    }
+
+    /// Mark the view as gone, so this and every copy of the handle resolve to nil
+    pub fn retire(self) {
+        if self.generation == 0 {
+            return;
+        }
+        let mut live = live_views();
+        if let Some(views) = live.as_mut() {
+            if views.get(&self.address) == Some(&self.generation) {
+                views.remove(&self.address);
+            }
+        }
+    }
+
+    /// Whether the handle still refers to the view it was created for
+    pub fn is_live(self) -> bool {
+        self.generation == 0
+            || live_views()
+                .as_ref()
+                .and_then(|views| views.get(&self.address))
+                == Some(&self.generation)
     }
 
-    /// Convert back to an Objective-C id
+    /// Convert back to an Objective-C id, or nil if the handle is stale
     ///
     /// # Safety
     /// - Must be called on the main thread
-    /// - The underlying view must still be valid
+    /// - Untracked handles must still point to a valid object
     pub unsafe fn as_id(self) -> id {
-        self.0 as id
+        if self.is_live() {
+            self.address as id
+        } else {
+            nil
+        }
+    }
+}
+
+// ============================================================================
+// Overlays
+// ============================================================================
+
+/// Auxiliary views owned by a glass view
+#[derive(Clone, Debug, Default)]
+pub struct Overlays {
+    /// Tint overlay view for NSVisualEffectView fallback (NSGlassEffectView has native tint support)
+    pub tint: Option<ViewHandle>,
+    /// Views for `tint_layers`, bottom-most first
+    pub tint_layers: Vec<ViewHandle>,
+    /// Opaque base view behind the glass (sibling of the glass view)
+    pub opaque_base: Option<ViewHandle>,
+    /// Shadow view below the glass and its opaque base (sibling of the glass view)
+    pub shadow: Option<ViewHandle>,
+}
+
+impl Overlays {
+    /// Iterate over all overlay views
+    pub fn views(&self) -> impl Iterator<Item = ViewHandle> + '_ {
+        self.tint
+            .iter()
+            .chain(self.tint_layers.iter())
+            .chain(self.opaque_base.iter())
+            .chain(self.shadow.iter())
+            .copied()
     }
 }
 
@@ -39,10 +133,17 @@ impl ViewHandle {
 // ============================================================================
 
 /// Entry for tracking a glass view.
+#[derive(Clone)]
 pub struct GlassViewEntry {
     pub glass_view: ViewHandle,
-    /// Tint overlay view for NSVisualEffectView fallback (NSGlassEffectView has native tint support)
-    pub tint_overlay: Option<ViewHandle>,
+    /// Auxiliary views layered on the glass view
+    pub overlays: Overlays,
+    /// Configuration currently applied to the view
+    pub config: LiquidGlassConfig,
+    /// Backend the view was created with
+    pub backend: GlassBackendKind,
+    /// Why the view was created with NSVisualEffectView, if it was
+    pub fallback: Option<FallbackReason>,
 }
 
 // SAFETY: GlassViewEntry stores ViewHandle which contains usize values (raw pointer addresses).
@@ -50,78 +151,370 @@ pub struct GlassViewEntry {
 unsafe impl Send for GlassViewEntry {}
 unsafe impl Sync for GlassViewEntry {}
 
+// ============================================================================
+// Glass Group Entry
+// ============================================================================
+
+/// Entry for tracking a container that hosts regions as one group
+#[derive(Clone)]
+pub struct GlassGroupEntry {
+    /// NSGlassEffectContainerView, or a plain view for the fallback backend
+    pub container: ViewHandle,
+    /// Ids of the regions hosted in the container, in stacking order
+    pub members: Vec<String>,
+}
+
+// SAFETY: see GlassViewEntry
+unsafe impl Send for GlassGroupEntry {}
+unsafe impl Sync for GlassGroupEntry {}
+
 // ============================================================================
 // Glass View Registry
 // ============================================================================
 
-/// Registry for tracking created glass views by window label
-pub struct GlassViewRegistry {
-    views: Mutex<HashMap<String, GlassViewEntry>>,
+/// Regions of a window, keyed by region id, in stacking order (bottom-most first)
+pub type Regions = Vec<(String, GlassViewEntry)>;
+
+/// Groups of a window, keyed by group id
+pub type Groups = Vec<(String, GlassGroupEntry)>;
+
+/// Glass views of one window
+#[derive(Default)]
+struct WindowViews {
+    glass: Option<GlassViewEntry>,
+    regions: Regions,
+    groups: Groups,
+    /// Set once the shard is dropped from the registry; writers that raced
+    /// with the removal retry with a fresh shard
+    retired: bool,
 }
 
-impl Default for GlassViewRegistry {
-    fn default() -> Self {
-        Self {
-            views: Mutex::new(HashMap::new()),
+impl WindowViews {
+    fn is_empty(&self) -> bool {
+        self.glass.is_none() && self.regions.is_empty() && self.groups.is_empty()
+    }
+
+    /// Drop group members whose region no longer exists
+    fn retain_group_members(&mut self) {
+        let regions = &self.regions;
+        for (_, group) in &mut self.groups {
+            group
+                .members
+                .retain(|member| regions.iter().any(|(id, _)| id == member));
         }
     }
 }
 
+/// Lock-protected views of one window
+type Shard = Arc<Mutex<WindowViews>>;
+
+/// Registry for tracking created glass views by window label
+///
+/// Each window has at most one full-window glass view plus any number of
+/// layout regions, some of which may be hosted in groups.
+///
+/// Windows are sharded: the outer map is only read-locked to find a window's
+/// shard, so updates to different windows never wait on each other, and no
+/// lock is held while a shard is locked except to drop emptied shards. Every
+/// lock is released before returning; callers make native calls on
+/// snapshots, never under a lock.
+#[derive(Default)]
+pub struct GlassViewRegistry {
+    windows: RwLock<HashMap<String, Shard>>,
+}
+
+fn lock(shard: &Shard) -> Result<MutexGuard<'_, WindowViews>> {
+    shard.lock().map_err(|_| Error::RegistryLockFailed)
+}
+
 impl GlassViewRegistry {
-    /// Check if a window has a registered glass view
-    pub fn contains(&self, label: &str) -> Result<bool> {
-        self.views
-            .lock()
-            .map(|views| views.contains_key(label))
+    /// Shard of a window, if it has any views
+    fn shard(&self, label: &str) -> Result<Option<Shard>> {
+        self.windows
+            .read()
+            .map(|windows| windows.get(label).cloned())
             .map_err(|_| Error::RegistryLockFailed)
     }
 
-    /// Insert a new glass view entry
-    pub fn insert(
-        &self,
-        label: String,
-        glass_view: ViewHandle,
-        tint_overlay: Option<ViewHandle>,
-    ) -> Result<()> {
-        self.views
-            .lock()
-            .map(|mut views| {
-                views.insert(
-                    label,
-                    GlassViewEntry {
-                        glass_view,
-                        tint_overlay,
-                    },
-                );
+    /// Snapshot of every window's shard
+    fn shards(&self) -> Result<Vec<(String, Shard)>> {
+        self.windows
+            .read()
+            .map(|windows| {
+                windows
+                    .iter()
+                    .map(|(label, shard)| (label.clone(), shard.clone()))
+                    .collect()
             })
             .map_err(|_| Error::RegistryLockFailed)
     }
 
+    /// Read a window's views, `None` if it has none
+    fn read<T>(&self, label: &str, read: impl FnOnce(&WindowViews) -> T) -> Result<Option<T>> {
+        match self.shard(label)? {
+            Some(shard) => Ok(Some(read(&*lock(&shard)?))),
+            None => Ok(None),
+        }
+    }
+
+    /// Modify a window's views, creating its shard first if `create` is set
+    ///
+    /// Returns `None` if the window has no shard and `create` is false.
+    /// Shards left empty are dropped.
+    fn write<T>(
+        &self,
+        label: &str,
+        create: bool,
+        write: impl FnOnce(&mut WindowViews) -> T,
+    ) -> Result<Option<T>> {
+        loop {
+            let shard = match self.shard(label)? {
+                Some(shard) => shard,
+                None if create => self
+                    .windows
+                    .write()
+                    .map_err(|_| Error::RegistryLockFailed)?
+                    .entry(label.to_string())
+                    .or_default()
+                    .clone(),
+                None => return Ok(None),
+            };
+
+            let (result, emptied) = {
+                let mut views = lock(&shard)?;
+                if views.retired {
+                    continue;
+                }
+                let result = write(&mut views);
+                (result, views.is_empty())
+            };
+
+            if emptied {
+                self.prune(label)?;
+            }
+            return Ok(Some(result));
+        }
+    }
+
+    /// Drop a window's shard if it is still empty
+    fn prune(&self, label: &str) -> Result<()> {
+        let mut windows = self
+            .windows
+            .write()
+            .map_err(|_| Error::RegistryLockFailed)?;
+        if let Some(shard) = windows.get(label) {
+            let mut views = lock(shard)?;
+            if views.is_empty() {
+                views.retired = true;
+                drop(views);
+                windows.remove(label);
+            }
+        }
+        Ok(())
+    }
+
+    /// Check if a window has a registered glass view
+    pub fn contains(&self, label: &str) -> Result<bool> {
+        Ok(self
+            .read(label, |views| views.glass.is_some())?
+            .unwrap_or(false))
+    }
+
+    /// Insert a new glass view entry
+    pub fn insert(&self, label: String, entry: GlassViewEntry) -> Result<()> {
+        self.write(&label, true, |views| views.glass = Some(entry))?;
+        Ok(())
+    }
+
     /// Get a glass view entry by label
-    pub fn get(&self, label: &str) -> Result<Option<(ViewHandle, Option<ViewHandle>)>> {
-        self.views
-            .lock()
-            .map(|views| views.get(label).map(|e| (e.glass_view, e.tint_overlay)))
-            .map_err(|_| Error::RegistryLockFailed)
+    pub fn get(&self, label: &str) -> Result<Option<GlassViewEntry>> {
+        Ok(self.read(label, |views| views.glass.clone())?.flatten())
     }
 
     /// Remove a glass view entry and return it
-    pub fn remove(&self, label: &str) -> Result<Option<(ViewHandle, Option<ViewHandle>)>> {
-        self.views
-            .lock()
-            .map(|mut views| views.remove(label).map(|e| (e.glass_view, e.tint_overlay)))
-            .map_err(|_| Error::RegistryLockFailed)
+    pub fn remove(&self, label: &str) -> Result<Option<GlassViewEntry>> {
+        Ok(self
+            .write(label, false, |views| views.glass.take())?
+            .flatten())
+    }
+
+    /// Get a snapshot of all entries with their window labels
+    pub fn entries(&self) -> Result<Vec<(String, GlassViewEntry)>> {
+        let mut entries = Vec::new();
+        for (label, shard) in self.shards()? {
+            if let Some(entry) = lock(&shard)?.glass.clone() {
+                entries.push((label, entry));
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Get the applied configuration of every window with full-window glass
+    pub fn configs(&self) -> Result<Vec<(String, LiquidGlassConfig)>> {
+        Ok(self
+            .entries()?
+            .into_iter()
+            .map(|(label, entry)| (label, entry.config))
+            .collect())
+    }
+
+    /// Get the labels of windows with any glass: full-window, regions or
+    /// groups
+    pub fn labels(&self) -> Result<Vec<String>> {
+        let mut labels = Vec::new();
+        for (label, shard) in self.shards()? {
+            if !lock(&shard)?.is_empty() {
+                labels.push(label);
+            }
+        }
+        Ok(labels)
     }
 
-    /// Update the tint overlay for an existing entry
-    pub fn update_tint(&self, label: &str, tint: Option<ViewHandle>) -> Result<()> {
-        self.views
-            .lock()
-            .map(|mut views| {
-                if let Some(entry) = views.get_mut(label) {
-                    entry.tint_overlay = tint;
+    /// Get the configuration applied to a window's glass view
+    pub fn get_config(&self, label: &str) -> Result<Option<LiquidGlassConfig>> {
+        Ok(self
+            .read(label, |views| {
+                views.glass.as_ref().map(|e| e.config.clone())
+            })?
+            .flatten())
+    }
+
+    /// Update the overlays and applied configuration for an existing entry
+    pub fn update(&self, label: &str, overlays: Overlays, config: LiquidGlassConfig) -> Result<()> {
+        self.write(label, false, |views| {
+            if let Some(entry) = views.glass.as_mut() {
+                entry.overlays = overlays;
+                entry.config = config;
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Get the regions of a window, bottom-most first
+    pub fn regions(&self, label: &str) -> Result<Regions> {
+        Ok(self
+            .read(label, |views| views.regions.clone())?
+            .unwrap_or_default())
+    }
+
+    /// Replace the regions of a window
+    pub fn set_regions(&self, label: &str, window_regions: Regions) -> Result<()> {
+        let create = !window_regions.is_empty();
+        self.write(label, create, |views| {
+            views.regions = window_regions;
+            views.retain_group_members();
+        })?;
+        Ok(())
+    }
+
+    /// Get the groups of a window
+    pub fn groups(&self, label: &str) -> Result<Groups> {
+        Ok(self
+            .read(label, |views| views.groups.clone())?
+            .unwrap_or_default())
+    }
+
+    /// Replace the groups of a window
+    pub fn set_groups(&self, label: &str, window_groups: Groups) -> Result<()> {
+        let create = !window_groups.is_empty();
+        self.write(label, create, |views| views.groups = window_groups)?;
+        Ok(())
+    }
+
+    /// Get a snapshot of all regions as `(window label, region id, entry)`
+    pub fn region_entries(&self) -> Result<Vec<(String, String, GlassViewEntry)>> {
+        let mut entries = Vec::new();
+        for (label, shard) in self.shards()? {
+            let views = lock(&shard)?;
+            entries.extend(
+                views
+                    .regions
+                    .iter()
+                    .map(|(id, entry)| (label.clone(), id.clone(), entry.clone())),
+            );
+        }
+        Ok(entries)
+    }
+
+    /// Update the overlays and applied configuration of a region
+    pub fn update_region(
+        &self,
+        label: &str,
+        id: &str,
+        overlays: Overlays,
+        config: LiquidGlassConfig,
+    ) -> Result<()> {
+        self.write(label, false, |views| {
+            let entry = views
+                .regions
+                .iter_mut()
+                .find(|(region_id, _)| region_id == id);
+            if let Some((_, entry)) = entry {
+                entry.overlays = overlays;
+                entry.config = config;
+            }
+        })?;
+        Ok(())
+    }
+
+    /// Remove the entry holding `glass_view` and return it
+    ///
+    /// `region` selects a region, `None` the full-window glass. Nothing is
+    /// removed if that slot no longer holds `glass_view`.
+    pub fn remove_view(
+        &self,
+        label: &str,
+        region: Option<&str>,
+        glass_view: ViewHandle,
+    ) -> Result<Option<GlassViewEntry>> {
+        let removed = self.write(label, false, |views| match region {
+            None => {
+                let matches = views
+                    .glass
+                    .as_ref()
+                    .is_some_and(|entry| entry.glass_view == glass_view);
+                if matches {
+                    views.glass.take()
+                } else {
+                    None
                 }
-            })
-            .map_err(|_| Error::RegistryLockFailed)
+            }
+            Some(id) => {
+                let index = views.regions.iter().position(|(region_id, entry)| {
+                    region_id == id && entry.glass_view == glass_view
+                })?;
+                let removed = views.regions.remove(index).1;
+                views.retain_group_members();
+                Some(removed)
+            }
+        })?;
+        Ok(removed.flatten())
+    }
+
+    /// Modify the stored configuration of the view `glass_view`
+    ///
+    /// `region` selects a region, `None` the full-window glass. Returns `None`
+    /// if that slot no longer holds `glass_view`, e.g. after it was recreated.
+    /// `modify` runs under the window's lock, so it must not call into AppKit.
+    pub fn modify_config<T>(
+        &self,
+        label: &str,
+        region: Option<&str>,
+        glass_view: ViewHandle,
+        modify: impl FnOnce(&mut LiquidGlassConfig) -> T,
+    ) -> Result<Option<T>> {
+        let modified = self.write(label, false, |views| {
+            let entry = match region {
+                None => views.glass.as_mut(),
+                Some(id) => views
+                    .regions
+                    .iter_mut()
+                    .find(|(region_id, _)| region_id == id)
+                    .map(|(_, entry)| entry),
+            };
+            let entry = entry.filter(|entry| entry.glass_view == glass_view)?;
+            Some(modify(&mut entry.config))
+        })?;
+        Ok(modified.flatten())
     }
 }
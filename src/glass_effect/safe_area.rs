@@ -0,0 +1,64 @@
+//! Safe-area insets of notched displays
+//!
+//! In fullscreen on a MacBook with a camera housing, the top of the window
+//! extends beside and under the notch. `NSScreen.safeAreaInsets` (macOS 12+)
+//! describes the unobstructed part of the screen; this module maps it onto a
+//! view's bounds.
+
+use cocoa::base::{id, nil, NO};
+use cocoa::foundation::NSRect;
+use objc::runtime::BOOL;
+use objc::{msg_send, sel, sel_impl};
+
+use crate::models::SafeAreaInsets;
+
+/// Mirrors the NSEdgeInsets struct
+#[repr(C)]
+struct NSEdgeInsets {
+    top: f64,
+    left: f64,
+    bottom: f64,
+    right: f64,
+}
+
+/// Insets of `view`'s bounds that lie outside its screen's safe area
+///
+/// All zero when the view is not on screen, the screen has no unsafe area or
+/// the OS predates `safeAreaInsets`.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSView
+pub unsafe fn safe_area_insets(view: id) -> SafeAreaInsets {
+    let window: id = msg_send![view, window];
+    if window == nil {
+        return SafeAreaInsets::default();
+    }
+    let screen: id = msg_send![window, screen];
+    if screen == nil {
+        return SafeAreaInsets::default();
+    }
+    let responds: BOOL = msg_send![screen, respondsToSelector: sel!(safeAreaInsets)];
+    if responds == NO {
+        return SafeAreaInsets::default();
+    }
+
+    let insets: NSEdgeInsets = msg_send![screen, safeAreaInsets];
+    let screen_frame: NSRect = msg_send![screen, frame];
+    let bounds: NSRect = msg_send![view, bounds];
+    let in_window: NSRect = msg_send![view, convertRect: bounds toView: nil];
+    let rect: NSRect = msg_send![window, convertRectToScreen: in_window];
+
+    // Screen coordinates have a bottom-left origin
+    let safe_min_x = screen_frame.origin.x + insets.left;
+    let safe_max_x = screen_frame.origin.x + screen_frame.size.width - insets.right;
+    let safe_min_y = screen_frame.origin.y + insets.bottom;
+    let safe_max_y = screen_frame.origin.y + screen_frame.size.height - insets.top;
+
+    SafeAreaInsets {
+        top: (rect.origin.y + rect.size.height - safe_max_y).max(0.0),
+        left: (safe_min_x - rect.origin.x).max(0.0),
+        bottom: (safe_min_y - rect.origin.y).max(0.0),
+        right: (rect.origin.x + rect.size.width - safe_max_x).max(0.0),
+    }
+}
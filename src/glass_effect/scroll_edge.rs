@@ -0,0 +1,291 @@
+//! Scroll-edge effect: a top glass strip that fades in once content scrolls under it
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use cocoa::appkit::{NSViewMaxYMargin, NSViewMinYMargin, NSViewWidthSizable};
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
+use dispatch::Queue;
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{AppHandle, Manager, Runtime, WebviewWindow};
+
+use super::backend;
+use super::operations::{apply_glass_config, update_glass_config};
+use super::registry::{Overlays, ViewHandle};
+use super::utils::run_on_main_sync;
+use super::window::ns_window_handle;
+use crate::error::{Error, Result};
+use crate::models::{GlassPlacement, ScrollEdgeConfig};
+
+/// NSWindowOrderingMode
+const NS_WINDOW_ABOVE: i64 = 1;
+
+/// A scroll-edge strip attached to a window
+#[derive(Clone)]
+struct ScrollEdgeStrip {
+    view: ViewHandle,
+    overlays: Overlays,
+    config: ScrollEdgeConfig,
+    visible: bool,
+}
+
+// SAFETY: ScrollEdgeStrip stores ViewHandle values (raw pointer addresses).
+// All actual view operations are performed on the main thread via run_on_main_sync.
+unsafe impl Send for ScrollEdgeStrip {}
+unsafe impl Sync for ScrollEdgeStrip {}
+
+/// Registry for tracking scroll-edge strips by window label
+#[derive(Default)]
+pub struct ScrollEdgeRegistry {
+    strips: Mutex<HashMap<String, ScrollEdgeStrip>>,
+}
+
+impl ScrollEdgeRegistry {
+    /// Drop the strip of a destroyed window without touching its views
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.strips
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label);
+        Ok(())
+    }
+}
+
+/// Install, update or (with `None`) remove the scroll-edge strip of a window
+///
+/// The strip starts hidden until a scroll offset past `show_after` is reported.
+pub fn set_scroll_edge_effect<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    config: Option<ScrollEdgeConfig>,
+) -> Result<()> {
+    let registry = app.state::<ScrollEdgeRegistry>();
+    let label = window.label().to_string();
+
+    let existing = registry
+        .strips
+        .lock()
+        .map_err(|_| Error::RegistryLockFailed)?
+        .remove(&label);
+
+    let Some(config) = config else {
+        if let Some(strip) = existing {
+            run_on_main_sync(move || unsafe {
+                for overlay in strip.overlays.views() {
+                    let _: () = msg_send![overlay.as_id(), removeFromSuperview];
+                    overlay.retire();
+                }
+                let _: () = msg_send![strip.view.as_id(), removeFromSuperview];
+                strip.view.retire();
+            });
+        }
+        return Ok(());
+    };
+
+    // The strip always sits over the webview, so like any view placed above
+    // it must let clicks and scrolling through to the page
+    let mut config = config;
+    config.glass.placement = GlassPlacement::Above;
+
+    let strip = match existing {
+        Some(strip) => {
+            let main_config = config.clone();
+            let visible = strip.visible;
+            let overlays = run_on_main_sync(move || unsafe {
+                let overlays = update_glass_config(
+                    strip.view,
+                    &strip.config.glass,
+                    &main_config.glass,
+                    strip.overlays,
+                );
+                layout_strip(strip.view, &main_config);
+                restore_hidden(strip.view, visible);
+                overlays
+            });
+            ScrollEdgeStrip {
+                overlays,
+                config,
+                ..strip
+            }
+        }
+        None => {
+            let ns_window = ns_window_handle(window)?;
+            let main_config = config.clone();
+            let (view, overlays) =
+                run_on_main_sync(move || unsafe { create_strip(ns_window, &main_config) })?;
+            ScrollEdgeStrip {
+                view,
+                overlays,
+                config,
+                visible: false,
+            }
+        }
+    };
+
+    registry
+        .strips
+        .lock()
+        .map_err(|_| Error::RegistryLockFailed)?
+        .insert(label, strip);
+
+    Ok(())
+}
+
+/// Report the content's scroll offset, fading the strip in or out as needed
+///
+/// Hysteresis is applied here: the strip shows once the offset reaches
+/// `show_after` and only hides again at or below `hide_below`.
+pub fn report_scroll_offset<R: Runtime>(
+    app: &AppHandle<R>,
+    window: &WebviewWindow<R>,
+    offset: f64,
+) -> Result<()> {
+    let registry = app.state::<ScrollEdgeRegistry>();
+
+    let (view, visible, fade) = {
+        let mut strips = registry
+            .strips
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?;
+        let Some(strip) = strips.get_mut(window.label()) else {
+            return Ok(()); // no strip installed, nothing to do
+        };
+
+        let visible = if strip.visible {
+            offset > strip.config.hide_below
+        } else {
+            offset >= strip.config.show_after
+        };
+        if visible == strip.visible {
+            return Ok(());
+        }
+        strip.visible = visible;
+
+        (
+            strip.view,
+            visible,
+            Duration::from_millis(strip.config.fade_duration_ms),
+        )
+    };
+
+    run_on_main_sync(move || unsafe { fade_strip(view, visible, fade) });
+
+    Ok(())
+}
+
+// ============================================================================
+// Main Thread Operations
+// ============================================================================
+
+/// Create the strip above the window's content, initially hidden
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `ns_window` must point to a valid NSWindow
+unsafe fn create_strip(
+    ns_window: ViewHandle,
+    config: &ScrollEdgeConfig,
+) -> Result<(ViewHandle, Overlays)> {
+    let content_view: id = msg_send![ns_window.as_id(), contentView];
+    if content_view == nil {
+        return Err(Error::ViewCreationFailed);
+    }
+
+    let bounds: NSRect = msg_send![content_view, bounds];
+    let (view, _, _) = backend::create_view(bounds)?;
+    let handle = ViewHandle::owned(view);
+
+    let _: () = msg_send![view, setAlphaValue: 0.0f64];
+    let _: () = msg_send![view, setHidden: YES];
+
+    // Above the webview so scrolled content is blurred beneath it
+    let _: () =
+        msg_send![content_view, addSubview: view positioned: NS_WINDOW_ABOVE relativeTo: nil];
+
+    let overlays = apply_glass_config(handle, &config.glass, Overlays::default());
+    layout_strip(handle, config);
+    restore_hidden(handle, false);
+    Ok((handle, overlays))
+}
+
+/// Pin the strip to the top edge of its superview, unless `config.glass`
+/// has a frame (already resolved by `apply_glass_config`)
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSView
+unsafe fn layout_strip(view: ViewHandle, config: &ScrollEdgeConfig) {
+    if config.glass.frame.is_some() {
+        return;
+    }
+
+    let height = config.height;
+    let view = view.as_id();
+    let superview: id = msg_send![view, superview];
+    if superview == nil {
+        return;
+    }
+
+    let bounds: NSRect = msg_send![superview, bounds];
+    let flipped: BOOL = msg_send![superview, isFlipped];
+    let height = height.clamp(0.0, bounds.size.height);
+
+    let (y, top_margin) = if flipped != NO {
+        (0.0, NSViewMaxYMargin)
+    } else {
+        (bounds.size.height - height, NSViewMinYMargin)
+    };
+
+    let frame = NSRect::new(NSPoint::new(0.0, y), NSSize::new(bounds.size.width, height));
+    let _: () = msg_send![view, setFrame: frame];
+    let _: () = msg_send![view, setAutoresizingMask: NSViewWidthSizable | top_margin];
+}
+
+/// Hide a faded-out strip again after `apply_glass_config` unhid it
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSView
+unsafe fn restore_hidden(view: ViewHandle, visible: bool) {
+    let strip = view.as_id();
+    let alpha: f64 = msg_send![strip, alphaValue];
+    // A fade-out still running hides the strip once it completes
+    if !visible && alpha == 0.0 {
+        let _: () = msg_send![strip, setHidden: YES];
+    }
+}
+
+/// Animate the strip's alpha, hiding it once fully faded out
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must point to a valid NSView
+unsafe fn fade_strip(view: ViewHandle, visible: bool, duration: Duration) {
+    let strip = view.as_id();
+
+    if visible {
+        let _: () = msg_send![strip, setHidden: NO];
+    }
+
+    let alpha: f64 = if visible { 1.0 } else { 0.0 };
+    let _: () = msg_send![class!(NSAnimationContext), beginGrouping];
+    let context: id = msg_send![class!(NSAnimationContext), currentContext];
+    let _: () = msg_send![context, setDuration: duration.as_secs_f64()];
+    let animator: id = msg_send![strip, animator];
+    let _: () = msg_send![animator, setAlphaValue: alpha];
+    let _: () = msg_send![class!(NSAnimationContext), endGrouping];
+
+    if !visible {
+        Queue::main().exec_after(duration, move || {
+            let strip = view.as_id();
+            let alpha: f64 = msg_send![strip, alphaValue];
+            // A later fade-in may have started meanwhile
+            if alpha == 0.0 {
+                let _: () = msg_send![strip, setHidden: YES];
+            }
+        });
+    }
+}
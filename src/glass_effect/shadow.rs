@@ -0,0 +1,177 @@
+//! Drop shadow view beneath a glass view
+//!
+//! The glass layer masks to its bounds, so it can't cast a shadow itself. The
+//! shadow is drawn by a layer-backed sibling placed below the glass (and its
+//! opaque base), whose mask cuts out the glass area so the shadow never shows
+//! through the translucent material. Both follow the glass's `mask` if set.
+
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::path::{cutout_path, rounded_rect_path, CGMutablePathRef, CGPathRelease};
+use super::registry::ViewHandle;
+use super::shape::{mask_path, radii_path};
+use super::utils::color_from_css;
+use crate::models::{GlassShadow, LiquidGlassConfig};
+
+/// NSWindowOrderingMode
+const NS_WINDOW_BELOW: i64 = -1;
+
+/// Add, update or remove the shadow view behind a glass view
+///
+/// The shadow is placed directly below `below`, which is the opaque base if
+/// there is one, else the glass itself.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `glass` must be a valid NSView already inserted into its superview
+/// - `below` must be `glass` or a sibling of it
+///
+/// Returns the shadow view handle if `config.shadow` is set
+pub unsafe fn apply_shadow(
+    glass: id,
+    below: id,
+    config: &LiquidGlassConfig,
+    existing: Option<ViewHandle>,
+) -> Option<ViewHandle> {
+    let superview: id = msg_send![glass, superview];
+    let Some(shadow) = config.shadow.as_ref().filter(|_| superview != nil) else {
+        if let Some(view) = existing {
+            let _: () = msg_send![view.as_id(), removeFromSuperview];
+            view.retire();
+        }
+        return None;
+    };
+
+    let frame: NSRect = msg_send![glass, frame];
+    let handle = existing.unwrap_or_else(|| {
+        let view: id = msg_send![class!(NSView), alloc];
+        let view: id = msg_send![view, initWithFrame: frame];
+        let _: () = msg_send![view, setWantsLayer: YES];
+        ViewHandle::owned(view)
+    });
+    let view = handle.as_id();
+
+    // Re-positioning an existing subview moves it, so an opaque base added
+    // after the shadow still ends up above it
+    let _: () =
+        msg_send![superview, addSubview: view positioned: NS_WINDOW_BELOW relativeTo: below];
+
+    let mask: u64 = msg_send![glass, autoresizingMask];
+    let hidden: BOOL = msg_send![glass, isHidden];
+    layout_shadow(view, frame, mask, hidden, config);
+
+    let layer: id = msg_send![view, layer];
+    if layer != nil {
+        let color = color_from_css(&shadow.color)
+            .unwrap_or_else(|| msg_send![class!(NSColor), shadowColor]);
+        let cg_color: id = msg_send![color, CGColor];
+        let _: () = msg_send![layer, setShadowColor: cg_color];
+        let _: () = msg_send![layer, setShadowOpacity: 1.0f32];
+        let _: () = msg_send![layer, setShadowRadius: shadow.radius.max(0.0)];
+        // The layer isn't flipped, so a downward offset is negative
+        let offset = NSSize::new(shadow.offset_x, -shadow.offset_y);
+        let _: () = msg_send![layer, setShadowOffset: offset];
+        let _: () = msg_send![layer, setMasksToBounds: NO];
+    }
+
+    Some(handle)
+}
+
+/// Move the shadow view to `frame` and rebuild its shadow path and cutout
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a shadow view created by [`apply_shadow`]
+pub unsafe fn layout_shadow(
+    view: id,
+    frame: NSRect,
+    mask: u64,
+    hidden: BOOL,
+    config: &LiquidGlassConfig,
+) {
+    let _: () = msg_send![view, setFrame: frame];
+    let _: () = msg_send![view, setAutoresizingMask: mask];
+    let _: () = msg_send![view, setHidden: hidden];
+
+    let layer: id = msg_send![view, layer];
+    let Some(shadow) = config.shadow.as_ref() else {
+        return;
+    };
+    if layer == nil {
+        return;
+    }
+
+    // Rebuilt on every resize step, so skip the implicit path animation
+    let _: () = msg_send![class!(CATransaction), begin];
+    let _: () = msg_send![class!(CATransaction), setDisableActions: YES];
+
+    let bounds: NSRect = msg_send![layer, bounds];
+    let path = glass_outline(view, bounds, config);
+    let _: () = msg_send![layer, setShadowPath: path];
+    CGPathRelease(path);
+
+    let cutout = cutout_mask(layer);
+    let _: () = msg_send![cutout, setFrame: bounds];
+    let path = cutout_path(
+        shadow_extent(bounds, shadow),
+        glass_outline(view, bounds, config),
+    );
+    let _: () = msg_send![cutout, setPath: path];
+    CGPathRelease(path);
+
+    let _: () = msg_send![class!(CATransaction), commit];
+}
+
+/// Outline of the glass in the shadow view's space: its mask if it has a
+/// valid one, else its corner radii or rounded rectangle
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a shadow view sharing the glass frame
+unsafe fn glass_outline(view: id, bounds: NSRect, config: &LiquidGlassConfig) -> CGMutablePathRef {
+    config
+        .mask
+        .as_ref()
+        .and_then(|mask| mask_path(view, mask).ok())
+        .unwrap_or_else(|| match config.corner_radii {
+            Some(radii) => radii_path(view, radii),
+            None => rounded_rect_path(bounds, config.corner_radius),
+        })
+}
+
+/// The layer's even-odd shape mask, created on first use
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `layer` must be a valid CALayer
+unsafe fn cutout_mask(layer: id) -> id {
+    let mask: id = msg_send![layer, mask];
+    if mask != nil {
+        return mask;
+    }
+
+    let mask: id = msg_send![class!(CAShapeLayer), layer];
+    let even_odd: id = msg_send![class!(NSString), stringWithUTF8String: c"even-odd".as_ptr()];
+    let _: () = msg_send![mask, setFillRule: even_odd];
+    let _: () = msg_send![layer, setMask: mask];
+    mask
+}
+
+/// Rect large enough to contain the blurred, offset shadow
+fn shadow_extent(bounds: NSRect, shadow: &GlassShadow) -> NSRect {
+    let margin =
+        shadow.radius.max(0.0) * 2.0 + shadow.offset_x.abs().max(shadow.offset_y.abs()) + 1.0;
+    NSRect::new(
+        NSPoint::new(bounds.origin.x - margin, bounds.origin.y - margin),
+        NSSize::new(
+            bounds.size.width + margin * 2.0,
+            bounds.size.height + margin * 2.0,
+        ),
+    )
+}
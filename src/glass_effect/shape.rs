@@ -0,0 +1,348 @@
+//! Clip glass to custom shapes
+//!
+//! Three shapes share a view's `CAShapeLayer` mask, in order of precedence: a
+//! [`GlassMask`] in the view's own coordinates, per-corner radii, and the
+//! silhouette of a custom-shaped window, described in content view
+//! coordinates and converted into the view's coordinate space. The mask is
+//! rebuilt on every layout pass, so it follows resizes.
+
+use std::ffi::CStr;
+
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
+use log::warn;
+use objc::runtime::{Sel, BOOL};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::path::{
+    build_path, corner_radii_path, ellipse_path, parse_svg_path, rounded_rect_path,
+    CGMutablePathRef, CGPathRelease, Segment,
+};
+use crate::models::{CornerRadii, GlassMask, LiquidGlassConfig, WindowShape};
+
+/// Name given to the mask layer so it can be told apart from other masks
+const MASK_NAME: &CStr = c"liquidGlassWindowShape";
+
+/// Install, update or (without any shape in `config`) remove the shape mask
+/// of a view
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn apply_shape(view: id, config: &LiquidGlassConfig) {
+    let superview: id = msg_send![view, superview];
+    if superview == nil {
+        return;
+    }
+
+    let _: () = msg_send![view, setWantsLayer: YES];
+    let layer: id = msg_send![view, layer];
+    if layer == nil {
+        return;
+    }
+
+    let existing = own_mask(layer);
+
+    let path = match (&config.mask, config.corner_radii, &config.window_shape) {
+        (Some(mask), _, _) => mask_path(view, mask),
+        (None, Some(radii), _) => Ok(radii_path(view, radii)),
+        (None, None, Some(shape)) => silhouette_path(view, superview, shape),
+        (None, None, None) => {
+            if existing != nil {
+                let _: () = msg_send![layer, setMask: nil];
+            }
+            return;
+        }
+    };
+    let path = match path {
+        Ok(path) => path,
+        Err(e) => {
+            warn!("Ignoring invalid glass shape: {}", e);
+            if existing != nil {
+                let _: () = msg_send![layer, setMask: nil];
+            }
+            return;
+        }
+    };
+
+    // Rebuilt on every resize step, so skip the implicit path animation
+    let _: () = msg_send![class!(CATransaction), begin];
+    let _: () = msg_send![class!(CATransaction), setDisableActions: YES];
+
+    let mask = if existing != nil {
+        existing
+    } else {
+        let mask: id = msg_send![class!(CAShapeLayer), layer];
+        let name: id = msg_send![class!(NSString), stringWithUTF8String: MASK_NAME.as_ptr()];
+        let _: () = msg_send![mask, setName: name];
+        let _: () = msg_send![layer, setMask: mask];
+        mask
+    };
+
+    let bounds: NSRect = msg_send![layer, bounds];
+    let _: () = msg_send![mask, setFrame: bounds];
+    let _: () = msg_send![mask, setPath: path];
+
+    let _: () = msg_send![class!(CATransaction), commit];
+
+    CGPathRelease(path);
+}
+
+/// The layer's mask if it was installed by [`apply_shape`], else nil
+unsafe fn own_mask(layer: id) -> id {
+    let mask: id = msg_send![layer, mask];
+    if mask == nil {
+        return nil;
+    }
+
+    let name: id = msg_send![mask, name];
+    if name == nil {
+        return nil;
+    }
+    let expected: id = msg_send![class!(NSString), stringWithUTF8String: MASK_NAME.as_ptr()];
+    let equal: BOOL = msg_send![name, isEqualToString: expected];
+    if equal != NO {
+        mask
+    } else {
+        nil
+    }
+}
+
+/// Build a glass mask in `view`'s coordinate space
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn mask_path(view: id, mask: &GlassMask) -> Result<CGMutablePathRef, String> {
+    let bounds: NSRect = msg_send![view, bounds];
+    let (width, height) = (bounds.size.width, bounds.size.height);
+
+    match mask {
+        GlassMask::RoundedRect { radius } => Ok(rounded_rect_path(bounds, *radius)),
+        GlassMask::Capsule => Ok(rounded_rect_path(bounds, width.min(height) / 2.0)),
+        GlassMask::Circle => Ok(ellipse_path(centered_square(bounds))),
+        GlassMask::Path { d, view_box } => {
+            let segments = parse_svg_path(d)?;
+            let flipped: BOOL = msg_send![view, isFlipped];
+            Ok(scaled_path(
+                &segments,
+                bounds,
+                *view_box,
+                flipped != NO,
+                |point| point,
+            ))
+        }
+    }
+}
+
+/// Build per-corner radii in `view`'s coordinate space
+///
+/// The caller owns the returned path and must release it with
+/// [`CGPathRelease`].
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+pub unsafe fn radii_path(view: id, radii: CornerRadii) -> CGMutablePathRef {
+    let bounds: NSRect = msg_send![view, bounds];
+    let flipped: BOOL = msg_send![view, isFlipped];
+    corner_radii_path(
+        bounds,
+        [
+            radii.top_left,
+            radii.top_right,
+            radii.bottom_right,
+            radii.bottom_left,
+        ],
+        flipped != NO,
+    )
+}
+
+/// Build SVG path segments (top-left origin) into `bounds`, scaling them by
+/// `view_box` if set and mapping each point through `map` afterwards
+///
+/// # Safety
+/// `map` must not unwind
+unsafe fn scaled_path(
+    segments: &[Segment],
+    bounds: NSRect,
+    view_box: Option<[f64; 2]>,
+    flipped: bool,
+    map: impl Fn((f64, f64)) -> (f64, f64),
+) -> CGMutablePathRef {
+    build_path(segments, |point| {
+        map(svg_point_in(point, bounds, view_box, flipped))
+    })
+}
+
+/// Largest square centered in `bounds`, the frame of a circle mask
+fn centered_square(bounds: NSRect) -> NSRect {
+    let (width, height) = (bounds.size.width, bounds.size.height);
+    let side = width.min(height);
+    let origin = NSPoint::new(
+        bounds.origin.x + (width - side) / 2.0,
+        bounds.origin.y + (height - side) / 2.0,
+    );
+    NSRect::new(origin, NSSize::new(side, side))
+}
+
+/// Map a point of SVG path data (top-left origin) into `bounds`, scaling it
+/// by `view_box` if set
+fn svg_point_in(
+    (x, y): (f64, f64),
+    bounds: NSRect,
+    view_box: Option<[f64; 2]>,
+    flipped: bool,
+) -> (f64, f64) {
+    let (scale_x, scale_y) = match view_box {
+        Some([width, height]) if width > 0.0 && height > 0.0 => {
+            (bounds.size.width / width, bounds.size.height / height)
+        }
+        _ => (1.0, 1.0),
+    };
+
+    let (x, y) = (x * scale_x, y * scale_y);
+    let y = if flipped { y } else { bounds.size.height - y };
+    (bounds.origin.x + x, bounds.origin.y + y)
+}
+
+/// Build the silhouette in `view`'s coordinate space
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView hosted in `superview`
+unsafe fn silhouette_path(
+    view: id,
+    superview: id,
+    shape: &WindowShape,
+) -> Result<CGMutablePathRef, String> {
+    let bounds: NSRect = msg_send![superview, bounds];
+
+    match shape {
+        WindowShape::Auto => {
+            let rect: NSRect = msg_send![view, convertRect: bounds fromView: superview];
+            Ok(rounded_rect_path(rect, window_corner_radius(view)))
+        }
+        WindowShape::Rounded { radius } => {
+            let rect: NSRect = msg_send![view, convertRect: bounds fromView: superview];
+            Ok(rounded_rect_path(rect, *radius))
+        }
+        WindowShape::Path { d, view_box } => {
+            let segments = parse_svg_path(d)?;
+            let flipped: BOOL = msg_send![superview, isFlipped];
+
+            // Path data uses a top-left origin in content view points
+            Ok(scaled_path(
+                &segments,
+                bounds,
+                *view_box,
+                flipped != NO,
+                |(x, y)| {
+                    let point: NSPoint =
+                        msg_send![view, convertPoint: NSPoint::new(x, y) fromView: superview];
+                    (point.x, point.y)
+                },
+            ))
+        }
+    }
+}
+
+/// Corner radius of the window hosting `view`
+///
+/// Read from the content view's layer, where custom-shaped borderless windows
+/// usually set it, falling back to the window's private `_cornerRadius`.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `view` must be a valid NSView
+unsafe fn window_corner_radius(view: id) -> f64 {
+    let window: id = msg_send![view, window];
+    if window == nil {
+        return 0.0;
+    }
+
+    let content_view: id = msg_send![window, contentView];
+    let content_layer: id = if content_view == nil {
+        nil
+    } else {
+        msg_send![content_view, layer]
+    };
+    if content_layer != nil {
+        let radius: f64 = msg_send![content_layer, cornerRadius];
+        if radius > 0.0 {
+            return radius;
+        }
+    }
+
+    let private_sel = Sel::register("_cornerRadius");
+    let responds: BOOL = msg_send![window, respondsToSelector: private_sel];
+    if responds != NO {
+        objc::__send_message(&*window, private_sel, ()).unwrap_or(0.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: f64, y: f64, width: f64, height: f64) -> NSRect {
+        NSRect::new(NSPoint::new(x, y), NSSize::new(width, height))
+    }
+
+    fn assert_rect(actual: NSRect, expected: NSRect) {
+        assert_eq!(
+            (actual.origin.x, actual.origin.y),
+            (expected.origin.x, expected.origin.y)
+        );
+        assert_eq!(
+            (actual.size.width, actual.size.height),
+            (expected.size.width, expected.size.height)
+        );
+    }
+
+    #[test]
+    fn circle_fits_the_shorter_side() {
+        assert_rect(
+            centered_square(rect(0.0, 0.0, 200.0, 100.0)),
+            rect(50.0, 0.0, 100.0, 100.0),
+        );
+        assert_rect(
+            centered_square(rect(10.0, 20.0, 40.0, 80.0)),
+            rect(10.0, 40.0, 40.0, 40.0),
+        );
+        assert_rect(
+            centered_square(rect(0.0, 0.0, 0.0, 50.0)),
+            rect(0.0, 25.0, 0.0, 0.0),
+        );
+    }
+
+    #[test]
+    fn svg_points_map_into_bounds() {
+        let bounds = rect(10.0, 20.0, 200.0, 100.0);
+        for (point, view_box, flipped, expected) in [
+            // Flipped views share the top-left origin
+            ((5.0, 5.0), None, true, (15.0, 25.0)),
+            // Unflipped views count y up from the bottom
+            ((5.0, 5.0), None, false, (15.0, 115.0)),
+            ((0.0, 0.0), None, false, (10.0, 120.0)),
+            // A viewBox scales each axis to the bounds
+            ((1.0, 1.0), Some([1.0, 1.0]), true, (210.0, 120.0)),
+            ((0.5, 0.5), Some([1.0, 2.0]), true, (110.0, 45.0)),
+            ((0.5, 0.5), Some([1.0, 2.0]), false, (110.0, 95.0)),
+            // Degenerate viewBoxes are ignored
+            ((5.0, 5.0), Some([0.0, 10.0]), true, (15.0, 25.0)),
+            ((5.0, 5.0), Some([-1.0, 10.0]), true, (15.0, 25.0)),
+        ] {
+            assert_eq!(
+                svg_point_in(point, bounds, view_box, flipped),
+                expected,
+                "{point:?} with {view_box:?}, flipped: {flipped}"
+            );
+        }
+    }
+}
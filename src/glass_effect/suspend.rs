@@ -0,0 +1,49 @@
+//! Global suspension of every glass view
+//!
+//! While suspended, each registered view is rendered from a flat variant of
+//! its config: the glass is hidden and its opaque base stands in for it. The
+//! registry keeps the real configs, so resuming only has to re-apply them,
+//! and configs changed while suspended take effect on resume.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use tauri::{AppHandle, Manager, Runtime};
+
+use super::operations::relayout_glass_views;
+use super::registry::GlassViewRegistry;
+use super::utils::run_on_main_sync;
+use crate::error::Result;
+use crate::models::LiquidGlassConfig;
+
+static SUSPENDED: AtomicBool = AtomicBool::new(false);
+
+/// Whether glass effects are currently suspended
+pub fn is_suspended() -> bool {
+    SUSPENDED.load(Ordering::SeqCst)
+}
+
+/// The config applied in place of `config` while suspended
+pub fn flat_config(config: &LiquidGlassConfig) -> LiquidGlassConfig {
+    LiquidGlassConfig {
+        opaque: true,
+        ..config.clone()
+    }
+}
+
+/// Suspend or resume every glass view, re-applying their configs
+///
+/// Returns false if the state was already `suspended`.
+pub fn set_suspended<R: Runtime>(app: &AppHandle<R>, suspended: bool) -> Result<bool> {
+    if SUSPENDED.swap(suspended, Ordering::SeqCst) == suspended {
+        return Ok(false);
+    }
+
+    let app = app.clone();
+    run_on_main_sync(move || unsafe {
+        match app.try_state::<GlassViewRegistry>() {
+            Some(registry) => relayout_glass_views(&registry, None),
+            None => Ok(()),
+        }
+    })?;
+    Ok(true)
+}
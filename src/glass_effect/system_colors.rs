@@ -0,0 +1,55 @@
+//! Read the user's accent and highlight colors and follow their changes
+//!
+//! The colors are dynamic, so they are resolved against the application's
+//! effective appearance and reported as `#rrggbbaa` sRGB strings.
+
+use std::cell::RefCell;
+
+use cocoa::base::{id, nil};
+use objc::{class, msg_send, sel, sel_impl};
+
+use super::observer::observe_notification;
+use super::utils::css_from_color;
+use crate::models::SystemColors;
+
+/// Resolve the current system colors
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn system_colors() -> SystemColors {
+    let app: id = msg_send![class!(NSApplication), sharedApplication];
+    let appearance: id = msg_send![app, effectiveAppearance];
+
+    // Dynamic colors resolve against the current drawing appearance
+    let previous: id = msg_send![class!(NSAppearance), currentAppearance];
+    let _: () = msg_send![class!(NSAppearance), setCurrentAppearance: appearance];
+
+    let resolve = |color: id| css_from_color(color).unwrap_or_default();
+    let colors = SystemColors {
+        accent: resolve(msg_send![class!(NSColor), controlAccentColor]),
+        highlight: resolve(msg_send![class!(NSColor), selectedTextBackgroundColor]),
+        focus_ring: resolve(msg_send![class!(NSColor), keyboardFocusIndicatorColor]),
+    };
+
+    let _: () = msg_send![class!(NSAppearance), setCurrentAppearance: previous];
+    colors
+}
+
+/// Invoke `on_change` with the resolved colors whenever
+/// `NSSystemColorsDidChangeNotification` reports a change
+///
+/// The notification also fires for colors not reported here, so only actual
+/// changes are forwarded.
+///
+/// # Safety
+/// Must be called on the main thread
+pub unsafe fn observe_system_colors(on_change: impl Fn(&SystemColors) + 'static) {
+    let last = RefCell::new(system_colors());
+    observe_notification(c"NSSystemColorsDidChangeNotification", nil, move |_| {
+        let colors = system_colors();
+        if *last.borrow() != colors {
+            on_change(&colors);
+            last.replace(colors);
+        }
+    });
+}
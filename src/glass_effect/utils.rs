@@ -1,10 +1,49 @@
 //! Utility functions for macOS native code
 
-use cocoa::base::id;
+use std::ffi::c_void;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Mutex, OnceLock};
+
+use cocoa::base::{id, nil, NO};
 use dispatch::Queue;
-use objc::runtime::{Class, BOOL};
+use log::warn;
+use objc::declare::ClassDecl;
+use objc::runtime::{Class, Sel, BOOL};
 use objc::{class, msg_send, sel, sel_impl};
 
+use crate::color::{parse_css_color, Rgba};
+use crate::error::{Error, Result};
+use crate::models::{Appearance, MacOSVersion, PrivateApiReport, SelectorProbe};
+
+extern "C" {
+    fn objc_getAssociatedObject(object: id, key: *const c_void) -> id;
+    fn objc_setAssociatedObject(object: id, key: *const c_void, value: id, policy: usize);
+}
+
+/// OBJC_ASSOCIATION_RETAIN_NONATOMIC
+const ASSOCIATION_RETAIN_NONATOMIC: usize = 1;
+
+/// Start declaring an Objective-C class, or get the class already
+/// registered under `name`
+///
+/// Class names are process-wide, so another copy of the plugin may own
+/// `name` already; its class is returned as `Err` instead of panicking.
+pub fn declare_class(
+    name: &str,
+    superclass: &Class,
+) -> std::result::Result<ClassDecl, &'static Class> {
+    loop {
+        if let Some(existing) = Class::get(name) {
+            return Err(existing);
+        }
+        // Only fails if `name` was registered since the lookup, which the
+        // next lookup then finds
+        if let Some(decl) = ClassDecl::new(name, superclass) {
+            return Ok(decl);
+        }
+    }
+}
+
 /// Execute a closure on the main thread synchronously.
 ///
 /// This is necessary because all NSView operations must be performed on the main thread.
@@ -30,6 +69,33 @@ where
     }
 }
 
+/// Execute a fallible closure on the main thread synchronously
+///
+/// Like [`run_on_main_sync`], but a panic in the closure or a lost reply
+/// becomes [`Error::MainThreadDispatchFailed`] instead of unwinding into the
+/// caller (or aborting inside the dispatch callback).
+pub fn try_run_on_main_sync<F, T>(f: F) -> Result<T>
+where
+    F: FnOnce() -> Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let f =
+        move || catch_unwind(AssertUnwindSafe(f)).unwrap_or(Err(Error::MainThreadDispatchFailed));
+
+    if is_main_thread() {
+        f()
+    } else {
+        use std::sync::mpsc;
+        let (tx, rx) = mpsc::channel();
+
+        Queue::main().exec_async(move || {
+            let _ = tx.send(f());
+        });
+
+        rx.recv().unwrap_or(Err(Error::MainThreadDispatchFailed))
+    }
+}
+
 /// Check if the current thread is the main thread
 fn is_main_thread() -> bool {
     unsafe {
@@ -38,33 +104,12 @@ fn is_main_thread() -> bool {
     }
 }
 
-/// Parse hex color string to NSColor
+/// Parse a CSS color string to NSColor
 ///
-/// Supports #RRGGBB and #RRGGBBAA formats
-pub fn color_from_hex(hex: &str) -> Option<id> {
-    let hex = hex.trim().trim_start_matches('#');
-
-    if hex.len() != 6 && hex.len() != 8 {
-        return None;
-    }
-
-    let rgba = u32::from_str_radix(hex, 16).ok()?;
-
-    let (r, g, b, a) = if hex.len() == 6 {
-        (
-            ((rgba >> 16) & 0xFF) as f64 / 255.0,
-            ((rgba >> 8) & 0xFF) as f64 / 255.0,
-            (rgba & 0xFF) as f64 / 255.0,
-            1.0,
-        )
-    } else {
-        (
-            ((rgba >> 24) & 0xFF) as f64 / 255.0,
-            ((rgba >> 16) & 0xFF) as f64 / 255.0,
-            ((rgba >> 8) & 0xFF) as f64 / 255.0,
-            (rgba & 0xFF) as f64 / 255.0,
-        )
-    };
+/// Supports hex (#RGB, #RGBA, #RRGGBB, #RRGGBBAA), rgb()/rgba(), hsl()/hsla()
+/// and CSS named colors
+pub fn color_from_css(css: &str) -> Option<id> {
+    let Rgba { r, g, b, a } = parse_css_color(css)?;
 
     unsafe {
         let color: id = msg_send![
@@ -78,7 +123,206 @@ pub fn color_from_hex(hex: &str) -> Option<id> {
     }
 }
 
+/// Format an NSColor as `#rrggbbaa`, `None` for nil or non-RGB colors
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `color` must be nil or a valid NSColor
+pub unsafe fn css_from_color(color: id) -> Option<String> {
+    if color == nil {
+        return None;
+    }
+    let srgb: id = msg_send![class!(NSColorSpace), sRGBColorSpace];
+    let color: id = msg_send![color, colorUsingColorSpace: srgb];
+    if color == nil {
+        return None;
+    }
+
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let r: f64 = msg_send![color, redComponent];
+    let g: f64 = msg_send![color, greenComponent];
+    let b: f64 = msg_send![color, blueComponent];
+    let a: f64 = msg_send![color, alphaComponent];
+    Some(format!(
+        "#{:02x}{:02x}{:02x}{:02x}",
+        channel(r),
+        channel(g),
+        channel(b),
+        channel(a)
+    ))
+}
+
 /// Check if NSGlassEffectView class is available
+///
+/// Class lookup is thread-safe, so this may be called from any thread. The
+/// result is cached since the class cannot appear while the process runs.
 pub fn glass_class_available() -> bool {
-    Class::get("NSGlassEffectView").is_some()
+    static AVAILABLE: OnceLock<bool> = OnceLock::new();
+
+    *AVAILABLE.get_or_init(|| Class::get("NSGlassEffectView").is_some())
+}
+
+/// NSGlassEffectView properties the plugin sets through selector lookup
+const PROBED_PROPERTIES: [&str; 5] = [
+    "variant",
+    "scrimState",
+    "subduedState",
+    "interactive",
+    "tintColor",
+];
+
+/// Setter selector names of a property, private `set_<key>:` first, then
+/// public `setKey:`
+pub fn setter_names(key: &str) -> [String; 2] {
+    let mut chars = key.chars();
+    let capitalized = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+    [format!("set_{key}:"), format!("set{capitalized}:")]
+}
+
+/// Getter selector names of a property, private `_<key>` first, then public
+/// `<key>`
+pub fn getter_names(key: &str) -> [String; 2] {
+    [format!("_{key}"), key.to_string()]
+}
+
+/// Report which NSGlassEffectView setters and getters the running OS build
+/// responds to (cached)
+///
+/// Variant, scrim, subdued and interactive go through private selectors
+/// that have changed between betas; this shows which of them exist without
+/// creating a view. Uses `instancesRespondToSelector:`, so it may be called
+/// from any thread.
+pub fn probe_private_api() -> PrivateApiReport {
+    static REPORT: OnceLock<PrivateApiReport> = OnceLock::new();
+
+    REPORT
+        .get_or_init(|| {
+            let Some(glass_class) = Class::get("NSGlassEffectView") else {
+                return PrivateApiReport::default();
+            };
+            let first_responding = |names: [String; 2]| {
+                names.into_iter().find(|name| {
+                    let responds: BOOL = unsafe {
+                        msg_send![glass_class, instancesRespondToSelector: Sel::register(name)]
+                    };
+                    responds != NO
+                })
+            };
+
+            PrivateApiReport {
+                class_available: true,
+                properties: PROBED_PROPERTIES
+                    .into_iter()
+                    .map(|key| SelectorProbe {
+                        key: key.to_string(),
+                        setter: first_responding(setter_names(key)),
+                        getter: first_responding(getter_names(key)),
+                    })
+                    .collect(),
+            }
+        })
+        .clone()
+}
+
+/// Warn that a requested property can't be applied on this OS build
+///
+/// Logged once per property so reapplying a config doesn't flood the log.
+pub fn warn_unapplied(key: &'static str) {
+    static WARNED: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+    let Ok(mut warned) = WARNED.lock() else {
+        return;
+    };
+    if !warned.contains(&key) {
+        warned.push(key);
+        warn!(
+            "NSGlassEffectView responds to no setter for `{key}` on this macOS build; \
+             the requested value is ignored"
+        );
+    }
+}
+
+/// Mirrors the NSOperatingSystemVersion struct
+#[repr(C)]
+struct NSOperatingSystemVersion {
+    major: isize,
+    minor: isize,
+    patch: isize,
+}
+
+/// Get the running macOS version (cached)
+///
+/// NSProcessInfo is thread-safe, so this may be called from any thread.
+pub fn macos_version() -> MacOSVersion {
+    static VERSION: OnceLock<MacOSVersion> = OnceLock::new();
+
+    VERSION
+        .get_or_init(|| unsafe {
+            let info: id = msg_send![class!(NSProcessInfo), processInfo];
+            let version: NSOperatingSystemVersion = msg_send![info, operatingSystemVersion];
+            MacOSVersion::new(
+                version.major as u32,
+                version.minor as u32,
+                version.patch as u32,
+            )
+        })
+        .clone()
+}
+
+/// Effective appearance of a view or window
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` must be a valid NSView or NSWindow
+pub unsafe fn effective_appearance(object: id) -> Appearance {
+    let appearance: id = msg_send![object, effectiveAppearance];
+    if appearance == nil {
+        return Appearance::Light;
+    }
+
+    let aqua: id =
+        msg_send![class!(NSString), stringWithUTF8String: c"NSAppearanceNameAqua".as_ptr()];
+    let dark_aqua: id =
+        msg_send![class!(NSString), stringWithUTF8String: c"NSAppearanceNameDarkAqua".as_ptr()];
+    let names: id =
+        msg_send![class!(NSArray), arrayWithObjects: [aqua, dark_aqua].as_ptr() count: 2usize];
+
+    let best: id = msg_send![appearance, bestMatchFromAppearancesWithNames: names];
+    if best == nil {
+        return Appearance::Light;
+    }
+    let is_dark: BOOL = msg_send![best, isEqualToString: dark_aqua];
+    if is_dark != NO {
+        Appearance::Dark
+    } else {
+        Appearance::Light
+    }
+}
+
+/// Object associated with `object` under `key`, or nil
+///
+/// The address of `key` identifies the association, so pass a `static`.
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` must be a valid Objective-C object
+pub unsafe fn associated_object(object: id, key: &'static u8) -> id {
+    objc_getAssociatedObject(object, key as *const u8 as *const c_void)
+}
+
+/// Retain `value` for the lifetime of `object`, under `key`
+///
+/// # Safety
+/// - Must be called on the main thread
+/// - `object` and `value` must be valid Objective-C objects
+pub unsafe fn set_associated_object(object: id, key: &'static u8, value: id) {
+    objc_setAssociatedObject(
+        object,
+        key as *const u8 as *const c_void,
+        value,
+        ASSOCIATION_RETAIN_NONATOMIC,
+    );
 }
@@ -0,0 +1,433 @@
+//! Native NSWindow configuration helpers
+
+use std::time::Duration;
+
+use cocoa::base::{id, nil, NO, YES};
+use cocoa::foundation::{NSPoint, NSRect, NSSize};
+use objc::runtime::BOOL;
+use objc::{class, msg_send, sel, sel_impl};
+use tauri::{LogicalPosition, LogicalSize, Runtime, WebviewWindow};
+
+use super::operations::find_webview;
+use super::registry::ViewHandle;
+use super::safe_area::safe_area_insets;
+use super::utils::{color_from_css, run_on_main_sync};
+use crate::error::{Error, Result};
+use crate::models::{SafeAreaInsets, WindowLevelConfig};
+
+// ============================================================================
+// Constants
+// ============================================================================
+
+/// NSWindowCollectionBehavior flags
+const COLLECTION_CAN_JOIN_ALL_SPACES: u64 = 1 << 0;
+const COLLECTION_STATIONARY: u64 = 1 << 4;
+const COLLECTION_IGNORES_CYCLE: u64 = 1 << 6;
+const COLLECTION_FULL_SCREEN_AUXILIARY: u64 = 1 << 8;
+
+/// NSWindowStyleMaskFullSizeContentView
+const STYLE_FULL_SIZE_CONTENT_VIEW: u64 = 1 << 15;
+
+/// NSWindowTitleHidden
+const TITLE_HIDDEN: i64 = 1;
+
+/// Flags managed by [`WindowLevelConfig`]; all other bits are preserved
+const MANAGED_COLLECTION_FLAGS: u64 = COLLECTION_CAN_JOIN_ALL_SPACES
+    | COLLECTION_STATIONARY
+    | COLLECTION_IGNORES_CYCLE
+    | COLLECTION_FULL_SCREEN_AUXILIARY;
+
+// ============================================================================
+// Operations
+// ============================================================================
+
+/// Get a handle to the window's NSWindow
+pub fn ns_window_handle<R: Runtime>(window: &WebviewWindow<R>) -> Result<ViewHandle> {
+    window
+        .ns_window()
+        .map(|ns_window| ViewHandle::new(ns_window as id))
+        .map_err(|_| Error::WindowNotFound(window.label().to_string()))
+}
+
+/// Set the window level and collection behavior
+pub fn set_window_level<R: Runtime>(
+    window: &WebviewWindow<R>,
+    config: &WindowLevelConfig,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    let level = config.level.ns_level();
+
+    let mut flags = 0;
+    for (enabled, flag) in [
+        (config.can_join_all_spaces, COLLECTION_CAN_JOIN_ALL_SPACES),
+        (config.stationary, COLLECTION_STATIONARY),
+        (config.ignores_cycle, COLLECTION_IGNORES_CYCLE),
+        (
+            config.full_screen_auxiliary,
+            COLLECTION_FULL_SCREEN_AUXILIARY,
+        ),
+    ] {
+        if enabled {
+            flags |= flag;
+        }
+    }
+
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        let _: () = msg_send![ns_window, setLevel: level];
+
+        let current: u64 = msg_send![ns_window, collectionBehavior];
+        let behavior = (current & !MANAGED_COLLECTION_FLAGS) | flags;
+        let _: () = msg_send![ns_window, setCollectionBehavior: behavior];
+    });
+
+    Ok(())
+}
+
+/// Set the window's background color, or make it clear with `None`
+///
+/// The window's opacity is left unchanged, so a translucent color only shows
+/// through on windows created transparent.
+pub fn set_window_background<R: Runtime>(
+    window: &WebviewWindow<R>,
+    color: Option<&str>,
+) -> Result<()> {
+    if let Some(css) = color {
+        if color_from_css(css).is_none() {
+            return Err(Error::InvalidColorFormat(css.to_string()));
+        }
+    }
+
+    let ns_window = ns_window_handle(window)?;
+    let color = color.map(String::from);
+
+    run_on_main_sync(move || unsafe {
+        apply_window_background(ns_window.as_id(), color.as_deref().and_then(color_from_css));
+    });
+
+    Ok(())
+}
+
+/// Toggle whether the window's webview draws its own opaque background
+///
+/// Uses the same `drawsBackground` KVC key the transparency check reads.
+pub fn set_webview_draws_background<R: Runtime>(
+    window: &WebviewWindow<R>,
+    draws_background: bool,
+) -> Result<()> {
+    with_webview(window, move |webview| unsafe {
+        apply_draws_background(webview, draws_background);
+    })
+}
+
+/// Switch the window between transparent (non-opaque with a clear
+/// background) and opaque (the system window background color)
+pub fn set_window_transparent<R: Runtime>(
+    window: &WebviewWindow<R>,
+    transparent: bool,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        apply_window_transparency(ns_window.as_id(), transparent);
+    });
+    Ok(())
+}
+
+/// Switch the window's webview between transparent (no background and a
+/// clear under-page color) and opaque (its default background)
+pub fn set_webview_transparent<R: Runtime>(
+    window: &WebviewWindow<R>,
+    transparent: bool,
+) -> Result<()> {
+    with_webview(window, move |webview| unsafe {
+        apply_webview_transparency(webview, transparent);
+    })
+}
+
+/// Run `f` on the main thread with the window's WKWebView
+///
+/// Fails with `WebviewNotFound` if the content view has none.
+fn with_webview<R: Runtime>(
+    window: &WebviewWindow<R>,
+    f: impl FnOnce(id) + Send + 'static,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+
+    let found = run_on_main_sync(move || unsafe {
+        let content_view: id = msg_send![ns_window.as_id(), contentView];
+        let Some(webview) = find_webview(content_view) else {
+            return false;
+        };
+        f(webview);
+        true
+    });
+
+    if found {
+        Ok(())
+    } else {
+        Err(Error::WebviewNotFound(window.label().to_string()))
+    }
+}
+
+/// Set the window's opacity and background color
+///
+/// # Safety
+/// Must be called on the main thread with a valid NSWindow
+pub unsafe fn apply_window_transparency(ns_window: id, transparent: bool) {
+    let background = if transparent {
+        None
+    } else {
+        let system: id = msg_send![class!(NSColor), windowBackgroundColor];
+        Some(system)
+    };
+    let _: () = msg_send![ns_window, setOpaque: if transparent { NO } else { YES }];
+    apply_window_background(ns_window, background);
+}
+
+/// Set the window's background color, or make it clear with `None`
+///
+/// # Safety
+/// Must be called on the main thread with a valid NSWindow and NSColor
+unsafe fn apply_window_background(ns_window: id, color: Option<id>) {
+    let background: id = match color {
+        Some(color) => color,
+        None => msg_send![class!(NSColor), clearColor],
+    };
+    let _: () = msg_send![ns_window, setBackgroundColor: background];
+}
+
+/// Set whether the webview draws its own background through the
+/// `drawsBackground` KVC key
+///
+/// # Safety
+/// Must be called on the main thread with a valid WKWebView
+unsafe fn apply_draws_background(webview: id, draws_background: bool) {
+    let value: id = msg_send![
+        class!(NSNumber),
+        numberWithBool: if draws_background { YES } else { NO }
+    ];
+    let key: id = msg_send![class!(NSString), stringWithUTF8String: c"drawsBackground".as_ptr()];
+    let _: () = msg_send![webview, setValue: value forKey: key];
+}
+
+/// Set whether the webview draws its background, and its under-page color
+/// (shown when overscrolling) where supported (macOS 12+)
+///
+/// # Safety
+/// Must be called on the main thread with a valid WKWebView
+pub unsafe fn apply_webview_transparency(webview: id, transparent: bool) {
+    apply_draws_background(webview, !transparent);
+
+    let responds: BOOL = msg_send![webview, respondsToSelector: sel!(setUnderPageBackgroundColor:)];
+    if responds != NO {
+        // nil restores the default
+        let color: id = if transparent {
+            msg_send![class!(NSColor), clearColor]
+        } else {
+            nil
+        };
+        let _: () = msg_send![webview, setUnderPageBackgroundColor: color];
+    }
+}
+
+/// Round a transparent window's corners and refresh its shadow
+///
+/// Makes the window non-opaque, hides the title bar chrome (the content view
+/// extends underneath it), masks the content view and its frame view to
+/// `radius`, and invalidates the shadow so it follows the rounded silhouette.
+pub fn round_window<R: Runtime>(window: &WebviewWindow<R>, radius: f64) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        clear_window_chrome(ns_window, true);
+
+        let content_view: id = msg_send![ns_window, contentView];
+        if content_view != nil {
+            // The frame view hosts the title bar; masking it too keeps its
+            // square corners from showing through the clear background
+            let frame_view: id = msg_send![content_view, superview];
+            for view in [content_view, frame_view] {
+                if view == nil {
+                    continue;
+                }
+                let _: () = msg_send![view, setWantsLayer: YES];
+                let layer: id = msg_send![view, layer];
+                if layer != nil {
+                    let _: () = msg_send![layer, setCornerRadius: radius];
+                    let _: () = msg_send![layer, setMasksToBounds: YES];
+                }
+            }
+        }
+
+        let _: () = msg_send![ns_window, setHasShadow: YES];
+        let _: () = msg_send![ns_window, invalidateShadow];
+    });
+
+    Ok(())
+}
+
+/// Make the window non-opaque with a clear background and a transparent
+/// title bar over a full-size content view, so glass behind the webview
+/// reaches the window edges
+pub fn make_window_transparent<R: Runtime>(
+    window: &WebviewWindow<R>,
+    hide_title: bool,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        clear_window_chrome(ns_window.as_id(), hide_title);
+    });
+    Ok(())
+}
+
+/// Clear the window's background and title bar
+///
+/// # Safety
+/// Must be called on the main thread with a valid NSWindow
+unsafe fn clear_window_chrome(ns_window: id, hide_title: bool) {
+    let clear: id = msg_send![class!(NSColor), clearColor];
+    let _: () = msg_send![ns_window, setOpaque: NO];
+    let _: () = msg_send![ns_window, setBackgroundColor: clear];
+
+    let style: u64 = msg_send![ns_window, styleMask];
+    let _: () = msg_send![ns_window, setStyleMask: style | STYLE_FULL_SIZE_CONTENT_VIEW];
+    let _: () = msg_send![ns_window, setTitlebarAppearsTransparent: YES];
+    if hide_title {
+        let _: () = msg_send![ns_window, setTitleVisibility: TITLE_HIDDEN];
+    }
+}
+
+/// Show the window above others without making it key or activating the app
+pub fn order_front_without_focus<R: Runtime>(window: &WebviewWindow<R>) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        let _: () = msg_send![ns_window.as_id(), orderFrontRegardless];
+    });
+    Ok(())
+}
+
+/// Move the window to a frame given in top-left logical screen coordinates,
+/// and optionally set its alpha, without animating
+pub fn set_window_frame<R: Runtime>(
+    window: &WebviewWindow<R>,
+    position: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+    alpha: Option<f64>,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        let _: () = msg_send![ns_window, setFrame: screen_frame(position, size) display: YES];
+        if let Some(alpha) = alpha {
+            let _: () = msg_send![ns_window, setAlphaValue: alpha];
+        }
+    });
+    Ok(())
+}
+
+/// Set the window's alpha immediately
+pub fn set_window_alpha<R: Runtime>(window: &WebviewWindow<R>, alpha: f64) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        let _: () = msg_send![ns_window.as_id(), setAlphaValue: alpha];
+    });
+    Ok(())
+}
+
+/// Insets of the window's content view that lie outside its screen's safe area
+pub fn content_safe_area_insets<R: Runtime>(window: &WebviewWindow<R>) -> Result<SafeAreaInsets> {
+    let ns_window = ns_window_handle(window)?;
+    Ok(run_on_main_sync(move || unsafe {
+        let content_view: id = msg_send![ns_window.as_id(), contentView];
+        if content_view == nil {
+            return SafeAreaInsets::default();
+        }
+        safe_area_insets(content_view)
+    }))
+}
+
+/// Animate the window's frame (top-left logical screen coordinates) and/or
+/// alpha with the system animator
+///
+/// Returns immediately; the animation runs on the main thread for `duration`.
+pub fn animate_window<R: Runtime>(
+    window: &WebviewWindow<R>,
+    frame: Option<(LogicalPosition<f64>, LogicalSize<f64>)>,
+    alpha: Option<f64>,
+    duration: Duration,
+) -> Result<()> {
+    let ns_window = ns_window_handle(window)?;
+    run_on_main_sync(move || unsafe {
+        let ns_window = ns_window.as_id();
+        let _: () = msg_send![class!(NSAnimationContext), beginGrouping];
+        let context: id = msg_send![class!(NSAnimationContext), currentContext];
+        let _: () = msg_send![context, setDuration: duration.as_secs_f64()];
+
+        let animator: id = msg_send![ns_window, animator];
+        if let Some((position, size)) = frame {
+            let _: () = msg_send![animator, setFrame: screen_frame(position, size) display: YES];
+        }
+        if let Some(alpha) = alpha {
+            let _: () = msg_send![animator, setAlphaValue: alpha];
+        }
+        let _: () = msg_send![class!(NSAnimationContext), endGrouping];
+    });
+    Ok(())
+}
+
+/// Convert a top-left logical frame to AppKit's bottom-left screen space,
+/// which is measured from the bottom of the primary screen
+///
+/// # Safety
+/// Must be called on the main thread
+unsafe fn screen_frame(position: LogicalPosition<f64>, size: LogicalSize<f64>) -> NSRect {
+    let screens: id = msg_send![class!(NSScreen), screens];
+    let count: usize = msg_send![screens, count];
+    let primary_height = if count == 0 {
+        0.0
+    } else {
+        let primary: id = msg_send![screens, objectAtIndex: 0usize];
+        let frame: NSRect = msg_send![primary, frame];
+        frame.size.height
+    };
+
+    NSRect::new(
+        NSPoint::new(position.x, primary_height - position.y - size.height),
+        NSSize::new(size.width, size.height),
+    )
+}
+
+/// Whether the primary mouse button is currently held down, e.g. while the
+/// user drags a window
+pub fn primary_mouse_button_down() -> bool {
+    let buttons: u64 = unsafe { msg_send![class!(NSEvent), pressedMouseButtons] };
+    buttons & 1 != 0
+}
+
+/// Present `sheet` as a sheet attached to `parent`, animating it in
+pub fn begin_sheet<R: Runtime>(parent: &WebviewWindow<R>, sheet: &WebviewWindow<R>) -> Result<()> {
+    let parent = ns_window_handle(parent)?;
+    let sheet = ns_window_handle(sheet)?;
+    run_on_main_sync(move || unsafe {
+        // A nil completion handler is allowed; dismissal is driven by end_sheet
+        let _: () = msg_send![parent.as_id(), beginSheet: sheet.as_id() completionHandler: nil];
+    });
+    Ok(())
+}
+
+/// Dismiss a window presented with [`begin_sheet`], animating it out
+///
+/// Returns false if the window is not currently attached as a sheet.
+pub fn end_sheet<R: Runtime>(sheet: &WebviewWindow<R>) -> Result<bool> {
+    let sheet = ns_window_handle(sheet)?;
+    Ok(run_on_main_sync(move || unsafe {
+        let sheet = sheet.as_id();
+        let parent: id = msg_send![sheet, sheetParent];
+        if parent == nil {
+            return false;
+        }
+        let _: () = msg_send![parent, endSheet: sheet];
+        true
+    }))
+}
@@ -0,0 +1,74 @@
+//! Theme sync groups
+//!
+//! Windows tagged into a named group share one glass configuration: setting
+//! the group's effect applies it to every member, and windows that join later
+//! receive it immediately. Useful for document-based apps with many identical
+//! windows.
+
+use std::collections::{BTreeSet, HashMap};
+use std::sync::Mutex;
+
+use crate::error::{Error, Result};
+use crate::models::LiquidGlassConfig;
+
+#[derive(Default)]
+struct Group {
+    members: BTreeSet<String>,
+    config: Option<LiquidGlassConfig>,
+}
+
+/// Group membership and configuration, keyed by group name
+#[derive(Default)]
+pub(crate) struct ThemeGroups {
+    groups: Mutex<HashMap<String, Group>>,
+}
+
+impl ThemeGroups {
+    /// Add a window to a group, leaving any group it was in before
+    ///
+    /// Returns the group's configuration, if one was set.
+    pub fn join(&self, group: &str, label: &str) -> Result<Option<LiquidGlassConfig>> {
+        let mut groups = self.groups.lock().map_err(|_| Error::RegistryLockFailed)?;
+
+        for other in groups.values_mut() {
+            other.members.remove(label);
+        }
+
+        let group = groups.entry(group.to_string()).or_default();
+        group.members.insert(label.to_string());
+        Ok(group.config.clone())
+    }
+
+    /// Remove a window from whichever group it is in
+    pub fn leave(&self, label: &str) -> Result<()> {
+        let mut groups = self.groups.lock().map_err(|_| Error::RegistryLockFailed)?;
+
+        for group in groups.values_mut() {
+            group.members.remove(label);
+        }
+        groups.retain(|_, group| !group.members.is_empty() || group.config.is_some());
+        Ok(())
+    }
+
+    /// Store a group's configuration and return its current members
+    pub fn set_config(&self, group: &str, config: LiquidGlassConfig) -> Result<Vec<String>> {
+        let mut groups = self.groups.lock().map_err(|_| Error::RegistryLockFailed)?;
+
+        let group = groups.entry(group.to_string()).or_default();
+        group.config = Some(config);
+        Ok(group.members.iter().cloned().collect())
+    }
+
+    /// Labels of the windows in a group
+    pub fn members(&self, group: &str) -> Result<Vec<String>> {
+        self.groups
+            .lock()
+            .map(|groups| {
+                groups
+                    .get(group)
+                    .map(|group| group.members.iter().cloned().collect())
+                    .unwrap_or_default()
+            })
+            .map_err(|_| Error::RegistryLockFailed)
+    }
+}
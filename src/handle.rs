@@ -0,0 +1,89 @@
+//! RAII guard for temporary glass effects
+
+use log::warn;
+use tauri::{Runtime, WebviewWindow};
+
+use crate::error::Result;
+use crate::models::LiquidGlassConfig;
+use crate::LiquidGlassExt;
+
+/// Guard returned by [`LiquidGlass::apply`](crate::LiquidGlass::apply)
+///
+/// Removes the window's glass effect when dropped, so temporary effects (e.g. while
+/// a modal is open) are cleaned up even if an early return skips explicit removal.
+/// Call [`detach`](Self::detach) to keep the effect applied.
+///
+/// The guard removes whatever effect the window has at drop time, including
+/// changes made through other APIs after it was created.
+///
+/// Dropping is fire-and-forget: the removal is queued on the main thread
+/// without waiting for it, so dropping never blocks, and failures are only
+/// logged. Call [`remove`](Self::remove) to wait for the removal and handle
+/// its error.
+#[must_use = "dropping the handle immediately removes the glass effect"]
+pub struct GlassEffectHandle<R: Runtime> {
+    window: Option<WebviewWindow<R>>,
+}
+
+impl<R: Runtime> GlassEffectHandle<R> {
+    pub(crate) fn new(window: WebviewWindow<R>) -> Self {
+        Self {
+            window: Some(window),
+        }
+    }
+
+    /// The window this effect is applied to
+    pub fn window(&self) -> &WebviewWindow<R> {
+        self.window
+            .as_ref()
+            .expect("window is only taken by detach, remove or drop")
+    }
+
+    /// Keep the effect applied and release the guard
+    pub fn detach(mut self) {
+        self.window.take();
+    }
+
+    /// Remove the effect now, reporting any error instead of logging it
+    pub fn remove(mut self) -> Result<()> {
+        match self.window.take() {
+            Some(window) => remove_effect(&window),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<R: Runtime> Drop for GlassEffectHandle<R> {
+    fn drop(&mut self) {
+        let Some(window) = self.window.take() else {
+            return;
+        };
+        let target = window.clone();
+        let queued = target.run_on_main_thread(move || {
+            if let Err(e) = remove_effect(&window) {
+                warn!(
+                    "Failed to remove glass effect from window '{}': {}",
+                    window.label(),
+                    e
+                );
+            }
+        });
+        if let Err(e) = queued {
+            warn!(
+                "Failed to queue glass effect removal for window '{}': {}",
+                target.label(),
+                e
+            );
+        }
+    }
+}
+
+fn remove_effect<R: Runtime>(window: &WebviewWindow<R>) -> Result<()> {
+    window.liquid_glass().set_effect(
+        window,
+        LiquidGlassConfig {
+            enabled: false,
+            ..Default::default()
+        },
+    )
+}
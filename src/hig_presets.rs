@@ -0,0 +1,155 @@
+//! Glass configurations replicating specific Apple components
+//!
+//! Unlike [`GlassPreset`](crate::GlassPreset), which only picks a material and
+//! radius, these carry a complete parameter set (variant, frame, radius, tint,
+//! rim and shadow) tuned against the system components they mimic. Since
+//! macOS 26 builds differ in the variants they honor and older releases draw
+//! these components without floating glass, [`HigComponent::config_for`]
+//! adapts the set to the running build.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    Anchor, GlassFrame, GlassMaterialVariant, GlassRim, GlassShadow, Length, LiquidGlassConfig,
+    SupportDetails,
+};
+
+/// Apple component a [`LiquidGlassConfig`] can replicate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum HigComponent {
+    /// Floating source-list sidebar of a Finder window
+    FinderSidebar,
+    /// Toolbar strip across the top of a Safari window
+    SafariToolbar,
+    /// Rounded module tile of Control Center
+    ControlCenterModule,
+    /// The Dock, as a bar floating above the bottom edge
+    Dock,
+}
+
+impl HigComponent {
+    /// All components in declaration order
+    pub const ALL: [Self; 4] = [
+        Self::FinderSidebar,
+        Self::SafariToolbar,
+        Self::ControlCenterModule,
+        Self::Dock,
+    ];
+
+    /// Variants matching the component, preferred first
+    fn variants(self) -> &'static [GlassMaterialVariant] {
+        use GlassMaterialVariant as V;
+        match self {
+            Self::FinderSidebar => &[V::Sidebar, V::AbuttedSidebar, V::Regular],
+            Self::SafariToolbar => &[V::Regular, V::Clear],
+            Self::ControlCenterModule => &[V::ControlCenter, V::Widgets, V::Regular],
+            Self::Dock => &[V::Dock, V::Regular],
+        }
+    }
+
+    /// Configuration as drawn by macOS 26 with every variant available
+    pub fn config(self) -> LiquidGlassConfig {
+        self.tuned(self.variants()[0], true)
+    }
+
+    /// Configuration validated against the running macOS build
+    ///
+    /// Picks the first variant of the component's fallback chain the build
+    /// honors. Without NSGlassEffectView, returns the flatter pre-Tahoe
+    /// appearance: flush sidebars, tighter radii and no rim.
+    pub fn config_for(self, support: &SupportDetails) -> LiquidGlassConfig {
+        if !support.supported {
+            return self.tuned(self.variants()[0], false);
+        }
+
+        let variant = self
+            .variants()
+            .iter()
+            .copied()
+            .find(|variant| support.supported_variants.contains(variant))
+            .unwrap_or_default();
+        self.tuned(variant, true)
+    }
+
+    /// Build the configuration for `variant`, in the floating Tahoe style or not
+    fn tuned(self, variant: GlassMaterialVariant, floating: bool) -> LiquidGlassConfig {
+        let points = |value: f64| Some(Length::Points(value));
+        let rim = |color: &str, width: f64| {
+            floating.then(|| GlassRim {
+                color: String::from(color),
+                width,
+            })
+        };
+        let shadow = |color: &str, radius: f64, offset_y: f64| {
+            floating.then(|| GlassShadow {
+                color: String::from(color),
+                radius,
+                offset_x: 0.0,
+                offset_y,
+            })
+        };
+
+        let config = LiquidGlassConfig {
+            variant,
+            ..Default::default()
+        };
+        match self {
+            Self::FinderSidebar => {
+                let inset = if floating { 8.0 } else { 0.0 };
+                LiquidGlassConfig {
+                    corner_radius: if floating { 16.0 } else { 0.0 },
+                    rim: rim("#FFFFFF26", 1.0),
+                    shadow: shadow("#0000001F", 8.0, 2.0),
+                    frame: Some(GlassFrame {
+                        left: points(inset),
+                        top: points(inset),
+                        bottom: points(inset),
+                        width: points(220.0),
+                        ..Default::default()
+                    }),
+                    ..config
+                }
+            }
+            Self::SafariToolbar => LiquidGlassConfig {
+                rim: rim("#FFFFFF1A", 0.5),
+                frame: Some(GlassFrame {
+                    left: points(0.0),
+                    top: points(0.0),
+                    right: points(0.0),
+                    height: points(52.0),
+                    ..Default::default()
+                }),
+                ..config
+            },
+            Self::ControlCenterModule => LiquidGlassConfig {
+                corner_radius: if floating { 20.0 } else { 12.0 },
+                tint_color: Some(String::from("#FFFFFF0D")),
+                rim: rim("#FFFFFF33", 1.0),
+                shadow: shadow("#00000033", 16.0, 6.0),
+                ..config
+            },
+            Self::Dock => LiquidGlassConfig {
+                corner_radius: if floating { 24.0 } else { 16.0 },
+                tint_color: Some(String::from("#FFFFFF14")),
+                rim: rim("#FFFFFF40", 1.0),
+                shadow: shadow("#00000040", 20.0, 8.0),
+                frame: Some(GlassFrame {
+                    anchor: Some(Anchor::Bottom),
+                    bottom: points(4.0),
+                    width: Some(Length::Percent(80.0)),
+                    max_width: points(960.0),
+                    height: points(70.0),
+                    ..Default::default()
+                }),
+                ..config
+            },
+        }
+    }
+}
+
+impl From<HigComponent> for LiquidGlassConfig {
+    fn from(component: HigComponent) -> Self {
+        component.config()
+    }
+}
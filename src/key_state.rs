@@ -0,0 +1,24 @@
+//! Key status events
+//!
+//! NSVisualEffectView fallbacks with `key_state_transition_ms` set crossfade
+//! to the inactive look when their window resigns key. The event carries the
+//! transition duration so web content can dim in lockstep.
+
+use serde::{Deserialize, Serialize};
+
+/// Event emitted when a window with key-state-following glass becomes or
+/// resigns key
+pub const KEY_STATE_CHANGED_EVENT: &str = "liquid-glass://key-state-changed";
+
+/// Payload of [`KEY_STATE_CHANGED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeyStateChanged {
+    /// Label of the window whose key status changed
+    pub window: String,
+    /// Whether the window is now the key window
+    pub key: bool,
+    /// Duration of the native crossfade in milliseconds (the longest, if
+    /// the window's views use different durations)
+    pub duration_ms: u64,
+}
@@ -0,0 +1,231 @@
+//! Spotlight-style launcher window preset
+//!
+//! A centered glass palette with a fixed width whose height follows its
+//! content, summoned with a keyboard shortcut and dismissed when it loses
+//! focus or the user presses Escape. With the `launcher-shortcut` feature,
+//! [`LauncherConfig::shortcut`] registers the global shortcut that toggles
+//! it; otherwise call [`toggle_launcher`](crate::LiquidGlass::toggle_launcher)
+//! from the app's own shortcut handler.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "launcher-shortcut")]
+use tauri::{AppHandle, Manager};
+use tauri::{Emitter, LogicalPosition, LogicalSize, Runtime, WebviewWindow};
+#[cfg(feature = "launcher-shortcut")]
+use tauri_plugin_global_shortcut::{GlobalShortcut, GlobalShortcutExt, ShortcutState};
+
+use crate::error::{Error, Result};
+use crate::models::LiquidGlassConfig;
+#[cfg(feature = "launcher-shortcut")]
+use crate::LiquidGlassExt;
+
+/// Event emitted when a launcher window is shown or dismissed
+pub const LAUNCHER_VISIBILITY_EVENT: &str = "liquid-glass://launcher-visibility";
+
+/// Configuration for a launcher window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LauncherConfig {
+    /// Fixed width of the palette in logical pixels
+    pub width: f64,
+
+    /// Smallest height the palette shrinks to, e.g. just the search field
+    pub min_height: f64,
+
+    /// Largest height the palette grows to with its content
+    pub max_height: f64,
+
+    /// Distance of the palette's top edge below the top of the work area, as
+    /// a fraction of the work area height
+    pub top_fraction: f64,
+
+    /// Corner radius of the palette window
+    pub corner_radius: f64,
+
+    /// Dismiss the palette when it loses focus
+    pub dismiss_on_blur: bool,
+
+    /// Switch the app to the accessory activation policy, so summoning the
+    /// palette shows no Dock icon and brings up no other window. This is
+    /// app-wide; turn it off for apps that also have regular windows.
+    pub hide_from_dock: bool,
+
+    /// Global shortcut that toggles the palette, e.g. `CmdOrCtrl+Space`
+    #[cfg(feature = "launcher-shortcut")]
+    pub shortcut: Option<String>,
+
+    /// Glass configuration for the palette
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for LauncherConfig {
+    fn default() -> Self {
+        Self {
+            width: 680.0,
+            min_height: 56.0,
+            max_height: 480.0,
+            top_fraction: 0.22,
+            corner_radius: 20.0,
+            dismiss_on_blur: true,
+            hide_from_dock: true,
+            #[cfg(feature = "launcher-shortcut")]
+            shortcut: None,
+            glass: LiquidGlassConfig::default(),
+        }
+    }
+}
+
+impl LauncherConfig {
+    /// Clamp a content height to the palette's height range
+    pub fn clamp_height(&self, height: f64) -> f64 {
+        height.min(self.max_height).max(self.min_height)
+    }
+}
+
+/// Payload of [`LAUNCHER_VISIBILITY_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LauncherVisibility {
+    /// Label of the launcher window
+    pub window: String,
+    /// Whether the launcher is now visible
+    pub visible: bool,
+}
+
+/// Tracks launcher windows and their configuration
+#[derive(Default)]
+pub(crate) struct Launchers {
+    launchers: Mutex<HashMap<String, LauncherConfig>>,
+}
+
+impl Launchers {
+    /// Start managing `window` as a launcher
+    pub fn insert(&self, label: &str, config: LauncherConfig) -> Result<()> {
+        self.launchers
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .insert(label.to_string(), config);
+        Ok(())
+    }
+
+    /// Configuration of a launcher window, if `label` is one
+    pub fn get(&self, label: &str) -> Result<Option<LauncherConfig>> {
+        Ok(self
+            .launchers
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .get(label)
+            .cloned())
+    }
+
+    /// Stop tracking a destroyed window
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.launchers
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label);
+        Ok(())
+    }
+}
+
+/// Center the palette horizontally on the monitor under the cursor, with its
+/// top edge `top_fraction` down the work area
+///
+/// The current height is kept, so the palette reopens at its last size.
+pub(crate) fn position_centered<R: Runtime>(
+    window: &WebviewWindow<R>,
+    config: &LauncherConfig,
+) -> Result<()> {
+    let cursor = window.cursor_position()?;
+    let Some(monitor) = window
+        .monitor_from_point(cursor.x, cursor.y)?
+        .or(window.current_monitor()?)
+    else {
+        return Err(Error::MonitorNotFound(window.label().to_string()));
+    };
+
+    let scale = monitor.scale_factor();
+    let area = monitor.work_area();
+    let origin = area.position.to_logical::<f64>(scale);
+    let size = area.size.to_logical::<f64>(scale);
+
+    let height = config.clamp_height(
+        window
+            .inner_size()?
+            .to_logical::<f64>(window.scale_factor()?)
+            .height,
+    );
+    let width = config.width.min(size.width);
+    let x = origin.x + (size.width - width) / 2.0;
+    let y = origin.y + size.height * config.top_fraction.clamp(0.0, 1.0);
+
+    window.set_size(LogicalSize::new(width, height))?;
+    window.set_position(LogicalPosition::new(x, y))?;
+    Ok(())
+}
+
+/// Resize the palette to `height`, keeping its top edge in place
+pub(crate) fn resize_keeping_top<R: Runtime>(window: &WebviewWindow<R>, height: f64) -> Result<()> {
+    let scale = window.scale_factor()?;
+    let position = window.outer_position()?.to_logical::<f64>(scale);
+    let width = window.inner_size()?.to_logical::<f64>(scale).width;
+
+    window.set_size(LogicalSize::new(width, height))?;
+    window.set_position(position)?;
+    Ok(())
+}
+
+pub(crate) fn emit_visibility<R: Runtime>(window: &WebviewWindow<R>, visible: bool) {
+    let payload = LauncherVisibility {
+        window: window.label().to_string(),
+        visible,
+    };
+    if let Err(e) = window.emit(LAUNCHER_VISIBILITY_EVENT, payload) {
+        warn!("Failed to emit launcher visibility: {}", e);
+    }
+}
+
+/// Register `shortcut` to toggle the launcher labelled `label`
+///
+/// Installs the global-shortcut plugin first if the app has not, and
+/// replaces an earlier registration of the same shortcut. The shortcut
+/// unregisters itself once the launcher window is gone.
+#[cfg(feature = "launcher-shortcut")]
+pub(crate) fn register_shortcut<R: Runtime>(
+    app: &AppHandle<R>,
+    label: &str,
+    shortcut: &str,
+) -> Result<()> {
+    if app.try_state::<GlobalShortcut<R>>().is_none() {
+        app.plugin(tauri_plugin_global_shortcut::Builder::new().build())?;
+    }
+    let failed = |e: tauri_plugin_global_shortcut::Error| {
+        Error::ShortcutFailed(shortcut.to_string(), e.to_string())
+    };
+
+    let shortcuts = app.global_shortcut();
+    if shortcuts.is_registered(shortcut) {
+        shortcuts.unregister(shortcut).map_err(failed)?;
+    }
+    let label = label.to_string();
+    shortcuts
+        .on_shortcut(shortcut, move |app, shortcut, event| {
+            if event.state != ShortcutState::Pressed {
+                return;
+            }
+            let Some(window) = app.get_webview_window(&label) else {
+                if let Err(e) = app.global_shortcut().unregister(*shortcut) {
+                    warn!("Failed to unregister launcher shortcut: {}", e);
+                }
+                return;
+            };
+            if let Err(e) = app.liquid_glass().toggle_launcher(&window) {
+                warn!("Failed to toggle launcher '{}': {}", label, e);
+            }
+        })
+        .map_err(failed)
+}
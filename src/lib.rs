@@ -26,22 +26,95 @@
 // functional benefit. These crates remain fully functional for our use case.
 #![allow(deprecated)]
 
+use std::collections::BTreeMap;
+
+use log::warn;
 use tauri::{
-    plugin::{Builder, TauriPlugin},
-    Manager, Runtime,
+    plugin::{Builder as PluginBuilder, TauriPlugin},
+    webview::PageLoadEvent,
+    Manager, RunEvent, Runtime, WindowEvent,
 };
 
+mod appearance;
+mod audit;
+mod color;
 mod commands;
+mod components;
+mod config;
+mod context_menu;
 mod desktop;
+#[cfg(feature = "dev-hotkey")]
+mod dev_hotkey;
 mod error;
+mod events;
+mod external;
+mod fade;
+mod fallback;
+mod foreground;
+mod frame;
+mod groups;
+mod handle;
+mod hig_presets;
+mod key_state;
+mod launcher;
+mod lifecycle;
+mod media_controls;
+mod menubar_panel;
 mod models;
+mod pip;
+mod rect_stream;
+mod region_channel;
+mod scope;
+mod sheet;
+mod suspend;
+mod system_colors;
+mod throttle;
+mod toast;
+mod window;
 
 #[cfg(target_os = "macos")]
 mod glass_effect;
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "windows")]
+mod windows;
 
+pub use appearance::{AppearanceChanged, APPEARANCE_CHANGED_EVENT};
+pub use audit::{AuditEntry, ConfigChange};
+pub use config::Config;
+pub use context_menu::{
+    ContextMenuConfig, ContextMenuDismissReason, ContextMenuDismissed, CONTEXT_MENU_DISMISSED_EVENT,
+};
 pub use desktop::LiquidGlass;
 pub use error::{Error, Result};
+pub use events::GlassEvent;
+pub use external::{GlassExternallyModified, GLASS_EXTERNALLY_MODIFIED_EVENT};
+pub use fallback::{GlassFallback, FALLBACK_EVENT};
+pub use foreground::{ForegroundChanged, FOREGROUND_CHANGED_EVENT};
+pub use frame::{FrameChanged, FRAME_CHANGED_EVENT};
+pub use handle::GlassEffectHandle;
+pub use hig_presets::HigComponent;
+pub use key_state::{KeyStateChanged, KEY_STATE_CHANGED_EVENT};
+pub use launcher::{LauncherConfig, LauncherVisibility, LAUNCHER_VISIBILITY_EVENT};
+pub use lifecycle::{GlassApplied, GlassRemoved, APPLIED_EVENT, REMOVED_EVENT};
+pub use media_controls::{
+    MediaControlsConfig, MediaControlsVisibility, MEDIA_CONTROLS_VISIBILITY_EVENT,
+};
+pub use menubar_panel::{
+    MenubarPanelConfig, MenubarPanelVisibility, MENUBAR_PANEL_VISIBILITY_EVENT,
+};
 pub use models::*;
+pub use pip::{PipConfig, PipSnapped, PIP_SNAPPED_EVENT};
+pub use region_channel::RegionMessage;
+pub use scope::OriginScope;
+pub use sheet::{SheetConfig, SheetVisibility, SHEET_VISIBILITY_EVENT};
+pub use suspend::{EffectsSuspended, EFFECTS_SUSPENDED_EVENT};
+pub use system_colors::{SystemColorsChanged, SYSTEM_COLORS_CHANGED_EVENT};
+pub use throttle::MainThreadStats;
+pub use toast::{
+    ToastContent, ToastDismissReason, ToastDismissed, ToastOptions, TOAST_DISMISSED_EVENT,
+};
+pub use window::{LiquidGlassWindow, LiquidGlassWindowExt};
 
 // ============================================================================
 // Extension Trait
@@ -80,7 +153,9 @@ impl<R: Runtime, T: Manager<R>> LiquidGlassExt<R> for T {
 // Plugin Initialization
 // ============================================================================
 
-/// Initialize the liquid-glass plugin
+/// Initialize the liquid-glass plugin with default options
+///
+/// Use [`Builder`] to customize the plugin.
 ///
 /// # Example
 ///
@@ -97,21 +172,377 @@ impl<R: Runtime, T: Manager<R>> LiquidGlassExt<R> for T {
 ///     .run(tauri::generate_context!())
 ///     .expect("error while running tauri application");
 /// ```
-pub fn init<R: Runtime>() -> TauriPlugin<R> {
-    Builder::new("liquid-glass")
-        .invoke_handler(tauri::generate_handler![
-            commands::is_glass_supported,
-            commands::set_liquid_glass_effect,
-        ])
-        .setup(|app, _api| {
-            // Manage the LiquidGlass struct for the extension trait
-            app.manage(LiquidGlass::new(app.clone()));
-
-            #[cfg(target_os = "macos")]
-            {
-                app.manage(glass_effect::GlassViewRegistry::default());
-            }
-            Ok(())
-        })
-        .build()
+pub fn init<R: Runtime>() -> TauriPlugin<R, Option<Config>> {
+    Builder::new().build()
+}
+
+/// Builder for the liquid-glass plugin
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_plugin_liquid_glass::GlassPreset;
+///
+/// tauri::Builder::default()
+///     .plugin(
+///         tauri_plugin_liquid_glass::Builder::new()
+///             .audit_log(100)
+///             .window_effect("main", GlassPreset::Sidebar)
+///             .build(),
+///     )
+///     .run(tauri::generate_context!())
+///     .expect("error while running tauri application");
+/// ```
+#[derive(Debug, Default)]
+pub struct Builder {
+    audit_log_capacity: Option<usize>,
+    window_effects: BTreeMap<String, LiquidGlassConfig>,
+    skip_orphan_sweep: bool,
+    safe_mode: bool,
+    live_resize_mode: LiveResizeMode,
+    ops_per_frame: Option<usize>,
+    #[cfg_attr(not(feature = "dev-hotkey"), allow(dead_code))]
+    devtools_shortcut: Option<String>,
+}
+
+impl Builder {
+    /// Create a builder with default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep an in-memory log of the last `capacity` glass effect operations
+    ///
+    /// The log is queryable via [`LiquidGlass::audit_log`] and the
+    /// `get_glass_audit_log` command. Disabled by default.
+    pub fn audit_log(mut self, capacity: usize) -> Self {
+        self.audit_log_capacity = Some(capacity);
+        self
+    }
+
+    /// Apply `config` to the window labelled `label` as soon as its webview is created
+    ///
+    /// The config is also injected into the webview's init script, so the
+    /// frontend can read it synchronously (`getInitialConfigSync()` in
+    /// guest-js) and style its first paint without an IPC call. It takes
+    /// precedence over a matching entry in the [`Config`] block of
+    /// `tauri.conf.json`.
+    pub fn window_effect(
+        mut self,
+        label: impl Into<String>,
+        config: impl Into<LiquidGlassConfig>,
+    ) -> Self {
+        self.window_effects.insert(label.into(), config.into());
+        self
+    }
+
+    /// Sweep the registry for orphaned glass views whenever a window gains focus
+    ///
+    /// Enabled by default. The sweep is the same check as
+    /// [`LiquidGlass::validate_glass_views`]; state of destroyed windows is
+    /// always dropped regardless of this setting.
+    pub fn orphan_sweep(mut self, enabled: bool) -> Self {
+        self.skip_orphan_sweep = !enabled;
+        self
+    }
+
+    /// Always render glass with NSVisualEffectView, even on macOS 26+
+    ///
+    /// Disabled by default. Useful to check the fallback look, or to avoid
+    /// NSGlassEffectView on OS builds where it misbehaves. Affected applies
+    /// emit [`FALLBACK_EVENT`] with [`FallbackReason::SafeMode`].
+    pub fn safe_mode(mut self, enabled: bool) -> Self {
+        self.safe_mode = enabled;
+        self
+    }
+
+    /// Choose how region glass views behave while a window is live-resized
+    ///
+    /// Defaults to [`LiveResizeMode::Full`]. Windows with many regions, or
+    /// regions with shadows, can resize more smoothly with
+    /// [`LiveResizeMode::Freeze`] or [`LiveResizeMode::Placeholder`]; regions
+    /// are restored to full fidelity when the resize ends.
+    pub fn live_resize_mode(mut self, mode: LiveResizeMode) -> Self {
+        self.live_resize_mode = mode;
+        self
+    }
+
+    /// Limit how many frontend glass updates reach the main thread per frame
+    ///
+    /// Defaults to 32. Effect, named view and scroll-edge updates over the
+    /// budget are parked until the next frame, where a newer update to the
+    /// same target replaces the parked one, so a runaway frontend loop can't
+    /// freeze the UI thread. See [`LiquidGlass::main_thread_stats`]. The Rust
+    /// API is not limited.
+    pub fn main_thread_budget(mut self, ops_per_frame: usize) -> Self {
+        self.ops_per_frame = Some(ops_per_frame);
+        self
+    }
+
+    /// Set the global shortcut that cycles the focused window's glass through
+    /// presets and variants
+    ///
+    /// Only used with the `dev-hotkey` feature, which registers
+    /// `CmdOrCtrl+Alt+Shift+G` when no shortcut is set. The current preset or
+    /// variant is logged at info level.
+    pub fn devtools_shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.devtools_shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// Build the plugin
+    pub fn build<R: Runtime>(self) -> TauriPlugin<R, Option<Config>> {
+        let window_effects = self.window_effects;
+        let page_effects = window_effects.clone();
+        let sweep_orphans = !self.skip_orphan_sweep;
+
+        PluginBuilder::<R, Option<Config>>::new("liquid-glass")
+            .js_init_script(init_script())
+            .invoke_handler(tauri::generate_handler![
+                commands::is_glass_supported,
+                commands::get_support_details,
+                commands::get_capabilities,
+                commands::get_private_api_report,
+                commands::get_macos_version,
+                commands::get_hig_preset,
+                commands::set_liquid_glass_effect,
+                commands::set_liquid_glass_effects,
+                commands::update_liquid_glass_effect,
+                commands::set_experimental_glass_property,
+                commands::apply_glass_layout,
+                commands::set_glass_view,
+                commands::add_glass_region,
+                commands::sync_glass_rect,
+                commands::remove_glass_view,
+                commands::claim_glass_handle,
+                commands::release_glass_handle,
+                commands::attach_region_to_channel,
+                commands::open_glass_rect_channel,
+                commands::close_glass_rect_channel,
+                commands::set_glass,
+                commands::get_glass,
+                commands::remove_glass,
+                commands::remove_all_effects,
+                commands::create_glass_group,
+                commands::remove_glass_group,
+                commands::join_theme_group,
+                commands::leave_theme_group,
+                commands::set_theme_group_effect,
+                commands::get_glass_audit_log,
+                commands::get_main_thread_stats,
+                commands::get_liquid_glass_effect,
+                commands::get_glass_state,
+                commands::get_recommended_foreground,
+                commands::get_glass_frame,
+                commands::get_safe_area_insets,
+                commands::get_system_colors,
+                commands::list_glass_views,
+                commands::list_effects,
+                commands::liquid_glass_doctor,
+                #[cfg(feature = "devtools")]
+                commands::dump_view_hierarchy,
+                commands::export_glass_state,
+                commands::import_glass_state,
+                commands::validate_glass_views,
+                commands::set_glass_window_level,
+                commands::make_rounded_glass_window,
+                commands::set_window_background,
+                commands::set_webview_draws_background,
+                commands::set_window_transparent,
+                commands::set_webview_transparent,
+                commands::attach_media_controls,
+                commands::reveal_media_controls,
+                commands::detach_media_controls,
+                commands::show_context_menu,
+                commands::dismiss_context_menu,
+                commands::create_launcher,
+                commands::toggle_launcher,
+                commands::dismiss_launcher,
+                commands::resize_launcher,
+                commands::suspend_all,
+                commands::resume_all,
+                commands::is_suspended,
+                commands::show_with_fade,
+                commands::hide_with_fade,
+                commands::present_sheet,
+                commands::dismiss_sheet,
+                commands::attach_pip,
+                commands::detach_pip,
+                commands::snap_pip,
+                commands::show_glass_toast,
+                commands::dismiss_glass_toast,
+                commands::attach_menubar_panel,
+                commands::toggle_menubar_panel,
+                commands::detach_menubar_panel,
+                commands::set_scroll_edge_effect,
+                commands::report_scroll_offset,
+            ])
+            .setup(move |app, api| {
+                app.manage(api.config().clone().unwrap_or_default());
+
+                // Manage the LiquidGlass struct for the extension trait
+                app.manage(LiquidGlass::new(
+                    app.clone(),
+                    self.audit_log_capacity,
+                    self.ops_per_frame
+                        .unwrap_or(throttle::DEFAULT_OPS_PER_FRAME),
+                ));
+
+                #[cfg(target_os = "macos")]
+                {
+                    app.manage(glass_effect::GlassViewRegistry::default());
+                    app.manage(glass_effect::ScrollEdgeRegistry::default());
+                    glass_effect::set_safe_mode(self.safe_mode);
+                    glass_effect::set_live_resize_mode(self.live_resize_mode);
+                    glass_effect::install_display_observers(app);
+                    app.liquid_glass().observe_system_colors();
+                }
+
+                #[cfg(feature = "dev-hotkey")]
+                dev_hotkey::register(
+                    app,
+                    self.devtools_shortcut
+                        .as_deref()
+                        .unwrap_or(dev_hotkey::DEFAULT_SHORTCUT),
+                )?;
+                Ok(())
+            })
+            .on_webview_ready(move |webview| {
+                let Some(window) = webview.get_webview_window(webview.label()) else {
+                    return;
+                };
+                window.liquid_glass().observe_appearance(&window);
+                window.liquid_glass().observe_key_state(&window);
+
+                let Some(config) = initial_config(&window, &window_effects, webview.label()) else {
+                    return;
+                };
+                if let Err(e) = window.liquid_glass().set_effect(&window, config) {
+                    warn!(
+                        "Failed to apply glass effect to window '{}': {}",
+                        webview.label(),
+                        e
+                    );
+                }
+            })
+            .on_page_load(move |webview, payload| {
+                if payload.event() == PageLoadEvent::Started {
+                    webview.liquid_glass().page_loading(webview.label());
+                    let config = initial_config(webview, &page_effects, webview.label());
+                    if let Err(e) = webview.eval(initial_config_script(config.as_ref())) {
+                        warn!(
+                            "Failed to pass the initial config to webview '{}': {}",
+                            webview.label(),
+                            e
+                        );
+                    }
+                }
+            })
+            .on_event(move |app, event| {
+                let RunEvent::WindowEvent { label, event, .. } = event else {
+                    return;
+                };
+                match event {
+                    WindowEvent::Destroyed => app.liquid_glass().forget_window(label),
+                    WindowEvent::Focused(true) if sweep_orphans => {
+                        app.liquid_glass().sweep_orphans()
+                    }
+                    WindowEvent::Focused(false) => app.liquid_glass().window_blurred(label),
+                    WindowEvent::Moved(_) => app.liquid_glass().window_moved(label),
+                    _ => {}
+                }
+            })
+            .build()
+    }
+}
+
+/// Script injected into every webview so guest-js can answer support queries
+/// and read its window's config synchronously during the first render,
+/// without an IPC round trip
+///
+/// Plugin init scripts are shared by every webview, so they can't carry
+/// per-window configs without showing each window the others' glass. The
+/// script only leaves a one-shot setter for [`initial_config_script`].
+fn init_script() -> String {
+    #[cfg(target_os = "macos")]
+    let (supported, macos_version) = (
+        glass_effect::glass_class_available(),
+        Some(glass_effect::macos_version()),
+    );
+    #[cfg(target_os = "windows")]
+    let (supported, macos_version): (bool, Option<MacOSVersion>) =
+        (windows::is_backdrop_supported(), None);
+    #[cfg(target_os = "linux")]
+    let (supported, macos_version): (bool, Option<MacOSVersion>) =
+        (linux::is_blur_supported(), None);
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    let (supported, macos_version): (bool, Option<MacOSVersion>) = (false, None);
+
+    let info = serde_json::json!({
+        "supported": supported,
+        "macosVersion": macos_version,
+    });
+    format!(
+        r#"(() => {{
+  let config = null;
+  Object.defineProperty(window, "{INITIAL_CONFIG_SETTER}", {{
+    configurable: true,
+    value: (value) => {{
+      config = value;
+    }},
+  }});
+  window.__LIQUID_GLASS__ = Object.freeze({{ ...{info}, initialConfig: () => config }});
+}})();"#
+    )
+}
+
+/// The config applied to a window when its webview is created: its
+/// `Builder::window_effect` entry, else the `tauri.conf.json` entry
+/// matching its label
+fn initial_config<R: Runtime>(
+    manager: &impl Manager<R>,
+    window_effects: &BTreeMap<String, LiquidGlassConfig>,
+    label: &str,
+) -> Option<LiquidGlassConfig> {
+    window_effects.get(label).cloned().or_else(|| {
+        manager
+            .try_state::<Config>()
+            .and_then(|config| config.window_effect(label).cloned())
+    })
+}
+
+/// Global the init script defines for handing a webview its window's config
+const INITIAL_CONFIG_SETTER: &str = "__LIQUID_GLASS_SET_INITIAL_CONFIG__";
+
+/// Script evaluated in a webview when its page starts loading: hands the
+/// init script its window's config, or `null`, and removes the setter
+fn initial_config_script(config: Option<&LiquidGlassConfig>) -> String {
+    let config = serde_json::to_string(&config).unwrap_or_else(|_| "null".into());
+    format!("window.{INITIAL_CONFIG_SETTER}?.({config}); delete window.{INITIAL_CONFIG_SETTER};")
+}
+
+#[cfg(test)]
+mod tests {
+    /// Names between `start` and the next `end` in `source`, one per line
+    fn listed<'a>(source: &'a str, start: &str, end: &str, prefix: &str) -> Vec<&'a str> {
+        let (_, rest) = source.split_once(start).expect("list start");
+        let (list, _) = rest.split_once(end).expect("list end");
+        list.lines()
+            .filter_map(|line| line.trim().strip_prefix(prefix))
+            .map(|name| name.trim_end_matches(',').trim_matches('"'))
+            .collect()
+    }
+
+    #[test]
+    fn build_script_permissions_match_handler() {
+        let built = listed(include_str!("../build.rs"), "const COMMANDS", "];", "\"");
+        let handled = listed(
+            include_str!("lib.rs"),
+            "generate_handler![",
+            "])",
+            "commands::",
+        );
+        assert!(!handled.is_empty());
+        assert_eq!(built, handled);
+    }
 }
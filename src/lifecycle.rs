@@ -0,0 +1,43 @@
+//! Glass lifecycle notifications
+//!
+//! Every successful change to a window's glass is announced to its webview,
+//! so web content can adapt its styling to the glass actually on screen, e.g.
+//! to the backend a view ended up with. Glass that falls back to
+//! NSVisualEffectView is additionally announced with
+//! [`FALLBACK_EVENT`](crate::FALLBACK_EVENT).
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{FallbackReason, GlassBackendKind, LiquidGlassConfig};
+
+/// Event emitted when glass is created or updated on a window
+pub const APPLIED_EVENT: &str = "liquid-glass://applied";
+
+/// Event emitted when glass is removed from a window
+pub const REMOVED_EVENT: &str = "liquid-glass://removed";
+
+/// Payload of [`APPLIED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassApplied {
+    /// Label of the window
+    pub window: String,
+    /// Id of the named view, `None` for the full-window glass
+    pub region: Option<String>,
+    /// Backend rendering the view, `None` off macOS
+    pub backend: Option<GlassBackendKind>,
+    /// Why the view fell back to NSVisualEffectView, `None` for native views
+    pub fallback_reason: Option<FallbackReason>,
+    /// Configuration now applied, in points
+    pub config: LiquidGlassConfig,
+}
+
+/// Payload of [`REMOVED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassRemoved {
+    /// Label of the window
+    pub window: String,
+    /// Id of the named view, `None` for the full-window glass
+    pub region: Option<String>,
+}
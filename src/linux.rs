@@ -0,0 +1,192 @@
+//! Linux compositor blur
+//!
+//! Linux has no per-view material, so the plugin's effect asks the compositor
+//! to blur what is behind the whole (transparent) window. The mechanism is
+//! picked at runtime, once:
+//!
+//! - Hyprland blurs transparent windows itself, following its
+//!   `decoration:blur` settings, so there is nothing to request per window.
+//!   Blur is reported as supported only while `decoration:blur:enabled` is
+//!   on, and it cannot be turned off for a single window
+//! - KWin and other X11 compositors that advertise
+//!   `_KDE_NET_WM_BLUR_BEHIND_REGION` in `_NET_SUPPORTED` blur windows that
+//!   carry that property; an empty region covers the whole window
+//!
+//! Other sessions report no support. That includes native Wayland windows on
+//! KWin and wlroots compositors, which would need the `org_kde_kwin_blur` or
+//! `ext-background-effect-v1` Wayland protocols; run those apps with
+//! `GDK_BACKEND=x11` to use KWin's X11 blur.
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use raw_window_handle::{HasWindowHandle, RawWindowHandle};
+use tauri::{Runtime, WebviewWindow};
+use x11rb::connection::Connection;
+use x11rb::protocol::xproto::{AtomEnum, ConnectionExt as _, PropMode};
+use x11rb::rust_connection::RustConnection;
+use x11rb::wrapper::ConnectionExt as _;
+
+use crate::error::{Error, Result};
+use crate::models::LiquidGlassConfig;
+
+/// Window property KWin reads the blur region from
+const KDE_BLUR_REGION: &[u8] = b"_KDE_NET_WM_BLUR_BEHIND_REGION";
+
+/// How the running session blurs windows
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BlurBackend {
+    /// Hyprland, which blurs transparent windows on its own
+    Hyprland,
+    /// An X11 compositor honoring `_KDE_NET_WM_BLUR_BEHIND_REGION`
+    KdeBlurRegion,
+}
+
+/// Check if the running session can blur behind windows
+///
+/// Under Hyprland this means blur can be enabled; disabling it per window
+/// still fails, see [`set_blur`].
+pub fn is_blur_supported() -> bool {
+    blur_backend().is_some()
+}
+
+/// Request compositor blur behind the whole window, or withdraw the request
+/// when the config is disabled
+///
+/// Hyprland blurs every transparent window by its own rules, so a disabled
+/// config fails with [`Error::UnsupportedCapability`] there.
+pub fn set_blur<R: Runtime>(window: &WebviewWindow<R>, config: &LiquidGlassConfig) -> Result<()> {
+    match blur_backend() {
+        None => Err(Error::UnsupportedPlatform),
+        Some(BlurBackend::Hyprland) if config.enabled => Ok(()),
+        Some(BlurBackend::Hyprland) => Err(Error::UnsupportedCapability(
+            "disabling blur under Hyprland".to_string(),
+        )),
+        Some(BlurBackend::KdeBlurRegion) => {
+            let xid = match window_handle(window)? {
+                RawWindowHandle::Xlib(handle) => handle.window as u32,
+                RawWindowHandle::Xcb(handle) => handle.window.get(),
+                _ => return Err(Error::UnsupportedPlatform),
+            };
+            set_kde_blur_region(xid, config.enabled).map_err(|e| Error::BlurFailed(e.to_string()))
+        }
+    }
+}
+
+/// Get the window's native handle
+fn window_handle<R: Runtime>(window: &WebviewWindow<R>) -> Result<RawWindowHandle> {
+    window
+        .window_handle()
+        .map(|handle| handle.as_raw())
+        .map_err(|_| Error::WindowNotFound(window.label().to_string()))
+}
+
+/// Connection to the X server, shared by every blur request
+struct X11 {
+    conn: RustConnection,
+    screen: usize,
+    /// `_KDE_NET_WM_BLUR_BEHIND_REGION`
+    blur_atom: u32,
+}
+
+/// Get the shared X11 connection, opened on first use
+fn x11() -> std::result::Result<&'static X11, Box<dyn std::error::Error>> {
+    static CONNECTION: OnceLock<Option<X11>> = OnceLock::new();
+    CONNECTION
+        .get_or_init(|| {
+            let connect = || -> std::result::Result<X11, Box<dyn std::error::Error>> {
+                let (conn, screen) = RustConnection::connect(None)?;
+                let blur_atom = conn.intern_atom(false, KDE_BLUR_REGION)?.reply()?.atom;
+                Ok(X11 {
+                    conn,
+                    screen,
+                    blur_atom,
+                })
+            };
+            connect().ok()
+        })
+        .as_ref()
+        .ok_or_else(|| "cannot connect to the X server".into())
+}
+
+/// Set or delete the KDE blur region of an X11 window
+fn set_kde_blur_region(
+    xid: u32,
+    enabled: bool,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    let X11 {
+        conn, blur_atom, ..
+    } = x11()?;
+    if enabled {
+        conn.change_property32(PropMode::REPLACE, xid, *blur_atom, AtomEnum::CARDINAL, &[])?
+            .check()?;
+    } else {
+        conn.delete_property(xid, *blur_atom)?.check()?;
+    }
+    conn.flush()?;
+    Ok(())
+}
+
+/// Detect the session's blur mechanism, once
+fn blur_backend() -> Option<BlurBackend> {
+    static BACKEND: OnceLock<Option<BlurBackend>> = OnceLock::new();
+    *BACKEND.get_or_init(|| {
+        if let Some(signature) = std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE") {
+            // Assume Hyprland's default (on) if the socket can't be queried
+            return hyprland_blur_enabled(&signature.to_string_lossy())
+                .unwrap_or(true)
+                .then_some(BlurBackend::Hyprland);
+        }
+        // GTK opens native Wayland windows unless told to use X11
+        let x11_backend = std::env::var("GDK_BACKEND").is_ok_and(|b| b.starts_with("x11"));
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() && !x11_backend {
+            return None;
+        }
+        advertises_kde_blur()
+            .unwrap_or(false)
+            .then_some(BlurBackend::KdeBlurRegion)
+    })
+}
+
+/// Ask Hyprland's control socket whether `decoration:blur:enabled` is on
+fn hyprland_blur_enabled(signature: &str) -> std::result::Result<bool, Box<dyn std::error::Error>> {
+    // Hyprland 0.40+ keeps its sockets under XDG_RUNTIME_DIR, older ones in /tmp
+    let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    let socket = runtime_dir
+        .map(|dir| dir.join("hypr").join(signature).join(".socket.sock"))
+        .filter(|path| path.exists())
+        .unwrap_or_else(|| {
+            PathBuf::from("/tmp/hypr")
+                .join(signature)
+                .join(".socket.sock")
+        });
+
+    let mut stream = UnixStream::connect(socket)?;
+    stream.write_all(b"j/getoption decoration:blur:enabled")?;
+    let mut reply = String::new();
+    stream.read_to_string(&mut reply)?;
+    let option: serde_json::Value = serde_json::from_str(&reply)?;
+    Ok(option["int"].as_i64().ok_or("unexpected getoption reply")? != 0)
+}
+
+/// Check the root window's `_NET_SUPPORTED` for the KDE blur region
+fn advertises_kde_blur() -> std::result::Result<bool, Box<dyn std::error::Error>> {
+    let X11 {
+        conn,
+        screen,
+        blur_atom,
+    } = x11()?;
+    let root = conn.setup().roots[*screen].root;
+    let supported = conn.intern_atom(true, b"_NET_SUPPORTED")?.reply()?.atom;
+    if supported == 0 {
+        return Ok(false);
+    }
+    let reply = conn
+        .get_property(false, root, supported, AtomEnum::ATOM, 0, u32::MAX)?
+        .reply()?;
+    Ok(reply
+        .value32()
+        .is_some_and(|mut atoms| atoms.any(|atom| atom == *blur_atom)))
+}
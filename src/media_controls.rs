@@ -0,0 +1,217 @@
+//! Fullscreen media-controls overlay helper
+//!
+//! Turns a small app-created window into a video-player style control bar: a
+//! bottom-anchored glass strip that floats over full-screen content and hides
+//! itself after a period of inactivity.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, LogicalPosition, LogicalSize, Runtime, WebviewWindow};
+
+use crate::error::{Error, Result};
+use crate::models::{GlassPreset, LiquidGlassConfig};
+
+/// Event emitted when a media-controls window is shown or auto-hidden
+pub const MEDIA_CONTROLS_VISIBILITY_EVENT: &str = "liquid-glass://media-controls-visibility";
+
+/// Configuration for a media-controls overlay window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MediaControlsConfig {
+    /// Width of the control bar in logical pixels. Defaults to 60% of the monitor width.
+    pub width: Option<f64>,
+
+    /// Height of the control bar in logical pixels
+    pub height: f64,
+
+    /// Distance from the bottom edge of the monitor in logical pixels
+    pub bottom_margin: f64,
+
+    /// Hide the controls after this many milliseconds without a reveal.
+    /// `None` disables auto-hide.
+    pub auto_hide_after_ms: Option<u64>,
+
+    /// Glass configuration for the control bar
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for MediaControlsConfig {
+    fn default() -> Self {
+        Self {
+            width: None,
+            height: 64.0,
+            bottom_margin: 24.0,
+            auto_hide_after_ms: Some(3000),
+            glass: GlassPreset::MediaControls.into(),
+        }
+    }
+}
+
+/// Payload of [`MEDIA_CONTROLS_VISIBILITY_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaControlsVisibility {
+    /// Label of the media-controls window
+    pub window: String,
+    /// Whether the controls are now visible
+    pub visible: bool,
+}
+
+/// Auto-hide state per media-controls window
+struct Attached {
+    auto_hide: Option<Duration>,
+    /// When the controls hide, pushed back by every reveal
+    hide_at: Option<Instant>,
+    /// Whether a watcher thread is waiting for `hide_at`
+    watching: bool,
+}
+
+/// Tracks windows attached as media controls
+#[derive(Default)]
+pub(crate) struct MediaControls {
+    attached: Arc<Mutex<HashMap<String, Attached>>>,
+}
+
+impl MediaControls {
+    /// Start managing `window` as media controls and show it
+    pub fn attach<R: Runtime>(
+        &self,
+        window: &WebviewWindow<R>,
+        auto_hide: Option<Duration>,
+    ) -> Result<()> {
+        self.attached
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .entry(window.label().to_string())
+            .and_modify(|entry| entry.auto_hide = auto_hide)
+            .or_insert(Attached {
+                auto_hide,
+                hide_at: None,
+                watching: false,
+            });
+
+        self.reveal(window)
+    }
+
+    /// Stop managing `window` as media controls; the window itself is left as is
+    pub fn detach(&self, label: &str) -> Result<()> {
+        self.attached
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(label);
+        Ok(())
+    }
+
+    /// Show the controls and restart the auto-hide timer
+    ///
+    /// Call this on user activity (e.g. mouse movement over the video). Each
+    /// window has at most one watcher thread; reveals while it waits only
+    /// push its deadline back.
+    pub fn reveal<R: Runtime>(&self, window: &WebviewWindow<R>) -> Result<()> {
+        let label = window.label().to_string();
+
+        let start_watching = {
+            let mut attached = self
+                .attached
+                .lock()
+                .map_err(|_| Error::RegistryLockFailed)?;
+            let entry = attached
+                .get_mut(&label)
+                .ok_or_else(|| Error::WindowNotFound(label.clone()))?;
+            entry.hide_at = entry.auto_hide.map(|delay| Instant::now() + delay);
+            entry.hide_at.is_some() && !std::mem::replace(&mut entry.watching, true)
+        };
+
+        if !window.is_visible()? {
+            window.show()?;
+            emit_visibility(window, true);
+        }
+
+        if start_watching {
+            let attached = Arc::clone(&self.attached);
+            let window = window.clone();
+            thread::spawn(move || watch_auto_hide(&attached, &window));
+        }
+
+        Ok(())
+    }
+}
+
+/// Sleep until the window's auto-hide deadline, following reveals that push
+/// it back, then hide the window
+///
+/// Returns without hiding once the window is detached or auto-hide is off.
+fn watch_auto_hide<R: Runtime>(
+    attached: &Mutex<HashMap<String, Attached>>,
+    window: &WebviewWindow<R>,
+) {
+    let label = window.label();
+    loop {
+        let Ok(mut attached) = attached.lock() else {
+            return;
+        };
+        let Some(entry) = attached.get_mut(label) else {
+            return; // detached
+        };
+        let Some(hide_at) = entry.hide_at else {
+            entry.watching = false;
+            return;
+        };
+
+        let now = Instant::now();
+        if now < hide_at {
+            drop(attached);
+            thread::sleep(hide_at - now);
+            continue;
+        }
+
+        // Hide while holding the lock, so a concurrent reveal sees the
+        // window hidden and shows it again
+        entry.hide_at = None;
+        entry.watching = false;
+        let hidden = window.hide();
+        drop(attached);
+        match hidden {
+            Ok(()) => emit_visibility(window, false),
+            Err(e) => warn!("Failed to auto-hide media controls '{}': {}", label, e),
+        }
+        return;
+    }
+}
+
+/// Size the window and anchor it to the bottom center of its monitor
+pub(crate) fn position_at_bottom<R: Runtime>(
+    window: &WebviewWindow<R>,
+    config: &MediaControlsConfig,
+) -> Result<()> {
+    let Some(monitor) = window.current_monitor()? else {
+        return Err(Error::MonitorNotFound(window.label().to_string()));
+    };
+
+    let scale = monitor.scale_factor();
+    let origin = monitor.position().to_logical::<f64>(scale);
+    let size = monitor.size().to_logical::<f64>(scale);
+
+    let width = config.width.unwrap_or(size.width * 0.6).min(size.width);
+    let x = origin.x + (size.width - width) / 2.0;
+    let y = origin.y + size.height - config.height - config.bottom_margin;
+
+    window.set_size(LogicalSize::new(width, config.height))?;
+    window.set_position(LogicalPosition::new(x, y))?;
+    Ok(())
+}
+
+fn emit_visibility<R: Runtime>(window: &WebviewWindow<R>, visible: bool) {
+    let payload = MediaControlsVisibility {
+        window: window.label().to_string(),
+        visible,
+    };
+    if let Err(e) = window.emit(MEDIA_CONTROLS_VISIBILITY_EVENT, payload) {
+        warn!("Failed to emit media controls visibility: {}", e);
+    }
+}
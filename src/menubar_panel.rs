@@ -0,0 +1,298 @@
+//! Menubar panel helper
+//!
+//! Covers the standard "menubar app" pattern: an app-created window shown as
+//! a glass panel directly under a tray (status) item, toggled by clicking the
+//! item and hidden again when it loses focus. The configuration types are
+//! always available; attaching panels requires the `tray-icon` feature.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::{GlassPreset, LiquidGlassConfig};
+
+#[cfg(feature = "tray-icon")]
+use {
+    crate::error::{Error, Result},
+    log::warn,
+    std::collections::HashMap,
+    std::sync::atomic::{AtomicBool, Ordering},
+    std::sync::Mutex,
+    std::time::{Duration, Instant},
+    tauri::tray::{MouseButton, MouseButtonState, TrayIconEvent},
+    tauri::{
+        AppHandle, Emitter, LogicalPosition, LogicalSize, Manager, Rect, Runtime, WebviewWindow,
+    },
+};
+
+/// Event emitted when a menubar panel is shown or hidden
+pub const MENUBAR_PANEL_VISIBILITY_EVENT: &str = "liquid-glass://menubar-panel-visibility";
+
+/// Clicks on the tray item this soon after the panel hid on blur are the
+/// click that caused the blur, so they must not reopen the panel
+#[cfg(feature = "tray-icon")]
+const BLUR_CLICK_GRACE: Duration = Duration::from_millis(250);
+
+/// Configuration for a menubar panel window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct MenubarPanelConfig {
+    /// Width of the panel in logical pixels
+    pub width: f64,
+
+    /// Height of the panel in logical pixels
+    pub height: f64,
+
+    /// Gap between the menu bar and the panel in logical pixels
+    pub gap: f64,
+
+    /// Corner radius of the panel window
+    pub corner_radius: f64,
+
+    /// Hide the panel when it loses focus
+    pub hide_on_blur: bool,
+
+    /// Glass configuration for the panel
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for MenubarPanelConfig {
+    fn default() -> Self {
+        Self {
+            width: 360.0,
+            height: 420.0,
+            gap: 4.0,
+            corner_radius: 12.0,
+            hide_on_blur: true,
+            glass: GlassPreset::Popover.into(),
+        }
+    }
+}
+
+/// Payload of [`MENUBAR_PANEL_VISIBILITY_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MenubarPanelVisibility {
+    /// Label of the panel window
+    pub window: String,
+    /// Id of the tray item the panel is attached to
+    pub tray: String,
+    /// Whether the panel is now visible
+    pub visible: bool,
+}
+
+/// A window attached to a tray item
+#[cfg(feature = "tray-icon")]
+#[derive(Clone)]
+struct Panel {
+    window: String,
+    config: MenubarPanelConfig,
+    /// When the panel last hid because it lost focus
+    blurred_at: Option<Instant>,
+}
+
+/// Tracks menubar panels by tray item id
+#[cfg(feature = "tray-icon")]
+#[derive(Default)]
+pub(crate) struct MenubarPanels {
+    panels: Mutex<HashMap<String, Panel>>,
+    listening: AtomicBool,
+}
+
+#[cfg(feature = "tray-icon")]
+impl MenubarPanels {
+    /// Attach `window` to the tray item `tray_id`, replacing any previous panel
+    ///
+    /// Returns true the first time a panel is attached, when the caller must
+    /// start forwarding tray and screen events.
+    pub fn attach(&self, tray_id: &str, window: &str, config: MenubarPanelConfig) -> Result<bool> {
+        self.panels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .insert(
+                tray_id.to_string(),
+                Panel {
+                    window: window.to_string(),
+                    config,
+                    blurred_at: None,
+                },
+            );
+        Ok(!self.listening.swap(true, Ordering::SeqCst))
+    }
+
+    /// Detach the panel of a tray item; the window itself is left as is
+    pub fn detach(&self, tray_id: &str) -> Result<bool> {
+        Ok(self
+            .panels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(tray_id)
+            .is_some())
+    }
+
+    /// Drop panels whose window was destroyed
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.panels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .retain(|_, panel| panel.window != label);
+        Ok(())
+    }
+
+    /// Toggle the panel of the tray item `tray_id` under `tray_rect`
+    pub fn toggle<R: Runtime>(
+        &self,
+        app: &AppHandle<R>,
+        tray_id: &str,
+        tray_rect: Rect,
+    ) -> Result<()> {
+        let Some(panel) = self.panel(tray_id)? else {
+            return Ok(());
+        };
+        let window = app
+            .get_webview_window(&panel.window)
+            .ok_or_else(|| Error::WindowNotFound(panel.window.clone()))?;
+
+        if window.is_visible()? {
+            window.hide()?;
+            emit_visibility(&window, tray_id, false);
+            return Ok(());
+        }
+
+        let just_blurred = panel
+            .blurred_at
+            .is_some_and(|at| at.elapsed() < BLUR_CLICK_GRACE);
+        if just_blurred {
+            return Ok(());
+        }
+
+        position_under(&window, &tray_rect, &panel.config)?;
+        window.show()?;
+        window.set_focus()?;
+        emit_visibility(&window, tray_id, true);
+        Ok(())
+    }
+
+    /// Hide the panel shown in `label` if it hides on blur
+    pub fn window_blurred<R: Runtime>(&self, window: &WebviewWindow<R>) -> Result<()> {
+        let tray_id = {
+            let mut panels = self.panels.lock().map_err(|_| Error::RegistryLockFailed)?;
+            let Some((tray_id, panel)) = panels
+                .iter_mut()
+                .find(|(_, panel)| panel.window == window.label())
+            else {
+                return Ok(());
+            };
+            if !panel.config.hide_on_blur {
+                return Ok(());
+            }
+            panel.blurred_at = Some(Instant::now());
+            tray_id.clone()
+        };
+
+        if window.is_visible()? {
+            window.hide()?;
+            emit_visibility(window, &tray_id, false);
+        }
+        Ok(())
+    }
+
+    /// Move visible panels back under their tray items, e.g. after the
+    /// display arrangement changed
+    #[cfg(target_os = "macos")]
+    pub fn reposition<R: Runtime>(&self, app: &AppHandle<R>) -> Result<()> {
+        let panels: Vec<_> = self
+            .panels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .iter()
+            .map(|(tray_id, panel)| (tray_id.clone(), panel.clone()))
+            .collect();
+
+        for (tray_id, panel) in panels {
+            let Some(window) = app.get_webview_window(&panel.window) else {
+                continue;
+            };
+            if !window.is_visible()? {
+                continue;
+            }
+            let Some(tray_rect) = app
+                .tray_by_id(&tray_id)
+                .and_then(|tray| tray.rect().ok().flatten())
+            else {
+                continue;
+            };
+            position_under(&window, &tray_rect, &panel.config)?;
+        }
+        Ok(())
+    }
+
+    fn panel(&self, tray_id: &str) -> Result<Option<Panel>> {
+        Ok(self
+            .panels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .get(tray_id)
+            .cloned())
+    }
+}
+
+/// Whether a tray event is a completed left click, and on which item
+#[cfg(feature = "tray-icon")]
+pub(crate) fn left_click(event: &TrayIconEvent) -> Option<(String, Rect)> {
+    match event {
+        TrayIconEvent::Click {
+            id,
+            rect,
+            button: MouseButton::Left,
+            button_state: MouseButtonState::Up,
+            ..
+        } => Some((id.as_ref().to_string(), *rect)),
+        _ => None,
+    }
+}
+
+/// Size the panel and center it under the tray item, kept on its monitor
+#[cfg(feature = "tray-icon")]
+fn position_under<R: Runtime>(
+    window: &WebviewWindow<R>,
+    tray_rect: &Rect,
+    config: &MenubarPanelConfig,
+) -> Result<()> {
+    let scale = window.scale_factor()?;
+    let item_position = tray_rect.position.to_physical::<f64>(scale);
+    let item_size = tray_rect.size.to_physical::<f64>(scale);
+    let center_x = item_position.x + item_size.width / 2.0;
+
+    let Some(monitor) = window
+        .monitor_from_point(center_x, item_position.y)?
+        .or(window.current_monitor()?)
+    else {
+        return Err(Error::MonitorNotFound(window.label().to_string()));
+    };
+
+    let scale = monitor.scale_factor();
+    let area = monitor.work_area();
+    let origin = area.position.to_logical::<f64>(scale);
+    let size = area.size.to_logical::<f64>(scale);
+
+    let item_bottom = (item_position.y + item_size.height) / scale;
+    let x = (center_x / scale - config.width / 2.0)
+        .min(origin.x + size.width - config.width)
+        .max(origin.x);
+    let y = item_bottom.max(origin.y) + config.gap;
+
+    window.set_size(LogicalSize::new(config.width, config.height))?;
+    window.set_position(LogicalPosition::new(x, y))?;
+    Ok(())
+}
+
+#[cfg(feature = "tray-icon")]
+fn emit_visibility<R: Runtime>(window: &WebviewWindow<R>, tray_id: &str, visible: bool) {
+    let payload = MenubarPanelVisibility {
+        window: window.label().to_string(),
+        tray: tray_id.to_string(),
+        visible,
+    };
+    if let Err(e) = window.emit(MENUBAR_PANEL_VISIBILITY_EVENT, payload) {
+        warn!("Failed to emit menubar panel visibility: {}", e);
+    }
+}
@@ -1,30 +1,1437 @@
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
+use tauri::WebviewUrl;
+
+use crate::color::parse_css_color;
 
 /// Configuration for the liquid glass effect
+///
+/// Deserializing runs [`migrate_config`] first, so configs written against an
+/// older schema keep working.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(remote = "Self", rename_all = "camelCase", default)]
+pub struct LiquidGlassConfig {
+    /// Schema version the config was written against, see
+    /// [`CONFIG_VERSION`]. Configs without one are treated as version 1.
+    pub version: u32,
+
+    /// Whether the glass effect is enabled
+    pub enabled: bool,
+
+    /// Corner radius for the glass view, in [`units`](Self::units)
+    pub corner_radius: f64,
+
+    /// Radius of each corner, e.g. only the top ones for sheet-style panels.
+    /// Overrides `corner_radius` and is applied as a shape mask, so it
+    /// yields to `mask` and takes precedence over `window_shape`.
+    pub corner_radii: Option<CornerRadii>,
+
+    /// Shape of rounded corners: circular arcs (the default) or the
+    /// continuous, squircle-like curve of system UI. Per-corner radii, masks
+    /// and shadows always use circular arcs.
+    pub corner_curve: CornerCurve,
+
+    /// Tint color as a CSS color string: hex (#RGB, #RRGGBB, #RRGGBBAA),
+    /// rgb()/rgba(), hsl()/hsla() or a named color
+    pub tint_color: Option<String>,
+
+    /// Glass material variant (experimental)
+    pub variant: GlassMaterialVariant,
+
+    /// Additional tint layers composed above the glass, bottom-most first
+    pub tint_layers: Vec<TintLayer>,
+
+    /// Material of the NSVisualEffectView fallback used before macOS 26.
+    /// `None` uses [`FallbackMaterial::UnderWindowBackground`]. Native glass
+    /// ignores this.
+    pub fallback_material: Option<FallbackMaterial>,
+
+    /// Whether the fallback blurs what is behind the window (the default) or
+    /// the window's own content below it. Within-window blending is needed
+    /// when the glass is layered above other native views.
+    pub fallback_blending_mode: FallbackBlendingMode,
+
+    /// Pin the fallback's active state. `None` (the default) keeps it active,
+    /// or follows key status when `key_state_transition_ms` is set; any
+    /// value here overrides that.
+    pub fallback_state: Option<FallbackState>,
+
+    /// Darken the content behind the glass (experimental, `scrimState`).
+    /// `None` leaves the view's current state; ignored where unsupported.
+    pub scrim: Option<bool>,
+
+    /// Render the subdued glass appearance (experimental, `subduedState`).
+    /// `None` leaves the view's current state; ignored where unsupported.
+    pub subdued: Option<bool>,
+
+    /// Highlight the glass under the pointer and when pressed, like system
+    /// controls (experimental, `interactive`). Only pointer input that
+    /// reaches the glass view counts. Ignored on NSVisualEffectView and on
+    /// builds without the property.
+    pub interactive: bool,
+
+    /// Insert an opaque base behind the glass so content stays legible over
+    /// bright desktops
+    pub opaque: bool,
+
+    /// Color of the opaque base as a CSS color string.
+    /// Defaults to the window's background color.
+    pub opaque_color: Option<String>,
+
+    /// Hairline border drawn along the glass edge
+    pub rim: Option<GlassRim>,
+
+    /// Drop shadow cast by the glass onto the content behind it
+    pub shadow: Option<GlassShadow>,
+
+    /// Frame of the glass within the window's content view.
+    /// `None` fills the whole content view.
+    pub frame: Option<GlassFrame>,
+
+    /// Unit of `corner_radius` and the frame's non-percentage lengths.
+    /// Pixel values are converted to points with the window's scale factor
+    /// when the config is applied, so stored configs are always in points.
+    pub units: Units,
+
+    /// Only show the glass while the content view matches this condition.
+    /// Re-evaluated natively on every resize.
+    pub visible_when: Option<SizeCondition>,
+
+    /// Clip the glass to the silhouette of a custom-shaped window so the blur
+    /// never bleeds outside it. Kept in sync on every resize.
+    pub window_shape: Option<WindowShape>,
+
+    /// Clip the glass to a shape within its own frame, e.g. a capsule for
+    /// pill buttons. Takes precedence over `window_shape`, is rebuilt on
+    /// every resize and is followed by the shadow.
+    pub mask: Option<GlassMask>,
+
+    /// Stack the glass below the webview (the default) or above it, e.g. for
+    /// HUD overlays. Glass above the webview lets clicks through to the web
+    /// content, so `interactive` has no effect there.
+    pub placement: GlassPlacement,
+
+    /// Let the glass view join the window's key view loop and draw a focus
+    /// ring. When false (the default) it refuses first responder, so Tab
+    /// navigation stays inside the webview.
+    pub focusable: bool,
+
+    /// Make the window and its webview transparent when the glass is
+    /// applied: the window becomes non-opaque with a clear background, and
+    /// the webview stops drawing its background and under-page color. When
+    /// false (the default) opaque windows and webviews are only warned
+    /// about.
+    pub auto_transparency: bool,
+
+    /// Make NSVisualEffectView fallbacks follow the window's key status,
+    /// crossfading to the inactive (desaturated) state over this many
+    /// milliseconds when the window resigns key, like system sidebars.
+    /// `None` (the default) keeps the fallback active. NSGlassEffectView
+    /// tracks key status on its own and ignores this.
+    pub key_state_transition_ms: Option<u64>,
+
+    /// Animate changes to an existing view (frame, corner radius, tint)
+    /// instead of applying them instantly. `None` (the default) snaps.
+    pub animation: Option<AnimationConfig>,
+
+    /// System backdrop used on Windows 11, where the whole window gets the
+    /// material and frame, corner radius and tint settings don't apply.
+    /// Ignored on macOS.
+    pub windows_backdrop: WindowsBackdrop,
+
+    /// Free-form JSON attached to the view for debugging, e.g. the component
+    /// that created it. Returned by `list_glass_views` and included in view
+    /// events; never affects rendering. At most [`MAX_METADATA_BYTES`] once
+    /// serialized.
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl Serialize for LiquidGlassConfig {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        LiquidGlassConfig::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LiquidGlassConfig {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+
+        let value = migrate_config(serde_json::Value::deserialize(deserializer)?)
+            .map_err(D::Error::custom)?;
+        LiquidGlassConfig::deserialize(value).map_err(D::Error::custom)
+    }
+}
+
+// ============================================================================
+// Schema versions and migrations
+// ============================================================================
+
+/// Version of the [`LiquidGlassConfig`] schema understood by this build
+pub const CONFIG_VERSION: u32 = 1;
+
+/// Upgrade a serialized config in place from one schema version to the next
+type Migration = fn(&mut serde_json::Map<String, serde_json::Value>);
+
+/// Config migrations, where entry `n` upgrades version `n + 1` to `n + 2`
+///
+/// When a change would misread older configs (a renamed or reinterpreted
+/// field), bump [`CONFIG_VERSION`] and append a step here. Purely additive
+/// fields need neither, since missing fields take their defaults.
+const CONFIG_MIGRATIONS: [Migration; CONFIG_VERSION as usize - 1] = [];
+
+/// Glass state document migrations, indexed like [`CONFIG_MIGRATIONS`]
+///
+/// The configs inside a document are migrated on their own when they are
+/// deserialized, so these only cover the document's own shape.
+const GLASS_STATE_MIGRATIONS: [Migration; GLASS_STATE_VERSION as usize - 1] = [];
+
+/// Upgrade a serialized [`LiquidGlassConfig`] to [`CONFIG_VERSION`]
+///
+/// Values that aren't objects are returned as is, so deserialization can
+/// report them. Fails for versions newer than this build understands.
+pub fn migrate_config(value: serde_json::Value) -> Result<serde_json::Value, String> {
+    migrate(value, "config", CONFIG_VERSION, &CONFIG_MIGRATIONS)
+}
+
+/// Upgrade a serialized [`GlassStateDocument`] to [`GLASS_STATE_VERSION`]
+pub fn migrate_glass_state(value: serde_json::Value) -> Result<serde_json::Value, String> {
+    migrate(
+        value,
+        "glass state",
+        GLASS_STATE_VERSION,
+        &GLASS_STATE_MIGRATIONS,
+    )
+}
+
+fn migrate(
+    mut value: serde_json::Value,
+    kind: &str,
+    current: u32,
+    migrations: &[Migration],
+) -> Result<serde_json::Value, String> {
+    let serde_json::Value::Object(map) = &mut value else {
+        return Ok(value);
+    };
+    let version = match map.get("version") {
+        None => 1,
+        Some(version) => version
+            .as_u64()
+            .and_then(|version| u32::try_from(version).ok())
+            .ok_or_else(|| format!("{kind} version must be a positive integer"))?,
+    };
+    if version == 0 || version > current {
+        return Err(format!(
+            "unsupported {kind} version {version} (expected 1 to {current})"
+        ));
+    }
+
+    for migration in &migrations[version as usize - 1..] {
+        migration(map);
+    }
+    map.insert("version".into(), current.into());
+    Ok(value)
+}
+
+/// Unit of a config's corner radius and frame lengths
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Units {
+    /// AppKit points, which equal CSS pixels in the webview at 100% zoom
+    #[default]
+    Points,
+    /// Device pixels, e.g. measured on a screenshot or taken from a design
+    /// exported at @2x; divided by the window's scale factor
+    Pixels,
+}
+
+/// Largest serialized size accepted for [`LiquidGlassConfig::metadata`]
+pub const MAX_METADATA_BYTES: usize = 4096;
+
+impl LiquidGlassConfig {
+    /// Convert `corner_radius`, rim and shadow sizes and frame lengths to
+    /// points, given the scale factor of the window the config is applied to
+    pub fn into_points(mut self, scale_factor: f64) -> Self {
+        if self.units == Units::Pixels && scale_factor > 0.0 {
+            self.corner_radius /= scale_factor;
+            if let Some(radii) = &mut self.corner_radii {
+                *radii = radii.scaled(1.0 / scale_factor);
+            }
+            if let Some(rim) = &mut self.rim {
+                rim.width /= scale_factor;
+            }
+            if let Some(shadow) = &mut self.shadow {
+                shadow.radius /= scale_factor;
+                shadow.offset_x /= scale_factor;
+                shadow.offset_y /= scale_factor;
+            }
+            self.frame = self.frame.map(|frame| frame.scaled(1.0 / scale_factor));
+            self.units = Units::Points;
+        }
+        self
+    }
+
+    /// Corner radius set on the glass layer: `corner_radius`, or zero when
+    /// `corner_radii` shapes the corners through a mask instead
+    pub fn layer_corner_radius(&self) -> f64 {
+        if self.corner_radii.is_some() {
+            0.0
+        } else {
+            self.corner_radius
+        }
+    }
+
+    /// Check that every CSS color in the config parses
+    ///
+    /// Returns the first offending string, so an unparseable tint fails
+    /// instead of silently removing the tint.
+    pub fn validate_colors(&self) -> Result<(), String> {
+        let colors = self
+            .tint_color
+            .iter()
+            .chain(&self.opaque_color)
+            .chain(self.tint_layers.iter().map(|layer| &layer.color))
+            .chain(self.rim.iter().map(|rim| &rim.color))
+            .chain(self.shadow.iter().map(|shadow| &shadow.color));
+        for color in colors {
+            if parse_css_color(color).is_none() {
+                return Err(color.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Check that the metadata blob stays within [`MAX_METADATA_BYTES`]
+    pub fn validate_metadata(&self) -> Result<(), String> {
+        let Some(metadata) = &self.metadata else {
+            return Ok(());
+        };
+        let size = serde_json::to_vec(metadata).map_or(0, |bytes| bytes.len());
+        if size > MAX_METADATA_BYTES {
+            return Err(format!(
+                "metadata is {size} bytes, the limit is {MAX_METADATA_BYTES}"
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for LiquidGlassConfig {
+    fn default() -> Self {
+        Self {
+            version: CONFIG_VERSION,
+            enabled: true,
+            corner_radius: 0.0,
+            corner_radii: None,
+            corner_curve: CornerCurve::default(),
+            tint_color: None,
+            variant: GlassMaterialVariant::default(),
+            tint_layers: Vec::new(),
+            fallback_material: None,
+            fallback_blending_mode: FallbackBlendingMode::default(),
+            fallback_state: None,
+            scrim: None,
+            subdued: None,
+            interactive: false,
+            opaque: false,
+            opaque_color: None,
+            rim: None,
+            shadow: None,
+            frame: None,
+            units: Units::default(),
+            visible_when: None,
+            window_shape: None,
+            mask: None,
+            placement: GlassPlacement::default(),
+            focusable: false,
+            auto_transparency: false,
+            key_state_transition_ms: None,
+            animation: None,
+            windows_backdrop: WindowsBackdrop::default(),
+            metadata: None,
+        }
+    }
+}
+
+/// Partial update of a [`LiquidGlassConfig`]
+///
+/// Only fields that are present are changed; the rest keep the values of
+/// the config currently applied. For fields that are optional in the config,
+/// `null` clears the value while a missing field leaves it, hence the
+/// nested `Option`s. Lengths are in the patch's [`units`](Self::units),
+/// points if unset.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LiquidGlassPatch {
+    pub enabled: Option<bool>,
+    pub corner_radius: Option<f64>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub corner_radii: Option<Option<CornerRadii>>,
+    pub corner_curve: Option<CornerCurve>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub tint_color: Option<Option<String>>,
+    pub variant: Option<GlassMaterialVariant>,
+    pub tint_layers: Option<Vec<TintLayer>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub fallback_material: Option<Option<FallbackMaterial>>,
+    pub fallback_blending_mode: Option<FallbackBlendingMode>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub fallback_state: Option<Option<FallbackState>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub scrim: Option<Option<bool>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub subdued: Option<Option<bool>>,
+    pub interactive: Option<bool>,
+    pub opaque: Option<bool>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub opaque_color: Option<Option<String>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub rim: Option<Option<GlassRim>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub shadow: Option<Option<GlassShadow>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub frame: Option<Option<GlassFrame>>,
+    /// Unit of the patch's lengths; the merged config is always in points
+    pub units: Option<Units>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub visible_when: Option<Option<SizeCondition>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub window_shape: Option<Option<WindowShape>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub mask: Option<Option<GlassMask>>,
+    pub placement: Option<GlassPlacement>,
+    pub focusable: Option<bool>,
+    pub auto_transparency: Option<bool>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub key_state_transition_ms: Option<Option<u64>>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub animation: Option<Option<AnimationConfig>>,
+    pub windows_backdrop: Option<WindowsBackdrop>,
+    #[serde(deserialize_with = "present", skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Option<serde_json::Value>>,
+}
+
+/// Deserialize a field that is present, `null` included, as `Some`
+///
+/// Missing fields take the struct default (`None`), so `Some(None)` means
+/// the field was explicitly `null`.
+fn present<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
+
+impl LiquidGlassPatch {
+    /// Apply the patch to `config`, which must be in points
+    ///
+    /// Patch lengths are converted to points with `scale_factor` first when
+    /// the patch is in pixels.
+    pub fn apply_to(self, config: &mut LiquidGlassConfig, scale_factor: f64) {
+        let Self {
+            enabled,
+            corner_radius,
+            corner_radii,
+            corner_curve,
+            tint_color,
+            variant,
+            tint_layers,
+            fallback_material,
+            fallback_blending_mode,
+            fallback_state,
+            scrim,
+            subdued,
+            interactive,
+            opaque,
+            opaque_color,
+            rim,
+            shadow,
+            frame,
+            units,
+            visible_when,
+            window_shape,
+            mask,
+            placement,
+            focusable,
+            auto_transparency,
+            key_state_transition_ms,
+            animation,
+            windows_backdrop,
+            metadata,
+        } = self;
+
+        // Scale the patch's lengths through a config holding only them
+        let lengths = LiquidGlassConfig {
+            corner_radius: corner_radius.unwrap_or_default(),
+            corner_radii: corner_radii.flatten(),
+            rim: rim.clone().flatten(),
+            shadow: shadow.clone().flatten(),
+            frame: frame.clone().flatten(),
+            units: units.unwrap_or_default(),
+            ..Default::default()
+        }
+        .into_points(scale_factor);
+        if corner_radius.is_some() {
+            config.corner_radius = lengths.corner_radius;
+        }
+        if corner_radii.is_some() {
+            config.corner_radii = lengths.corner_radii;
+        }
+        if rim.is_some() {
+            config.rim = lengths.rim;
+        }
+        if shadow.is_some() {
+            config.shadow = lengths.shadow;
+        }
+        if frame.is_some() {
+            config.frame = lengths.frame;
+        }
+
+        if let Some(enabled) = enabled {
+            config.enabled = enabled;
+        }
+        if let Some(corner_curve) = corner_curve {
+            config.corner_curve = corner_curve;
+        }
+        if let Some(tint_color) = tint_color {
+            config.tint_color = tint_color;
+        }
+        if let Some(variant) = variant {
+            config.variant = variant;
+        }
+        if let Some(tint_layers) = tint_layers {
+            config.tint_layers = tint_layers;
+        }
+        if let Some(fallback_material) = fallback_material {
+            config.fallback_material = fallback_material;
+        }
+        if let Some(fallback_blending_mode) = fallback_blending_mode {
+            config.fallback_blending_mode = fallback_blending_mode;
+        }
+        if let Some(fallback_state) = fallback_state {
+            config.fallback_state = fallback_state;
+        }
+        if let Some(scrim) = scrim {
+            config.scrim = scrim;
+        }
+        if let Some(subdued) = subdued {
+            config.subdued = subdued;
+        }
+        if let Some(interactive) = interactive {
+            config.interactive = interactive;
+        }
+        if let Some(opaque) = opaque {
+            config.opaque = opaque;
+        }
+        if let Some(opaque_color) = opaque_color {
+            config.opaque_color = opaque_color;
+        }
+        if let Some(visible_when) = visible_when {
+            config.visible_when = visible_when;
+        }
+        if let Some(window_shape) = window_shape {
+            config.window_shape = window_shape;
+        }
+        if let Some(mask) = mask {
+            config.mask = mask;
+        }
+        if let Some(placement) = placement {
+            config.placement = placement;
+        }
+        if let Some(focusable) = focusable {
+            config.focusable = focusable;
+        }
+        if let Some(auto_transparency) = auto_transparency {
+            config.auto_transparency = auto_transparency;
+        }
+        if let Some(key_state_transition_ms) = key_state_transition_ms {
+            config.key_state_transition_ms = key_state_transition_ms;
+        }
+        if let Some(animation) = animation {
+            config.animation = animation;
+        }
+        if let Some(windows_backdrop) = windows_backdrop {
+            config.windows_backdrop = windows_backdrop;
+        }
+        if let Some(metadata) = metadata {
+            config.metadata = metadata;
+        }
+    }
+}
+
+/// Transition used when a config is applied to an existing view
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AnimationConfig {
+    /// Length of the transition in milliseconds; 0 applies instantly
+    pub duration_ms: u64,
+    /// Pacing of the transition
+    pub timing: TimingFunction,
+}
+
+impl Default for AnimationConfig {
+    fn default() -> Self {
+        Self {
+            duration_ms: 250,
+            timing: TimingFunction::default(),
+        }
+    }
+}
+
+/// Pacing of an [`AnimationConfig`], after Core Animation's named timing functions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimingFunction {
+    /// Constant speed
+    Linear,
+    /// Starts slowly
+    EaseIn,
+    /// Ends slowly
+    EaseOut,
+    /// Starts and ends slowly
+    #[default]
+    EaseInOut,
+    /// The system's default curve, a gentle ease in and out
+    Default,
+}
+
+/// Where a glass view is stacked relative to the webview
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GlassPlacement {
+    /// Behind the webview, showing through its transparent areas
+    #[default]
+    Below,
+    /// Over the webview, passing clicks through to it
+    Above,
+}
+
+/// Windows 11 system backdrop material (`DWM_SYSTEMBACKDROP_TYPE`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowsBackdrop {
+    /// Mica, tinted with the desktop wallpaper; for long-lived main windows
+    #[default]
+    Mica,
+    /// Acrylic, a blur of what is behind the window; for transient surfaces
+    Acrylic,
+    /// Mica Alt, a stronger wallpaper tint; for tabbed title bars
+    Tabbed,
+}
+
+/// Silhouette of a custom-shaped (usually borderless) window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum WindowShape {
+    /// Rounded rectangle using the window's own corner radius, read from the
+    /// content view's layer (or the window's private `_cornerRadius`)
+    Auto,
+
+    /// Rounded rectangle covering the content view
+    Rounded { radius: f64 },
+
+    /// SVG path data in content view points with a top-left origin. With a
+    /// `viewBox` of `[width, height]` the path is scaled to the content size.
+    /// Arc commands are not supported.
+    Path {
+        d: String,
+        #[serde(default, rename = "viewBox")]
+        view_box: Option<[f64; 2]>,
+    },
+}
+
+/// NSVisualEffectView material for the fallback backend
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FallbackMaterial {
+    Titlebar,
+    Selection,
+    Menu,
+    Popover,
+    Sidebar,
+    HeaderView,
+    Sheet,
+    WindowBackground,
+    HudWindow,
+    FullScreenUi,
+    Tooltip,
+    ContentBackground,
+    /// The area behind the window's content, like the desktop shows through
+    #[default]
+    UnderWindowBackground,
+    UnderPageBackground,
+}
+
+/// What the fallback blurs (`NSVisualEffectBlendingMode`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FallbackBlendingMode {
+    /// The desktop and windows behind the window
+    #[default]
+    BehindWindow,
+    /// The window's own content below the view
+    WithinWindow,
+}
+
+/// Active state of the fallback (`NSVisualEffectState`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FallbackState {
+    /// Always rendered as active
+    Active,
+    /// Always rendered as inactive (desaturated)
+    Inactive,
+    /// Active while the window is key, switched by AppKit without a crossfade
+    FollowsWindowActiveState,
+}
+
+/// Curve of rounded corners (`CALayer.cornerCurve`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CornerCurve {
+    /// Quarter circles, `kCACornerCurveCircular`
+    #[default]
+    Circular,
+    /// Continuous curvature like system controls, `kCACornerCurveContinuous`
+    Continuous,
+}
+
+/// Radius of each corner of a glass view, in the config's units
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CornerRadii {
+    pub top_left: f64,
+    pub top_right: f64,
+    pub bottom_left: f64,
+    pub bottom_right: f64,
+}
+
+impl CornerRadii {
+    /// Multiply every radius by `factor`
+    pub fn scaled(self, factor: f64) -> Self {
+        Self {
+            top_left: self.top_left * factor,
+            top_right: self.top_right * factor,
+            bottom_left: self.bottom_left * factor,
+            bottom_right: self.bottom_right * factor,
+        }
+    }
+}
+
+/// Shape a glass view is clipped to, in the view's own coordinates
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum GlassMask {
+    /// Rounded rectangle filling the view
+    RoundedRect { radius: f64 },
+
+    /// Rounded rectangle with half the shorter side as radius (a pill)
+    Capsule,
+
+    /// Largest circle centered in the view
+    Circle,
+
+    /// SVG path data in the view's points with a top-left origin. With a
+    /// `viewBox` of `[width, height]` the path is scaled to the view's size.
+    /// Arc commands are not supported.
+    Path {
+        d: String,
+        #[serde(default, rename = "viewBox")]
+        view_box: Option<[f64; 2]>,
+    },
+}
+
+/// A length in points or as a percentage of the content view
+///
+/// Deserializes from a number (points) or a string such as `"50%"`.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "LengthRepr", into = "LengthRepr")]
+pub enum Length {
+    Points(f64),
+    Percent(f64),
+}
+
+impl Length {
+    /// Resolve to points within a container of the given extent
+    pub fn resolve(self, extent: f64) -> f64 {
+        match self {
+            Self::Points(points) => points,
+            Self::Percent(percent) => extent * percent / 100.0,
+        }
+    }
+
+    /// Multiply a fixed length by `factor`; percentages are unchanged
+    pub fn scaled(self, factor: f64) -> Self {
+        match self {
+            Self::Points(points) => Self::Points(points * factor),
+            Self::Percent(_) => self,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum LengthRepr {
+    Points(f64),
+    Percent(String),
+}
+
+impl TryFrom<LengthRepr> for Length {
+    type Error = String;
+
+    fn try_from(repr: LengthRepr) -> Result<Self, Self::Error> {
+        match repr {
+            LengthRepr::Points(points) => Ok(Self::Points(points)),
+            LengthRepr::Percent(s) => s
+                .trim()
+                .strip_suffix('%')
+                .and_then(|n| n.trim().parse().ok())
+                .map(Self::Percent)
+                .ok_or_else(|| format!("invalid length {s:?}, expected a number or \"N%\"")),
+        }
+    }
+}
+
+impl From<Length> for LengthRepr {
+    fn from(length: Length) -> Self {
+        match length {
+            Length::Points(points) => Self::Points(points),
+            Length::Percent(percent) => Self::Percent(format!("{percent}%")),
+        }
+    }
+}
+
+/// Content view size range in points, used for responsive glass
+///
+/// All bounds are inclusive; unset bounds are unconstrained.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SizeCondition {
+    pub min_width: Option<f64>,
+    pub max_width: Option<f64>,
+    pub min_height: Option<f64>,
+    pub max_height: Option<f64>,
+}
+
+impl SizeCondition {
+    /// Whether a content view of the given size satisfies the condition
+    pub fn matches(&self, width: f64, height: f64) -> bool {
+        self.min_width.map_or(true, |min| width >= min)
+            && self.max_width.map_or(true, |max| width <= max)
+            && self.min_height.map_or(true, |min| height >= min)
+            && self.max_height.map_or(true, |max| height <= max)
+    }
+}
+
+/// Edge a framed glass view is pinned to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Anchor {
+    /// Pinned to the top edge, centered horizontally
+    Top,
+    /// Pinned to the bottom edge, centered horizontally
+    Bottom,
+    /// Pinned to the leading (left) edge, centered vertically
+    Leading,
+    /// Pinned to the trailing (right) edge, centered vertically
+    Trailing,
+    /// Centered on both axes
+    Center,
+}
+
+/// Frame of a glass view within the window's content view
+///
+/// Works like CSS absolute positioning: each axis is resolved from its edge
+/// insets and size. Setting both insets stretches the view between them,
+/// setting one inset and the size pins it to that edge. Unset insets
+/// default to 0 and an unset size fills the remaining space. Percentages
+/// are relative to the content view and re-resolved natively on resize.
+///
+/// With an `anchor`, the view is pinned to that edge and centered along it;
+/// insets act as margins. `min_*`/`max_*` clamp the resolved size.
+///
+/// A toolbar strip is `{ top: 0, left: 0, right: 0, height: 52 }`; a dock is
+/// `{ anchor: "bottom", bottom: 12, width: "60%", maxWidth: 720, height: 64 }`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassFrame {
+    pub anchor: Option<Anchor>,
+    pub left: Option<Length>,
+    pub top: Option<Length>,
+    pub right: Option<Length>,
+    pub bottom: Option<Length>,
+    pub width: Option<Length>,
+    pub height: Option<Length>,
+    pub min_width: Option<Length>,
+    pub max_width: Option<Length>,
+    pub min_height: Option<Length>,
+    pub max_height: Option<Length>,
+    /// Position and size the view within the content view's safe area, e.g.
+    /// below the camera housing of a notched display in fullscreen
+    pub safe_area: bool,
+}
+
+impl GlassFrame {
+    /// Multiply every fixed length by `factor`; percentages are unchanged
+    pub fn scaled(&self, factor: f64) -> Self {
+        let scale = |length: Option<Length>| length.map(|length| length.scaled(factor));
+        Self {
+            anchor: self.anchor,
+            left: scale(self.left),
+            top: scale(self.top),
+            right: scale(self.right),
+            bottom: scale(self.bottom),
+            width: scale(self.width),
+            height: scale(self.height),
+            min_width: scale(self.min_width),
+            max_width: scale(self.max_width),
+            min_height: scale(self.min_height),
+            max_height: scale(self.max_height),
+            safe_area: self.safe_area,
+        }
+    }
+
+    /// Resolve to `(x, y, width, height)` in points, measured from the
+    /// container's top-left corner
+    pub fn resolve(&self, container_width: f64, container_height: f64) -> (f64, f64, f64, f64) {
+        let (align_x, align_y) = match self.anchor {
+            None => (None, None),
+            Some(Anchor::Top) => (Some(Align::Center), Some(Align::Start)),
+            Some(Anchor::Bottom) => (Some(Align::Center), Some(Align::End)),
+            Some(Anchor::Leading) => (Some(Align::Start), Some(Align::Center)),
+            Some(Anchor::Trailing) => (Some(Align::End), Some(Align::Center)),
+            Some(Anchor::Center) => (Some(Align::Center), Some(Align::Center)),
+        };
+
+        let (x, width) = Axis {
+            start: self.left,
+            end: self.right,
+            size: self.width,
+            min: self.min_width,
+            max: self.max_width,
+        }
+        .resolve(align_x, container_width);
+        let (y, height) = Axis {
+            start: self.top,
+            end: self.bottom,
+            size: self.height,
+            min: self.min_height,
+            max: self.max_height,
+        }
+        .resolve(align_y, container_height);
+
+        (x, y, width, height)
+    }
+
+    /// Resolve like [`resolve`](Self::resolve), but within the part of the
+    /// container left by `insets` when [`safe_area`](Self::safe_area) is set
+    pub fn resolve_in_safe_area(
+        &self,
+        container_width: f64,
+        container_height: f64,
+        insets: SafeAreaInsets,
+    ) -> (f64, f64, f64, f64) {
+        if !self.safe_area {
+            return self.resolve(container_width, container_height);
+        }
+        let (x, y, width, height) = self.resolve(
+            (container_width - insets.left - insets.right).max(0.0),
+            (container_height - insets.top - insets.bottom).max(0.0),
+        );
+        (x + insets.left, y + insets.top, width, height)
+    }
+}
+
+/// Distances from each edge of a view to the display's safe area, in points
+///
+/// Non-zero only where the view extends into an unsafe part of the screen,
+/// such as beside the camera housing of a notched display in fullscreen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafeAreaInsets {
+    pub top: f64,
+    pub left: f64,
+    pub bottom: f64,
+    pub right: f64,
+}
+
+/// Alignment of a view along one axis
+#[derive(Clone, Copy)]
+enum Align {
+    Start,
+    End,
+    Center,
+}
+
+/// Constraints of a frame along one axis
+struct Axis {
+    start: Option<Length>,
+    end: Option<Length>,
+    size: Option<Length>,
+    min: Option<Length>,
+    max: Option<Length>,
+}
+
+impl Axis {
+    /// Resolve to `(position, size)`
+    ///
+    /// Without an explicit alignment the view is end-aligned only when just
+    /// the end inset and size are set, matching CSS.
+    fn resolve(&self, align: Option<Align>, extent: f64) -> (f64, f64) {
+        let length = |l: Option<Length>| l.map(|l| l.resolve(extent));
+        let (start, end, size) = (length(self.start), length(self.end), length(self.size));
+
+        let align = align.unwrap_or(match (start, end, size) {
+            (None, Some(_), Some(_)) => Align::End,
+            _ => Align::Start,
+        });
+
+        let start = start.unwrap_or(0.0);
+        let end = end.unwrap_or(0.0);
+        let available = extent - start - end;
+
+        let mut size = size.unwrap_or(available);
+        if let Some(max) = length(self.max) {
+            size = size.min(max);
+        }
+        if let Some(min) = length(self.min) {
+            size = size.max(min);
+        }
+        let size = size.max(0.0);
+
+        let position = match align {
+            Align::Start => start,
+            Align::End => extent - end - size,
+            Align::Center => start + (available - size) / 2.0,
+        };
+
+        (position, size)
+    }
+}
+
+/// Hairline border drawn along the glass edge, following its corner radius
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassRim {
+    /// Border color as a CSS color string
+    pub color: String,
+
+    /// Border width, in the config's units
+    pub width: f64,
+}
+
+impl Default for GlassRim {
+    fn default() -> Self {
+        Self {
+            color: String::from("#FFFFFF33"),
+            width: 1.0,
+        }
+    }
+}
+
+/// Drop shadow cast by the glass
+///
+/// Drawn by a sibling view below the glass and cut out under it, so the
+/// shadow never shows through the translucent material.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassShadow {
+    /// Shadow color as a CSS color string, its alpha sets the shadow's opacity
+    pub color: String,
+
+    /// Blur radius, in the config's units
+    pub radius: f64,
+
+    /// Horizontal offset, positive moves right
+    pub offset_x: f64,
+
+    /// Vertical offset, positive moves down
+    pub offset_y: f64,
+}
+
+impl Default for GlassShadow {
+    fn default() -> Self {
+        Self {
+            color: String::from("#00000040"),
+            radius: 12.0,
+            offset_x: 0.0,
+            offset_y: 4.0,
+        }
+    }
+}
+
+/// A tint layer composed above the glass
+///
+/// Stacking layers reproduces effects like a subtle accent wash plus a dark scrim.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct TintLayer {
+    /// Layer color as a CSS color string
+    pub color: String,
+
+    /// How the layer blends with the content beneath it
+    pub blend_mode: BlendMode,
+
+    /// Layer opacity from 0.0 to 1.0, applied on top of the color's own alpha
+    pub opacity: f64,
+}
+
+impl Default for TintLayer {
+    fn default() -> Self {
+        Self {
+            color: String::from("transparent"),
+            blend_mode: BlendMode::default(),
+            opacity: 1.0,
+        }
+    }
+}
+
+/// Blend modes for tint layers
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BlendMode {
+    #[default]
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    SoftLight,
+    HardLight,
+    ColorDodge,
+    ColorBurn,
+    Darken,
+    Lighten,
+    Difference,
+    Exclusion,
+    Hue,
+    Saturation,
+    Color,
+    Luminosity,
+}
+
+/// Corner of a monitor's work area, for toasts and picture-in-picture windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ScreenCorner {
+    /// Top left corner, below the menu bar
+    TopLeft,
+    /// Top right corner, below the menu bar, where system notifications appear
+    #[default]
+    TopRight,
+    /// Bottom left corner, above the Dock if it is at the bottom
+    BottomLeft,
+    /// Bottom right corner, above the Dock if it is at the bottom
+    BottomRight,
+}
+
+impl ScreenCorner {
+    pub(crate) fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight)
+    }
+
+    pub(crate) fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// NSWindow level for overlay glass windows
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WindowLevel {
+    /// Regular window level
+    #[default]
+    Normal,
+    /// Above normal windows (utility panels)
+    Floating,
+    /// Modal panel level
+    ModalPanel,
+    /// Menu bar level
+    MainMenu,
+    /// Status item level
+    Status,
+    /// Pop-up menu level
+    PopUpMenu,
+    /// Above everything, including full-screen apps
+    ScreenSaver,
+}
+
+impl WindowLevel {
+    /// The corresponding NSWindowLevel value
+    pub fn ns_level(self) -> i64 {
+        match self {
+            Self::Normal => 0,
+            Self::Floating => 3,
+            Self::ModalPanel => 8,
+            Self::MainMenu => 24,
+            Self::Status => 25,
+            Self::PopUpMenu => 101,
+            Self::ScreenSaver => 1000,
+        }
+    }
+}
+
+/// Window level and collection behavior for overlay glass windows
+///
+/// To keep a glass overlay above full-screen apps, combine a high `level`
+/// with `fullScreenAuxiliary` and `canJoinAllSpaces`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowLevelConfig {
+    /// Window level
+    pub level: WindowLevel,
+
+    /// Show the window on every Space
+    pub can_join_all_spaces: bool,
+
+    /// Allow the window to appear over full-screen windows
+    pub full_screen_auxiliary: bool,
+
+    /// Keep the window in place when Mission Control or Exposé runs
+    pub stationary: bool,
+
+    /// Exclude the window from the Cmd+` window cycle
+    pub ignores_cycle: bool,
+}
+
+/// Options for a window created with
+/// [`create_glass_window`](crate::LiquidGlass::create_glass_window)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct WindowOptions {
+    /// Page the window's webview loads
+    pub url: WebviewUrl,
+
+    /// Window title, shown unless `hiddenTitle` is set
+    pub title: String,
+
+    /// Inner width in logical pixels
+    pub width: f64,
+
+    /// Inner height in logical pixels
+    pub height: f64,
+
+    /// Allow resizing the window
+    pub resizable: bool,
+
+    /// Center the window on the screen
+    pub center: bool,
+
+    /// Show the window once the glass is attached
+    pub visible: bool,
+
+    /// Hide the title text from the transparent title bar
+    pub hidden_title: bool,
+}
+
+impl Default for WindowOptions {
+    fn default() -> Self {
+        Self {
+            url: WebviewUrl::default(),
+            title: String::new(),
+            width: 800.0,
+            height: 600.0,
+            resizable: true,
+            center: true,
+            visible: true,
+            hidden_title: true,
+        }
+    }
+}
+
+/// A named glass region in a [`GlassLayout`]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassRegion {
+    /// Unique id of the region within the window, e.g. `"sidebar"`
+    pub id: String,
+
+    /// Frame of the region within the content view
+    pub frame: GlassFrame,
+
+    /// Preset used when `config` is not set
+    pub preset: Option<GlassPreset>,
+
+    /// Full glass configuration; its `frame` is replaced by the region's
+    pub config: Option<LiquidGlassConfig>,
+
+    /// Only show the region while the window matches this condition,
+    /// e.g. hide a sidebar below 700pt with `{ minWidth: 700 }`
+    pub when: Option<SizeCondition>,
+
+    /// Metadata for the region's view, overriding `config.metadata`
+    pub metadata: Option<serde_json::Value>,
+}
+
+impl GlassRegion {
+    /// The configuration to apply to this region's glass view
+    pub fn resolved_config(&self) -> LiquidGlassConfig {
+        let mut config = match (&self.config, self.preset) {
+            (Some(config), _) => config.clone(),
+            (None, Some(preset)) => preset.into(),
+            (None, None) => LiquidGlassConfig::default(),
+        };
+        config.enabled = true;
+        config.frame = Some(self.frame.clone());
+        if self.when.is_some() {
+            config.visible_when = self.when;
+        }
+        if self.metadata.is_some() {
+            config.metadata = self.metadata.clone();
+        }
+        config
+    }
+}
+
+/// Declarative description of all glass regions in a window
+///
+/// Applying a layout creates, updates and removes regions so the window ends
+/// up with exactly these regions, stacked in list order (first is bottom-most).
+///
+/// ```json
+/// {
+///   "regions": [
+///     { "id": "sidebar", "preset": "sidebar", "frame": { "top": 0, "bottom": 0, "left": 0, "width": 240 } },
+///     { "id": "toolbar", "frame": { "top": 0, "left": 240, "right": 0, "height": 52 } }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassLayout {
+    pub regions: Vec<GlassRegion>,
+}
+
+impl GlassLayout {
+    /// Convert regions whose config is in pixels to points, frames included
+    pub fn into_points(mut self, scale_factor: f64) -> Self {
+        for region in &mut self.regions {
+            let Some(config) = region.config.take() else {
+                continue;
+            };
+            if config.units == Units::Pixels && scale_factor > 0.0 {
+                region.frame = region.frame.scaled(1.0 / scale_factor);
+            }
+            region.config = Some(config.into_points(scale_factor));
+        }
+        self
+    }
+
+    /// Check that every region has a unique, non-empty id and small metadata
+    pub fn validate(&self) -> Result<(), String> {
+        let mut seen = std::collections::HashSet::new();
+        for region in &self.regions {
+            if region.id.trim().is_empty() {
+                return Err("region id must not be empty".into());
+            }
+            if !seen.insert(region.id.as_str()) {
+                return Err(format!("duplicate region id {:?}", region.id));
+            }
+            region
+                .resolved_config()
+                .validate_metadata()
+                .map_err(|e| format!("region {:?}: {e}", region.id))?;
+        }
+        Ok(())
+    }
+}
+
+/// Region ids affected by applying a [`GlassLayout`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayoutDiff {
+    pub added: Vec<String>,
+    pub updated: Vec<String>,
+    pub removed: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+/// Configuration for the scroll-edge effect
+///
+/// A glass strip along the top of the window that fades in once content
+/// scrolls under it, like the toolbar of macOS 26 apps.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", default)]
-pub struct LiquidGlassConfig {
-    /// Whether the glass effect is enabled
-    pub enabled: bool,
+pub struct ScrollEdgeConfig {
+    /// Height of the strip in points, typically the titlebar/toolbar height
+    pub height: f64,
 
-    /// Corner radius for the glass view in pixels
-    pub corner_radius: f64,
+    /// Scroll offset at which the strip fades in
+    pub show_after: f64,
 
-    /// Tint color in hex format (#RRGGBB or #RRGGBBAA)
-    pub tint_color: Option<String>,
+    /// Scroll offset at or below which the strip fades out again.
+    /// Keep below `show_after` so small scroll jitter doesn't toggle the strip.
+    pub hide_below: f64,
 
-    /// Glass material variant (experimental)
-    pub variant: GlassMaterialVariant,
+    /// Fade duration in milliseconds
+    pub fade_duration_ms: u64,
+
+    /// Glass configuration for the strip. A `frame` replaces the top-edge
+    /// pinning and `height`; it is resolved when the config is set, not on
+    /// window resize, so callers tracking an element re-send it. `placement`
+    /// is always `Above`: the strip sits over the webview and lets clicks
+    /// and scrolling through.
+    pub glass: LiquidGlassConfig,
 }
 
-impl Default for LiquidGlassConfig {
+impl Default for ScrollEdgeConfig {
     fn default() -> Self {
         Self {
-            enabled: true,
-            corner_radius: 0.0,
-            tint_color: None,
-            variant: GlassMaterialVariant::default(),
+            height: 52.0,
+            show_after: 8.0,
+            hide_below: 2.0,
+            fade_duration_ms: 150,
+            glass: LiquidGlassConfig::default(),
+        }
+    }
+}
+
+/// Ready-made glass configurations for common surfaces
+///
+/// Converts into a [`LiquidGlassConfig`], so presets can be passed wherever a
+/// config is accepted through `impl Into<LiquidGlassConfig>`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GlassPreset {
+    /// Full-window regular glass
+    #[default]
+    Regular,
+    /// Highly transparent glass
+    Clear,
+    /// Source-list style sidebar
+    Sidebar,
+    /// Trailing inspector panel
+    Inspector,
+    /// Small popover with rounded corners
+    Popover,
+    /// Widget-style card
+    Widget,
+    /// Control Center style module
+    ControlCenter,
+    /// Dark heads-up display
+    Hud,
+    /// Video-player control bar
+    MediaControls,
+}
+
+impl From<GlassPreset> for LiquidGlassConfig {
+    fn from(preset: GlassPreset) -> Self {
+        let (variant, corner_radius, tint_color) = match preset {
+            GlassPreset::Regular => (GlassMaterialVariant::Regular, 0.0, None),
+            GlassPreset::Clear => (GlassMaterialVariant::Clear, 0.0, None),
+            GlassPreset::Sidebar => (GlassMaterialVariant::Sidebar, 0.0, None),
+            GlassPreset::Inspector => (GlassMaterialVariant::Inspector, 0.0, None),
+            GlassPreset::Popover => (GlassMaterialVariant::CartouchePopover, 12.0, None),
+            GlassPreset::Widget => (GlassMaterialVariant::Widgets, 20.0, None),
+            GlassPreset::ControlCenter => (GlassMaterialVariant::ControlCenter, 24.0, None),
+            GlassPreset::Hud => (GlassMaterialVariant::Clear, 16.0, Some("#00000040")),
+            GlassPreset::MediaControls => (GlassMaterialVariant::Avplayer, 16.0, None),
+        };
+
+        Self {
+            corner_radius,
+            tint_color: tint_color.map(String::from),
+            variant,
+            ..Default::default()
         }
     }
 }
@@ -62,3 +1469,1068 @@ pub enum GlassMaterialVariant {
     Camera = 22,
     CartouchePopover = 23,
 }
+
+impl GlassMaterialVariant {
+    /// All known variants in declaration order
+    pub const ALL: [GlassMaterialVariant; 24] = [
+        Self::Regular,
+        Self::Clear,
+        Self::Dock,
+        Self::AppIcons,
+        Self::Widgets,
+        Self::Text,
+        Self::Avplayer,
+        Self::Facetime,
+        Self::ControlCenter,
+        Self::NotificationCenter,
+        Self::Monogram,
+        Self::Bubbles,
+        Self::Identity,
+        Self::FocusBorder,
+        Self::FocusPlatter,
+        Self::Keyboard,
+        Self::Sidebar,
+        Self::AbuttedSidebar,
+        Self::Inspector,
+        Self::Control,
+        Self::Loupe,
+        Self::Slider,
+        Self::Camera,
+        Self::CartouchePopover,
+    ];
+}
+
+/// Detailed runtime support information
+///
+/// Different macOS 26 builds honor different material variants, so this reports
+/// what the running OS actually accepts rather than what the enum declares.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SupportDetails {
+    /// Whether NSGlassEffectView is available
+    pub supported: bool,
+
+    /// Variants that were verified to be honored by NSGlassEffectView.
+    /// Empty when falling back to NSVisualEffectView, on non-macOS platforms,
+    /// or when the view has no `variant` getter to verify against.
+    pub supported_variants: Vec<GlassMaterialVariant>,
+
+    /// Experimental properties whose selectors NSGlassEffectView responds to.
+    /// Empty when falling back to NSVisualEffectView or on non-macOS platforms.
+    pub experimental_properties: Vec<ExperimentalProperty>,
+}
+
+/// Glass features usable on the running system
+///
+/// Finer-grained than [`SupportDetails`], for apps that degrade feature by
+/// feature. Everything is false or empty off macOS.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassCapabilities {
+    /// Backend new glass views are created with, accounting for safe mode.
+    /// `None` off macOS.
+    pub backend: Option<GlassBackendKind>,
+
+    /// Whether NSGlassEffectView is available
+    pub native_glass: bool,
+
+    /// Whether NSGlassEffectView implements `setTintColor:`; the fallback
+    /// always tints with an overlay view
+    pub tint_color: bool,
+
+    /// Whether NSGlassEffectContainerView is available, so grouped glass
+    /// merges
+    pub glass_container: bool,
+
+    /// Variants verified to be honored by NSGlassEffectView
+    pub supported_variants: Vec<GlassMaterialVariant>,
+
+    /// Whether the private `scrimState` setter exists
+    pub scrim: bool,
+
+    /// Whether the private `subduedState` setter exists
+    pub subdued: bool,
+
+    /// Whether the private `interactive` setter exists
+    pub interactive: bool,
+
+    /// Running macOS version, `None` off macOS
+    pub macos_version: Option<MacOSVersion>,
+}
+
+/// Which NSGlassEffectView selectors the running OS build responds to
+///
+/// Empty off macOS or when NSGlassEffectView is missing.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrivateApiReport {
+    /// Whether NSGlassEffectView is available
+    pub class_available: bool,
+
+    /// One probe per property the plugin sets by selector lookup
+    pub properties: Vec<SelectorProbe>,
+}
+
+/// Selectors found for one NSGlassEffectView property
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelectorProbe {
+    /// Property key, e.g. `scrimState`
+    pub key: String,
+
+    /// Responding setter, private `set_<key>:` preferred over `setKey:`.
+    /// `None` means setting the property has no effect.
+    pub setter: Option<String>,
+
+    /// Responding getter, private `_<key>` preferred over `<key>`
+    pub getter: Option<String>,
+}
+
+/// Experimental NSGlassEffectView properties, set through private selectors
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ExperimentalProperty {
+    /// Darkening scrim behind the glass (`scrimState`)
+    Scrim,
+    /// Reduced, subdued glass appearance (`subduedState`)
+    Subdued,
+    /// Interactive glass that reacts to pointer input (`interactive`)
+    Interactive,
+}
+
+impl ExperimentalProperty {
+    /// Every experimental property, in declaration order
+    pub const ALL: [Self; 3] = [Self::Scrim, Self::Subdued, Self::Interactive];
+
+    /// Name used in errors and by the guest-js API
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Scrim => "scrim",
+            Self::Subdued => "subdued",
+            Self::Interactive => "interactive",
+        }
+    }
+
+    /// Write the property into the matching field of a stored configuration
+    #[cfg(target_os = "macos")]
+    pub(crate) fn store(self, config: &mut LiquidGlassConfig, enabled: bool) {
+        match self {
+            Self::Scrim => config.scrim = Some(enabled),
+            Self::Subdued => config.subdued = Some(enabled),
+            Self::Interactive => config.interactive = enabled,
+        }
+    }
+}
+
+/// System appearance a glass view is rendered with
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Appearance {
+    Light,
+    Dark,
+}
+
+/// Suggested foreground color for content drawn over a glass view
+///
+/// Estimated from the effective tint, variant and appearance; the content
+/// behind the glass is unknown, so treat the ratio as approximate.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ForegroundRecommendation {
+    /// Recommended foreground as a hex color (`#ffffff` or `#000000`)
+    pub color: String,
+
+    /// Estimated WCAG contrast ratio of `color` against the glass (1 to 21)
+    pub contrast_ratio: f64,
+
+    /// Appearance the estimate was made for
+    pub appearance: Appearance,
+}
+
+/// User-chosen system colors, resolved for the app's effective appearance
+///
+/// Each color is an sRGB `#rrggbbaa` string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemColors {
+    /// Accent color (`controlAccentColor`)
+    pub accent: String,
+
+    /// Text highlight color (`selectedTextBackgroundColor`)
+    pub highlight: String,
+
+    /// Keyboard focus ring color (`keyboardFocusIndicatorColor`)
+    pub focus_ring: String,
+}
+
+/// Version of the running macOS
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MacOSVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+
+    /// Whether this is macOS 26 (Tahoe) or later, where NSGlassEffectView ships
+    pub is_tahoe_or_later: bool,
+}
+
+impl MacOSVersion {
+    /// Create a version, deriving `is_tahoe_or_later` from `major`
+    pub fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            is_tahoe_or_later: major >= 26,
+        }
+    }
+}
+
+/// Native view type backing a glass view
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GlassBackendKind {
+    /// NSGlassEffectView (macOS 26+)
+    Native,
+    /// NSVisualEffectView fallback; variants are ignored
+    VisualEffect,
+}
+
+/// How region glass views behave while their window is being live-resized
+///
+/// Every resize step re-resolves region frames and rebuilds shadow paths,
+/// which can make resizing lag with many regions. The cheaper modes trade
+/// fidelity during the drag for smoothness; full fidelity is restored when
+/// the resize ends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum LiveResizeMode {
+    /// Re-lay out regions on every resize step
+    #[default]
+    Full,
+    /// Keep region frames where they were until the resize ends
+    Freeze,
+    /// Render regions as flat opaque placeholders that follow the resize
+    Placeholder,
+}
+
+/// Why a glass view was created with NSVisualEffectView instead of
+/// NSGlassEffectView
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum FallbackReason {
+    /// NSGlassEffectView does not exist on this macOS version
+    ClassUnavailable,
+    /// NSGlassEffectView exists but failed to initialize
+    InitFailed,
+    /// The plugin was built with `safe_mode(true)`
+    SafeMode,
+}
+
+/// Description of a glass view tracked by the plugin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassViewInfo {
+    /// Label of the window hosting the view
+    pub window: String,
+
+    /// Id of a named view (layout region or `set_glass_view`), `None` for
+    /// the full-window glass
+    pub region: Option<String>,
+
+    /// Backend the view was created with
+    pub backend: GlassBackendKind,
+
+    /// Why the view fell back to NSVisualEffectView, `None` for native views
+    #[serde(default)]
+    pub fallback_reason: Option<FallbackReason>,
+
+    /// Configuration currently applied to the view
+    pub config: LiquidGlassConfig,
+}
+
+/// Version of the [`GlassStateDocument`] format written by this build
+pub const GLASS_STATE_VERSION: u32 = 1;
+
+/// Snapshot of every window's glass views, e.g. to save as a theme file or
+/// attach to a bug report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassStateDocument {
+    /// Format version, [`GLASS_STATE_VERSION`] when exported by this build
+    pub version: u32,
+
+    /// macOS version the state was captured on, `None` on other platforms
+    #[serde(default)]
+    pub macos_version: Option<MacOSVersion>,
+
+    /// Windows that have glass, ordered by label
+    pub windows: Vec<WindowGlassState>,
+}
+
+/// Glass views of one window in a [`GlassStateDocument`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindowGlassState {
+    /// Label of the window
+    pub label: String,
+
+    /// The full-window glass first, if any, then named views in stacking order
+    pub views: Vec<GlassViewState>,
+}
+
+/// One glass view in a [`WindowGlassState`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassViewState {
+    /// Id of a named view, `None` for the full-window glass
+    #[serde(default)]
+    pub region: Option<String>,
+
+    /// Backend the view was created with
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backend: Option<GlassBackendKind>,
+
+    /// Configuration applied to the view
+    pub config: LiquidGlassConfig,
+}
+
+impl GlassStateDocument {
+    /// Check that the document is at the current version and every window
+    /// appears once, with at most one full-window view and unique, non-empty
+    /// region ids
+    pub fn validate(&self) -> Result<(), String> {
+        if self.version != GLASS_STATE_VERSION {
+            return Err(format!(
+                "version {} needs migrating to {} first",
+                self.version, GLASS_STATE_VERSION
+            ));
+        }
+        let mut labels = std::collections::HashSet::new();
+        for window in &self.windows {
+            if !labels.insert(window.label.as_str()) {
+                return Err(format!("duplicate window {:?}", window.label));
+            }
+            let mut effects = window.views.iter().filter(|v| v.region.is_none());
+            if let Some(view) = effects.next() {
+                view.config
+                    .validate_metadata()
+                    .map_err(|e| format!("window {:?}: {e}", window.label))?;
+            }
+            if effects.next().is_some() {
+                return Err(format!(
+                    "window {:?} has more than one full-window view",
+                    window.label
+                ));
+            }
+            window
+                .layout()
+                .validate()
+                .map_err(|e| format!("window {:?}: {e}", window.label))?;
+        }
+        Ok(())
+    }
+}
+
+impl WindowGlassState {
+    /// Config of the full-window glass, if the window has one
+    pub fn effect(&self) -> Option<LiquidGlassConfig> {
+        self.views
+            .iter()
+            .find(|view| view.region.is_none())
+            .map(|view| view.config.clone())
+    }
+
+    /// The window's named views as a layout
+    pub fn layout(&self) -> GlassLayout {
+        let regions = self
+            .views
+            .iter()
+            .filter_map(|view| {
+                let id = view.region.clone()?;
+                Some(GlassRegion {
+                    id,
+                    frame: view.config.frame.clone().unwrap_or_default(),
+                    config: Some(view.config.clone()),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        GlassLayout { regions }
+    }
+}
+
+/// Options for importing a [`GlassStateDocument`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GlassStateImportOptions {
+    /// Apply the state of a document window to a differently labelled
+    /// window, keyed by the label in the document
+    pub window_labels: std::collections::HashMap<String, String>,
+
+    /// Skip document windows that don't exist instead of failing
+    pub skip_missing_windows: bool,
+}
+
+/// A rectangle with a top-left origin
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewRect {
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+impl From<ViewRect> for GlassFrame {
+    /// Pin a view at a fixed rectangle, measured from the top-left corner
+    fn from(rect: ViewRect) -> Self {
+        Self {
+            left: Some(Length::Points(rect.x)),
+            top: Some(Length::Points(rect.y)),
+            width: Some(Length::Points(rect.width)),
+            height: Some(Length::Points(rect.height)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Current native frame of a glass view
+///
+/// Read back from AppKit rather than the stored config, so it reflects what
+/// is actually on screen after autoresizing and relayouts.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassViewFrame {
+    /// Frame in points, relative to the window's content view
+    pub points: ViewRect,
+
+    /// Frame in CSS pixels, relative to the webview's viewport (accounts for
+    /// page zoom), comparable with `getBoundingClientRect()`
+    pub css: ViewRect,
+
+    /// Backing scale factor of the window (physical pixels per point)
+    pub scale_factor: f64,
+
+    /// Whether the view is currently hidden, e.g. by `visible_when`
+    pub hidden: bool,
+}
+
+/// Glass view property watched for changes made outside the plugin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ObservedProperty {
+    TintColor,
+    Variant,
+    CornerRadius,
+}
+
+/// Why a registered glass view was considered orphaned
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OrphanReason {
+    /// The Tauri window it was created for no longer exists
+    WindowClosed,
+    /// The view was removed from its superview by other code
+    NoSuperview,
+    /// The view's hierarchy is no longer attached to a window
+    NoWindow,
+}
+
+/// A registry entry pruned by a health check
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrphanedView {
+    /// Label of the window the view was registered for
+    pub window: String,
+
+    /// Region id, `None` for the full-window glass
+    pub region: Option<String>,
+
+    /// Why the view was pruned
+    pub reason: OrphanReason,
+}
+
+/// Result of validating the glass view registry
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassHealthReport {
+    /// Number of registered views that were checked
+    pub checked: usize,
+
+    /// Orphaned views that were pruned from the registry
+    pub orphans: Vec<OrphanedView>,
+}
+
+/// Title bar setup of a window, which decides whether glass reaches the top
+/// edge
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TitlebarStyle {
+    /// Whether the window has a title bar at all
+    pub titled: bool,
+
+    /// Whether the content view extends under the title bar
+    pub full_size_content_view: bool,
+
+    /// Whether the title bar draws no background of its own
+    pub transparent: bool,
+
+    /// Whether the title text is hidden
+    pub title_hidden: bool,
+}
+
+/// Everything that decides whether glass shows in a window, for debugging
+/// "the effect is applied but nothing shows"
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GlassDoctorReport {
+    /// Label of the diagnosed window
+    pub window: String,
+
+    /// macOS version, glass class availability and the backend new views get
+    pub capabilities: GlassCapabilities,
+
+    /// Whether all effects are suspended
+    pub suspended: bool,
+
+    /// Whether the NSWindow is opaque, `None` off macOS
+    pub window_opaque: Option<bool>,
+
+    /// Alpha of the NSWindow's background color, `None` off macOS
+    pub background_alpha: Option<f64>,
+
+    /// Whether the webview draws its own background, `None` off macOS or
+    /// when no webview was found
+    pub webview_draws_background: Option<bool>,
+
+    /// Title bar setup, `None` off macOS
+    pub titlebar: Option<TitlebarStyle>,
+
+    /// Glass views registered for the window, full-window view first
+    pub views: Vec<GlassViewInfo>,
+
+    /// Problems found, in plain language. Empty when nothing looks wrong.
+    pub problems: Vec<String>,
+}
+
+/// Core Animation state of a view's backing layer
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LayerInfo {
+    /// Objective-C class of the layer
+    pub class: String,
+
+    /// Layer opacity
+    pub opacity: f64,
+
+    /// Corner radius in points
+    pub corner_radius: f64,
+
+    /// Whether sublayers are clipped to the layer's bounds
+    pub masks_to_bounds: bool,
+
+    /// Whether the layer has a mask layer, e.g. a window shape or glass mask
+    pub has_mask: bool,
+
+    /// Whether the layer is marked opaque
+    pub opaque: bool,
+}
+
+/// A node of a window's view hierarchy, as dumped by
+/// [`dump_view_hierarchy`](crate::LiquidGlass::dump_view_hierarchy)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ViewNode {
+    /// Objective-C class of the view, e.g. `NSGlassEffectView` or `WKWebView`
+    pub class: String,
+
+    /// Frame within the superview in points, with a top-left origin
+    pub frame: ViewRect,
+
+    /// Whether the view is hidden
+    pub hidden: bool,
+
+    /// View alpha
+    pub alpha: f64,
+
+    /// Whether the view is a glass view registered by the plugin
+    pub glass: bool,
+
+    /// Backing layer, `None` for views without one
+    pub layer: Option<LayerInfo>,
+
+    /// Subviews, back to front
+    pub subviews: Vec<ViewNode>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Test step renaming `old` to `new`
+    fn rename_old(map: &mut serde_json::Map<String, serde_json::Value>) {
+        if let Some(value) = map.remove("old") {
+            map.insert("new".into(), value);
+        }
+    }
+
+    /// Test step doubling `new`
+    fn double_new(map: &mut serde_json::Map<String, serde_json::Value>) {
+        if let Some(value) = map.get("new").and_then(|value| value.as_f64()) {
+            map.insert("new".into(), json!(value * 2.0));
+        }
+    }
+
+    #[test]
+    fn migration_steps_run_from_the_payload_version() {
+        let migrations: [Migration; 2] = [rename_old, double_new];
+        for (input, expected) in [
+            (json!({ "old": 2.0 }), json!({ "version": 3, "new": 4.0 })),
+            (
+                json!({ "version": 1, "old": 2.0 }),
+                json!({ "version": 3, "new": 4.0 }),
+            ),
+            (
+                json!({ "version": 2, "new": 2.0 }),
+                json!({ "version": 3, "new": 4.0 }),
+            ),
+            (
+                json!({ "version": 3, "new": 2.0 }),
+                json!({ "version": 3, "new": 2.0 }),
+            ),
+        ] {
+            assert_eq!(
+                migrate(input.clone(), "test", 3, &migrations).as_ref(),
+                Ok(&expected),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_versions_are_rejected() {
+        for version in [json!(0), json!(CONFIG_VERSION + 1), json!(-1), json!("1")] {
+            let input = json!({ "version": version, "enabled": true });
+            assert!(migrate_config(input.clone()).is_err(), "{input}");
+        }
+        let future = json!({ "version": GLASS_STATE_VERSION + 1, "windows": [] });
+        assert!(migrate_glass_state(future).is_err());
+    }
+
+    #[test]
+    fn configs_without_a_version_load() {
+        let config: LiquidGlassConfig =
+            serde_json::from_value(json!({ "cornerRadius": 12.0 })).unwrap();
+        assert_eq!(config.version, CONFIG_VERSION);
+        assert_eq!(config.corner_radius, 12.0);
+
+        let future = json!({ "version": CONFIG_VERSION + 1 });
+        assert!(serde_json::from_value::<LiquidGlassConfig>(future).is_err());
+    }
+
+    #[test]
+    fn glass_mask_shapes() {
+        for (input, expected) in [
+            (
+                json!({ "type": "roundedRect", "radius": 8.0 }),
+                GlassMask::RoundedRect { radius: 8.0 },
+            ),
+            (json!({ "type": "capsule" }), GlassMask::Capsule),
+            (json!({ "type": "circle" }), GlassMask::Circle),
+            (
+                json!({ "type": "path", "d": "M0 0 L1 1 Z" }),
+                GlassMask::Path {
+                    d: "M0 0 L1 1 Z".into(),
+                    view_box: None,
+                },
+            ),
+            (
+                json!({ "type": "path", "d": "M0 0", "viewBox": [24.0, 24.0] }),
+                GlassMask::Path {
+                    d: "M0 0".into(),
+                    view_box: Some([24.0, 24.0]),
+                },
+            ),
+        ] {
+            let mask: GlassMask = serde_json::from_value(input.clone()).unwrap();
+            assert_eq!(mask, expected, "{input}");
+            assert_eq!(serde_json::to_value(&mask).unwrap()["type"], input["type"]);
+        }
+        assert!(serde_json::from_value::<GlassMask>(json!({ "type": "star" })).is_err());
+    }
+
+    #[test]
+    fn lengths() {
+        for (input, expected) in [
+            (json!(12.0), Length::Points(12.0)),
+            (json!(-4), Length::Points(-4.0)),
+            (json!("50%"), Length::Percent(50.0)),
+            (json!(" 12.5 % "), Length::Percent(12.5)),
+        ] {
+            let length: Length = serde_json::from_value(input.clone()).unwrap();
+            assert_eq!(length, expected, "{input}");
+        }
+        for input in [json!("50"), json!("%"), json!("half%"), json!(true)] {
+            assert!(
+                serde_json::from_value::<Length>(input.clone()).is_err(),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            serde_json::to_value(Length::Points(12.0)).unwrap(),
+            json!(12.0)
+        );
+        assert_eq!(
+            serde_json::to_value(Length::Percent(50.0)).unwrap(),
+            json!("50%")
+        );
+        assert_eq!(Length::Percent(25.0).resolve(800.0), 200.0);
+        assert_eq!(Length::Percent(25.0).scaled(2.0), Length::Percent(25.0));
+        assert_eq!(Length::Points(10.0).scaled(2.0), Length::Points(20.0));
+    }
+
+    #[test]
+    fn frame_resolution() {
+        for (input, expected) in [
+            (
+                json!({ "top": 0, "left": 0, "right": 0, "height": 52 }),
+                (0.0, 0.0, 800.0, 52.0),
+            ),
+            (
+                json!({ "right": 10, "bottom": 20, "width": 100, "height": 50 }),
+                (690.0, 530.0, 100.0, 50.0),
+            ),
+            (
+                json!({ "left": "10%", "right": "10%", "top": 10, "bottom": 10 }),
+                (80.0, 10.0, 640.0, 580.0),
+            ),
+            (
+                json!({ "width": 50, "minWidth": 100, "height": 900, "maxHeight": "50%" }),
+                (0.0, 0.0, 100.0, 300.0),
+            ),
+            (
+                json!({ "left": 500, "right": 500 }),
+                (500.0, 0.0, 0.0, 600.0),
+            ),
+            (
+                json!({
+                    "anchor": "bottom",
+                    "bottom": 12,
+                    "width": "60%",
+                    "maxWidth": 720,
+                    "height": 64
+                }),
+                (160.0, 524.0, 480.0, 64.0),
+            ),
+            (
+                json!({ "anchor": "trailing", "right": 8, "width": 40, "height": 200 }),
+                (752.0, 200.0, 40.0, 200.0),
+            ),
+            (
+                json!({ "anchor": "center", "width": 200, "height": 100 }),
+                (300.0, 250.0, 200.0, 100.0),
+            ),
+        ] {
+            let frame: GlassFrame = serde_json::from_value(input.clone()).unwrap();
+            assert_eq!(frame.resolve(800.0, 600.0), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn frames_in_the_safe_area() {
+        let insets = SafeAreaInsets {
+            top: 32.0,
+            left: 0.0,
+            bottom: 0.0,
+            right: 40.0,
+        };
+        let toolbar = json!({ "top": 0, "left": 0, "right": 0, "height": 52 });
+
+        let frame: GlassFrame = serde_json::from_value(toolbar.clone()).unwrap();
+        assert_eq!(
+            frame.resolve_in_safe_area(800.0, 600.0, insets),
+            (0.0, 0.0, 800.0, 52.0)
+        );
+
+        let mut safe = toolbar;
+        safe["safeArea"] = json!(true);
+        let frame: GlassFrame = serde_json::from_value(safe).unwrap();
+        assert_eq!(
+            frame.resolve_in_safe_area(800.0, 600.0, insets),
+            (0.0, 32.0, 760.0, 52.0)
+        );
+    }
+
+    #[test]
+    fn layout_validation() {
+        let region = |id: &str| GlassRegion {
+            id: id.into(),
+            ..Default::default()
+        };
+        let layout = |regions| GlassLayout { regions };
+
+        assert_eq!(layout(vec![]).validate(), Ok(()));
+        assert_eq!(layout(vec![region("a"), region("b")]).validate(), Ok(()));
+        assert!(layout(vec![region("a"), region(" ")]).validate().is_err());
+        assert!(layout(vec![region("a"), region("b"), region("a")])
+            .validate()
+            .is_err());
+
+        let oversized = GlassRegion {
+            metadata: Some(json!("x".repeat(MAX_METADATA_BYTES))),
+            ..region("a")
+        };
+        assert!(layout(vec![oversized]).validate().is_err());
+    }
+
+    #[test]
+    fn region_configs() {
+        let frame: GlassFrame = serde_json::from_value(json!({ "width": 240 })).unwrap();
+        let disabled = LiquidGlassConfig {
+            enabled: false,
+            corner_radius: 12.0,
+            frame: Some(GlassFrame::default()),
+            metadata: Some(json!("config")),
+            ..Default::default()
+        };
+
+        let config = GlassRegion {
+            id: "sidebar".into(),
+            frame: frame.clone(),
+            preset: Some(GlassPreset::Popover),
+            config: Some(disabled.clone()),
+            ..Default::default()
+        }
+        .resolved_config();
+        assert!(config.enabled);
+        assert_eq!(config.corner_radius, 12.0);
+        assert_eq!(config.frame.as_ref(), Some(&frame));
+        assert_eq!(config.metadata, Some(json!("config")));
+
+        let when = SizeCondition {
+            min_width: Some(700.0),
+            ..Default::default()
+        };
+        let config = GlassRegion {
+            id: "sidebar".into(),
+            config: Some(disabled),
+            when: Some(when),
+            metadata: Some(json!("region")),
+            ..Default::default()
+        }
+        .resolved_config();
+        assert_eq!(config.visible_when, Some(when));
+        assert_eq!(config.metadata, Some(json!("region")));
+
+        let preset = GlassRegion {
+            id: "popover".into(),
+            preset: Some(GlassPreset::Popover),
+            ..Default::default()
+        };
+        let expected = LiquidGlassConfig {
+            frame: Some(GlassFrame::default()),
+            ..GlassPreset::Popover.into()
+        };
+        assert_eq!(preset.resolved_config(), expected);
+    }
+
+    #[test]
+    fn size_conditions() {
+        for (condition, (width, height), expected) in [
+            (json!({}), (0.0, 0.0), true),
+            (json!({ "minWidth": 700 }), (700.0, 400.0), true),
+            (json!({ "minWidth": 700 }), (699.5, 400.0), false),
+            (json!({ "maxWidth": 700 }), (700.0, 400.0), true),
+            (json!({ "maxWidth": 700 }), (701.0, 400.0), false),
+            (
+                json!({ "minHeight": 300, "maxHeight": 500 }),
+                (100.0, 400.0),
+                true,
+            ),
+            (
+                json!({ "minHeight": 300, "maxHeight": 500 }),
+                (100.0, 200.0),
+                false,
+            ),
+            (
+                json!({ "minHeight": 300, "maxHeight": 500 }),
+                (100.0, 600.0),
+                false,
+            ),
+            (
+                json!({ "minWidth": 700, "minHeight": 300 }),
+                (800.0, 200.0),
+                false,
+            ),
+        ] {
+            let when: SizeCondition = serde_json::from_value(condition.clone()).unwrap();
+            assert_eq!(
+                when.matches(width, height),
+                expected,
+                "{condition} at {width}x{height}"
+            );
+        }
+    }
+
+    #[test]
+    fn glass_state_validation() {
+        let document = |windows: serde_json::Value| -> GlassStateDocument {
+            serde_json::from_value(json!({ "version": GLASS_STATE_VERSION, "windows": windows }))
+                .unwrap()
+        };
+        let effect = json!({ "config": {} });
+        let region = |id: &str| json!({ "region": id, "config": {} });
+
+        for (windows, valid) in [
+            (json!([]), true),
+            (
+                json!([
+                    { "label": "main", "views": [effect, region("a"), region("b")] },
+                    { "label": "settings", "views": [region("a")] }
+                ]),
+                true,
+            ),
+            (
+                json!([
+                    { "label": "main", "views": [] },
+                    { "label": "main", "views": [] }
+                ]),
+                false,
+            ),
+            (
+                json!([{ "label": "main", "views": [effect, effect] }]),
+                false,
+            ),
+            (
+                json!([{ "label": "main", "views": [region("a"), region("a")] }]),
+                false,
+            ),
+            (json!([{ "label": "main", "views": [region("")] }]), false),
+        ] {
+            assert_eq!(
+                document(windows.clone()).validate().is_ok(),
+                valid,
+                "{windows}"
+            );
+        }
+
+        let mut outdated = document(json!([]));
+        outdated.version = GLASS_STATE_VERSION - 1;
+        assert!(outdated.validate().is_err());
+    }
+
+    #[test]
+    fn window_state_views() {
+        let window: WindowGlassState = serde_json::from_value(json!({
+            "label": "main",
+            "views": [
+                { "config": { "cornerRadius": 8 } },
+                { "region": "toolbar", "config": { "frame": { "height": 52 } } },
+                { "region": "sidebar", "config": {} }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(
+            window.effect().map(|config| config.corner_radius),
+            Some(8.0)
+        );
+
+        let layout = window.layout();
+        let ids: Vec<_> = layout
+            .regions
+            .iter()
+            .map(|region| region.id.as_str())
+            .collect();
+        assert_eq!(ids, ["toolbar", "sidebar"]);
+        assert_eq!(layout.regions[0].frame.height, Some(Length::Points(52.0)));
+        assert_eq!(layout.regions[1].frame, GlassFrame::default());
+
+        let regions_only = WindowGlassState {
+            label: "main".into(),
+            views: window.views[1..].to_vec(),
+        };
+        assert_eq!(regions_only.effect(), None);
+    }
+
+    #[test]
+    fn patch_nulls_differ_from_missing_fields() {
+        for (input, expected) in [
+            (json!({}), None),
+            (json!({ "tintColor": null }), Some(None)),
+            (
+                json!({ "tintColor": "#fff" }),
+                Some(Some("#fff".to_string())),
+            ),
+        ] {
+            let patch: LiquidGlassPatch = serde_json::from_value(input.clone()).unwrap();
+            assert_eq!(patch.tint_color, expected, "{input}");
+            assert_eq!(
+                serde_json::to_value(&patch).unwrap()["tintColor"],
+                input["tintColor"]
+            );
+            let round_trip = serde_json::from_value(serde_json::to_value(&patch).unwrap());
+            assert_eq!(round_trip.ok(), Some(patch), "{input}");
+        }
+
+        let patch: LiquidGlassPatch =
+            serde_json::from_value(json!({ "frame": null, "metadata": null })).unwrap();
+        assert_eq!(patch.frame, Some(None));
+        assert_eq!(patch.metadata, Some(None));
+        assert_eq!(patch.mask, None);
+    }
+
+    #[test]
+    fn patches_apply_only_present_fields() {
+        let frame: GlassFrame = serde_json::from_value(json!({ "height": 52 })).unwrap();
+        let base = LiquidGlassConfig {
+            corner_radius: 12.0,
+            tint_color: Some("#fff".into()),
+            frame: Some(frame.clone()),
+            interactive: true,
+            ..Default::default()
+        };
+
+        let mut config = base.clone();
+        LiquidGlassPatch::default().apply_to(&mut config, 2.0);
+        assert_eq!(config, base);
+
+        let patch: LiquidGlassPatch = serde_json::from_value(json!({
+            "tintColor": null,
+            "enabled": false,
+            "metadata": { "role": "toolbar" }
+        }))
+        .unwrap();
+        let mut config = base.clone();
+        patch.apply_to(&mut config, 2.0);
+        assert_eq!(config.tint_color, None);
+        assert!(!config.enabled);
+        assert_eq!(config.metadata, Some(json!({ "role": "toolbar" })));
+        assert_eq!(config.corner_radius, 12.0);
+        assert_eq!(config.frame.as_ref(), Some(&frame));
+        assert!(config.interactive);
+
+        let patch: LiquidGlassPatch = serde_json::from_value(json!({
+            "units": "pixels",
+            "cornerRadius": 20,
+            "frame": { "height": 104 }
+        }))
+        .unwrap();
+        let mut config = base.clone();
+        patch.apply_to(&mut config, 2.0);
+        assert_eq!(config.corner_radius, 10.0);
+        assert_eq!(config.frame, Some(frame));
+        assert_eq!(config.units, Units::Points);
+        assert_eq!(config.tint_color, base.tint_color);
+    }
+
+    #[test]
+    fn non_objects_pass_through() {
+        assert_eq!(migrate_config(json!(null)), Ok(json!(null)));
+        assert!(serde_json::from_value::<LiquidGlassConfig>(json!(42)).is_err());
+    }
+}
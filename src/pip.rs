@@ -0,0 +1,238 @@
+//! Picture-in-picture window preset
+//!
+//! Turns a small app-created window into a floating glass mini player that
+//! stays above other windows and snaps to the nearest corner of its monitor
+//! once the user lets go of it after a drag.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, LogicalPosition, LogicalSize, Runtime, WebviewWindow};
+
+use crate::error::{Error, Result};
+use crate::models::{GlassPreset, LiquidGlassConfig, ScreenCorner, WindowLevel, WindowLevelConfig};
+
+/// Event emitted when a picture-in-picture window snaps to a corner
+pub const PIP_SNAPPED_EVENT: &str = "liquid-glass://pip-snapped";
+
+/// How long a window must stop moving before it counts as dropped
+pub(crate) const SNAP_DEBOUNCE: Duration = Duration::from_millis(120);
+
+/// Configuration for a picture-in-picture window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PipConfig {
+    /// Width of the window in logical pixels
+    pub width: f64,
+
+    /// Height of the window in logical pixels
+    pub height: f64,
+
+    /// Corner the window starts in
+    pub corner: ScreenCorner,
+
+    /// Distance from the edges of the work area in logical pixels
+    pub margin: f64,
+
+    /// Corner radius of the window
+    pub corner_radius: f64,
+
+    /// Duration of the snap animation in milliseconds
+    pub snap_animation_ms: u64,
+
+    /// Window level and collection behavior; floats over every Space and
+    /// full-screen app by default
+    pub level: WindowLevelConfig,
+
+    /// Glass configuration for the window
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for PipConfig {
+    fn default() -> Self {
+        Self {
+            width: 320.0,
+            height: 180.0,
+            corner: ScreenCorner::BottomRight,
+            margin: 16.0,
+            corner_radius: 12.0,
+            snap_animation_ms: 180,
+            level: WindowLevelConfig {
+                level: WindowLevel::Floating,
+                can_join_all_spaces: true,
+                full_screen_auxiliary: true,
+                ..Default::default()
+            },
+            glass: GlassPreset::Hud.into(),
+        }
+    }
+}
+
+/// Payload of [`PIP_SNAPPED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PipSnapped {
+    /// Label of the picture-in-picture window
+    pub window: String,
+    /// Corner the window snapped to
+    pub corner: ScreenCorner,
+}
+
+/// Snapping state of a picture-in-picture window
+struct Pip {
+    config: PipConfig,
+    /// Incremented on every move, so a watcher can tell the window settled
+    generation: u64,
+    /// Whether a thread is waiting for the window to settle
+    watching: bool,
+    /// Moves before this are caused by the plugin's own snap animation
+    settle_until: Option<Instant>,
+}
+
+/// Tracks picture-in-picture windows
+#[derive(Default)]
+pub(crate) struct Pips {
+    pips: Mutex<HashMap<String, Pip>>,
+}
+
+impl Pips {
+    /// Start managing `label` as a picture-in-picture window
+    pub fn attach(&self, label: &str, config: PipConfig) -> Result<()> {
+        self.lock()?.insert(
+            label.to_string(),
+            Pip {
+                config,
+                generation: 0,
+                watching: false,
+                settle_until: None,
+            },
+        );
+        Ok(())
+    }
+
+    /// Stop managing `label`; the window itself is left as is
+    pub fn detach(&self, label: &str) -> Result<bool> {
+        Ok(self.lock()?.remove(label).is_some())
+    }
+
+    /// Record a move of `label`
+    ///
+    /// Returns true if the caller should start watching for the window to
+    /// settle: it is a picture-in-picture window, the move was not caused by
+    /// a snap animation, and nobody is watching yet.
+    pub fn moved(&self, label: &str) -> Result<bool> {
+        let mut pips = self.lock()?;
+        let Some(pip) = pips.get_mut(label) else {
+            return Ok(false);
+        };
+        if pip.settle_until.is_some_and(|until| Instant::now() < until) {
+            return Ok(false);
+        }
+        pip.generation += 1;
+        Ok(!std::mem::replace(&mut pip.watching, true))
+    }
+
+    /// Latest move generation of `label`, or `None` once it was detached
+    pub fn generation(&self, label: &str) -> Result<Option<u64>> {
+        Ok(self.lock()?.get(label).map(|pip| pip.generation))
+    }
+
+    /// Record that the window settled and is about to snap
+    pub fn settled(&self, label: &str) -> Result<()> {
+        if let Some(pip) = self.lock()?.get_mut(label) {
+            pip.watching = false;
+        }
+        Ok(())
+    }
+
+    /// Record a snap, ignoring the moves it causes
+    ///
+    /// Returns the window's config, or `None` if it is not managed.
+    pub fn snapping(&self, label: &str) -> Result<Option<PipConfig>> {
+        let mut pips = self.lock()?;
+        let Some(pip) = pips.get_mut(label) else {
+            return Ok(None);
+        };
+        pip.settle_until = Some(
+            Instant::now() + Duration::from_millis(pip.config.snap_animation_ms) + SNAP_DEBOUNCE,
+        );
+        Ok(Some(pip.config.clone()))
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, HashMap<String, Pip>>> {
+        self.pips.lock().map_err(|_| Error::RegistryLockFailed)
+    }
+}
+
+/// The work area of the monitor under the window's center, in logical pixels
+pub(crate) fn work_area<R: Runtime>(
+    window: &WebviewWindow<R>,
+) -> Result<(LogicalPosition<f64>, LogicalSize<f64>)> {
+    let position = window.outer_position()?;
+    let size = window.outer_size()?;
+    let center_x = position.x as f64 + size.width as f64 / 2.0;
+    let center_y = position.y as f64 + size.height as f64 / 2.0;
+
+    let Some(monitor) = window
+        .monitor_from_point(center_x, center_y)?
+        .or(window.current_monitor()?)
+    else {
+        return Err(Error::MonitorNotFound(window.label().to_string()));
+    };
+    let scale = monitor.scale_factor();
+    let area = monitor.work_area();
+    Ok((area.position.to_logical(scale), area.size.to_logical(scale)))
+}
+
+/// The corner of the work area nearest to the window's center
+pub(crate) fn nearest_corner<R: Runtime>(
+    window: &WebviewWindow<R>,
+    origin: LogicalPosition<f64>,
+    area: LogicalSize<f64>,
+) -> Result<ScreenCorner> {
+    let scale = window.scale_factor()?;
+    let position = window.outer_position()?.to_logical::<f64>(scale);
+    let size = window.outer_size()?.to_logical::<f64>(scale);
+    let left = position.x + size.width / 2.0 < origin.x + area.width / 2.0;
+    let top = position.y + size.height / 2.0 < origin.y + area.height / 2.0;
+
+    Ok(match (top, left) {
+        (true, true) => ScreenCorner::TopLeft,
+        (true, false) => ScreenCorner::TopRight,
+        (false, true) => ScreenCorner::BottomLeft,
+        (false, false) => ScreenCorner::BottomRight,
+    })
+}
+
+/// Top-left position of a window of `config`'s size in `corner`
+pub(crate) fn corner_position(
+    corner: ScreenCorner,
+    config: &PipConfig,
+    origin: LogicalPosition<f64>,
+    area: LogicalSize<f64>,
+) -> LogicalPosition<f64> {
+    let x = if corner.is_left() {
+        origin.x + config.margin
+    } else {
+        origin.x + area.width - config.margin - config.width
+    };
+    let y = if corner.is_top() {
+        origin.y + config.margin
+    } else {
+        origin.y + area.height - config.margin - config.height
+    };
+    LogicalPosition::new(x, y)
+}
+
+pub(crate) fn emit_snapped<R: Runtime>(window: &WebviewWindow<R>, corner: ScreenCorner) {
+    let payload = PipSnapped {
+        window: window.label().to_string(),
+        corner,
+    };
+    if let Err(e) = window.emit(PIP_SNAPPED_EVENT, payload) {
+        warn!("Failed to emit pip snap: {}", e);
+    }
+}
@@ -0,0 +1,149 @@
+//! Rect streams: fire-and-forget frame updates for named glass views
+//!
+//! Awaiting an `invoke` per animation frame is too slow for glass that
+//! follows scrolling. A stream is opened for one view and hands the webview
+//! an event name; rects emitted on it are parsed off the IPC path, and only
+//! the latest rect per view is kept. The first rect of a batch schedules a
+//! flush on the main thread, which moves every pending view inside one
+//! CATransaction, so updates coalesce to the display's frame rate. Tauri
+//! channels only carry messages to the webview, hence the event.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use tauri::{EventId, Listener, Runtime};
+
+use crate::error::{Error, Result};
+use crate::models::ViewRect;
+
+/// Prefix of the event names handed out for rect streams
+pub(crate) const RECT_STREAM_EVENT_PREFIX: &str = "liquid-glass://rect-stream/";
+
+/// An open stream
+struct RectStream {
+    label: String,
+    region: String,
+    listener: EventId,
+}
+
+/// Open streams by event name, and the rects waiting for the next flush
+#[derive(Default)]
+pub(crate) struct RectStreams {
+    streams: Mutex<HashMap<String, RectStream>>,
+    pending: Mutex<HashMap<(String, String), ViewRect>>,
+    scheduled: AtomicBool,
+}
+
+impl RectStreams {
+    /// A new, hard to guess event name
+    ///
+    /// Any webview can emit events, so the name is all that ties a stream
+    /// to the page that opened it.
+    pub fn event_name(&self) -> String {
+        let token = RandomState::new().build_hasher().finish();
+        format!("{RECT_STREAM_EVENT_PREFIX}{token:016x}")
+    }
+
+    /// Record a stream listening under `event`
+    pub fn insert(
+        &self,
+        event: String,
+        label: &str,
+        region: &str,
+        listener: EventId,
+    ) -> Result<()> {
+        let stream = RectStream {
+            label: label.to_string(),
+            region: region.to_string(),
+            listener,
+        };
+        self.streams
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .insert(event, stream);
+        Ok(())
+    }
+
+    /// Queue a rect, returning whether a flush has to be scheduled
+    pub fn push(&self, label: &str, region: &str, rect: ViewRect) -> bool {
+        let Ok(mut pending) = self.pending.lock() else {
+            return false;
+        };
+        pending.insert((label.to_string(), region.to_string()), rect);
+        !self.scheduled.swap(true, Ordering::AcqRel)
+    }
+
+    /// Take the queued rects, allowing the next push to schedule a flush
+    pub fn take(&self) -> Vec<(String, String, ViewRect)> {
+        self.scheduled.store(false, Ordering::Release);
+        self.pending
+            .lock()
+            .map(|mut pending| {
+                pending
+                    .drain()
+                    .map(|((label, region), rect)| (label, region, rect))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Close the stream listening under `event` if it belongs to `label`
+    pub fn close<R: Runtime>(
+        &self,
+        listener: &impl Listener<R>,
+        label: &str,
+        event: &str,
+    ) -> Result<bool> {
+        self.close_where(listener, |stream| stream.label == label, Some(event))
+            .map(|closed| closed > 0)
+    }
+
+    /// Close the streams of a view
+    pub fn close_region<R: Runtime>(&self, listener: &impl Listener<R>, label: &str, region: &str) {
+        let _ = self.close_where(
+            listener,
+            |stream| stream.label == label && stream.region == region,
+            None,
+        );
+    }
+
+    /// Close the streams of a window
+    pub fn close_window<R: Runtime>(&self, listener: &impl Listener<R>, label: &str) -> Result<()> {
+        self.close_where(listener, |stream| stream.label == label, None)
+            .map(|_| ())
+    }
+
+    fn close_where<R: Runtime>(
+        &self,
+        listener: &impl Listener<R>,
+        matches: impl Fn(&RectStream) -> bool,
+        event: Option<&str>,
+    ) -> Result<usize> {
+        let closed: Vec<RectStream> = {
+            let mut streams = self.streams.lock().map_err(|_| Error::RegistryLockFailed)?;
+            let events: Vec<String> = streams
+                .iter()
+                .filter(|(name, stream)| {
+                    event.map_or(true, |event| *name == event) && matches(stream)
+                })
+                .map(|(name, _)| name.clone())
+                .collect();
+            events
+                .iter()
+                .filter_map(|name| streams.remove(name))
+                .collect()
+        };
+        for stream in &closed {
+            listener.unlisten(stream.listener);
+        }
+        if let Ok(mut pending) = self.pending.lock() {
+            for stream in &closed {
+                pending.remove(&(stream.label.clone(), stream.region.clone()));
+            }
+        }
+        Ok(closed.len())
+    }
+}
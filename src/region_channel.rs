@@ -0,0 +1,89 @@
+//! Channels bound to named glass views
+//!
+//! Element bindings (the Svelte action and Vue directive in guest-js) attach
+//! a Tauri channel to the view they own. The plugin streams the view's
+//! native frame changes over it, so the binding can follow relayouts it did
+//! not cause, and tells it when the view was removed behind its back, so
+//! the next sync recreates it. Rects still flow the other way through
+//! `sync_glass_rect`, since channels only carry messages to the webview.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use log::warn;
+use serde::Serialize;
+use tauri::ipc::Channel;
+
+use crate::error::{Error, Result};
+use crate::models::GlassViewFrame;
+
+/// Message sent over a region's channel
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RegionMessage {
+    /// The view's native frame changed, or was first measured
+    Frame {
+        /// The new frame
+        frame: GlassViewFrame,
+    },
+    /// The view was removed; no more messages follow
+    Removed,
+}
+
+/// Attached channels keyed by window label and view id
+#[derive(Default)]
+pub(crate) struct RegionChannels {
+    channels: Mutex<HashMap<(String, String), Channel<RegionMessage>>>,
+}
+
+impl RegionChannels {
+    /// Attach a channel to a view, replacing any earlier one
+    pub fn attach(&self, label: &str, id: &str, channel: Channel<RegionMessage>) -> Result<()> {
+        self.channels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .insert((label.to_string(), id.to_string()), channel);
+        Ok(())
+    }
+
+    /// Send a view's new frame, if it has a channel
+    #[cfg(target_os = "macos")]
+    pub fn frame(&self, label: &str, id: &str, frame: &GlassViewFrame) {
+        let channel = self
+            .channels
+            .lock()
+            .ok()
+            .and_then(|channels| channels.get(&(label.to_string(), id.to_string())).cloned());
+        if let Some(channel) = channel {
+            let message = RegionMessage::Frame {
+                frame: frame.clone(),
+            };
+            if let Err(e) = channel.send(message) {
+                warn!("Failed to send frame of glass view '{}': {}", id, e);
+            }
+        }
+    }
+
+    /// Tell a view's channel that it was removed, and detach it
+    pub fn removed(&self, label: &str, id: &str) {
+        let channel = self
+            .channels
+            .lock()
+            .ok()
+            .and_then(|mut channels| channels.remove(&(label.to_string(), id.to_string())));
+        if let Some(channel) = channel {
+            if let Err(e) = channel.send(RegionMessage::Removed) {
+                warn!("Failed to send removal of glass view '{}': {}", id, e);
+            }
+        }
+    }
+
+    /// Drop the channels of a destroyed window
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.channels
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .retain(|(window, _), _| window != label);
+        Ok(())
+    }
+}
@@ -0,0 +1,266 @@
+//! Origin-based access scope for the liquid-glass commands
+//!
+//! Commands that mutate native views are rejected when invoked from a remote
+//! URL unless the capability file explicitly allows that origin:
+//!
+//! ```json
+//! {
+//!   "identifier": "liquid-glass:allow-set-liquid-glass-effect",
+//!   "allow": [{ "origin": "https://*.example.com" }]
+//! }
+//! ```
+//!
+//! The check covers both the invoking webview's URL and the `Origin` of the
+//! IPC request itself, so a remote iframe inside a local page is treated as
+//! remote. Only the app's own protocols (`tauri`, `asset`, `ipc`), the
+//! configured `frontendDist` URL and, in development builds, `devUrl` count
+//! as local; `file:`, `data:`, `blob:` and `about:` pages are remote like
+//! any other.
+//!
+//! The `data:` windows the plugin creates for HTML toasts are rejected
+//! outright, so no scope entry can let toast markup drive the plugin.
+//!
+//! Every command is guarded, including queries of window or glass state,
+//! except the ones describing only the running OS build
+//! (`is_glass_supported`, `get_support_details`, `get_capabilities`,
+//! `get_private_api_report`, `get_macos_version`, `get_hig_preset`). The
+//! init script already shows those facts to every page.
+
+use serde::{Deserialize, Serialize};
+use tauri::ipc::{CommandArg, CommandItem, CommandScope, InvokeError, ScopeObjectMatch};
+use tauri::utils::config::FrontendDist;
+use tauri::{Manager, Runtime, Url, Webview, WebviewWindow};
+
+use crate::error::{Error, Result};
+use crate::toast;
+
+/// Schemes served by the app itself
+///
+/// On Windows and Android they are served as `http(s)://<scheme>.localhost`.
+const LOCAL_SCHEMES: &[&str] = &["tauri", "asset", "ipc"];
+
+/// Scope entry describing a remote origin
+///
+/// `origin` is either an exact origin (`https://example.com`), a subdomain
+/// wildcard (`https://*.example.com`) or `*` to match any origin.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OriginScope {
+    pub origin: String,
+}
+
+impl ScopeObjectMatch for OriginScope {
+    type Input = Url;
+
+    fn matches(&self, url: &Url) -> bool {
+        if self.origin == "*" {
+            return true;
+        }
+
+        let origin = url.origin().ascii_serialization();
+        let pattern = self.origin.trim_end_matches('/');
+
+        match pattern.split_once("://*.") {
+            Some((scheme, domain)) => {
+                url.scheme() == scheme
+                    && url
+                        .host_str()
+                        .and_then(|host| host.strip_suffix(domain))
+                        .is_some_and(|prefix| prefix.ends_with('.'))
+            }
+            None => origin == pattern,
+        }
+    }
+}
+
+/// The webview that invoked a command, with the origin and scope to check
+///
+/// A command argument: guarded commands take `caller: Caller<R>` and call
+/// [`ensure_origin_allowed`] before doing anything.
+pub(crate) struct Caller<R: Runtime> {
+    webview: Webview<R>,
+    /// `Origin` header of the IPC request, absent for the postMessage
+    /// fallback
+    origin: Option<Url>,
+    scope: CommandScope<OriginScope>,
+}
+
+impl<'a, R: Runtime> CommandArg<'a, R> for Caller<R> {
+    fn from_command(command: CommandItem<'a, R>) -> std::result::Result<Self, InvokeError> {
+        let webview = command.message.webview();
+        let origin = command
+            .message
+            .headers()
+            .get("Origin")
+            .and_then(|origin| origin.to_str().ok())
+            .and_then(|origin| Url::parse(origin).ok());
+        let scope = CommandScope::from_command(command)?;
+        Ok(Self {
+            webview,
+            origin,
+            scope,
+        })
+    }
+}
+
+/// Ensure the calling webview and request origin may invoke a glass command
+///
+/// Local content (the app's own protocols, `devUrl`, `frontendDist`) is
+/// always allowed. Remote content must match an `allow` entry of the command
+/// scope and no `deny` entry. Plugin-created toast windows are always denied.
+pub(crate) fn ensure_origin_allowed<R: Runtime>(caller: &Caller<R>) -> Result<()> {
+    let label = caller.webview.label();
+    if toast::is_toast_label(label) {
+        return Err(Error::ToastCallerDenied(label.to_string()));
+    }
+    let url = caller.webview.url()?;
+    ensure_url_allowed(caller, &url)?;
+    match &caller.origin {
+        Some(origin) => ensure_url_allowed(caller, origin),
+        None => Ok(()),
+    }
+}
+
+/// Ensure the caller may act on `target`, which may be another window
+///
+/// Only local content may address other windows; a remote page allowed by
+/// the scope is limited to its own window, so it can't reach into the app's
+/// other windows.
+pub(crate) fn ensure_target_allowed<R: Runtime>(
+    caller: &Caller<R>,
+    target: &WebviewWindow<R>,
+) -> Result<()> {
+    if target.label() == caller.webview.label() {
+        return Ok(());
+    }
+    let url = caller.webview.url()?;
+    let origin = caller.origin.as_ref().unwrap_or(&url);
+    if is_local_url(&caller.webview, &url) && is_local_url(&caller.webview, origin) {
+        Ok(())
+    } else {
+        Err(Error::RemoteOriginDenied(
+            origin.origin().ascii_serialization(),
+        ))
+    }
+}
+
+fn ensure_url_allowed<R: Runtime>(caller: &Caller<R>, url: &Url) -> Result<()> {
+    if is_local_url(&caller.webview, url) {
+        return Ok(());
+    }
+
+    if !caller.scope.allows().is_empty() && caller.scope.matches(url) {
+        Ok(())
+    } else {
+        Err(Error::RemoteOriginDenied(
+            url.origin().ascii_serialization(),
+        ))
+    }
+}
+
+fn is_local_url<R: Runtime>(webview: &Webview<R>, url: &Url) -> bool {
+    let build = &webview.config().build;
+    let frontend_url = match &build.frontend_dist {
+        Some(FrontendDist::Url(url)) => Some(url),
+        _ => None,
+    };
+    // Any local process can serve the dev server's origin, so it only
+    // counts as local in development builds
+    let dev_url = build.dev_url.as_ref().filter(|_| tauri::is_dev());
+    is_local(url, dev_url.into_iter().chain(frontend_url))
+}
+
+/// Whether `url` is served by the app itself or from one of `local_origins`
+fn is_local<'a>(url: &Url, mut local_origins: impl Iterator<Item = &'a Url>) -> bool {
+    if LOCAL_SCHEMES.contains(&url.scheme()) {
+        return true;
+    }
+
+    let is_http = matches!(url.scheme(), "http" | "https");
+    if is_http
+        && url.host_str().is_some_and(|host| {
+            LOCAL_SCHEMES
+                .iter()
+                .any(|scheme| host.strip_suffix(".localhost") == Some(*scheme))
+        })
+    {
+        return true;
+    }
+
+    local_origins.any(|local| local.origin() == url.origin())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(input: &str) -> Url {
+        Url::parse(input).unwrap_or_else(|e| panic!("{input}: {e}"))
+    }
+
+    #[test]
+    fn origin_scope_matching() {
+        for (origin, input, matches) in [
+            ("*", "https://anything.test/path", true),
+            ("*", "file:///index.html", true),
+            ("https://example.com", "https://example.com/page", true),
+            ("https://example.com/", "https://example.com", true),
+            ("https://example.com", "http://example.com", false),
+            ("https://example.com", "https://example.com:8443", false),
+            (
+                "https://example.com:8443",
+                "https://example.com:8443/",
+                true,
+            ),
+            ("https://example.com", "https://www.example.com", false),
+            ("https://*.example.com", "https://app.example.com", true),
+            ("https://*.example.com", "https://a.b.example.com", true),
+            ("https://*.example.com", "https://example.com", false),
+            ("https://*.example.com", "https://badexample.com", false),
+            ("https://*.example.com", "http://app.example.com", false),
+            (
+                "https://*.example.com",
+                "https://app.example.com.evil.test",
+                false,
+            ),
+        ] {
+            let scope = OriginScope {
+                origin: origin.to_string(),
+            };
+            assert_eq!(
+                scope.matches(&url(input)),
+                matches,
+                "{origin:?} against {input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn local_urls() {
+        let dev = url("http://localhost:5173");
+        for (input, local) in [
+            ("tauri://localhost/index.html", true),
+            ("asset://localhost/image.png", true),
+            ("ipc://localhost/cmd", true),
+            ("http://tauri.localhost/", true),
+            ("https://asset.localhost/image.png", true),
+            ("http://localhost:5173/settings", true),
+            ("http://localhost:5174/", false),
+            ("https://localhost:5173/", false),
+            ("http://evil.localhost/", false),
+            ("file:///index.html", false),
+            ("data:text/html,<p>hi</p>", false),
+            ("about:blank", false),
+            ("https://example.com/", false),
+        ] {
+            assert_eq!(
+                is_local(&url(input), [&dev].into_iter()),
+                local,
+                "{input:?}"
+            );
+        }
+        assert!(!is_local(
+            &url("http://localhost:5173/"),
+            std::iter::empty()
+        ));
+    }
+}
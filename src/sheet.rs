@@ -0,0 +1,108 @@
+//! Glass sheets
+//!
+//! Presents an app-created window as a document-modal sheet attached to a
+//! parent window (`beginSheet`), with the same glass treatment as the rest of
+//! the app. AppKit animates the sheet in and out of the parent's title bar
+//! and keeps it attached while the parent moves.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use log::warn;
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Runtime, WebviewWindow};
+
+use crate::error::{Error, Result};
+use crate::models::LiquidGlassConfig;
+
+/// Event emitted when a sheet is presented or dismissed
+pub const SHEET_VISIBILITY_EVENT: &str = "liquid-glass://sheet-visibility";
+
+/// Configuration for a sheet window
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SheetConfig {
+    /// Width of the sheet in logical pixels. Defaults to the window's current width.
+    pub width: Option<f64>,
+
+    /// Height of the sheet in logical pixels. Defaults to the window's current height.
+    pub height: Option<f64>,
+
+    /// Corner radius of the sheet window
+    pub corner_radius: f64,
+
+    /// Glass configuration for the sheet
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for SheetConfig {
+    fn default() -> Self {
+        Self {
+            width: None,
+            height: None,
+            corner_radius: 16.0,
+            glass: LiquidGlassConfig::default(),
+        }
+    }
+}
+
+/// Payload of [`SHEET_VISIBILITY_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SheetVisibility {
+    /// Label of the sheet window
+    pub window: String,
+    /// Label of the window the sheet is attached to
+    pub parent: String,
+    /// Whether the sheet is now presented
+    pub visible: bool,
+}
+
+/// Tracks presented sheets by sheet label, with their parent's label
+#[derive(Default)]
+pub(crate) struct Sheets {
+    parents: Mutex<HashMap<String, String>>,
+}
+
+impl Sheets {
+    /// Record `sheet` as presented on `parent`
+    ///
+    /// Returns false if the sheet is already presented.
+    pub fn present(&self, sheet: &str, parent: &str) -> Result<bool> {
+        let mut parents = self.parents.lock().map_err(|_| Error::RegistryLockFailed)?;
+        if parents.contains_key(sheet) {
+            return Ok(false);
+        }
+        parents.insert(sheet.to_string(), parent.to_string());
+        Ok(true)
+    }
+
+    /// Stop tracking `sheet`, returning its parent's label if it was presented
+    pub fn dismiss(&self, sheet: &str) -> Result<Option<String>> {
+        Ok(self
+            .parents
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .remove(sheet))
+    }
+
+    /// Drop sheets that were, or were attached to, a destroyed window
+    pub fn forget(&self, label: &str) -> Result<()> {
+        self.parents
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .retain(|sheet, parent| sheet != label && parent != label);
+        Ok(())
+    }
+}
+
+pub(crate) fn emit_visibility<R: Runtime>(window: &WebviewWindow<R>, parent: &str, visible: bool) {
+    let payload = SheetVisibility {
+        window: window.label().to_string(),
+        parent: parent.to_string(),
+        visible,
+    };
+    if let Err(e) = window.emit(SHEET_VISIBILITY_EVENT, payload) {
+        warn!("Failed to emit sheet visibility: {}", e);
+    }
+}
@@ -0,0 +1,18 @@
+//! Suspension events
+//!
+//! `suspend_all()` swaps every glass view for a flat fill, e.g. during screen
+//! recording or when the user turns fancy effects off; the event lets web
+//! content switch to matching flat styles.
+
+use serde::{Deserialize, Serialize};
+
+/// Event emitted when glass effects are suspended or resumed
+pub const EFFECTS_SUSPENDED_EVENT: &str = "liquid-glass://effects-suspended";
+
+/// Payload of [`EFFECTS_SUSPENDED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectsSuspended {
+    /// Whether glass effects are now suspended
+    pub suspended: bool,
+}
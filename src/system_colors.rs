@@ -0,0 +1,19 @@
+//! System color change events
+//!
+//! Observed once for the whole app from plugin setup, so frontends can follow
+//! the user's accent and highlight colors without polling.
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::SystemColors;
+
+/// Event emitted when the user changes their accent or highlight color
+pub const SYSTEM_COLORS_CHANGED_EVENT: &str = "liquid-glass://system-colors-changed";
+
+/// Payload of [`SYSTEM_COLORS_CHANGED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemColorsChanged {
+    /// The newly resolved colors
+    pub colors: SystemColors,
+}
@@ -0,0 +1,450 @@
+//! Main-thread flood protection for frontend-driven updates
+//!
+//! Every glass change is a round trip to the main thread. A frontend loop
+//! that sends updates faster than frames are drawn would queue work faster
+//! than AppKit can run it and freeze the UI. Commands that set state
+//! (effects, named views, the scroll-edge strip) therefore run through a
+//! per-frame budget: updates over budget are parked and applied at the next
+//! frame, a newer update to the same target replaces a parked one, and once
+//! too many targets are parked further updates are rejected. Parked updates
+//! are applied in the order they were last submitted, and every submission
+//! resolves with the outcome of the update that finally ran.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::oneshot;
+
+use crate::error::{Error, Result};
+
+/// Length of a budget frame
+const FRAME: Duration = Duration::from_millis(16);
+
+/// Default operations dispatched to the main thread per frame
+pub const DEFAULT_OPS_PER_FRAME: usize = 32;
+
+/// Parked targets after which updates are rejected
+const MAX_PARKED: usize = 256;
+
+/// What a throttled update changes; updates to the same target merge
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum Target {
+    /// The full-window glass
+    Effect,
+    /// A patch to the full-window glass, numbered so parked patches never
+    /// replace each other
+    EffectPatch(u64),
+    /// A named view
+    View(String),
+    /// Only the frame of a named view
+    Frame(String),
+    /// The scroll-edge strip config
+    ScrollEdge,
+    /// The scroll-edge strip offset
+    ScrollOffset,
+}
+
+impl Target {
+    /// Target for a new full-window config patch
+    pub fn effect_patch() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(0);
+        Self::EffectPatch(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+
+    /// Whether an update to `self` must not overtake a parked update to
+    /// `other`, e.g. a frame update over a config carrying an older frame
+    fn ordered_with(&self, other: &Target) -> bool {
+        match (self, other) {
+            (Self::View(a) | Self::Frame(a), Self::View(b) | Self::Frame(b)) => a == b,
+            (Self::Effect | Self::EffectPatch(_), Self::Effect | Self::EffectPatch(_)) => true,
+            _ => self == other,
+        }
+    }
+}
+
+/// Counters of the main-thread guard since startup
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MainThreadStats {
+    /// Updates dispatched to the main thread, counting parked ones once applied
+    pub dispatched: u64,
+    /// Updates over budget, parked until the next frame
+    pub deferred: u64,
+    /// Parked updates replaced by a newer update to the same target
+    pub merged: u64,
+    /// Updates rejected because too many targets were parked
+    pub dropped: u64,
+}
+
+type Operation = Box<dyn FnOnce() -> Result<()> + Send>;
+
+/// A parked update and everyone waiting for it
+struct Parked {
+    /// Sequence number of the last submission
+    seq: u64,
+    op: Operation,
+    /// Replies of this and every merged submission, oldest first
+    replies: Vec<oneshot::Sender<Result<()>>>,
+}
+
+/// Outcome of [`MainThreadGuard::submit`]
+#[must_use]
+pub(crate) enum Completion {
+    /// The update ran inline
+    Done(Result<()>),
+    /// The update was parked; resolves once it or a newer update merged
+    /// over it has run
+    Parked(oneshot::Receiver<Result<()>>),
+}
+
+impl Completion {
+    /// Wait for the update to be applied
+    ///
+    /// An update discarded by [`MainThreadGuard::cancel`] resolves with
+    /// `Ok`, since the operation that cancelled it replaces it.
+    pub async fn wait(self) -> Result<()> {
+        match self {
+            Self::Done(result) => result,
+            Self::Parked(reply) => reply.await.unwrap_or(Ok(())),
+        }
+    }
+}
+
+struct State {
+    frame_start: Instant,
+    in_frame: usize,
+    parked: HashMap<(String, Target), Parked>,
+    next_seq: u64,
+    flushing: bool,
+}
+
+struct Inner {
+    ops_per_frame: usize,
+    frame: Duration,
+    state: Mutex<State>,
+    dispatched: AtomicU64,
+    deferred: AtomicU64,
+    merged: AtomicU64,
+    dropped: AtomicU64,
+}
+
+impl Inner {
+    fn state(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl State {
+    /// Start a new frame if the current one is over
+    fn roll(&mut self, frame: Duration) {
+        if self.frame_start.elapsed() >= frame {
+            self.frame_start = Instant::now();
+            self.in_frame = 0;
+        }
+    }
+
+    /// Whether an update to `key` has to wait for a parked update
+    fn must_wait(&self, (window, target): &(String, Target)) -> bool {
+        self.parked
+            .keys()
+            .any(|(label, parked)| label == window && target.ordered_with(parked))
+    }
+}
+
+/// Per-frame budget of main-thread operations
+pub(crate) struct MainThreadGuard {
+    inner: Arc<Inner>,
+}
+
+impl MainThreadGuard {
+    pub fn new(ops_per_frame: usize) -> Self {
+        Self::with_frame(ops_per_frame, FRAME)
+    }
+
+    fn with_frame(ops_per_frame: usize, frame: Duration) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                ops_per_frame: ops_per_frame.max(1),
+                frame,
+                state: Mutex::new(State {
+                    frame_start: Instant::now(),
+                    in_frame: 0,
+                    parked: HashMap::new(),
+                    next_seq: 0,
+                    flushing: false,
+                }),
+                dispatched: AtomicU64::new(0),
+                deferred: AtomicU64::new(0),
+                merged: AtomicU64::new(0),
+                dropped: AtomicU64::new(0),
+            }),
+        }
+    }
+
+    /// Run `op` now if the frame has budget left, else park it
+    ///
+    /// A parked update completes when it is applied at a later frame; a
+    /// merged one completes with the result of the update that replaced it.
+    /// An update is parked, not run, while an older update to the same
+    /// target (or to the config of the view whose frame it sets) is parked,
+    /// so updates never apply out of order.
+    pub fn submit(
+        &self,
+        window: &str,
+        target: Target,
+        op: impl FnOnce() -> Result<()> + Send + 'static,
+    ) -> Completion {
+        let key = (window.to_string(), target);
+        let mut state = self.inner.state();
+        state.roll(self.inner.frame);
+
+        if state.in_frame < self.inner.ops_per_frame && !state.must_wait(&key) {
+            state.in_frame += 1;
+            drop(state);
+            self.inner.dispatched.fetch_add(1, Ordering::Relaxed);
+            return Completion::Done(op());
+        }
+
+        if !state.parked.contains_key(&key) && state.parked.len() >= MAX_PARKED {
+            self.inner.dropped.fetch_add(1, Ordering::Relaxed);
+            return Completion::Done(Err(Error::Throttled));
+        }
+
+        self.inner.deferred.fetch_add(1, Ordering::Relaxed);
+        let seq = state.next_seq;
+        state.next_seq += 1;
+        let (reply, completion) = oneshot::channel();
+        let mut replies = match state.parked.remove(&key) {
+            Some(merged) => {
+                self.inner.merged.fetch_add(1, Ordering::Relaxed);
+                merged.replies
+            }
+            None => Vec::new(),
+        };
+        replies.push(reply);
+        state.parked.insert(
+            key,
+            Parked {
+                seq,
+                op: Box::new(op),
+                replies,
+            },
+        );
+        if !state.flushing {
+            state.flushing = true;
+            let inner = self.inner.clone();
+            thread::spawn(move || flush(&inner));
+        }
+        Completion::Parked(completion)
+    }
+
+    /// Discard parked updates of a window matching `target`
+    ///
+    /// Called before operations that would be undone by a stale update
+    /// applied later, such as removing the view it targets.
+    pub fn cancel(&self, window: &str, target: impl Fn(&Target) -> bool) {
+        self.inner
+            .state()
+            .parked
+            .retain(|(label, parked), _| label != window || !target(parked));
+    }
+
+    pub fn stats(&self) -> MainThreadStats {
+        MainThreadStats {
+            dispatched: self.inner.dispatched.load(Ordering::Relaxed),
+            deferred: self.inner.deferred.load(Ordering::Relaxed),
+            merged: self.inner.merged.load(Ordering::Relaxed),
+            dropped: self.inner.dropped.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Apply parked updates frame by frame until none are left
+fn flush(inner: &Inner) {
+    loop {
+        let ops: Vec<_> = {
+            let mut state = inner.state();
+            let next_frame = inner.frame.saturating_sub(state.frame_start.elapsed());
+            if !next_frame.is_zero() {
+                drop(state);
+                thread::sleep(next_frame);
+                continue;
+            }
+
+            state.roll(inner.frame);
+            if state.parked.is_empty() {
+                state.flushing = false;
+                return;
+            }
+            let mut keys: Vec<_> = state
+                .parked
+                .iter()
+                .map(|(key, parked)| (parked.seq, key.clone()))
+                .collect();
+            keys.sort_unstable_by_key(|(seq, _)| *seq);
+            keys.truncate(inner.ops_per_frame);
+            state.in_frame += keys.len();
+            keys.into_iter()
+                .filter_map(|(_, key)| state.parked.remove(&key))
+                .collect()
+        };
+
+        for Parked { op, replies, .. } in ops {
+            inner.dispatched.fetch_add(1, Ordering::Relaxed);
+            reply(replies, op());
+        }
+    }
+}
+
+/// Send the result of a parked update to its submitter and to the older
+/// submissions merged into it
+fn reply(replies: Vec<oneshot::Sender<Result<()>>>, result: Result<()>) {
+    let mut replies = replies.into_iter().rev();
+    let Some(latest) = replies.next() else {
+        return;
+    };
+    let merged = result.as_ref().map_err(ToString::to_string).copied();
+    for reply in replies {
+        let _ = reply.send(merged.clone().map_err(Error::MergedUpdateFailed));
+    }
+    let _ = latest.send(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc;
+
+    use super::*;
+
+    /// Long enough that a test never crosses a frame by accident
+    const TEST_FRAME: Duration = Duration::from_millis(200);
+
+    fn guard(ops_per_frame: usize) -> MainThreadGuard {
+        MainThreadGuard::with_frame(ops_per_frame, TEST_FRAME)
+    }
+
+    fn wait(completion: Completion) -> Result<()> {
+        tauri::async_runtime::block_on(completion.wait())
+    }
+
+    fn is_done(completion: &Completion) -> bool {
+        matches!(completion, Completion::Done(_))
+    }
+
+    #[test]
+    fn runs_inline_within_budget() {
+        let guard = guard(2);
+        let first = guard.submit("main", Target::Effect, || Ok(()));
+        let second = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        let third = guard.submit("main", Target::ScrollOffset, || Ok(()));
+
+        assert!(is_done(&first) && is_done(&second));
+        assert!(!is_done(&third));
+        assert!(wait(third).is_ok());
+
+        let stats = guard.stats();
+        assert_eq!((stats.dispatched, stats.deferred), (3, 1));
+    }
+
+    #[test]
+    fn parks_behind_a_parked_update_to_the_same_target() {
+        let guard = guard(1);
+        let (tx, rx) = mpsc::channel();
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        for n in 0..2 {
+            let tx = tx.clone();
+            let _ = guard.submit("main", Target::View(format!("v{n}")), move || {
+                tx.send(n).unwrap();
+                Ok(())
+            });
+        }
+
+        // The next frame has budget again, but must not overtake view `v0`
+        thread::sleep(TEST_FRAME);
+        let tx = tx.clone();
+        let frame = guard.submit("main", Target::Frame("v0".into()), move || {
+            tx.send(2).unwrap();
+            Ok(())
+        });
+        assert!(!is_done(&frame));
+        assert!(wait(frame).is_ok());
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn merged_updates_resolve_with_the_newest_result() {
+        let guard = guard(1);
+        let (tx, rx) = mpsc::channel();
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        let older = {
+            let tx = tx.clone();
+            guard.submit("main", Target::Effect, move || {
+                tx.send("older").unwrap();
+                Ok(())
+            })
+        };
+        let newer = guard.submit("main", Target::Effect, move || {
+            tx.send("newer").unwrap();
+            Err(Error::ViewCreationFailed)
+        });
+
+        assert!(matches!(wait(newer), Err(Error::ViewCreationFailed)));
+        assert!(matches!(wait(older), Err(Error::MergedUpdateFailed(_))));
+        assert_eq!(rx.try_iter().collect::<Vec<_>>(), ["newer"]);
+        assert_eq!(guard.stats().merged, 1);
+    }
+
+    #[test]
+    fn effect_patches_never_merge() {
+        let guard = guard(1);
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        let first = guard.submit("main", Target::effect_patch(), || Ok(()));
+        let second = guard.submit("main", Target::effect_patch(), || Ok(()));
+
+        assert!(wait(first).is_ok() && wait(second).is_ok());
+        assert_eq!(guard.stats().merged, 0);
+    }
+
+    #[test]
+    fn parked_failures_reach_the_caller() {
+        let guard = guard(1);
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        let parked = guard.submit("main", Target::Effect, || {
+            Err(Error::WindowNotFound("main".into()))
+        });
+
+        assert!(matches!(wait(parked), Err(Error::WindowNotFound(_))));
+    }
+
+    #[test]
+    fn rejects_updates_once_too_many_targets_are_parked() {
+        let guard = guard(1);
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        for n in 0..MAX_PARKED {
+            let _ = guard.submit("main", Target::View(n.to_string()), || Ok(()));
+        }
+
+        let rejected = guard.submit("main", Target::Effect, || Ok(()));
+        assert!(matches!(wait(rejected), Err(Error::Throttled)));
+        // A target that is already parked still merges
+        let merged = guard.submit("main", Target::View("0".into()), || Ok(()));
+        assert!(!is_done(&merged));
+        assert_eq!(guard.stats().dropped, 1);
+    }
+
+    #[test]
+    fn cancelled_updates_resolve_without_running() {
+        let guard = guard(1);
+        let _ = guard.submit("main", Target::ScrollEdge, || Ok(()));
+        let parked = guard.submit("main", Target::View("a".into()), || {
+            panic!("cancelled update ran")
+        });
+        guard.cancel("main", |target| matches!(target, Target::View(_)));
+
+        assert!(wait(parked).is_ok());
+    }
+}
@@ -0,0 +1,255 @@
+//! Glass toast windows
+//!
+//! Transient glass mini-windows stacked in a corner of the primary monitor,
+//! for apps that can't use system notifications or want richer content. A
+//! toast either renders a snippet of HTML in a window the plugin creates, or
+//! shows an app-created window. Toasts slide in, push older toasts at the same
+//! corner away from it, and fade out when they expire or are dismissed.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tauri::{LogicalPosition, LogicalSize, Url};
+
+use crate::error::{Error, Result};
+use crate::models::{GlassPreset, LiquidGlassConfig, ScreenCorner};
+
+/// Event emitted when a toast is dismissed
+pub const TOAST_DISMISSED_EVENT: &str = "liquid-glass://toast-dismissed";
+
+/// Prefix of the labels of windows the plugin creates for HTML toasts
+const TOAST_LABEL_PREFIX: &str = "liquid-glass-toast-";
+
+/// Horizontal distance a toast slides in from
+#[cfg(target_os = "macos")]
+pub(crate) const ENTER_OFFSET: f64 = 24.0;
+
+/// What a toast shows
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ToastContent {
+    /// Body HTML, rendered in a window created (and closed) by the plugin.
+    /// The page has no IPC access; use an app window for interactive toasts.
+    Html(String),
+    /// Label of an app-created window, hidden again when the toast ends
+    Window(String),
+}
+
+impl Default for ToastContent {
+    fn default() -> Self {
+        Self::Html(String::new())
+    }
+}
+
+/// Options for a toast
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", default)]
+pub struct ToastOptions {
+    /// HTML or window to show
+    pub content: ToastContent,
+
+    /// Dismiss the toast after this many milliseconds; 0 keeps it until
+    /// it is dismissed
+    pub duration_ms: u64,
+
+    /// Corner of the primary monitor's work area to stack the toast in
+    pub position: ScreenCorner,
+
+    /// Width of the toast in logical pixels
+    pub width: f64,
+
+    /// Height of the toast in logical pixels
+    pub height: f64,
+
+    /// Distance from the edges of the work area in logical pixels
+    pub margin: f64,
+
+    /// Gap between stacked toasts in logical pixels
+    pub spacing: f64,
+
+    /// Corner radius of the toast window
+    pub corner_radius: f64,
+
+    /// Duration of the enter, exit and restacking animations in milliseconds
+    pub animation_ms: u64,
+
+    /// Glass configuration for the toast
+    pub glass: LiquidGlassConfig,
+}
+
+impl Default for ToastOptions {
+    fn default() -> Self {
+        Self {
+            content: ToastContent::default(),
+            duration_ms: 4000,
+            position: ScreenCorner::default(),
+            width: 340.0,
+            height: 76.0,
+            margin: 16.0,
+            spacing: 8.0,
+            corner_radius: 16.0,
+            animation_ms: 200,
+            glass: GlassPreset::Popover.into(),
+        }
+    }
+}
+
+/// Why a toast was dismissed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ToastDismissReason {
+    /// The toast's duration elapsed
+    Timeout,
+    /// The app dismissed the toast
+    Dismissed,
+}
+
+/// Payload of [`TOAST_DISMISSED_EVENT`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToastDismissed {
+    /// Label of the toast window, as returned by `show_glass_toast`
+    pub window: String,
+    /// Why the toast was dismissed
+    pub reason: ToastDismissReason,
+}
+
+/// A toast that is currently shown
+#[derive(Debug, Clone)]
+pub(crate) struct Toast {
+    pub label: String,
+    pub position: ScreenCorner,
+    pub size: LogicalSize<f64>,
+    pub margin: f64,
+    pub spacing: f64,
+    pub animation_ms: u64,
+    /// Whether the plugin created the window and closes it when done
+    pub owned: bool,
+    /// Distinguishes re-shows of the same window so stale timers do nothing
+    pub generation: u64,
+}
+
+/// Tracks shown toasts, newest first
+#[derive(Default)]
+pub(crate) struct Toasts {
+    toasts: Mutex<Vec<Toast>>,
+    next_id: AtomicU64,
+}
+
+/// Whether `label` belongs to a window the plugin created for an HTML toast
+pub(crate) fn is_toast_label(label: &str) -> bool {
+    label.starts_with(TOAST_LABEL_PREFIX)
+}
+
+impl Toasts {
+    /// Label for a new plugin-created toast window
+    pub fn next_label(&self) -> String {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        format!("{}{}", TOAST_LABEL_PREFIX, id)
+    }
+
+    /// Generation for a newly shown toast
+    pub fn next_generation(&self) -> u64 {
+        self.next_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Add a toast on top of its stack, replacing an earlier show of the
+    /// same window
+    pub fn push(&self, toast: Toast) -> Result<()> {
+        let mut toasts = self.toasts.lock().map_err(|_| Error::RegistryLockFailed)?;
+        toasts.retain(|existing| existing.label != toast.label);
+        toasts.insert(0, toast);
+        Ok(())
+    }
+
+    /// Remove a toast, only if it is still the show with `generation` when
+    /// one is given
+    pub fn remove(&self, label: &str, generation: Option<u64>) -> Result<Option<Toast>> {
+        let mut toasts = self.toasts.lock().map_err(|_| Error::RegistryLockFailed)?;
+        let Some(index) = toasts.iter().position(|toast| {
+            toast.label == label && generation.map_or(true, |g| toast.generation == g)
+        }) else {
+            return Ok(None);
+        };
+        Ok(Some(toasts.remove(index)))
+    }
+
+    /// Toasts stacked in `position`, newest first
+    pub fn stack(&self, position: ScreenCorner) -> Result<Vec<Toast>> {
+        Ok(self
+            .toasts
+            .lock()
+            .map_err(|_| Error::RegistryLockFailed)?
+            .iter()
+            .filter(|toast| toast.position == position)
+            .cloned()
+            .collect())
+    }
+}
+
+/// Top-left corner of each toast in a stack, given the work area
+///
+/// The newest toast sits in the corner and older ones are pushed towards the
+/// middle of the screen.
+pub(crate) fn slots(
+    stack: &[Toast],
+    origin: LogicalPosition<f64>,
+    size: LogicalSize<f64>,
+) -> Vec<LogicalPosition<f64>> {
+    let mut offset = 0.0;
+    stack
+        .iter()
+        .map(|toast| {
+            let x = if toast.position.is_left() {
+                origin.x + toast.margin
+            } else {
+                origin.x + size.width - toast.margin - toast.size.width
+            };
+            let y = if toast.position.is_top() {
+                origin.y + toast.margin + offset
+            } else {
+                origin.y + size.height - toast.margin - offset - toast.size.height
+            };
+            offset += toast.size.height + toast.spacing;
+            LogicalPosition::new(x, y)
+        })
+        .collect()
+}
+
+/// Where a toast starts sliding in from, next to its slot towards the
+/// nearest screen edge
+#[cfg(target_os = "macos")]
+pub(crate) fn enter_from(toast: &Toast, slot: LogicalPosition<f64>) -> LogicalPosition<f64> {
+    let dx = if toast.position.is_left() {
+        -ENTER_OFFSET
+    } else {
+        ENTER_OFFSET
+    };
+    LogicalPosition::new(slot.x + dx, slot.y)
+}
+
+/// `data:` URL of a transparent page rendering `body`
+///
+/// The page is not app content: the origin guard rejects every glass command
+/// from toast windows, whatever the scope allows.
+pub(crate) fn html_url(body: &str) -> Result<Url> {
+    let page = format!(
+        "<!doctype html><html><head><meta charset=\"utf-8\"><style>\
+         :root{{color-scheme:light dark}}\
+         html,body{{margin:0;height:100%;background:transparent;overflow:hidden;\
+         font:13px -apple-system,system-ui,sans-serif;color:CanvasText;\
+         -webkit-user-select:none;user-select:none;cursor:default}}\
+         </style></head><body>{}</body></html>",
+        body
+    );
+    let mut url = String::from("data:text/html;charset=utf-8,");
+    for byte in page.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-_.~".contains(&byte) {
+            url.push(byte as char);
+        } else {
+            url.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    Url::parse(&url).map_err(|e| tauri::Error::InvalidUrl(e).into())
+}
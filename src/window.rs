@@ -0,0 +1,104 @@
+//! Window-bound Liquid Glass API
+
+use tauri::{Runtime, WebviewWindow};
+
+use crate::error::Result;
+use crate::models::{GlassMaterialVariant, LiquidGlassConfig};
+use crate::LiquidGlassExt;
+
+/// Extension trait for accessing the Liquid Glass API bound to a window
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use tauri_plugin_liquid_glass::{GlassMaterialVariant, GlassPreset, LiquidGlassWindowExt};
+///
+/// fn style(window: tauri::WebviewWindow) -> tauri_plugin_liquid_glass::Result<()> {
+///     let glass = window.liquid_glass_window();
+///     glass.enable(GlassPreset::Sidebar)?;
+///     glass.set_tint("rgb(0 0 0 / 0.2)")?;
+///     glass.set_variant(GlassMaterialVariant::Clear)?;
+///     glass.disable()
+/// }
+/// ```
+pub trait LiquidGlassWindowExt<R: Runtime> {
+    /// Returns the Liquid Glass API bound to this window
+    fn liquid_glass_window(&self) -> LiquidGlassWindow<R>;
+}
+
+impl<R: Runtime> LiquidGlassWindowExt<R> for WebviewWindow<R> {
+    fn liquid_glass_window(&self) -> LiquidGlassWindow<R> {
+        LiquidGlassWindow {
+            window: self.clone(),
+        }
+    }
+}
+
+/// Liquid Glass API bound to a single window
+///
+/// Setters modify the window's current configuration (or the default one if
+/// no effect is applied yet) and apply the result, so calling one on a window
+/// without glass enables the effect.
+pub struct LiquidGlassWindow<R: Runtime> {
+    window: WebviewWindow<R>,
+}
+
+impl<R: Runtime> LiquidGlassWindow<R> {
+    /// The window this API is bound to
+    pub fn window(&self) -> &WebviewWindow<R> {
+        &self.window
+    }
+
+    /// Get the configuration currently applied, if the window has a glass effect
+    pub fn config(&self) -> Result<Option<LiquidGlassConfig>> {
+        self.window.liquid_glass().current_config(&self.window)
+    }
+
+    /// Enable the effect with a preset or a full configuration
+    pub fn enable(&self, config: impl Into<LiquidGlassConfig>) -> Result<()> {
+        let config = LiquidGlassConfig {
+            enabled: true,
+            ..config.into()
+        };
+        self.window.liquid_glass().set_effect(&self.window, config)
+    }
+
+    /// Set the tint color (any CSS color string)
+    pub fn set_tint(&self, color: impl Into<String>) -> Result<()> {
+        let color = color.into();
+        self.modify(|config| config.tint_color = Some(color))
+    }
+
+    /// Remove the tint color
+    pub fn clear_tint(&self) -> Result<()> {
+        self.modify(|config| config.tint_color = None)
+    }
+
+    /// Set the glass material variant
+    pub fn set_variant(&self, variant: GlassMaterialVariant) -> Result<()> {
+        self.modify(|config| config.variant = variant)
+    }
+
+    /// Set the corner radius in pixels
+    pub fn set_corner_radius(&self, radius: f64) -> Result<()> {
+        self.modify(|config| config.corner_radius = radius)
+    }
+
+    /// Remove the glass effect
+    pub fn disable(&self) -> Result<()> {
+        self.window.liquid_glass().set_effect(
+            &self.window,
+            LiquidGlassConfig {
+                enabled: false,
+                ..Default::default()
+            },
+        )
+    }
+
+    fn modify(&self, f: impl FnOnce(&mut LiquidGlassConfig)) -> Result<()> {
+        let mut config = self.config()?.unwrap_or_default();
+        config.enabled = true;
+        f(&mut config);
+        self.window.liquid_glass().set_effect(&self.window, config)
+    }
+}
@@ -0,0 +1,115 @@
+//! Windows 11 system backdrops
+//!
+//! Windows has no per-view glass, so the plugin's effect maps to the window's
+//! DWM system backdrop (Mica, Acrylic or Mica Alt) set with
+//! `DwmSetWindowAttribute`. The frame is extended into the whole client area
+//! so the backdrop shows through a transparent webview. Backdrops need
+//! Windows 11 22H2 (build 22621); older builds report no support.
+
+use tauri::{Runtime, Theme, WebviewWindow};
+use windows_sys::Wdk::System::SystemServices::RtlGetVersion;
+use windows_sys::Win32::Foundation::HWND;
+use windows_sys::Win32::Graphics::Dwm::{
+    DwmExtendFrameIntoClientArea, DwmSetWindowAttribute, DWMSBT_AUTO, DWMSBT_MAINWINDOW,
+    DWMSBT_TABBEDWINDOW, DWMSBT_TRANSIENTWINDOW, DWMWA_SYSTEMBACKDROP_TYPE,
+    DWMWA_USE_IMMERSIVE_DARK_MODE, DWM_SYSTEMBACKDROP_TYPE,
+};
+use windows_sys::Win32::System::SystemInformation::OSVERSIONINFOW;
+use windows_sys::Win32::UI::Controls::MARGINS;
+
+use crate::error::{Error, Result};
+use crate::models::{LiquidGlassConfig, WindowsBackdrop};
+
+/// First build with `DWMWA_SYSTEMBACKDROP_TYPE` (Windows 11 22H2)
+const MIN_BACKDROP_BUILD: u32 = 22621;
+
+/// Check if the running Windows build supports system backdrops
+pub fn is_backdrop_supported() -> bool {
+    windows_build() >= MIN_BACKDROP_BUILD
+}
+
+/// Apply a config as the window's system backdrop, or clear it when the
+/// config is disabled
+pub fn set_backdrop<R: Runtime>(
+    window: &WebviewWindow<R>,
+    config: &LiquidGlassConfig,
+) -> Result<()> {
+    if !is_backdrop_supported() {
+        return Err(Error::UnsupportedPlatform);
+    }
+    let hwnd: HWND = window
+        .hwnd()
+        .map_err(|_| Error::WindowNotFound(window.label().to_string()))?
+        .0;
+
+    let (backdrop, inset) = if config.enabled {
+        (backdrop_type(config.windows_backdrop), -1)
+    } else {
+        (DWMSBT_AUTO, 0)
+    };
+    // Mica and Mica Alt tint by the window's theme, not the system's
+    let dark = i32::from(window.theme()? == Theme::Dark);
+    let margins = MARGINS {
+        cxLeftWidth: inset,
+        cxRightWidth: inset,
+        cyTopHeight: inset,
+        cyBottomHeight: inset,
+    };
+
+    // SAFETY: `hwnd` is the live window's handle and the attribute values
+    // are i32s, as DWMWA_SYSTEMBACKDROP_TYPE and the dark mode flag expect
+    unsafe {
+        set_attribute(hwnd, DWMWA_USE_IMMERSIVE_DARK_MODE as u32, dark)?;
+        hresult(DwmExtendFrameIntoClientArea(hwnd, &margins))?;
+        set_attribute(hwnd, DWMWA_SYSTEMBACKDROP_TYPE as u32, backdrop)
+    }
+}
+
+/// DWM backdrop type of a [`WindowsBackdrop`]
+fn backdrop_type(backdrop: WindowsBackdrop) -> DWM_SYSTEMBACKDROP_TYPE {
+    match backdrop {
+        WindowsBackdrop::Mica => DWMSBT_MAINWINDOW,
+        WindowsBackdrop::Acrylic => DWMSBT_TRANSIENTWINDOW,
+        WindowsBackdrop::Tabbed => DWMSBT_TABBEDWINDOW,
+    }
+}
+
+/// Set an i32 DWM window attribute
+///
+/// # Safety
+/// `hwnd` must be a valid window handle
+unsafe fn set_attribute(hwnd: HWND, attribute: u32, value: i32) -> Result<()> {
+    hresult(DwmSetWindowAttribute(
+        hwnd,
+        attribute,
+        &value as *const i32 as *const _,
+        std::mem::size_of::<i32>() as u32,
+    ))
+}
+
+/// Turn a failed HRESULT into [`Error::BackdropFailed`]
+fn hresult(result: i32) -> Result<()> {
+    if result < 0 {
+        Err(Error::BackdropFailed(result))
+    } else {
+        Ok(())
+    }
+}
+
+/// Get the Windows build number, read once and cached
+fn windows_build() -> u32 {
+    static BUILD: std::sync::OnceLock<u32> = std::sync::OnceLock::new();
+    *BUILD.get_or_init(|| {
+        // SAFETY: OSVERSIONINFOW is plain data and RtlGetVersion only writes
+        // to it, after reading the size field
+        unsafe {
+            let mut info: OSVERSIONINFOW = std::mem::zeroed();
+            info.dwOSVersionInfoSize = std::mem::size_of::<OSVERSIONINFOW>() as u32;
+            if RtlGetVersion(&mut info) == 0 {
+                info.dwBuildNumber
+            } else {
+                0
+            }
+        }
+    })
+}